@@ -0,0 +1,106 @@
+//! Runtime array-of-bytes scanning over the game executable's image.
+//!
+//! The base addresses in [`crate::codegen::base_addresses`] are computed ahead
+//! of time by `cargo xtask codegen`; this module covers the pointers that are
+//! only resolved once the game is running.
+
+use std::mem;
+
+use windows::Win32::System::LibraryLoader::GetModuleHandleA;
+use windows::Win32::System::Memory::{
+    VirtualQuery, MEMORY_BASIC_INFORMATION, MEM_COMMIT, PAGE_GUARD, PAGE_NOACCESS,
+};
+use windows::Win32::System::ProcessStatus::{GetModuleInformation, MODULEINFO};
+use windows::Win32::System::Threading::GetCurrentProcess;
+
+/// Parses a pattern in the `"48 8B 05 ?? ?? ?? ??"` format. Wildcards match
+/// any byte.
+fn parse_pattern(pattern: &str) -> Option<Vec<Option<u8>>> {
+    pattern
+        .split_whitespace()
+        .map(|b| match b {
+            "?" | "??" => Some(None),
+            b => u8::from_str_radix(b, 16).ok().map(Some),
+        })
+        .collect()
+}
+
+/// Returns the readable memory regions of the main module.
+fn module_regions() -> Vec<&'static [u8]> {
+    let mut regions = Vec::new();
+
+    let Ok(module) = (unsafe { GetModuleHandleA(None) }) else {
+        return regions;
+    };
+
+    let mut module_info = MODULEINFO::default();
+    if unsafe {
+        GetModuleInformation(
+            GetCurrentProcess(),
+            module,
+            &mut module_info,
+            mem::size_of::<MODULEINFO>() as u32,
+        )
+    }
+    .is_err()
+    {
+        return regions;
+    }
+
+    let start = module_info.lpBaseOfDll as usize;
+    let end = start + module_info.SizeOfImage as usize;
+    let mut addr = start;
+
+    while addr < end {
+        let mut mbi = MEMORY_BASIC_INFORMATION::default();
+        let written = unsafe {
+            VirtualQuery(Some(addr as _), &mut mbi, mem::size_of::<MEMORY_BASIC_INFORMATION>())
+        };
+        if written == 0 || mbi.RegionSize == 0 {
+            break;
+        }
+
+        let region_end = (mbi.BaseAddress as usize + mbi.RegionSize).min(end);
+        if mbi.State == MEM_COMMIT
+            && !mbi.Protect.contains(PAGE_NOACCESS)
+            && !mbi.Protect.contains(PAGE_GUARD)
+        {
+            regions
+                .push(unsafe { std::slice::from_raw_parts(addr as *const u8, region_end - addr) });
+        }
+
+        addr = region_end;
+    }
+
+    regions
+}
+
+/// Returns the address of the first match of `pattern` in the game's image.
+pub fn aob_scan(pattern: &str) -> Option<usize> {
+    let pattern = parse_pattern(pattern)?;
+
+    module_regions().into_iter().find_map(|region| {
+        region
+            .windows(pattern.len())
+            .position(|window| {
+                window.iter().zip(pattern.iter()).all(|(b, p)| p.map(|p| p == *b).unwrap_or(true))
+            })
+            .map(|offset| region.as_ptr() as usize + offset)
+    })
+}
+
+/// Finds `pattern` and resolves the RIP-relative displacement found at
+/// `displacement_offset` into the matched instruction, which is
+/// `instruction_len` bytes long. Returns the absolute address the instruction
+/// refers to, e.g. the address of a static singleton pointer.
+pub fn aob_scan_rip_relative(
+    pattern: &str,
+    displacement_offset: usize,
+    instruction_len: usize,
+) -> Option<usize> {
+    let addr = aob_scan(pattern)?;
+    let displacement =
+        unsafe { std::ptr::read_unaligned((addr + displacement_offset) as *const i32) };
+
+    Some((addr as isize + instruction_len as isize + displacement as isize) as usize)
+}
@@ -0,0 +1,131 @@
+// Generated by `cargo xtask codegen` from `xtask/src/codegen/aob_scans.rs`.
+
+use crate::codegen::base_addresses::BaseAddresses;
+use crate::signatures::{Resolve, Signature};
+
+/// Signatures of the base addresses, for scanning them at runtime.
+pub(crate) static SIGNATURES: [Signature; 19] = [
+    Signature {
+        name: "WorldChrMan",
+        pattern: "48 8B 1D ?? ?? ?? 04 48 8B F9 48 85 DB ?? ?? 8B 11 85 D2 ?? ?? 8D",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "WorldChrManDbg",
+        pattern: "48 8B 05 ?? ?? ?? ?? 66 0F 7F 44 24 40 48 85 C0",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "MenuMan",
+        pattern: "48 89 15 ?? ?? ?? ?? 44 8b 82 ?? ?? ?? ?? 44 8b 8a ?? ?? ?? ?? 48 8b c3",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "BaseA",
+        pattern: "48 8B 05 ?? ?? ?? ?? 48 85 C0 ?? ?? 48 8b 40 ?? C3",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "BaseD",
+        pattern: "48 8B 0D ?? ?? ?? ?? 48 85 C9 74 26 44 8B",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "SprjDebugEvent",
+        pattern: "48 8B 05 ?? ?? ?? ?? 41 0F B6 D8 8B EA",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "Debug",
+        pattern: "C6 05 ?? ?? ?? ?? 01 48 8B 8C 24 ?? ?? ?? ?? 48 33 CC E8 ?? ?? ?? ?? 4C 8D 9C 24",
+        resolve: Resolve::RipRelative(2, 7),
+    },
+    Signature {
+        name: "Grend",
+        pattern: "C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? \
+                  ?? 00 4C 8B 05 ?? ?? ?? ?? 4C 89 44 24 58",
+        resolve: Resolve::RipRelative(2, 7),
+    },
+    Signature {
+        name: "BaseHBD",
+        pattern: "48 8B 0D ?? ?? ?? ?? 41 B0 01 E8 ?? ?? ?? ?? 48 8B D3 48 8B CF",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "MapItemMan",
+        pattern: "48 8B 0D ?? ?? ?? ?? 48 8B 89 ?? ?? ?? ?? E8 ?? ?? ?? ?? E9",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "SpawnItemFuncPtr",
+        pattern: "E8 ?? ?? ?? ?? C7 44 24 20 00 01 00 00 4C 8D 4C 24 40 41 B8",
+        resolve: Resolve::RipRelative(1, 5),
+    },
+    Signature {
+        name: "Param",
+        pattern: "48 8B 0D ?? ?? ?? ?? 48 85 C9 74 0B 4C 8B C0 48 8B D7",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+    Signature {
+        name: "FormatString",
+        pattern: "3C 00 54 00 45 00 58 00 54 00 46 00 4F 00 52 00 4D 00 41 00 54 00",
+        resolve: Resolve::Direct,
+    },
+    Signature {
+        name: "NoLogo",
+        pattern: "E8 ?? ?? ?? FF 90 4D 8B C7 49 8B D4 48 8B C8 E8 ?? ?? ?? FF",
+        resolve: Resolve::Direct,
+    },
+    Signature {
+        name: "CurrentTarget",
+        pattern: "48 8B 80 ?? ?? ?? ?? 48 8B 08 48 8B ?? 58",
+        resolve: Resolve::Direct,
+    },
+    Signature {
+        name: "MenuTravel",
+        pattern: "40 55 53 56 57 41 56 48 8D 6C 24 C9 48 81 EC 00 01 00 00 48 C7 45 97 FE FF FF FF",
+        resolve: Resolve::Direct,
+    },
+    Signature {
+        name: "MenuAttune",
+        pattern: "48 8D 45 0F 48 89 45 EF 48 8D 45 0F 48 89 45 F7 48 8D ?? ?? ?? ?? ?? 48 89 45 \
+                  0F 48 8D ?? ?? ?? ?? ?? 48 89 45 0F 48 8D ?? ?? ?? ?? ?? 48 89 45 17",
+        resolve: Resolve::Direct,
+    },
+    Signature {
+        name: "XA",
+        pattern: "48 8B 83 ?? ?? ?? ?? 48 8B 10 48 85 D2 ?? ?? 8B",
+        resolve: Resolve::Immediate(3),
+    },
+    Signature {
+        name: "BaseFPS",
+        pattern: "48 8B 0D ?? ?? ?? ?? 84 C0 74 32 48 85 C9 75 26 4C 8D 0D ?? ?? ?? ?? 4C 8D 05 \
+                  ?? ?? ?? ?? 48 8D 0D ?? ?? ?? ??",
+        resolve: Resolve::RipRelative(3, 7),
+    },
+];
+
+/// Base addresses from the address `scanned` finds for each signature.
+pub(crate) fn scanned_base_addresses(scanned: impl Fn(&str) -> usize) -> BaseAddresses {
+    BaseAddresses {
+        world_chr_man: scanned("WorldChrMan"),
+        world_chr_man_dbg: scanned("WorldChrManDbg"),
+        menu_man: scanned("MenuMan"),
+        base_a: scanned("BaseA"),
+        base_d: scanned("BaseD"),
+        sprj_debug_event: scanned("SprjDebugEvent"),
+        debug: scanned("Debug"),
+        grend: scanned("Grend"),
+        base_hbd: scanned("BaseHBD"),
+        map_item_man: scanned("MapItemMan"),
+        spawn_item_func_ptr: scanned("SpawnItemFuncPtr"),
+        param: scanned("Param"),
+        format_string: scanned("FormatString"),
+        no_logo: scanned("NoLogo"),
+        current_target: scanned("CurrentTarget"),
+        menu_travel: scanned("MenuTravel"),
+        menu_attune: scanned("MenuAttune"),
+        xa: scanned("XA"),
+        base_fps: scanned("BaseFPS"),
+    }
+}
@@ -0,0 +1,5 @@
+// Generated by `cargo xtask codegen` from `xtask/src/codegen/boss_positions.txt`.
+
+/// Boss defeated flags and where to stand to fight the boss: x, y, z, angle.
+pub static BOSS_POSITIONS: [(u32, [f32; 4]); 0] = [
+];
@@ -0,0 +1,25 @@
+// Generated by `cargo xtask codegen` from `xtask/src/codegen/map_names.txt`.
+
+/// Map IDs, packed as in [`crate::pointers::MapId`], and their names.
+pub static MAP_NAMES: [(u32, &str); 20] = [
+    (0x1e000000, "High Wall of Lothric"),
+    (0x1e010000, "Lothric Castle"),
+    (0x1f000000, "Undead Settlement"),
+    (0x20000000, "Archdragon Peak"),
+    (0x21000000, "Road of Sacrifices"),
+    (0x22010000, "Grand Archives"),
+    (0x23000000, "Cathedral of the Deep"),
+    (0x25000000, "Irithyll of the Boreal Valley"),
+    (0x26000000, "Catacombs of Carthus"),
+    (0x27000000, "Irithyll Dungeon"),
+    (0x28000000, "Cemetery of Ash"),
+    (0x29000000, "Kiln of the First Flame"),
+    (0x2d000000, "Painted World of Ariandel"),
+    (0x2e000000, "Grand Roof"),
+    (0x2f000000, "Kiln of Flame"),
+    (0x32000000, "The Dreg Heap"),
+    (0x33000000, "The Ringed City"),
+    (0x33010000, "Filianore's Rest"),
+    (0x35000000, "Dragon Ruins"),
+    (0x36000000, "Round Plaza"),
+];
@@ -1 +1,7 @@
 pub mod base_addresses;
+pub mod base_signatures;
+#[rustfmt::skip]
+pub mod boss_positions;
+pub mod map_names;
+#[rustfmt::skip]
+pub mod param_fields;
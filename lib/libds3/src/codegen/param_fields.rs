@@ -0,0 +1,4460 @@
+// Generated by `cargo xtask codegen` from `lib/libds3/src/params/param_data.rs`.
+
+/// Names and types of the fields of each param, in the order they are visited.
+pub static PARAM_FIELDS: [(&str, &[(&str, &str)]); 98] = [
+    ("ActionButtonParam", &[
+        ("region_type", "u8"),
+        ("dummy_poly1", "i32"),
+        ("dummy_poly2", "i32"),
+        ("radius", "f32"),
+        ("angle", "i32"),
+        ("depth", "f32"),
+        ("width", "f32"),
+        ("height", "f32"),
+        ("base_height_offset", "f32"),
+        ("angle_check_type", "u8"),
+        ("allow_angle", "i32"),
+        ("text_box_type", "u8"),
+        ("text_id", "i32"),
+        ("invalid_flag", "i32"),
+        ("grayout_flag", "i32"),
+        ("priority", "i32"),
+        ("exec_invalid_time", "f32"),
+        ("exec_button_circle", "u8"),
+    ]),
+    ("AiSoundParam", &[
+        ("radius", "f32"),
+        ("life_frame", "f32"),
+        ("b_sp_effect_enable", "u8"),
+        ("ty", "u8"),
+        ("fake_target_type", "u8"),
+        ("interest_category", "u8"),
+        ("use_hit_damage_team", "u8"),
+    ]),
+    ("AtkParam_Npc", &[
+        ("hit0_radius", "f32"),
+        ("hit1_radius", "f32"),
+        ("hit2_radius", "f32"),
+        ("hit3_radius", "f32"),
+        ("knockback_dist", "f32"),
+        ("hit_stop_time", "f32"),
+        ("sp_effect0", "i32"),
+        ("sp_effect1", "i32"),
+        ("sp_effect2", "i32"),
+        ("sp_effect3", "i32"),
+        ("sp_effect4", "i32"),
+        ("hit0_dmy_poly1", "i16"),
+        ("hit1_dmy_poly1", "i16"),
+        ("hit2_dmy_poly1", "i16"),
+        ("hit3_dmy_poly1", "i16"),
+        ("hit0_dmy_poly2", "i16"),
+        ("hit1_dmy_poly2", "i16"),
+        ("hit2_dmy_poly2", "i16"),
+        ("hit3_dmy_poly2", "i16"),
+        ("blowing", "i16"),
+        ("atk_phys_correction", "i16"),
+        ("atk_mag_correction", "i16"),
+        ("atk_fire_correction", "i16"),
+        ("atk_thun_correction", "i16"),
+        ("atk_stam_correction", "i16"),
+        ("guard_atk_rate_correction", "i16"),
+        ("guard_break_correction", "i16"),
+        ("atk_throw_escape_correction", "i16"),
+        ("atk_super_armor_correction", "i16"),
+        ("atk_phys", "i16"),
+        ("atk_mag", "i16"),
+        ("atk_fire", "i16"),
+        ("atk_thun", "i16"),
+        ("atk_stam", "i16"),
+        ("guard_atk_rate", "i16"),
+        ("guard_break_rate", "i16"),
+        ("atk_super_armor", "i16"),
+        ("atk_throw_escape", "i16"),
+        ("atk_obj", "i16"),
+        ("guard_stamina_cut_rate", "i16"),
+        ("guard_rate", "i16"),
+        ("throw_type_id", "i16"),
+        ("hit0_hit_type", "u8"),
+        ("hit1_hit_type", "u8"),
+        ("hit2_hit_type", "u8"),
+        ("hit3_hit_type", "u8"),
+        ("hit0_priority", "u8"),
+        ("hit1_priority", "u8"),
+        ("hit2_priority", "u8"),
+        ("hit3_priority", "u8"),
+        ("damage_level", "u8"),
+        ("map_hit_type", "u8"),
+        ("guard_cut_cancel_rate", "u8"),
+        ("atk_attribute", "i8"),
+        ("sp_attribute", "i8"),
+        ("atk_type", "i8"),
+        ("atk_material", "i8"),
+        ("atk_size", "i8"),
+        ("def_material", "i8"),
+        ("def_sfx_material", "i8"),
+        ("hit_source_type", "u8"),
+        ("throw_flag", "u8"),
+        ("disableGuard", "bool"),
+        ("disableStaminaAttack", "bool"),
+        ("disableHitSpEffect", "bool"),
+        ("IgnoreNotifyMissSwingForAI", "bool"),
+        ("repeatHitSfx", "bool"),
+        ("IsArrowAtk", "bool"),
+        ("IsGhostAtk", "bool"),
+        ("isDisableNoDamage", "bool"),
+        ("atk_pow_for_sfx_se", "u8"),
+        ("atk_dir_for_sfx_se", "u8"),
+        ("opposeTarget", "bool"),
+        ("friendlyTarget", "bool"),
+        ("selfTarget", "bool"),
+        ("isChargeAtk", "bool"),
+        ("isShareHitList", "bool"),
+        ("isCheckObjPenetration", "bool"),
+        ("field0x81", "bool"),
+        ("field0x81_0", "bool"),
+        ("pad1", "u8"),
+        ("regainable_slot_id", "u8"),
+        ("death_cause_id", "i32"),
+        ("decal_id1", "i32"),
+        ("decal_id2", "i32"),
+        ("spawn_ai_sound_id", "i32"),
+        ("hit_ai_sound_id", "i32"),
+        ("rumble_id0", "i32"),
+        ("rumble_id1", "i32"),
+        ("rumble_id2", "i32"),
+        ("rumble_id3", "i32"),
+        ("hit0_vfx_id", "i32"),
+        ("hit0_dummy_poly_id0", "i32"),
+        ("hit0_dummy_poly_id1", "i32"),
+        ("hit1_vfx_id1", "i32"),
+        ("hit1_dummy_poly_id0", "i32"),
+        ("hit1_dummy_poly_id1", "i32"),
+        ("hit2_vfx_id", "i32"),
+        ("hit2_dummy_poly_id0", "i32"),
+        ("hit2_dummy_poly_id1", "i32"),
+        ("hit3_vfx_id", "i32"),
+        ("hit3_dummy_poly_id0", "i32"),
+        ("hit3_dummy_poly_id1", "i32"),
+        ("hit4_vfx_id", "i32"),
+        ("hit4_dummy_poly_id0", "i32"),
+        ("hit4_dummy_poly_id1", "i32"),
+        ("hit5_vfx_id", "i32"),
+        ("hit5_dummy_poly_id0", "i32"),
+        ("hit5_dummy_poly_id1", "i32"),
+        ("hit6_vfx_id", "i32"),
+        ("hit6_dummy_poly_id0", "i32"),
+        ("hit6_dummy_poly_id1", "i32"),
+        ("hit7_vfx_id", "i32"),
+        ("hit7_dummy_poly_id0", "i32"),
+        ("hit7_dummy_poly_id1", "i32"),
+        ("hit4_radius", "f32"),
+        ("hit5_radius", "f32"),
+        ("hit6_radius", "f32"),
+        ("hit7_radius", "f32"),
+        ("hit8_radius", "f32"),
+        ("hit9_radius", "f32"),
+        ("hit10_radius", "f32"),
+        ("hit11_radius", "f32"),
+        ("hit12_radius", "f32"),
+        ("hit13_radius", "f32"),
+        ("hit14_radius", "f32"),
+        ("hit15_radius", "f32"),
+        ("hit4_dmy_poly1", "i16"),
+        ("hit5_dmy_poly1", "i16"),
+        ("hit6_dmy_poly1", "i16"),
+        ("hit7_dmy_poly1", "i16"),
+        ("hit8_dmy_poly1", "i16"),
+        ("hit9_dmy_poly1", "i16"),
+        ("hit10_dmy_poly1", "i16"),
+        ("hit11_dmy_poly1", "i16"),
+        ("hit12_dmy_poly1", "i16"),
+        ("hit13_dmy_poly1", "i16"),
+        ("hit14_dmy_poly1", "i16"),
+        ("hit15_dmy_poly1", "i16"),
+        ("hit4_dmy_poly2", "i16"),
+        ("hit5_dmy_poly2", "i16"),
+        ("hit6_dmy_poly2", "i16"),
+        ("hit7_dmy_poly2", "i16"),
+        ("hit8_dmy_poly2", "i16"),
+        ("hit9_dmy_poly2", "i16"),
+        ("hit10_dmy_poly2", "i16"),
+        ("hit11_dmy_poly2", "i16"),
+        ("hit12_dmy_poly2", "i16"),
+        ("hit13_dmy_poly2", "i16"),
+        ("hit14_dmy_poly2", "i16"),
+        ("hit15_dmy_poly2", "i16"),
+        ("hit4_hit_type", "u8"),
+        ("hit5_hit_type", "u8"),
+        ("hit6_hit_type", "u8"),
+        ("hit7_hit_type", "u8"),
+        ("hit8_hit_type", "u8"),
+        ("hit9_hit_type", "u8"),
+        ("hit10_hit_type", "u8"),
+        ("hit11_hit_type", "u8"),
+        ("hit12_hit_type", "u8"),
+        ("hit13_hit_type", "u8"),
+        ("hit14_hit_type", "u8"),
+        ("hit15_hit_type", "u8"),
+        ("field0x174", "i32"),
+        ("field0x178", "i32"),
+        ("field0x17_c", "i32"),
+        ("def_material_val0", "i16"),
+        ("def_material_val1", "i16"),
+        ("def_material_val2", "i16"),
+        ("atk_dark_correction", "i16"),
+        ("atk_dark", "i16"),
+        ("IsChargeAtk2", "bool"),
+        ("IsChargeAtk3", "bool"),
+        ("field0x18A", "bool"),
+        ("field0x18A_0", "bool"),
+        ("field0x18A_1", "bool"),
+        ("field0x18A_2", "bool"),
+        ("field0x18A_3", "bool"),
+        ("field0x18A_4", "bool"),
+        ("field0x18_b", "u8"),
+        ("phys_sp_correction", "i16"),
+        ("mag_sp_correction", "i16"),
+        ("fire_sp_correction", "i16"),
+        ("thun_sp_correction", "i16"),
+        ("damage_level_parameter", "u8"),
+        ("field0x195", "u8"),
+        ("dark_sp_correction", "i16"),
+        ("atk_element_correct_id", "i32"),
+    ]),
+    ("AtkParam_Pc", &[
+        ("hit0_radius", "f32"),
+        ("hit1_radius", "f32"),
+        ("hit2_radius", "f32"),
+        ("hit3_radius", "f32"),
+        ("knockback_dist", "f32"),
+        ("hit_stop_time", "f32"),
+        ("sp_effect0", "i32"),
+        ("sp_effect1", "i32"),
+        ("sp_effect2", "i32"),
+        ("sp_effect3", "i32"),
+        ("sp_effect4", "i32"),
+        ("hit0_dmy_poly1", "i16"),
+        ("hit1_dmy_poly1", "i16"),
+        ("hit2_dmy_poly1", "i16"),
+        ("hit3_dmy_poly1", "i16"),
+        ("hit0_dmy_poly2", "i16"),
+        ("hit1_dmy_poly2", "i16"),
+        ("hit2_dmy_poly2", "i16"),
+        ("hit3_dmy_poly2", "i16"),
+        ("blowing", "i16"),
+        ("atk_phys_correction", "i16"),
+        ("atk_mag_correction", "i16"),
+        ("atk_fire_correction", "i16"),
+        ("atk_thun_correction", "i16"),
+        ("atk_stam_correction", "i16"),
+        ("guard_atk_rate_correction", "i16"),
+        ("guard_break_correction", "i16"),
+        ("atk_throw_escape_correction", "i16"),
+        ("atk_super_armor_correction", "i16"),
+        ("atk_phys", "i16"),
+        ("atk_mag", "i16"),
+        ("atk_fire", "i16"),
+        ("atk_thun", "i16"),
+        ("atk_stam", "i16"),
+        ("guard_atk_rate", "i16"),
+        ("guard_break_rate", "i16"),
+        ("atk_super_armor", "i16"),
+        ("atk_throw_escape", "i16"),
+        ("atk_obj", "i16"),
+        ("guard_stamina_cut_rate", "i16"),
+        ("guard_rate", "i16"),
+        ("throw_type_id", "i16"),
+        ("hit0_hit_type", "u8"),
+        ("hit1_hit_type", "u8"),
+        ("hit2_hit_type", "u8"),
+        ("hit3_hit_type", "u8"),
+        ("hit0_priority", "u8"),
+        ("hit1_priority", "u8"),
+        ("hit2_priority", "u8"),
+        ("hit3_priority", "u8"),
+        ("damage_level", "u8"),
+        ("map_hit_type", "u8"),
+        ("guard_cut_cancel_rate", "u8"),
+        ("atk_attribute", "i8"),
+        ("sp_attribute", "i8"),
+        ("atk_type", "i8"),
+        ("atk_material", "i8"),
+        ("atk_size", "i8"),
+        ("def_material", "i8"),
+        ("def_sfx_material", "i8"),
+        ("hit_source_type", "u8"),
+        ("throw_flag", "u8"),
+        ("disableGuard", "bool"),
+        ("disableStaminaAttack", "bool"),
+        ("disableHitSpEffect", "bool"),
+        ("IgnoreNotifyMissSwingForAI", "bool"),
+        ("repeatHitSfx", "bool"),
+        ("IsArrowAtk", "bool"),
+        ("IsGhostAtk", "bool"),
+        ("isDisableNoDamage", "bool"),
+        ("atk_pow_for_sfx_se", "u8"),
+        ("atk_dir_for_sfx_se", "u8"),
+        ("opposeTarget", "bool"),
+        ("friendlyTarget", "bool"),
+        ("selfTarget", "bool"),
+        ("isChargeAtk", "bool"),
+        ("isShareHitList", "bool"),
+        ("isCheckObjPenetration", "bool"),
+        ("field0x81", "bool"),
+        ("field0x81_0", "bool"),
+        ("pad1", "u8"),
+        ("regainable_slot_id", "u8"),
+        ("death_cause_id", "i32"),
+        ("decal_id1", "i32"),
+        ("decal_id2", "i32"),
+        ("spawn_ai_sound_id", "i32"),
+        ("hit_ai_sound_id", "i32"),
+        ("rumble_id0", "i32"),
+        ("rumble_id1", "i32"),
+        ("rumble_id2", "i32"),
+        ("rumble_id3", "i32"),
+        ("hit0_vfx_id", "i32"),
+        ("hit0_dummy_poly_id0", "i32"),
+        ("hit0_dummy_poly_id1", "i32"),
+        ("hit1_vfx_id1", "i32"),
+        ("hit1_dummy_poly_id0", "i32"),
+        ("hit1_dummy_poly_id1", "i32"),
+        ("hit2_vfx_id", "i32"),
+        ("hit2_dummy_poly_id0", "i32"),
+        ("hit2_dummy_poly_id1", "i32"),
+        ("hit3_vfx_id", "i32"),
+        ("hit3_dummy_poly_id0", "i32"),
+        ("hit3_dummy_poly_id1", "i32"),
+        ("hit4_vfx_id", "i32"),
+        ("hit4_dummy_poly_id0", "i32"),
+        ("hit4_dummy_poly_id1", "i32"),
+        ("hit5_vfx_id", "i32"),
+        ("hit5_dummy_poly_id0", "i32"),
+        ("hit5_dummy_poly_id1", "i32"),
+        ("hit6_vfx_id", "i32"),
+        ("hit6_dummy_poly_id0", "i32"),
+        ("hit6_dummy_poly_id1", "i32"),
+        ("hit7_vfx_id", "i32"),
+        ("hit7_dummy_poly_id0", "i32"),
+        ("hit7_dummy_poly_id1", "i32"),
+        ("hit4_radius", "f32"),
+        ("hit5_radius", "f32"),
+        ("hit6_radius", "f32"),
+        ("hit7_radius", "f32"),
+        ("hit8_radius", "f32"),
+        ("hit9_radius", "f32"),
+        ("hit10_radius", "f32"),
+        ("hit11_radius", "f32"),
+        ("hit12_radius", "f32"),
+        ("hit13_radius", "f32"),
+        ("hit14_radius", "f32"),
+        ("hit15_radius", "f32"),
+        ("hit4_dmy_poly1", "i16"),
+        ("hit5_dmy_poly1", "i16"),
+        ("hit6_dmy_poly1", "i16"),
+        ("hit7_dmy_poly1", "i16"),
+        ("hit8_dmy_poly1", "i16"),
+        ("hit9_dmy_poly1", "i16"),
+        ("hit10_dmy_poly1", "i16"),
+        ("hit11_dmy_poly1", "i16"),
+        ("hit12_dmy_poly1", "i16"),
+        ("hit13_dmy_poly1", "i16"),
+        ("hit14_dmy_poly1", "i16"),
+        ("hit15_dmy_poly1", "i16"),
+        ("hit4_dmy_poly2", "i16"),
+        ("hit5_dmy_poly2", "i16"),
+        ("hit6_dmy_poly2", "i16"),
+        ("hit7_dmy_poly2", "i16"),
+        ("hit8_dmy_poly2", "i16"),
+        ("hit9_dmy_poly2", "i16"),
+        ("hit10_dmy_poly2", "i16"),
+        ("hit11_dmy_poly2", "i16"),
+        ("hit12_dmy_poly2", "i16"),
+        ("hit13_dmy_poly2", "i16"),
+        ("hit14_dmy_poly2", "i16"),
+        ("hit15_dmy_poly2", "i16"),
+        ("hit4_hit_type", "u8"),
+        ("hit5_hit_type", "u8"),
+        ("hit6_hit_type", "u8"),
+        ("hit7_hit_type", "u8"),
+        ("hit8_hit_type", "u8"),
+        ("hit9_hit_type", "u8"),
+        ("hit10_hit_type", "u8"),
+        ("hit11_hit_type", "u8"),
+        ("hit12_hit_type", "u8"),
+        ("hit13_hit_type", "u8"),
+        ("hit14_hit_type", "u8"),
+        ("hit15_hit_type", "u8"),
+        ("field0x174", "i32"),
+        ("field0x178", "i32"),
+        ("field0x17_c", "i32"),
+        ("def_material_val0", "i16"),
+        ("def_material_val1", "i16"),
+        ("def_material_val2", "i16"),
+        ("atk_dark_correction", "i16"),
+        ("atk_dark", "i16"),
+        ("IsChargeAtk2", "bool"),
+        ("IsChargeAtk3", "bool"),
+        ("field0x18A", "bool"),
+        ("field0x18A_0", "bool"),
+        ("field0x18A_1", "bool"),
+        ("field0x18A_2", "bool"),
+        ("field0x18A_3", "bool"),
+        ("field0x18A_4", "bool"),
+        ("field0x18_b", "u8"),
+        ("phys_sp_correction", "i16"),
+        ("mag_sp_correction", "i16"),
+        ("fire_sp_correction", "i16"),
+        ("thun_sp_correction", "i16"),
+        ("damage_level_parameter", "u8"),
+        ("field0x195", "u8"),
+        ("dark_sp_correction", "i16"),
+        ("atk_element_correct_id", "i32"),
+    ]),
+    ("AttackElementCorrectParam", &[
+        ("bitfield0", "u32"),
+        ("add_rate0", "i16"),
+        ("add_rate1", "i16"),
+        ("add_rate2", "i16"),
+        ("add_rate3", "i16"),
+        ("add_rate4", "i16"),
+        ("add_rate5", "i16"),
+        ("add_rate6", "i16"),
+        ("add_rate7", "i16"),
+        ("add_rate8", "i16"),
+        ("add_rate9", "i16"),
+        ("add_rate10", "i16"),
+        ("add_rate11", "i16"),
+        ("add_rate12", "i16"),
+        ("add_rate13", "i16"),
+        ("add_rate14", "i16"),
+        ("add_rate15", "i16"),
+        ("add_rate16", "i16"),
+        ("add_rate17", "i16"),
+        ("add_rate18", "i16"),
+        ("add_rate19", "i16"),
+        ("add_rate20", "i16"),
+        ("add_rate21", "i16"),
+        ("add_rate22", "i16"),
+        ("add_rate23", "i16"),
+        ("add_rate24", "i16"),
+        ("corr_rate0", "i16"),
+        ("corr_rate1", "i16"),
+        ("corr_rate2", "i16"),
+        ("corr_rate3", "i16"),
+        ("corr_rate4", "i16"),
+        ("corr_rate5", "i16"),
+        ("corr_rate6", "i16"),
+        ("corr_rate7", "i16"),
+        ("corr_rate8", "i16"),
+        ("corr_rate9", "i16"),
+        ("corr_rate10", "i16"),
+        ("corr_rate11", "i16"),
+        ("corr_rate12", "i16"),
+        ("corr_rate13", "i16"),
+        ("corr_rate14", "i16"),
+        ("corr_rate15", "i16"),
+        ("corr_rate16", "i16"),
+        ("corr_rate17", "i16"),
+        ("corr_rate18", "i16"),
+        ("corr_rate19", "i16"),
+        ("corr_rate20", "i16"),
+        ("corr_rate21", "i16"),
+        ("corr_rate22", "i16"),
+        ("corr_rate23", "i16"),
+        ("corr_rate24", "i16"),
+    ]),
+    ("BehaviorParam", &[
+        ("variation_id", "i32"),
+        ("behavior_judge_id", "i32"),
+        ("ez_state_behavior_type_old", "u8"),
+        ("ref_type", "u8"),
+        ("ref_id", "i32"),
+        ("sfx_variation_id", "i32"),
+        ("stamina", "i32"),
+        ("mp", "i32"),
+        ("category", "u8"),
+        ("hero_point", "u8"),
+    ]),
+    ("BehaviorParam_PC", &[
+        ("variation_id", "i32"),
+        ("behavior_judge_id", "i32"),
+        ("ez_state_behavior_type_old", "u8"),
+        ("ref_type", "u8"),
+        ("ref_id", "i32"),
+        ("sfx_variation_id", "i32"),
+        ("stamina", "i32"),
+        ("mp", "i32"),
+        ("category", "u8"),
+        ("hero_point", "u8"),
+    ]),
+    ("BonfireWarpParam", &[
+        ("location_event_id", "i32"),
+        ("warp_event_id", "i32"),
+        ("bonfire_name_id", "i32"),
+        ("description_text_id", "i32"),
+        ("picture_id", "i32"),
+        ("list_id", "u8"),
+        ("is_disable_quickwarp", "u8"),
+        ("ceremony_id", "i16"),
+        ("online_area_id", "i32"),
+    ]),
+    ("BudgetParam", &[
+        ("memory_budget0", "f32"),
+        ("memory_budget1", "f32"),
+        ("memory_budget2", "f32"),
+        ("memory_budget3", "f32"),
+        ("memory_budget4", "f32"),
+        ("memory_budget5", "f32"),
+        ("memory_budget6", "f32"),
+        ("memory_budget7", "f32"),
+        ("memory_budget8", "f32"),
+        ("memory_budget9", "f32"),
+        ("memory_budget10", "f32"),
+        ("memory_budget11", "f32"),
+        ("memory_budget12", "f32"),
+        ("memory_budget13", "f32"),
+        ("memory_budget14", "f32"),
+        ("memory_budget15", "f32"),
+        ("memory_budget16", "f32"),
+        ("memory_budget17", "f32"),
+        ("memory_budget18", "f32"),
+        ("memory_budget19", "f32"),
+        ("memory_budget20", "f32"),
+        ("memory_budget21", "f32"),
+        ("memory_budget22", "f32"),
+        ("memory_budget23", "f32"),
+        ("memory_budget24", "f32"),
+        ("memory_budget25", "f32"),
+    ]),
+    ("Bullet", &[
+        ("atk_bullet_id", "i32"),
+        ("sfx_id_bullet", "i32"),
+        ("sfx_id_hit", "i32"),
+        ("sfx_id_flick", "i32"),
+        ("life", "f32"),
+        ("dist", "f32"),
+        ("shoot_interval", "f32"),
+        ("gravity_in_range", "f32"),
+        ("gravity_out_range", "f32"),
+        ("homing_stop_range", "f32"),
+        ("init_vellocity", "f32"),
+        ("accel_in_range", "f32"),
+        ("accel_out_range", "f32"),
+        ("max_vellocity", "f32"),
+        ("min_vellocity", "f32"),
+        ("accel_time", "f32"),
+        ("homing_begin_dist", "f32"),
+        ("hit_radius", "f32"),
+        ("hit_radius_max", "f32"),
+        ("spread_time", "f32"),
+        ("exp_delay", "f32"),
+        ("homing_offset_range", "f32"),
+        ("dmg_hit_record_life_time", "f32"),
+        ("external_force", "f32"),
+        ("sp_effect_id_for_shooter", "i32"),
+        ("auto_search_npc_think_id", "i32"),
+        ("hit_bullet_id", "i32"),
+        ("sp_effect_id0", "i32"),
+        ("sp_effect_id1", "i32"),
+        ("sp_effect_id2", "i32"),
+        ("sp_effect_id3", "i32"),
+        ("sp_effect_id4", "i32"),
+        ("num_shoot", "i16"),
+        ("homing_angle", "i16"),
+        ("shoot_angle", "i16"),
+        ("shoot_angle_interval", "i16"),
+        ("shoot_angle_xinterval", "i16"),
+        ("damage_damp", "u8"),
+        ("magic_damage_damp", "u8"),
+        ("fire_damage_damp", "u8"),
+        ("thunder_damage_damp", "u8"),
+        ("stamina_damp", "u8"),
+        ("knockback_damp", "u8"),
+        ("shoot_angle_xz", "u8"),
+        ("lock_shoot_limit_ang", "u8"),
+        ("is_penetrate", "u8"),
+        ("prev_vellocity_dir_rate", "u8"),
+        ("atk_attribute", "u8"),
+        ("sp_attribute", "u8"),
+        ("material_attack_type", "u8"),
+        ("material_attack_material", "u8"),
+        ("material_size", "u8"),
+        ("launch_condition_type", "u8"),
+        ("FollowType0", "bool"),
+        ("FollowType1", "bool"),
+        ("FollowType2", "bool"),
+        ("EmittePosType0", "bool"),
+        ("EmittePosType1", "bool"),
+        ("EmittePosType2", "bool"),
+        ("IsAttackSfx", "bool"),
+        ("IsEndlessHit", "bool"),
+        ("IsPenetrateMap", "bool"),
+        ("IsHitBothTeam", "bool"),
+        ("IsUseShardHitList", "bool"),
+        ("IsUseMultiDmyPolyIfPlace", "bool"),
+        ("attachEffectType0", "bool"),
+        ("attachEffectType1", "bool"),
+        ("IsHitForceMagic", "bool"),
+        ("IsIgnoreSfxIfHitWater", "bool"),
+        ("IsIgnoreMoveStateIfHitWater", "bool"),
+        ("IsHitDarkForceMagic", "bool"),
+        ("IsInheritEffectToChild", "bool"),
+        ("IsInheritSpeedToChild", "bool"),
+        ("IsLockModule", "bool"),
+        ("IsSearchForTarget", "bool"),
+        ("IsEmittedBullet", "bool"),
+        ("IsInheritLifeToChild", "bool"),
+        ("dark_damage_damp", "u8"),
+        ("bullet_hit_type0", "u8"),
+        ("bullet_hit_type1", "u8"),
+        ("sp_homing_yangle", "f32"),
+        ("rand_shoot_angle_xz", "f32"),
+        ("rand_shoot_angle_y", "f32"),
+        ("bullet_emitter_bullet_id", "i32"),
+        ("bullet_emitter_shoot_interval0", "f32"),
+        ("bullet_emitter_init_interval", "f32"),
+        ("sp_homing_yangle_for_enemy", "f32"),
+        ("bullet_emitter_shoot_interval1", "f32"),
+        ("launch_type", "u8"),
+        ("limit_bullet_param_id", "u8"),
+        ("counter_hit_type", "u8"),
+        ("IsPosHitBullet", "bool"),
+        ("IsAttachAttackSfx", "bool"),
+        ("IsSpCorrection0", "bool"),
+        ("IsSpCorrection1", "bool"),
+        ("IsPenetrateTarget", "bool"),
+        ("IsSpCorrection2", "bool"),
+        ("IsDisableIntScale", "bool"),
+        ("field0xC3", "bool"),
+        ("emitte_pos_radius", "f32"),
+        ("hit_object_id", "i32"),
+        ("sub_life", "f32"),
+        ("sub_homing_angle", "i16"),
+        ("lock_shoot_correction_ang", "f32"),
+    ]),
+    ("BulletCreateLimitParam", &[("max_ammount", "u8")]),
+    ("CalcCorrectGraph", &[
+        ("stage_max_val0", "f32"),
+        ("stage_max_val1", "f32"),
+        ("stage_max_val2", "f32"),
+        ("stage_max_val3", "f32"),
+        ("stage_max_val4", "f32"),
+        ("stage_max_grow_val0", "f32"),
+        ("stage_max_grow_val1", "f32"),
+        ("stage_max_grow_val2", "f32"),
+        ("stage_max_grow_val3", "f32"),
+        ("stage_max_grow_val4", "f32"),
+        ("adj_pt_max_grow_val0", "f32"),
+        ("adj_pt_max_grow_val1", "f32"),
+        ("adj_pt_max_grow_val2", "f32"),
+        ("adj_pt_max_grow_val3", "f32"),
+        ("adj_pt_max_grow_val4", "f32"),
+        ("init_inclination_soul", "f32"),
+        ("adjustment_value", "f32"),
+        ("boundry_inclination_soul", "f32"),
+        ("boundry_value", "f32"),
+    ]),
+    ("Ceremony", &[
+        ("event_layer_id", "i32"),
+        ("map_studio_layer_id", "i32"),
+        ("gparam_id", "i16"),
+        ("gparam_id_0", "i16"),
+        ("point", "i32"),
+        ("g_i_texture_id", "i32"),
+        ("light", "i32"),
+        ("is_reload", "u8"),
+        ("is_disable_online", "u8"),
+    ]),
+    ("CharMakeMenuListItemParam", &[("value", "i32"), ("caption_id", "i32"), ("icon_id", "u8")]),
+    ("CharMakeMenuTopParam", &[
+        ("command_id", "i32"),
+        ("face_param_id", "i32"),
+        ("table_id", "i32"),
+        ("view_condition", "i32"),
+        ("preview_mode", "u8"),
+        ("menu_type", "i8"),
+    ]),
+    ("CharaInitParam", &[
+        ("base_rec_mp", "f32"),
+        ("base_rec_sp", "f32"),
+        ("red_falldam", "f32"),
+        ("soul", "i32"),
+        ("equip_wep_right", "i32"),
+        ("equip_subwep_right", "i32"),
+        ("equip_wep_left", "i32"),
+        ("equip_subwep_left", "i32"),
+        ("equip_helm", "i32"),
+        ("equip_armor", "i32"),
+        ("equip_gaunt", "i32"),
+        ("equip_leg", "i32"),
+        ("equip_arrow", "i32"),
+        ("equip_bolt", "i32"),
+        ("equip_sub_arrow", "i32"),
+        ("equip_sub_bolt", "i32"),
+        ("equip_accessory1", "i32"),
+        ("equip_accessory2", "i32"),
+        ("equip_accessory3", "i32"),
+        ("equip_accessory4", "i32"),
+        ("equip_accessory5", "i32"),
+        ("equip_skill_01", "i32"),
+        ("equip_skill_02", "i32"),
+        ("equip_skill_03", "i32"),
+        ("equip_spell_01", "i32"),
+        ("equip_spell_02", "i32"),
+        ("equip_spell_03", "i32"),
+        ("equip_spell_04", "i32"),
+        ("equip_spell_05", "i32"),
+        ("equip_spell_06", "i32"),
+        ("equip_spell_07", "i32"),
+        ("item_01", "i32"),
+        ("item_02", "i32"),
+        ("item_03", "i32"),
+        ("item_04", "i32"),
+        ("item_05", "i32"),
+        ("item_06", "i32"),
+        ("item_07", "i32"),
+        ("item_08", "i32"),
+        ("item_09", "i32"),
+        ("item_10", "i32"),
+        ("npc_player_face_gen_id", "i32"),
+        ("npc_player_think_id", "i32"),
+        ("base_hp", "i16"),
+        ("base_mp", "i16"),
+        ("base_sp", "i16"),
+        ("arrow_num", "i16"),
+        ("bolt_num", "i16"),
+        ("sub_arrow_num", "i16"),
+        ("sub_bolt_num", "i16"),
+        ("q_wc_sb", "i16"),
+        ("q_wc_mw", "i16"),
+        ("q_wc_cd", "i16"),
+        ("soul_lvl", "i16"),
+        ("base_vit", "i8"),
+        ("base_wil", "i8"),
+        ("base_end", "i8"),
+        ("base_str", "i8"),
+        ("base_dex", "i8"),
+        ("base_mag", "i8"),
+        ("base_fai", "i8"),
+        ("base_luc", "i8"),
+        ("base_hero_point", "i8"),
+        ("base_durability", "i8"),
+        ("item_num_01", "u8"),
+        ("item_num_02", "u8"),
+        ("item_num_03", "u8"),
+        ("item_num_04", "u8"),
+        ("item_num_05", "u8"),
+        ("item_num_06", "u8"),
+        ("item_num_07", "u8"),
+        ("item_num_08", "u8"),
+        ("item_num_09", "u8"),
+        ("item_num_10", "u8"),
+        ("body_scale_head", "u8"),
+        ("body_scale_breast", "u8"),
+        ("body_scale_abdomen", "u8"),
+        ("body_scale_arm", "u8"),
+        ("body_scale_leg", "u8"),
+        ("gestureid0", "u8"),
+        ("gestureid1", "u8"),
+        ("gestureid2", "u8"),
+        ("gestureid3", "u8"),
+        ("gestureid4", "u8"),
+        ("gestureid5", "u8"),
+        ("gestureid6", "u8"),
+        ("npc_player_type", "u8"),
+        ("npc_player_draw_type", "u8"),
+        ("npc_player_sex", "u8"),
+        ("vow_type", "u8"),
+        ("voice_type", "u8"),
+        ("equip_wep_right_gen_id", "i32"),
+        ("equip_subwep_right_gen_id", "i32"),
+        ("equip_wep_left_gen_id", "i32"),
+        ("equip_subwep_left_gen_id", "i32"),
+        ("equip_helm_gen_id", "i32"),
+        ("equip_armor_gen_id", "i32"),
+        ("equip_gaunt_gen_id", "i32"),
+        ("equip_leg_gen_id", "i32"),
+        ("equip_wep_body_gen_id", "i32"),
+        ("secondary_item_01", "i32"),
+        ("secondary_item_02", "i32"),
+        ("secondary_item_03", "i32"),
+        ("secondary_item_04", "i32"),
+        ("secondary_item_05", "i32"),
+        ("secondary_item_06", "i32"),
+        ("secondary_item_07", "i32"),
+        ("secondary_item_08", "i32"),
+        ("secondary_item_num_01", "u8"),
+        ("secondary_item_num_02", "u8"),
+        ("secondary_item_num_03", "u8"),
+        ("secondary_item_num_04", "u8"),
+        ("secondary_item_num_05", "u8"),
+        ("secondary_item_num_06", "u8"),
+        ("secondary_item_num_07", "u8"),
+        ("secondary_item_num_08", "u8"),
+    ]),
+    ("CharacterLoadParam", &[
+        ("chr_bnd_type", "u8"),
+        ("ani_bnd_type", "u8"),
+        ("tex_bnd_type", "u8"),
+        ("beh_bnd_type", "u8"),
+        ("snd_chr_type", "u8"),
+    ]),
+    ("ClearCountCorrectParam", &[
+        ("h_p", "f32"),
+        ("mana", "f32"),
+        ("stamina", "f32"),
+        ("phys_dmg", "f32"),
+        ("slash_dmg", "f32"),
+        ("blow_dmg", "f32"),
+        ("thrust_dmg", "f32"),
+        ("neutral", "f32"),
+        ("magic_dmg", "f32"),
+        ("fire_dmg", "f32"),
+        ("thunder_dmg", "f32"),
+        ("dark_dmg", "f32"),
+        ("phys_resist", "f32"),
+        ("magic_resist", "f32"),
+        ("fire_resist", "f32"),
+        ("thunder_resist", "f32"),
+        ("dark_resist", "f32"),
+        ("stamina_dmg", "f32"),
+        ("mp_recover", "f32"),
+        ("poison_resist", "f32"),
+        ("toxic_resist", "f32"),
+        ("bleed_resist", "f32"),
+        ("curse_resist", "f32"),
+        ("frost_resist", "f32"),
+        ("hp_recover", "f32"),
+        ("sub_mp_recover", "f32"),
+        ("sub_hp_recover", "f32"),
+    ]),
+    ("CoolTimeParam", &[
+        ("limitation_time_0", "f32"),
+        ("observation_time_0", "f32"),
+        ("limitation_time_1", "f32"),
+        ("observation_time_1", "f32"),
+        ("limitation_time_2", "f32"),
+        ("observation_time_2", "f32"),
+        ("limitation_time_3", "f32"),
+        ("observation_time_3", "f32"),
+    ]),
+    ("CultSettingParam", &[
+        ("distance", "f32"),
+        ("angle", "f32"),
+        ("event_flag_id", "i32"),
+        ("coefficient", "i16"),
+        ("cult_state1", "i8"),
+        ("cult_state2", "i8"),
+    ]),
+    ("DecalParam", &[
+        ("texture_id0", "i32"),
+        ("dmy_poly_id", "i32"),
+        ("pitch_angle", "f32"),
+        ("yaw_angle", "f32"),
+        ("near_distance", "f32"),
+        ("far_distance", "f32"),
+        ("near_size", "f32"),
+        ("far_size", "f32"),
+        ("mask_sp_effect_id", "i32"),
+        ("bitfield0", "u32"),
+        ("random_size_min", "i16"),
+        ("random_size_max", "i16"),
+        ("random_roll_min", "f32"),
+        ("random_roll_max", "f32"),
+        ("random_pitch_min", "f32"),
+        ("random_pitch_max", "f32"),
+        ("random_yaw_min", "f32"),
+        ("random_yaw_max", "f32"),
+        ("pom_height_scale", "f32"),
+        ("pom_sample_min", "u8"),
+        ("pom_sample_max", "u8"),
+        ("contrast1", "u8"),
+        ("texture_length_type", "u8"),
+        ("texture_width_x", "f32"),
+        ("texture_width_z", "f32"),
+        ("texture_life_time", "f32"),
+        ("field0x58", "i32"),
+        ("field0x5_c", "f32"),
+        ("field0x60", "i32"),
+        ("field0x64", "i32"),
+        ("field0x68", "i32"),
+        ("field0x6_c", "i32"),
+        ("texture_id1", "i32"),
+        ("texture_id2", "i32"),
+        ("texture_id3", "i32"),
+        ("brightness", "f32"),
+        ("texture_id4", "i32"),
+        ("texture_id5", "i32"),
+        ("texture_id6", "i32"),
+        ("r", "u8"),
+        ("g", "u8"),
+        ("b", "u8"),
+        ("unk_na_m", "i8"),
+        ("r_0", "u8"),
+        ("g_1", "u8"),
+        ("b_2", "u8"),
+        ("delayed_spawn_state", "i8"),
+        ("contrast2", "f32"),
+        ("field0x98", "f32"),
+        ("field0x9_c", "f32"),
+        ("field0x_a0", "u8"),
+        ("field0x_a1", "u8"),
+        ("field0x_a2", "u8"),
+        ("field0x_a3", "u8"),
+        ("min_val", "f32"),
+        ("max_val", "f32"),
+        ("field0x_ac", "u8"),
+        ("field0x_ad", "u8"),
+        ("decal_spawn_delay", "u16"),
+        ("bitfield1", "u32"),
+        ("texture_spawn_delay", "f32"),
+    ]),
+    ("DirectionCameraParam", &[("rumble_state", "u8")]),
+    ("EquipMtrlSetParam", &[
+        ("material_id01", "i32"),
+        ("material_id02", "i32"),
+        ("material_id03", "i32"),
+        ("material_id04", "i32"),
+        ("material_id05", "i32"),
+        ("item_num01", "i8"),
+        ("item_num02", "i8"),
+        ("item_num03", "i8"),
+        ("item_num04", "i8"),
+        ("item_num05", "i8"),
+    ]),
+    ("EquipParamAccessory", &[
+        ("ref_id0", "i32"),
+        ("sfx_variation_id", "i32"),
+        ("weight", "f32"),
+        ("behavior_id", "i32"),
+        ("basic_price", "i32"),
+        ("sell_value", "i32"),
+        ("sort_id", "i32"),
+        ("qwc_id", "i32"),
+        ("equip_model_id", "i16"),
+        ("icon_id", "i16"),
+        ("shop_lv", "i16"),
+        ("trophy_sgrade_id", "i16"),
+        ("trophy_seq_id", "i16"),
+        ("equip_model_category", "u8"),
+        ("equip_model_gender", "u8"),
+        ("accessory_category", "u8"),
+        ("ref_category", "u8"),
+        ("sp_effect_category", "u8"),
+        ("vagrant_item_lot_id", "i32"),
+        ("vagrant_bonus_ene_drop_item_lot_id", "i32"),
+        ("vagrant_item_ene_drop_item_lot_id", "i32"),
+        ("IsDeposit", "bool"),
+        ("IsEquipOutBrake", "bool"),
+        ("disableMultiDropShare", "bool"),
+        ("IsDiscard", "bool"),
+        ("IsBool", "bool"),
+        ("field0x3C", "bool"),
+        ("field0x3C_0", "bool"),
+        ("field0x3C_1", "bool"),
+        ("costvalue", "i32"),
+        ("ring_compatibility_id", "i16"),
+        ("vow_id", "u8"),
+        ("achievement_id", "u8"),
+        ("ref_id1", "i32"),
+        ("ref_id2", "i32"),
+        ("ref_id3", "i32"),
+        ("ref_id4", "i32"),
+    ]),
+    ("EquipParamGoods", &[
+        ("ref_id1", "i32"),
+        ("sfx_variation_id", "i32"),
+        ("weight", "f32"),
+        ("fragment_num", "i32"),
+        ("sell_value", "i32"),
+        ("replace_item_id", "i32"),
+        ("behavior_id", "i32"),
+        ("sort_id", "i32"),
+        ("qwc_id", "i32"),
+        ("yes_no_dialog_message_id", "i32"),
+        ("magic_id", "i32"),
+        ("icon_id", "i16"),
+        ("model_id", "i16"),
+        ("shop_lv", "i16"),
+        ("comp_trophy_sed_id", "i16"),
+        ("trophy_seq_id", "i16"),
+        ("max_num", "i16"),
+        ("consume_hero_point", "u8"),
+        ("over_dexterity", "u8"),
+        ("goods_type", "u8"),
+        ("ref_category", "u8"),
+        ("sp_effect_category", "u8"),
+        ("goods_category", "u8"),
+        ("goods_use_anim", "i8"),
+        ("opme_menu_type", "u8"),
+        ("use_limit_category", "u8"),
+        ("replace_category", "u8"),
+        ("vowType0", "bool"),
+        ("vowType1", "bool"),
+        ("vowType2", "bool"),
+        ("vowType3", "bool"),
+        ("vowType4", "bool"),
+        ("vowType5", "bool"),
+        ("vowType6", "bool"),
+        ("vowType7", "bool"),
+        ("vowType8", "bool"),
+        ("vowType9", "bool"),
+        ("vowType10", "bool"),
+        ("vowType11", "bool"),
+        ("vowType12", "bool"),
+        ("vowType13", "bool"),
+        ("vowType14", "bool"),
+        ("vowType15", "bool"),
+        ("enable_live", "bool"),
+        ("enable_gray", "bool"),
+        ("enable_white", "bool"),
+        ("enable_black", "bool"),
+        ("enable_multi", "bool"),
+        ("disable_offline", "bool"),
+        ("isEquip", "bool"),
+        ("isConsume", "bool"),
+        ("isAutoEquip", "bool"),
+        ("isEstablishment", "bool"),
+        ("isOnlyOne", "bool"),
+        ("isDrop", "bool"),
+        ("isDeposit", "bool"),
+        ("isDisableHand", "bool"),
+        ("isTravelItem", "bool"),
+        ("isSuppleItem", "bool"),
+        ("isFullSuppleItem", "bool"),
+        ("isEnhance", "bool"),
+        ("isFixItem", "bool"),
+        ("disableMutliDropShare", "bool"),
+        ("disableUseAtColiseum", "bool"),
+        ("disableUseAtOutofColiseum", "bool"),
+        ("useBulletMaxNum", "bool"),
+        ("useHpCureMaxNum", "bool"),
+        ("isAutoReplenish", "bool"),
+        ("canMultiUse", "bool"),
+        ("isGuestDrop", "bool"),
+        ("isEnchantLeftHand", "bool"),
+        ("isApplySpecialEffect", "bool"),
+        ("Unk1", "bool"),
+        ("Unk2", "bool"),
+        ("Unk3", "bool"),
+        ("ref_id2", "i32"),
+        ("reinforce_param_weapon", "i32"),
+        ("vagrant_item_lot_id", "i32"),
+        ("vagrant_bonus_ene_drop_item_lot_id", "i32"),
+        ("vagrant_itemene_drop_item_lot_id", "i32"),
+        ("ref_virtual_wep_id", "i32"),
+        ("replace_item_id_by_sp_effect", "i32"),
+        ("replace_trigger_sp_effect_id", "i32"),
+        ("isLoadOfCinder", "bool"),
+        ("isPlayRegion1", "bool"),
+        ("isLadder", "bool"),
+        ("isMultiPlay", "bool"),
+        ("useSelected", "bool"),
+        ("Unk4", "bool"),
+        ("isPlayRegion2", "bool"),
+        ("isNetPenalized", "bool"),
+        ("supple_item_type", "u8"),
+        ("menu_adhoc_type", "u8"),
+        ("drop", "u8"),
+        ("max_rep_num", "i16"),
+        ("invade_type", "u8"),
+        ("shop_id", "i32"),
+        ("fp_consume", "i16"),
+        ("use_limit_category2", "i16"),
+    ]),
+    ("EquipParamProtector", &[
+        ("sort_id", "i32"),
+        ("wandering_equip_id", "i32"),
+        ("vagrant_item_lot_id", "i32"),
+        ("vagrant_bonusene_drop_item_lot_id", "i32"),
+        ("vagrant_item_ene_drop_item_lot_id", "i32"),
+        ("fix_price", "i32"),
+        ("basic_price", "i32"),
+        ("sell_value", "i32"),
+        ("weight", "f32"),
+        ("resident_sp_effect_id1", "i32"),
+        ("resident_sp_effect_id2", "i32"),
+        ("resident_sp_effect_id3", "i32"),
+        ("material_set_id", "i32"),
+        ("parts_damage_rate", "f32"),
+        ("correct_sarecover", "f32"),
+        ("origin_equip_pro1", "i32"),
+        ("origin_equip_pro2", "i32"),
+        ("origin_equip_pro3", "i32"),
+        ("origin_equip_pro4", "i32"),
+        ("origin_equip_pro5", "i32"),
+        ("origin_equip_pro6", "i32"),
+        ("origin_equip_pro7", "i32"),
+        ("origin_equip_pro8", "i32"),
+        ("origin_equip_pro9", "i32"),
+        ("origin_equip_pro10", "i32"),
+        ("origin_equip_pro11", "i32"),
+        ("origin_equip_pro12", "i32"),
+        ("origin_equip_pro13", "i32"),
+        ("origin_equip_pro14", "i32"),
+        ("origin_equip_pro15", "i32"),
+        ("origin_equip_pro16", "i32"),
+        ("face_scale_m_scale_x", "f32"),
+        ("face_scale_m_scale_z", "f32"),
+        ("face_scale_m_max_x", "f32"),
+        ("face_scale_m_max_z", "f32"),
+        ("face_scale_f_scale_x", "f32"),
+        ("face_scale_f_scale_z", "f32"),
+        ("face_scale_f_max_x", "f32"),
+        ("face_scale_f_max_x_0", "f32"),
+        ("qwc_id", "i32"),
+        ("equip_model_id", "i16"),
+        ("icon_id_m", "i16"),
+        ("icon_id_f", "i16"),
+        ("knockback", "i16"),
+        ("knockback_bounce_rate", "i16"),
+        ("durability", "i16"),
+        ("durability_max", "i16"),
+        ("sa_durability", "i16"),
+        ("def_flick_power", "i16"),
+        ("defense_phys", "i16"),
+        ("defense_magic", "i16"),
+        ("defense_fire", "i16"),
+        ("defense_thunder", "i16"),
+        ("defense_slash", "i16"),
+        ("defense_blow", "i16"),
+        ("defense_thrust", "i16"),
+        ("resist_poison", "i16"),
+        ("resist_toxic", "i16"),
+        ("resist_blood", "i16"),
+        ("resist_curse", "i16"),
+        ("reinforce_type_id", "i16"),
+        ("comp_trophy_sed_id", "i16"),
+        ("shop_lv", "i16"),
+        ("knockback_param_id", "u8"),
+        ("flick_damage_cut_rate", "u8"),
+        ("equip_model_category", "u8"),
+        ("equip_model_gender", "u8"),
+        ("protector_category", "u8"),
+        ("defense_material", "u8"),
+        ("defense_material_sfx", "u8"),
+        ("parts_dmg_type", "u8"),
+        ("defense_material_weak", "u8"),
+        ("defense_material_sfx_weak", "u8"),
+        ("isDeposit", "bool"),
+        ("headEquip", "bool"),
+        ("bodyEquip", "bool"),
+        ("armEquip", "bool"),
+        ("legEquip", "bool"),
+        ("useFaceScale", "bool"),
+        ("InvisibleFlag00", "bool"),
+        ("InvisibleFlag01", "bool"),
+        ("InvisibleFlag02", "bool"),
+        ("InvisibleFlag03", "bool"),
+        ("InvisibleFlag04", "bool"),
+        ("InvisibleFlag05", "bool"),
+        ("InvisibleFlag06", "bool"),
+        ("InvisibleFlag07", "bool"),
+        ("InvisibleFlag08", "bool"),
+        ("InvisibleFlag09", "bool"),
+        ("InvisibleFlag10", "bool"),
+        ("InvisibleFlag11", "bool"),
+        ("InvisibleFlag12", "bool"),
+        ("InvisibleFlag13", "bool"),
+        ("InvisibleFlag14", "bool"),
+        ("InvisibleFlag15", "bool"),
+        ("InvisibleFlag16", "bool"),
+        ("InvisibleFlag17", "bool"),
+        ("InvisibleFlag18", "bool"),
+        ("InvisibleFlag19", "bool"),
+        ("InvisibleFlag20", "bool"),
+        ("InvisibleFlag21", "bool"),
+        ("InvisibleFlag22", "bool"),
+        ("InvisibleFlag23", "bool"),
+        ("InvisibleFlag24", "bool"),
+        ("InvisibleFlag25", "bool"),
+        ("InvisibleFlag26", "bool"),
+        ("InvisibleFlag27", "bool"),
+        ("InvisibleFlag28", "bool"),
+        ("InvisibleFlag29", "bool"),
+        ("InvisibleFlag30", "bool"),
+        ("InvisibleFlag31", "bool"),
+        ("InvisibleFlag32", "bool"),
+        ("InvisibleFlag33", "bool"),
+        ("InvisibleFlag34", "bool"),
+        ("InvisibleFlag35", "bool"),
+        ("InvisibleFlag36", "bool"),
+        ("InvisibleFlag37", "bool"),
+        ("InvisibleFlag38", "bool"),
+        ("InvisibleFlag39", "bool"),
+        ("InvisibleFlag40", "bool"),
+        ("InvisibleFlag41", "bool"),
+        ("InvisibleFlag42", "bool"),
+        ("InvisibleFlag43", "bool"),
+        ("InvisibleFlag44", "bool"),
+        ("InvisibleFlag45", "bool"),
+        ("InvisibleFlag46", "bool"),
+        ("InvisibleFlag47", "bool"),
+        ("disableMultiDropShare", "bool"),
+        ("simpleModelForDlc1", "bool"),
+        ("isGuestDrop0", "bool"),
+        ("isGuestDrop1", "bool"),
+        ("simpleModelForDlc2", "bool"),
+        ("Unk1", "bool"),
+        ("Unk2", "bool"),
+        ("Unk3", "bool"),
+        ("Unk4", "bool"),
+        ("Unk5", "bool"),
+        ("phys_damage_cut_rate", "f32"),
+        ("slash_damage_cut_rate", "f32"),
+        ("strike_damage_cut_rate", "f32"),
+        ("thrust_damage_cut_rate", "f32"),
+        ("magic_damage_cut_rate", "f32"),
+        ("fire_damage_cut_rate", "f32"),
+        ("thunder_damage_cut_rate", "f32"),
+        ("material_id0", "u16"),
+        ("material_id1", "u16"),
+        ("material_id2", "u16"),
+        ("material_id3", "u16"),
+        ("material_id4", "u16"),
+        ("material_id5", "u16"),
+        ("material_id6", "u16"),
+        ("material_id7", "u16"),
+        ("protector_category_id", "i32"),
+        ("poise", "f32"),
+        ("dark_damage_cut_rate", "f32"),
+        ("defense_dark", "i16"),
+        ("unk6", "u8"),
+        ("unk7", "u8"),
+        ("unk8", "i32"),
+        ("upper_arm_id", "i32"),
+        ("unk9", "i32"),
+        ("resist_frost", "i16"),
+        ("mask00", "u8"),
+        ("mask01", "u8"),
+        ("mask02", "u8"),
+        ("mask03", "u8"),
+        ("mask04", "u8"),
+        ("mask05", "u8"),
+        ("mask06", "u8"),
+        ("mask07", "u8"),
+        ("mask08", "u8"),
+        ("mask09", "u8"),
+        ("mask10", "u8"),
+        ("mask11", "u8"),
+        ("mask12", "u8"),
+        ("mask13", "u8"),
+        ("mask14", "u8"),
+        ("mask15", "u8"),
+        ("mask16", "u8"),
+        ("mask17", "u8"),
+        ("mask18", "u8"),
+        ("mask19", "u8"),
+        ("mask20", "u8"),
+        ("mask21", "u8"),
+        ("mask22", "u8"),
+        ("mask23", "u8"),
+        ("mask24", "u8"),
+        ("mask25", "u8"),
+        ("mask26", "u8"),
+        ("mask27", "u8"),
+        ("mask28", "u8"),
+        ("mask29", "u8"),
+        ("mask30", "u8"),
+        ("mask31", "u8"),
+        ("mask32", "u8"),
+        ("mask33", "u8"),
+        ("mask34", "u8"),
+        ("mask35", "u8"),
+        ("mask36", "u8"),
+        ("mask37", "u8"),
+        ("mask38", "u8"),
+        ("mask39", "u8"),
+        ("mask40", "u8"),
+        ("mask41", "u8"),
+        ("mask42", "u8"),
+        ("mask43", "u8"),
+        ("mask44", "u8"),
+        ("mask45", "u8"),
+        ("mask46", "u8"),
+        ("mask47", "u8"),
+        ("mask48", "u8"),
+        ("mask49", "u8"),
+        ("mask50", "u8"),
+        ("mask51", "u8"),
+        ("mask52", "u8"),
+        ("mask53", "u8"),
+        ("mask54", "u8"),
+        ("mask55", "u8"),
+        ("mask56", "u8"),
+        ("mask57", "u8"),
+        ("mask58", "u8"),
+        ("mask59", "u8"),
+        ("mask60", "u8"),
+        ("mask61", "u8"),
+        ("mask62", "u8"),
+        ("mask63", "u8"),
+        ("mask64", "u8"),
+        ("mask65", "u8"),
+        ("mask66", "u8"),
+        ("mask67", "u8"),
+        ("mask68", "u8"),
+        ("mask69", "u8"),
+        ("mask70", "u8"),
+        ("mask71", "u8"),
+        ("mask72", "u8"),
+        ("mask73", "u8"),
+        ("mask74", "u8"),
+        ("mask75", "u8"),
+        ("mask76", "u8"),
+        ("mask77", "u8"),
+        ("mask78", "u8"),
+        ("mask79", "u8"),
+        ("mask80", "u8"),
+        ("mask81", "u8"),
+        ("mask82", "u8"),
+        ("mask83", "u8"),
+        ("mask84", "u8"),
+        ("mask85", "u8"),
+        ("mask86", "u8"),
+        ("mask87", "u8"),
+        ("mask88", "u8"),
+        ("mask89", "u8"),
+        ("mask90", "u8"),
+        ("mask91", "u8"),
+        ("mask92", "u8"),
+        ("mask93", "u8"),
+        ("mask94", "u8"),
+        ("mask95", "u8"),
+        ("mask96", "u8"),
+        ("mask97", "u8"),
+    ]),
+    ("EquipParamWeapon", &[
+        ("behavior_variation_id", "i32"),
+        ("sort_id", "i32"),
+        ("wandering_equip_id", "i32"),
+        ("weight", "f32"),
+        ("weapon_weight_rate", "f32"),
+        ("fix_price", "i32"),
+        ("basic_price", "i32"),
+        ("sell_value", "i32"),
+        ("correct_strength", "f32"),
+        ("correct_agility", "f32"),
+        ("corret_magic", "f32"),
+        ("corret_faith", "f32"),
+        ("phys_guard_cut_rate", "f32"),
+        ("mag_guard_cut_rate", "f32"),
+        ("fire_guard_cut_rate", "f32"),
+        ("thun_guard_cut_rate", "f32"),
+        ("sp_effect_behavior_id0", "i32"),
+        ("sp_effect_behavior_id1", "i32"),
+        ("sp_effect_behavior_id2", "i32"),
+        ("resident_sp_effect_id0", "i32"),
+        ("resident_sp_effect_id1", "i32"),
+        ("resident_sp_effect_id2", "i32"),
+        ("material_set_id", "i32"),
+        ("origin_equip_wep0", "i32"),
+        ("origin_equip_wep1", "i32"),
+        ("origin_equip_wep2", "i32"),
+        ("origin_equip_wep3", "i32"),
+        ("origin_equip_wep4", "i32"),
+        ("origin_equip_wep5", "i32"),
+        ("origin_equip_wep6", "i32"),
+        ("origin_equip_wep7", "i32"),
+        ("origin_equip_wep8", "i32"),
+        ("origin_equip_wep9", "i32"),
+        ("origin_equip_wep10", "i32"),
+        ("origin_equip_wep11", "i32"),
+        ("origin_equip_wep12", "i32"),
+        ("origin_equip_wep13", "i32"),
+        ("origin_equip_wep14", "i32"),
+        ("origin_equip_wep15", "i32"),
+        ("anti_demon_damage_rate", "f32"),
+        ("ant_undead_damage_rate", "f32"),
+        ("ant_hollow_damage_rate", "f32"),
+        ("ant_abyssal_damage_rate", "f32"),
+        ("vagrant_item_lot_id", "i32"),
+        ("vagrant_bonus_ene_drop_item_lot_id", "i32"),
+        ("vagrant_item_ene_drop_item_lot_id", "i32"),
+        ("equip_model_id", "i16"),
+        ("icon_id", "i16"),
+        ("durability", "i16"),
+        ("duraility_max", "i16"),
+        ("attack_throw_escape", "i16"),
+        ("parry_damage_life", "i16"),
+        ("atk_base_physics", "i16"),
+        ("atk_base_magic", "i16"),
+        ("atk_base_fire", "i16"),
+        ("atk_base_thunder", "i16"),
+        ("atk_base_stamina", "i16"),
+        ("sa_weapon_damage", "i16"),
+        ("sa_durability", "i16"),
+        ("guard_angle", "i16"),
+        ("stamina_guard_def", "i16"),
+        ("reinforce_type_id", "i16"),
+        ("trophy_sgrade_id", "i16"),
+        ("trophy_seq_id", "i16"),
+        ("throw_atk_rate", "i16"),
+        ("bow_dist_rate", "i16"),
+        ("equip_model_category", "u8"),
+        ("equip_model_gender", "u8"),
+        ("weapon_category", "u8"),
+        ("wepmotion_category", "u8"),
+        ("guardmotion_category", "u8"),
+        ("atk_material", "u8"),
+        ("def_material", "u8"),
+        ("def_sfx_material", "u8"),
+        ("correct_type", "u8"),
+        ("sp_attribute", "u8"),
+        ("sp_atk_category", "i16"),
+        ("wepmotion_one_hand_id", "u8"),
+        ("wepmotion_both_hand_id", "u8"),
+        ("proper_strength", "u8"),
+        ("proper_agility", "u8"),
+        ("proper_magic", "u8"),
+        ("proper_faith", "u8"),
+        ("over_strength", "u8"),
+        ("attack_base_parry", "u8"),
+        ("defense_base_parry", "u8"),
+        ("guard_base_repel", "u8"),
+        ("attack_base_repel", "u8"),
+        ("guard_cut_cancel_rate", "u8"),
+        ("guard_level", "u8"),
+        ("slash_guard_cut_rate", "u8"),
+        ("blow_guard_cut_rate", "u8"),
+        ("thrust_guard_cut_rate", "u8"),
+        ("poison_guard_resist", "u8"),
+        ("toxic_guard_resist", "u8"),
+        ("blood_guard_resist", "u8"),
+        ("curse_guard_resist", "u8"),
+        ("is_durability_divergence", "u8"),
+        ("rightHandEquipable", "bool"),
+        ("leftHandEquipable", "bool"),
+        ("bothHandEquipable", "bool"),
+        ("arrowSlotEquipable", "bool"),
+        ("boltSlotEquipable", "bool"),
+        ("enableGuard", "bool"),
+        ("enableParry", "bool"),
+        ("enableMagic", "bool"),
+        ("enablePyromancy", "bool"),
+        ("enableMiracle", "bool"),
+        ("enableVowMagic", "bool"),
+        ("isNormalAttackType", "bool"),
+        ("isBlowAttackType", "bool"),
+        ("isSlashAttackType", "bool"),
+        ("isThrustAttackType", "bool"),
+        ("isEnhance", "bool"),
+        ("isLuckCorrect", "bool"),
+        ("isCustom", "bool"),
+        ("disableBaseChangeReset", "bool"),
+        ("disableRepair", "bool"),
+        ("isDarkHand", "bool"),
+        ("simpleModelForDlc", "bool"),
+        ("ubyteLanternWep", "bool"),
+        ("isVersusGhostWep", "bool"),
+        ("baseChangeCategory", "bool"),
+        ("isDragonSlayer", "bool"),
+        ("isDeposit", "bool"),
+        ("disableMultiDropShare", "bool"),
+        ("IsDiscard", "bool"),
+        ("IsDrop", "bool"),
+        ("Bool3", "bool"),
+        ("Bool4", "bool"),
+        ("unk6", "u8"),
+        ("unk7", "u8"),
+        ("unk8", "u8"),
+        ("unk9", "u8"),
+        ("unk10", "u8"),
+        ("unk11", "u8"),
+        ("group0_atk_vfx_id", "i32"),
+        ("group0_dummy_poly_id0", "i32"),
+        ("group0_dummy_poly_id1", "i32"),
+        ("group1_atk_vfx_id", "i32"),
+        ("group1_dummy_poly_id0", "i32"),
+        ("group1_dummy_poly_id1", "i32"),
+        ("group2_atk_vfx_id", "i32"),
+        ("group2_dummy_poly_id0", "i32"),
+        ("group2_dummy_poly_id1", "i32"),
+        ("group3_atk_vfx_id", "i32"),
+        ("group3_dummy_poly_id0", "i32"),
+        ("group3_dummy_poly_id1", "i32"),
+        ("group4_atk_vfx_id", "i32"),
+        ("group4_dummy_poly_id0", "i32"),
+        ("group4_dummy_poly_id1", "i32"),
+        ("group5_atk_vfx_id", "i32"),
+        ("group5_dummy_poly_id0", "i32"),
+        ("group5_dummy_poly_id1", "i32"),
+        ("group6_atk_vfx_id", "i32"),
+        ("group6_dummy_poly_id0", "i32"),
+        ("group6_dummy_poly_id1", "i32"),
+        ("group7_atk_vfx_id", "i32"),
+        ("group7_dummy_poly_id0", "i32"),
+        ("group7_dummy_poly_id1", "i32"),
+        ("material_val0", "i16"),
+        ("material_val1", "i16"),
+        ("wep_absorp_pos_id", "i32"),
+        ("unk12", "f32"),
+        ("Bool5", "bool"),
+        ("Bool6", "bool"),
+        ("Bool7", "bool"),
+        ("Unk13", "bool"),
+        ("Unk14", "bool"),
+        ("IsAutoEquip", "bool"),
+        ("Unk16", "bool"),
+        ("Unk17", "bool"),
+        ("unk21", "u8"),
+        ("unk22", "u8"),
+        ("unk23", "u8"),
+        ("unk24", "f32"),
+        ("unk25", "f32"),
+        ("dark_guard_cut_rate", "f32"),
+        ("atk_base_dark", "i16"),
+        ("unk26", "u8"),
+        ("atk_throw_escape_a", "u8"),
+        ("sword_art_act_id", "i32"),
+        ("atk_throw_escape_b", "u8"),
+        ("unk30", "u8"),
+        ("unk31", "u8"),
+        ("menu_adhoc", "u8"),
+        ("sword_art_id", "i32"),
+        ("correct_luck", "f32"),
+        ("reinforce_weapon_id", "i32"),
+        ("unk34", "i16"),
+        ("display_type_id", "i16"),
+        ("calc_correct_val0", "f32"),
+        ("calc_correct_val1", "f32"),
+        ("calc_correct_val2", "f32"),
+        ("calc_correct_val3", "f32"),
+        ("calc_correct_val4", "f32"),
+        ("calc_correct_val5", "f32"),
+        ("calc_correct_val6", "f32"),
+        ("calc_correct_val7", "f32"),
+        ("calc_correct_val8", "f32"),
+        ("calc_correct_val9", "f32"),
+        ("calc_correct_val10", "f32"),
+        ("weapon_vfx0", "i32"),
+        ("weapon_vfx1", "i32"),
+        ("weapon_vfx2", "i32"),
+        ("weapon_vfx3", "i32"),
+        ("weapon_vfx4", "i32"),
+        ("weapon_vfx5", "i32"),
+        ("weapon_vfx6", "i32"),
+        ("weapon_vfx7", "i32"),
+        ("stamina_consume_rate", "f32"),
+        ("unk48", "f32"),
+        ("unk49", "f32"),
+        ("unk50", "f32"),
+        ("unk51", "f32"),
+        ("unk52", "f32"),
+        ("unk53", "f32"),
+        ("unk54", "f32"),
+        ("unk55", "f32"),
+        ("unk56", "i32"),
+        ("unk57", "i32"),
+        ("unk58", "i32"),
+        ("unk59", "i32"),
+        ("unk60", "i32"),
+        ("attack_element_correct_id", "i32"),
+        ("shop_price", "i32"),
+        ("unk62", "u8"),
+        ("max_num", "u8"),
+        ("Unk18", "bool"),
+        ("Unk19", "bool"),
+        ("Unk20", "bool"),
+        ("WepSpMask0", "bool"),
+        ("WepSpMask1", "bool"),
+        ("WepSpMask2", "bool"),
+        ("WepSpMask3", "bool"),
+        ("WepSpMask4", "bool"),
+        ("unk65", "u8"),
+        ("unk66", "i32"),
+        ("sp_eff9600", "i16"),
+    ]),
+    ("FaceGenParam", &[
+        ("face_geo_data01", "u8"),
+        ("face_geo_data02", "u8"),
+        ("face_geo_data03", "u8"),
+        ("face_geo_data04", "u8"),
+        ("face_geo_data05", "u8"),
+        ("face_geo_data06", "u8"),
+        ("face_geo_data07", "u8"),
+        ("face_geo_data08", "u8"),
+        ("face_geo_data09", "u8"),
+        ("face_geo_data10", "u8"),
+        ("face_geo_data11", "u8"),
+        ("face_geo_data12", "u8"),
+        ("face_geo_data13", "u8"),
+        ("face_geo_data14", "u8"),
+        ("face_geo_data15", "u8"),
+        ("face_geo_data16", "u8"),
+        ("face_geo_data17", "u8"),
+        ("face_geo_data18", "u8"),
+        ("face_geo_data19", "u8"),
+        ("face_geo_data20", "u8"),
+        ("face_geo_data21", "u8"),
+        ("face_geo_data22", "u8"),
+        ("face_geo_data23", "u8"),
+        ("face_geo_data24", "u8"),
+        ("face_geo_data25", "u8"),
+        ("face_geo_data26", "u8"),
+        ("face_geo_data27", "u8"),
+        ("face_geo_data28", "u8"),
+        ("face_geo_data29", "u8"),
+        ("face_geo_data30", "u8"),
+        ("face_geo_data31", "u8"),
+        ("face_geo_data32", "u8"),
+        ("face_geo_data33", "u8"),
+        ("face_geo_data34", "u8"),
+        ("face_geo_data35", "u8"),
+        ("face_geo_data36", "u8"),
+        ("face_geo_data37", "u8"),
+        ("face_geo_data38", "u8"),
+        ("face_geo_data39", "u8"),
+        ("face_geo_data40", "u8"),
+        ("face_geo_data41", "u8"),
+        ("face_geo_data42", "u8"),
+        ("face_geo_data43", "u8"),
+        ("face_geo_data44", "u8"),
+        ("face_geo_data45", "u8"),
+        ("face_geo_data46", "u8"),
+        ("face_geo_data47", "u8"),
+        ("face_geo_data48", "u8"),
+        ("face_geo_data49", "u8"),
+        ("face_tex_data00", "u8"),
+        ("face_tex_data01", "u8"),
+        ("face_tex_data02", "u8"),
+        ("face_tex_data03", "u8"),
+        ("face_tex_data04", "u8"),
+        ("face_tex_data05", "u8"),
+        ("face_tex_data06", "u8"),
+        ("face_tex_data07", "u8"),
+        ("face_tex_data08", "u8"),
+        ("face_tex_data09", "u8"),
+        ("face_tex_data10", "u8"),
+        ("face_tex_data11", "u8"),
+        ("face_tex_data12", "u8"),
+        ("face_tex_data13", "u8"),
+        ("face_tex_data14", "u8"),
+        ("face_tex_data15", "u8"),
+        ("face_tex_data16", "u8"),
+        ("face_tex_data17", "u8"),
+        ("face_tex_data18", "u8"),
+        ("face_tex_data19", "u8"),
+        ("face_tex_data20", "u8"),
+        ("face_tex_data21", "u8"),
+        ("face_tex_data22", "u8"),
+        ("face_tex_data23", "u8"),
+        ("face_tex_data24", "u8"),
+        ("face_tex_data25", "u8"),
+        ("face_tex_data26", "u8"),
+        ("face_tex_data27", "u8"),
+        ("face_tex_data28", "u8"),
+        ("face_tex_data29", "u8"),
+        ("face_tex_data30", "u8"),
+        ("face_tex_data31", "u8"),
+        ("face_tex_data32", "u8"),
+        ("face_tex_data33", "u8"),
+        ("face_tex_data34", "u8"),
+        ("face_tex_data35", "u8"),
+        ("face_tex_data36", "u8"),
+        ("face_tex_data37", "u8"),
+        ("face_tex_data38", "u8"),
+        ("face_tex_data39", "u8"),
+        ("face_tex_data40", "u8"),
+        ("face_tex_data41", "u8"),
+        ("face_tex_data42", "u8"),
+        ("face_tex_data43", "u8"),
+        ("face_tex_data44", "u8"),
+        ("face_tex_data45", "u8"),
+        ("face_tex_data46", "u8"),
+        ("face_tex_data47", "u8"),
+        ("face_tex_data48", "u8"),
+        ("face_tex_data49", "u8"),
+        ("face_parts_id", "u8"),
+        ("skin_color_r", "u8"),
+        ("skin_color_g", "u8"),
+        ("skin_color_b", "u8"),
+        ("hair_parts_id", "u8"),
+        ("hair_color_r", "u8"),
+        ("hair_color_g", "u8"),
+        ("hair_color_b", "u8"),
+        ("hair_color_g_0", "u8"),
+        ("eye_lparts_id", "u8"),
+        ("eye_lcolor_r", "u8"),
+        ("eye_lcolor_g", "u8"),
+        ("eye_lcolor_b", "u8"),
+        ("eye_rparts_id", "u8"),
+        ("eye_rcolor_r", "u8"),
+        ("eye_rcolor_g", "u8"),
+        ("eye_rcolor_b", "u8"),
+        ("eye_brow_parts_id", "u8"),
+        ("eye_brow_color_r", "u8"),
+        ("eye_brow_color_g", "u8"),
+        ("eye_brow_color_b", "u8"),
+        ("beard_parts_id", "u8"),
+        ("beard_color_r", "u8"),
+        ("beard_color_g", "u8"),
+        ("beard_color_b", "u8"),
+        ("accessories_parts_id", "u8"),
+        ("accessories_color_r", "u8"),
+        ("accessories_color_g", "u8"),
+        ("accessories_color_b", "u8"),
+        ("decal_parts_id", "u8"),
+        ("decal_color_r", "u8"),
+        ("decal_color_g", "u8"),
+        ("decal_color_b", "u8"),
+        ("decal_pos_x", "u8"),
+        ("decal_pos_y", "u8"),
+        ("decal_angle", "u8"),
+        ("decal_scale", "u8"),
+    ]),
+    ("FaceParam", &[
+        ("face_parts_id", "u8"),
+        ("skin_color_r", "u8"),
+        ("skin_color_g", "u8"),
+        ("skin_color_b", "u8"),
+        ("hair_parts_id", "u8"),
+        ("hair_color_r", "u8"),
+        ("hair_color_g", "u8"),
+        ("hair_color_b", "u8"),
+        ("eye_lparts_id", "u8"),
+        ("eye_lcolor_r", "u8"),
+        ("eye_lcolor_g", "u8"),
+        ("eye_lcolor_b", "u8"),
+        ("eye_rparts_id", "u8"),
+        ("eye_rcolor_r", "u8"),
+        ("eye_rcolor_g", "u8"),
+        ("eye_rcolor_b", "u8"),
+        ("eye_brow_parts_id", "u8"),
+        ("eye_brow_color_r", "u8"),
+        ("eye_brow_color_g", "u8"),
+        ("eye_brow_color_b", "u8"),
+        ("beard_parts_id", "u8"),
+        ("beard_color_r", "u8"),
+        ("beard_color_g", "u8"),
+        ("beard_color_b", "u8"),
+        ("accessories_parts_id", "u8"),
+        ("accessories_color_r", "u8"),
+        ("accessories_color_g", "u8"),
+        ("accessories_color_b", "u8"),
+        ("decal_parts_id", "u8"),
+        ("decal_color_r", "u8"),
+        ("decal_color_g", "u8"),
+        ("decal_color_b", "u8"),
+        ("decal_pos_x", "u8"),
+        ("decal_pos_y", "u8"),
+        ("decal_angle", "u8"),
+        ("decal_scale", "u8"),
+        ("chr_body_scale_head", "u8"),
+        ("chr_body_scale_breast", "u8"),
+        ("chr_body_scale_abdomen", "u8"),
+        ("chr_body_scale_arm", "u8"),
+        ("chr_body_scale_leg", "u8"),
+        ("age", "u8"),
+        ("gender", "u8"),
+        ("carricature_geometry", "u8"),
+        ("carricature_texture", "u8"),
+        ("face_geo_data00", "u8"),
+        ("face_geo_data01", "u8"),
+        ("face_geo_data02", "u8"),
+        ("face_geo_data03", "u8"),
+        ("face_geo_data04", "u8"),
+        ("face_geo_data05", "u8"),
+        ("face_geo_data06", "u8"),
+        ("face_geo_data07", "u8"),
+        ("face_geo_data08", "u8"),
+        ("face_geo_data09", "u8"),
+        ("face_geo_data10", "u8"),
+        ("face_geo_data11", "u8"),
+        ("face_geo_data12", "u8"),
+        ("face_geo_data13", "u8"),
+        ("face_geo_data14", "u8"),
+        ("face_geo_data15", "u8"),
+        ("face_geo_data16", "u8"),
+        ("face_geo_data17", "u8"),
+        ("face_geo_data18", "u8"),
+        ("face_geo_data19", "u8"),
+        ("face_geo_data20", "u8"),
+        ("face_geo_data21", "u8"),
+        ("face_geo_data22", "u8"),
+        ("face_geo_data23", "u8"),
+        ("face_geo_data24", "u8"),
+        ("face_geo_data25", "u8"),
+        ("face_geo_data26", "u8"),
+        ("face_geo_data27", "u8"),
+        ("face_geo_data28", "u8"),
+        ("face_geo_data29", "u8"),
+        ("face_geo_data30", "u8"),
+        ("face_geo_data31", "u8"),
+        ("face_geo_data32", "u8"),
+        ("face_geo_data33", "u8"),
+        ("face_geo_data34", "u8"),
+        ("face_geo_data35", "u8"),
+        ("face_geo_data36", "u8"),
+        ("face_geo_data37", "u8"),
+        ("face_geo_data38", "u8"),
+        ("face_geo_data39", "u8"),
+        ("face_geo_data40", "u8"),
+        ("face_geo_data41", "u8"),
+        ("face_geo_data42", "u8"),
+        ("face_geo_data43", "u8"),
+        ("face_geo_data44", "u8"),
+        ("face_geo_data45", "u8"),
+        ("face_geo_data46", "u8"),
+        ("face_geo_data47", "u8"),
+        ("face_geo_data48", "u8"),
+        ("face_geo_data49", "u8"),
+        ("face_geo_data50", "u8"),
+        ("face_geo_data51", "u8"),
+        ("face_geo_data52", "u8"),
+        ("face_geo_data53", "u8"),
+        ("face_geo_data54", "u8"),
+        ("face_geo_data55", "u8"),
+        ("face_geo_data56", "u8"),
+        ("face_geo_data57", "u8"),
+        ("face_geo_data58", "u8"),
+        ("face_geo_data59", "u8"),
+        ("face_geo_data60", "u8"),
+        ("face_tex_data00", "u8"),
+        ("face_tex_data01", "u8"),
+        ("face_tex_data02", "u8"),
+        ("face_tex_data03", "u8"),
+        ("face_tex_data04", "u8"),
+        ("face_tex_data05", "u8"),
+        ("face_tex_data06", "u8"),
+        ("face_tex_data07", "u8"),
+        ("face_tex_data08", "u8"),
+        ("face_tex_data09", "u8"),
+        ("face_tex_data10", "u8"),
+        ("face_tex_data11", "u8"),
+        ("face_tex_data12", "u8"),
+        ("face_tex_data13", "u8"),
+        ("face_tex_data14", "u8"),
+        ("face_tex_data15", "u8"),
+        ("face_tex_data16", "u8"),
+        ("face_tex_data17", "u8"),
+        ("face_tex_data18", "u8"),
+        ("face_tex_data19", "u8"),
+        ("face_tex_data20", "u8"),
+        ("face_tex_data21", "u8"),
+        ("face_tex_data22", "u8"),
+        ("face_tex_data23", "u8"),
+        ("face_tex_data24", "u8"),
+        ("face_tex_data25", "u8"),
+        ("face_tex_data26", "u8"),
+        ("face_tex_data27", "u8"),
+        ("face_tex_data28", "u8"),
+        ("face_tex_data29", "u8"),
+        ("face_tex_data30", "u8"),
+        ("face_tex_data31", "u8"),
+        ("face_tex_data32", "u8"),
+        ("face_tex_data33", "u8"),
+        ("face_tex_data34", "u8"),
+        ("face_tex_data35", "u8"),
+        ("face_geo_asym_data00", "u8"),
+        ("face_geo_asym_data01", "u8"),
+        ("face_geo_asym_data02", "u8"),
+        ("face_geo_asym_data03", "u8"),
+        ("face_geo_asym_data04", "u8"),
+        ("face_geo_asym_data05", "u8"),
+        ("face_geo_asym_data06", "u8"),
+        ("face_geo_asym_data07", "u8"),
+        ("face_geo_asym_data08", "u8"),
+        ("face_geo_asym_data09", "u8"),
+        ("face_geo_asym_data10", "u8"),
+        ("face_geo_asym_data11", "u8"),
+        ("face_geo_asym_data12", "u8"),
+        ("face_geo_asym_data13", "u8"),
+        ("face_geo_asym_data14", "u8"),
+        ("face_geo_asym_data15", "u8"),
+        ("face_geo_asym_data16", "u8"),
+        ("face_geo_asym_data17", "u8"),
+        ("face_geo_asym_data18", "u8"),
+        ("face_geo_asym_data19", "u8"),
+        ("face_geo_asym_data20", "u8"),
+        ("face_geo_asym_data21", "u8"),
+        ("face_geo_asym_data22", "u8"),
+        ("face_geo_asym_data23", "u8"),
+        ("face_geo_asym_data24", "u8"),
+        ("face_geo_asym_data25", "u8"),
+        ("face_geo_asym_data26", "u8"),
+        ("face_geo_asym_data27", "u8"),
+        ("face_geo_asym_data28", "u8"),
+        ("face_geo_asym_data29", "u8"),
+        ("face_geo_asym_data30", "u8"),
+        ("face_geo_asym_data31", "u8"),
+    ]),
+    ("FaceRangeParam", &[
+        ("face_geo_data00", "f32"),
+        ("face_geo_data01", "f32"),
+        ("face_geo_data02", "f32"),
+        ("face_geo_data03", "f32"),
+        ("face_geo_data04", "f32"),
+        ("face_geo_data05", "f32"),
+        ("face_geo_data06", "f32"),
+        ("face_geo_data07", "f32"),
+        ("face_geo_data08", "f32"),
+        ("face_geo_data09", "f32"),
+        ("face_geo_data10", "f32"),
+        ("face_geo_data11", "f32"),
+        ("face_geo_data12", "f32"),
+        ("face_geo_data13", "f32"),
+        ("face_geo_data14", "f32"),
+        ("face_geo_data15", "f32"),
+        ("face_geo_data16", "f32"),
+        ("face_geo_data17", "f32"),
+        ("face_geo_data18", "f32"),
+        ("face_geo_data19", "f32"),
+        ("face_geo_data20", "f32"),
+        ("face_geo_data21", "f32"),
+        ("face_geo_data22", "f32"),
+        ("face_geo_data23", "f32"),
+        ("face_geo_data24", "f32"),
+        ("face_geo_data25", "f32"),
+        ("face_geo_data26", "f32"),
+        ("face_geo_data27", "f32"),
+        ("face_geo_data28", "f32"),
+        ("face_geo_data29", "f32"),
+        ("face_geo_data30", "f32"),
+        ("face_geo_data31", "f32"),
+        ("face_geo_data32", "f32"),
+        ("face_geo_data33", "f32"),
+        ("face_geo_data34", "f32"),
+        ("face_geo_data35", "f32"),
+        ("face_geo_data36", "f32"),
+        ("face_geo_data37", "f32"),
+        ("face_geo_data38", "f32"),
+        ("face_geo_data39", "f32"),
+        ("face_geo_data40", "f32"),
+        ("face_geo_data41", "f32"),
+        ("face_geo_data42", "f32"),
+        ("face_geo_data43", "f32"),
+        ("face_geo_data44", "f32"),
+        ("face_geo_data45", "f32"),
+        ("face_geo_data46", "f32"),
+        ("face_geo_data47", "f32"),
+        ("face_geo_data48", "f32"),
+        ("face_geo_data49", "f32"),
+        ("face_geo_data50", "f32"),
+        ("face_geo_data51", "f32"),
+        ("face_geo_data52", "f32"),
+        ("face_geo_data53", "f32"),
+        ("face_geo_data54", "f32"),
+        ("face_geo_data55", "f32"),
+        ("face_geo_data56", "f32"),
+        ("face_geo_data57", "f32"),
+        ("face_geo_data58", "f32"),
+        ("face_geo_data59", "f32"),
+        ("face_geo_data60", "f32"),
+        ("face_tex_data00", "f32"),
+        ("face_tex_data01", "f32"),
+        ("face_tex_data02", "f32"),
+        ("face_tex_data03", "f32"),
+        ("face_tex_data04", "f32"),
+        ("face_tex_data05", "f32"),
+        ("face_tex_data06", "f32"),
+        ("face_tex_data07", "f32"),
+        ("face_tex_data08", "f32"),
+        ("face_tex_data09", "f32"),
+        ("face_tex_data10", "f32"),
+        ("face_tex_data11", "f32"),
+        ("face_tex_data12", "f32"),
+        ("face_tex_data13", "f32"),
+        ("face_tex_data14", "f32"),
+        ("face_tex_data15", "f32"),
+        ("face_tex_data16", "f32"),
+        ("face_tex_data17", "f32"),
+        ("face_tex_data18", "f32"),
+        ("face_tex_data19", "f32"),
+        ("face_tex_data20", "f32"),
+        ("face_tex_data21", "f32"),
+        ("face_tex_data22", "f32"),
+        ("face_tex_data23", "f32"),
+        ("face_tex_data24", "f32"),
+        ("face_tex_data25", "f32"),
+        ("face_tex_data26", "f32"),
+        ("face_tex_data27", "f32"),
+        ("face_tex_data28", "f32"),
+        ("face_tex_data29", "f32"),
+        ("face_tex_data30", "f32"),
+        ("face_tex_data31", "f32"),
+        ("face_tex_data32", "f32"),
+        ("face_tex_data33", "f32"),
+        ("face_tex_data34", "f32"),
+        ("face_tex_data35", "f32"),
+    ]),
+    ("FootSfxParam", &[
+        ("foot_sfx_id000", "i32"),
+        ("foot_sfx_id001", "i32"),
+        ("foot_sfx_id002", "i32"),
+        ("foot_sfx_id003", "i32"),
+        ("foot_sfx_id004", "i32"),
+        ("foot_sfx_id005", "i32"),
+        ("foot_sfx_id006", "i32"),
+        ("foot_sfx_id007", "i32"),
+        ("foot_sfx_id008", "i32"),
+        ("foot_sfx_id009", "i32"),
+        ("foot_sfx_id010", "i32"),
+        ("foot_sfx_id011", "i32"),
+        ("foot_sfx_id012", "i32"),
+        ("foot_sfx_id013", "i32"),
+        ("foot_sfx_id014", "i32"),
+        ("foot_sfx_id015", "i32"),
+        ("foot_sfx_id016", "i32"),
+        ("foot_sfx_id017", "i32"),
+        ("foot_sfx_id018", "i32"),
+        ("foot_sfx_id019", "i32"),
+        ("foot_sfx_id020", "i32"),
+        ("foot_sfx_id021", "i32"),
+        ("foot_sfx_id022", "i32"),
+        ("foot_sfx_id023", "i32"),
+        ("foot_sfx_id024", "i32"),
+        ("foot_sfx_id025", "i32"),
+        ("foot_sfx_id026", "i32"),
+        ("foot_sfx_id027", "i32"),
+        ("foot_sfx_id028", "i32"),
+        ("foot_sfx_id029", "i32"),
+        ("foot_sfx_id030", "i32"),
+        ("foot_sfx_id031", "i32"),
+        ("foot_sfx_id032", "i32"),
+        ("foot_sfx_id033", "i32"),
+        ("foot_sfx_id034", "i32"),
+        ("foot_sfx_id035", "i32"),
+        ("foot_sfx_id036", "i32"),
+        ("foot_sfx_id037", "i32"),
+        ("foot_sfx_id038", "i32"),
+        ("foot_sfx_id039", "i32"),
+        ("foot_sfx_id040", "i32"),
+        ("foot_sfx_id041", "i32"),
+        ("foot_sfx_id042", "i32"),
+        ("foot_sfx_id043", "i32"),
+        ("foot_sfx_id044", "i32"),
+        ("foot_sfx_id045", "i32"),
+        ("foot_sfx_id046", "i32"),
+        ("foot_sfx_id047", "i32"),
+        ("foot_sfx_id048", "i32"),
+        ("foot_sfx_id049", "i32"),
+        ("foot_sfx_id050", "i32"),
+        ("foot_sfx_id051", "i32"),
+        ("foot_sfx_id052", "i32"),
+        ("foot_sfx_id053", "i32"),
+        ("foot_sfx_id054", "i32"),
+        ("foot_sfx_id055", "i32"),
+        ("foot_sfx_id056", "i32"),
+        ("foot_sfx_id057", "i32"),
+        ("foot_sfx_id058", "i32"),
+        ("foot_sfx_id059", "i32"),
+        ("foot_sfx_id060", "i32"),
+        ("foot_sfx_id061", "i32"),
+        ("foot_sfx_id062", "i32"),
+        ("foot_sfx_id063", "i32"),
+        ("foot_sfx_id064", "i32"),
+        ("foot_sfx_id065", "i32"),
+        ("foot_sfx_id066", "i32"),
+        ("foot_sfx_id067", "i32"),
+        ("foot_sfx_id068", "i32"),
+        ("foot_sfx_id069", "i32"),
+        ("foot_sfx_id070", "i32"),
+        ("foot_sfx_id071", "i32"),
+        ("foot_sfx_id072", "i32"),
+        ("foot_sfx_id073", "i32"),
+        ("foot_sfx_id074", "i32"),
+        ("foot_sfx_id075", "i32"),
+        ("foot_sfx_id076", "i32"),
+        ("foot_sfx_id077", "i32"),
+        ("foot_sfx_id078", "i32"),
+        ("foot_sfx_id079", "i32"),
+        ("foot_sfx_id080", "i32"),
+        ("foot_sfx_id081", "i32"),
+        ("foot_sfx_id082", "i32"),
+        ("foot_sfx_id083", "i32"),
+        ("foot_sfx_id084", "i32"),
+        ("foot_sfx_id085", "i32"),
+        ("foot_sfx_id086", "i32"),
+        ("foot_sfx_id087", "i32"),
+        ("foot_sfx_id088", "i32"),
+        ("foot_sfx_id089", "i32"),
+        ("foot_sfx_id090", "i32"),
+        ("foot_sfx_id091", "i32"),
+        ("foot_sfx_id092", "i32"),
+        ("foot_sfx_id093", "i32"),
+        ("foot_sfx_id094", "i32"),
+        ("foot_sfx_id095", "i32"),
+        ("foot_sfx_id096", "i32"),
+        ("foot_sfx_id097", "i32"),
+        ("foot_sfx_id098", "i32"),
+        ("foot_sfx_id099", "i32"),
+        ("foot_sfx_id100", "i32"),
+        ("foot_sfx_id101", "i32"),
+        ("foot_sfx_id102", "i32"),
+        ("foot_sfx_id103", "i32"),
+        ("foot_sfx_id104", "i32"),
+        ("foot_sfx_id105", "i32"),
+        ("foot_sfx_id106", "i32"),
+        ("foot_sfx_id107", "i32"),
+        ("foot_sfx_id108", "i32"),
+        ("foot_sfx_id109", "i32"),
+        ("foot_sfx_id110", "i32"),
+        ("foot_sfx_id111", "i32"),
+        ("foot_sfx_id112", "i32"),
+        ("foot_sfx_id113", "i32"),
+        ("foot_sfx_id114", "i32"),
+        ("foot_sfx_id115", "i32"),
+        ("foot_sfx_id116", "i32"),
+        ("foot_sfx_id117", "i32"),
+        ("foot_sfx_id118", "i32"),
+        ("foot_sfx_id119", "i32"),
+        ("foot_sfx_id120", "i32"),
+        ("foot_sfx_id121", "i32"),
+        ("foot_sfx_id122", "i32"),
+        ("foot_sfx_id123", "i32"),
+        ("foot_sfx_id124", "i32"),
+        ("foot_sfx_id125", "i32"),
+        ("foot_sfx_id126", "i32"),
+        ("foot_sfx_id127", "i32"),
+        ("foot_sfx_id128", "i32"),
+        ("foot_sfx_id129", "i32"),
+        ("foot_sfx_id130", "i32"),
+        ("foot_sfx_id131", "i32"),
+        ("foot_sfx_id132", "i32"),
+        ("foot_sfx_id133", "i32"),
+        ("foot_sfx_id134", "i32"),
+        ("foot_sfx_id135", "i32"),
+        ("foot_sfx_id136", "i32"),
+        ("foot_sfx_id137", "i32"),
+        ("foot_sfx_id138", "i32"),
+        ("foot_sfx_id139", "i32"),
+        ("foot_sfx_id140", "i32"),
+        ("foot_sfx_id141", "i32"),
+        ("foot_sfx_id142", "i32"),
+        ("foot_sfx_id143", "i32"),
+        ("foot_sfx_id144", "i32"),
+        ("foot_sfx_id145", "i32"),
+        ("foot_sfx_id146", "i32"),
+        ("foot_sfx_id147", "i32"),
+        ("foot_sfx_id148", "i32"),
+        ("foot_sfx_id149", "i32"),
+        ("foot_sfx_id150", "i32"),
+        ("foot_sfx_id151", "i32"),
+        ("foot_sfx_id152", "i32"),
+        ("foot_sfx_id153", "i32"),
+        ("foot_sfx_id154", "i32"),
+        ("foot_sfx_id155", "i32"),
+        ("foot_sfx_id156", "i32"),
+        ("foot_sfx_id157", "i32"),
+        ("foot_sfx_id158", "i32"),
+        ("foot_sfx_id159", "i32"),
+        ("foot_sfx_id160", "i32"),
+        ("foot_sfx_id161", "i32"),
+        ("foot_sfx_id162", "i32"),
+        ("foot_sfx_id163", "i32"),
+        ("foot_sfx_id164", "i32"),
+        ("foot_sfx_id165", "i32"),
+        ("foot_sfx_id166", "i32"),
+        ("foot_sfx_id167", "i32"),
+        ("foot_sfx_id168", "i32"),
+        ("foot_sfx_id169", "i32"),
+        ("foot_sfx_id170", "i32"),
+        ("foot_sfx_id171", "i32"),
+        ("foot_sfx_id172", "i32"),
+        ("foot_sfx_id173", "i32"),
+        ("foot_sfx_id174", "i32"),
+        ("foot_sfx_id175", "i32"),
+        ("foot_sfx_id176", "i32"),
+        ("foot_sfx_id177", "i32"),
+        ("foot_sfx_id178", "i32"),
+        ("foot_sfx_id179", "i32"),
+        ("foot_sfx_id180", "i32"),
+        ("foot_sfx_id181", "i32"),
+        ("foot_sfx_id182", "i32"),
+        ("foot_sfx_id183", "i32"),
+        ("foot_sfx_id184", "i32"),
+        ("foot_sfx_id185", "i32"),
+        ("foot_sfx_id186", "i32"),
+        ("foot_sfx_id187", "i32"),
+        ("foot_sfx_id188", "i32"),
+        ("foot_sfx_id189", "i32"),
+        ("foot_sfx_id190", "i32"),
+        ("foot_sfx_id191", "i32"),
+        ("foot_sfx_id192", "i32"),
+        ("foot_sfx_id193", "i32"),
+        ("foot_sfx_id194", "i32"),
+        ("foot_sfx_id195", "i32"),
+        ("foot_sfx_id196", "i32"),
+        ("foot_sfx_id197", "i32"),
+        ("foot_sfx_id198", "i32"),
+        ("foot_sfx_id199", "i32"),
+    ]),
+    ("GameAreaParam", &[
+        ("bonus_soul_single", "i32"),
+        ("bonus_soul_multi", "i32"),
+        ("humanity_point_count_flag_id_top", "i32"),
+        ("humanity_drop_point1", "i16"),
+        ("humanity_drop_point2", "i16"),
+        ("humanity_drop_point3", "i16"),
+        ("humanity_drop_point4", "i16"),
+        ("humanity_drop_point5", "i16"),
+        ("humanity_drop_point6", "i16"),
+        ("humanity_drop_point7", "i16"),
+        ("humanity_drop_point8", "i16"),
+        ("humanity_drop_point9", "i16"),
+        ("humanity_drop_point10", "i16"),
+        ("sub_bonus_soul_single", "i32"),
+        ("subbonus_soul_multi", "i32"),
+    ]),
+    ("GameProgressParam", &[("event_flag_id", "i32"), ("progress_id", "u8")]),
+    ("GemCategoryParam", &[
+        ("sort_no", "i32"),
+        ("manifest_rate", "f32"),
+        ("directional_id", "i32"),
+        ("cate_group_id", "i32"),
+        ("exclude_group_id", "i32"),
+        ("isNegative", "bool"),
+        ("enableSlotTypeA", "bool"),
+        ("enableSlotTypeB", "bool"),
+        ("enableSlotTypeC", "bool"),
+        ("enableSlotTypeD", "bool"),
+        ("enableSlotTypeE", "bool"),
+        ("enableSlotTypeF", "bool"),
+        ("holygrailTypeGroup", "bool"),
+        ("affinity_cate_id_0", "i32"),
+        ("affinity_modify_rate_0", "f32"),
+        ("affinity_cate_id_1", "i32"),
+        ("affinity_modify_rate_1", "f32"),
+        ("affinity_cate_id_2", "i32"),
+        ("affinity_modify_rate_2", "f32"),
+        ("affinity_cate_id_3", "i32"),
+        ("affinity_modify_rate_3", "f32"),
+    ]),
+    ("GemDropDopingParam", &[
+        ("rank_min", "i32"),
+        ("rank_max", "i32"),
+        ("normal_distribution_ave", "i32"),
+        ("normal_distribution_sigma", "i32"),
+        ("slot_type_a", "f32"),
+        ("slot_type_b", "f32"),
+        ("slot_type_c", "f32"),
+        ("slot_type_d", "f32"),
+        ("slot_type_e", "f32"),
+        ("slot_type_f", "f32"),
+        ("directional_id_rate_0", "f32"),
+        ("directional_id_rate_1", "f32"),
+        ("directional_id_rate_2", "f32"),
+        ("directional_id_rate_3", "f32"),
+        ("directional_id_rate_4", "f32"),
+        ("directional_id_rate_5", "f32"),
+        ("directional_id_rate_6", "f32"),
+        ("directional_id_rate_7", "f32"),
+    ]),
+    ("GemDropModifyParam", &[
+        ("slot_type_rate_a", "f32"),
+        ("slot_type_rate_b", "f32"),
+        ("slot_type_rate_c", "f32"),
+        ("slot_type_rate_d", "f32"),
+        ("slot_type_rate_e", "f32"),
+        ("slot_type_rate_f", "f32"),
+        ("directional_id_rate_0", "f32"),
+        ("directional_id_rate_1", "f32"),
+        ("directional_id_rate_2", "f32"),
+        ("directional_id_rate_3", "f32"),
+        ("directional_id_rate_4", "f32"),
+        ("directional_id_rate_5", "f32"),
+        ("directional_id_rate_6", "f32"),
+        ("directional_id_rate_7", "f32"),
+        ("affinity_cate_id_0", "i32"),
+        ("affinity_modify_rate_0", "f32"),
+        ("affinity_cate_id_1", "i32"),
+        ("affinity_modify_rate_1", "f32"),
+        ("affinity_cate_id_2", "i32"),
+        ("affinity_modify_rate_2", "f32"),
+        ("affinity_cate_id_3", "i32"),
+        ("affinity_modify_rate_3", "f32"),
+        ("manifest_rate_0", "f32"),
+        ("manifest_rate_1", "f32"),
+        ("manifest_rate_2", "f32"),
+        ("manifest_rate_3", "f32"),
+        ("manifest_rate_4", "f32"),
+        ("manifest_rate_5", "f32"),
+        ("negativize_rate_0", "f32"),
+        ("normal_distribution_ave", "i32"),
+        ("normal_distribution_sigma", "i32"),
+    ]),
+    ("GemGenParam", &[
+        ("field0x04", "i32"),
+        ("gem_name_id_offset", "i32"),
+        ("disable_slot_rate_modify", "i32"),
+        ("slot_type_rate_a", "f32"),
+        ("slot_type_rate_b", "f32"),
+        ("slot_type_rate_c", "f32"),
+        ("slot_type_rate_d", "f32"),
+        ("slot_type_rate_e", "f32"),
+        ("slot_type_rate_f", "f32"),
+        ("gem_rank_doping", "u8"),
+        ("gemeffect_gen_param_type_0", "i32"),
+        ("gemeffect_gen_param_0", "i32"),
+        ("manifest_rate_0", "f32"),
+        ("negativize_rate_0", "f32"),
+        ("gemeffect_gen_param_type_1", "i32"),
+        ("gemeffect_gen_param_1", "i32"),
+        ("manifest_rate_1", "f32"),
+        ("negativize_rate_1", "f32"),
+        ("gemeffect_gen_param_type_2", "i32"),
+        ("gemeffect_gen_param_2", "i32"),
+        ("manifest_rate_2", "f32"),
+        ("negativize_rate_2", "f32"),
+        ("gemeffect_gen_param_type_3", "i32"),
+        ("gemeffect_gen_param_3", "i32"),
+        ("manifest_rate_3", "f32"),
+        ("negativize_rate_3", "f32"),
+        ("gemeffect_gen_param_type_4", "i32"),
+        ("gemeffect_gen_param_4", "i32"),
+        ("manifest_rate_4", "f32"),
+        ("negativize_rate_4", "f32"),
+        ("gemeffect_gen_param_type_5", "i32"),
+        ("gemeffect_gen_param_5", "i32"),
+        ("manifest_rate_5", "f32"),
+        ("negativize_rate_5", "f32"),
+    ]),
+    ("GemeffectParam", &[
+        ("sp_effect_id", "i32"),
+        ("category_id", "i32"),
+        ("effect_rank", "i32"),
+        ("rank_min", "i32"),
+        ("rank_max", "i32"),
+        ("disposal_price", "i32"),
+        ("gem_icon_id_offset", "i16"),
+        ("sp_effect_id_for_atk", "i32"),
+    ]),
+    ("HPEstusFlaskRecoveryParam", &[
+        ("recovery_count0", "u8"),
+        ("recovery_count1", "u8"),
+        ("recovery_count2", "u8"),
+        ("recovery_count3", "u8"),
+        ("recovery_count4", "u8"),
+        ("recovery_count5", "u8"),
+        ("recovery_count6", "u8"),
+        ("recovery_count7", "u8"),
+        ("recovery_count8", "u8"),
+        ("recovery_count9", "u8"),
+        ("recovery_count10", "u8"),
+        ("recovery_count11", "u8"),
+        ("recovery_count12", "u8"),
+        ("recovery_count13", "u8"),
+        ("recovery_count14", "u8"),
+        ("recovery_count15", "u8"),
+        ("recovery_count16", "u8"),
+        ("recovery_count17", "u8"),
+        ("recovery_count18", "u8"),
+        ("recovery_count19", "u8"),
+        ("recovery_count20", "u8"),
+        ("recovery_count21", "u8"),
+        ("recovery_count22", "u8"),
+        ("recovery_count23", "u8"),
+    ]),
+    ("HitEffectSeParam", &[
+        ("h00_hit_effect_se_id0", "i32"),
+        ("h00_hit_effect_se_id1", "i32"),
+        ("h00_hit_effect_se_id2", "i32"),
+        ("h00_hit_effect_se_id3", "i32"),
+        ("h00_hit_effect_se_id4", "i32"),
+        ("h00_hit_effect_se_id5", "i32"),
+        ("h00_hit_effect_se_id6", "i32"),
+        ("h00_hit_effect_se_id7", "i32"),
+        ("h01_hit_effect_se_id0", "i32"),
+        ("h01_hit_effect_se_id1", "i32"),
+        ("h01_hit_effect_se_id2", "i32"),
+        ("h01_hit_effect_se_id3", "i32"),
+        ("h01_hit_effect_se_id4", "i32"),
+        ("h01_hit_effect_se_id5", "i32"),
+        ("h01_hit_effect_se_id6", "i32"),
+        ("h01_hit_effect_se_id7", "i32"),
+        ("h02_hit_effect_se_id0", "i32"),
+        ("h02_hit_effect_se_id1", "i32"),
+        ("h02_hit_effect_se_id2", "i32"),
+        ("h02_hit_effect_se_id3", "i32"),
+        ("h02_hit_effect_se_id4", "i32"),
+        ("h02_hit_effect_se_id5", "i32"),
+        ("h02_hit_effect_se_id6", "i32"),
+        ("h02_hit_effect_se_id7", "i32"),
+        ("h03_hit_effect_se_id0", "i32"),
+        ("h03_hit_effect_se_id1", "i32"),
+        ("h03_hit_effect_se_id2", "i32"),
+        ("h03_hit_effect_se_id3", "i32"),
+        ("h03_hit_effect_se_id4", "i32"),
+        ("h03_hit_effect_se_id5", "i32"),
+        ("h03_hit_effect_se_id6", "i32"),
+        ("h03_hit_effect_se_id7", "i32"),
+        ("h04_hit_effect_se_id0", "i32"),
+        ("h04_hit_effect_se_id1", "i32"),
+        ("h04_hit_effect_se_id2", "i32"),
+        ("h04_hit_effect_se_id3", "i32"),
+        ("h04_hit_effect_se_id4", "i32"),
+        ("h04_hit_effect_se_id5", "i32"),
+        ("h04_hit_effect_se_id6", "i32"),
+        ("h04_hit_effect_se_id7", "i32"),
+        ("h05_hit_effect_se_id0", "i32"),
+        ("h05_hit_effect_se_id1", "i32"),
+        ("h05_hit_effect_se_id2", "i32"),
+        ("h05_hit_effect_se_id3", "i32"),
+        ("h05_hit_effect_se_id4", "i32"),
+        ("h05_hit_effect_se_id5", "i32"),
+        ("h05_hit_effect_se_id6", "i32"),
+        ("h05_hit_effect_se_id7", "i32"),
+        ("h06_hit_effect_se_id0", "i32"),
+        ("h06_hit_effect_se_id1", "i32"),
+        ("h06_hit_effect_se_id2", "i32"),
+        ("h06_hit_effect_se_id3", "i32"),
+        ("h06_hit_effect_se_id4", "i32"),
+        ("h06_hit_effect_se_id5", "i32"),
+        ("h06_hit_effect_se_id6", "i32"),
+        ("h06_hit_effect_se_id7", "i32"),
+        ("h07_hit_effect_se_id0", "i32"),
+        ("h07_hit_effect_se_id1", "i32"),
+        ("h07_hit_effect_se_id2", "i32"),
+        ("h07_hit_effect_se_id3", "i32"),
+        ("h07_hit_effect_se_id4", "i32"),
+        ("h07_hit_effect_se_id5", "i32"),
+        ("h07_hit_effect_se_id6", "i32"),
+        ("h07_hit_effect_se_id7", "i32"),
+        ("h08_hit_effect_se_id0", "i32"),
+        ("h08_hit_effect_se_id1", "i32"),
+        ("h08_hit_effect_se_id2", "i32"),
+        ("h08_hit_effect_se_id3", "i32"),
+        ("h08_hit_effect_se_id4", "i32"),
+        ("h08_hit_effect_se_id5", "i32"),
+        ("h08_hit_effect_se_id6", "i32"),
+        ("h08_hit_effect_se_id7", "i32"),
+        ("h09_hit_effect_se_id0", "i32"),
+        ("h09_hit_effect_se_id1", "i32"),
+        ("h09_hit_effect_se_id2", "i32"),
+        ("h09_hit_effect_se_id3", "i32"),
+        ("h09_hit_effect_se_id4", "i32"),
+        ("h09_hit_effect_se_id5", "i32"),
+        ("h09_hit_effect_se_id6", "i32"),
+        ("h09_hit_effect_se_id7", "i32"),
+        ("h10_hit_effect_se_id0", "i32"),
+        ("h10_hit_effect_se_id1", "i32"),
+        ("h10_hit_effect_se_id2", "i32"),
+        ("h10_hit_effect_se_id3", "i32"),
+        ("h10_hit_effect_se_id4", "i32"),
+        ("h10_hit_effect_se_id5", "i32"),
+        ("h10_hit_effect_se_id6", "i32"),
+        ("h10_hit_effect_se_id7", "i32"),
+        ("h11_hit_effect_se_id0", "i32"),
+        ("h11_hit_effect_se_id1", "i32"),
+        ("h11_hit_effect_se_id2", "i32"),
+        ("h11_hit_effect_se_id3", "i32"),
+        ("h11_hit_effect_se_id4", "i32"),
+        ("h11_hit_effect_se_id5", "i32"),
+        ("h11_hit_effect_se_id6", "i32"),
+        ("h11_hit_effect_se_id7", "i32"),
+        ("h12_hit_effect_se_id0", "i32"),
+        ("h12_hit_effect_se_id1", "i32"),
+        ("h12_hit_effect_se_id2", "i32"),
+        ("h12_hit_effect_se_id3", "i32"),
+        ("h12_hit_effect_se_id4", "i32"),
+        ("h12_hit_effect_se_id5", "i32"),
+        ("h12_hit_effect_se_id6", "i32"),
+        ("h12_hit_effect_se_id7", "i32"),
+        ("h13_hit_effect_se_id0", "i32"),
+        ("h13_hit_effect_se_id1", "i32"),
+        ("h13_hit_effect_se_id2", "i32"),
+        ("h13_hit_effect_se_id3", "i32"),
+        ("h13_hit_effect_se_id4", "i32"),
+        ("h13_hit_effect_se_id5", "i32"),
+        ("h13_hit_effect_se_id6", "i32"),
+        ("h13_hit_effect_se_id7", "i32"),
+        ("h14_hit_effect_se_id0", "i32"),
+        ("h14_hit_effect_se_id1", "i32"),
+        ("h14_hit_effect_se_id2", "i32"),
+        ("h14_hit_effect_se_id3", "i32"),
+        ("h14_hit_effect_se_id4", "i32"),
+        ("h14_hit_effect_se_id5", "i32"),
+        ("h14_hit_effect_se_id6", "i32"),
+        ("h14_hit_effect_se_id7", "i32"),
+        ("h15_hit_effect_se_id0", "i32"),
+        ("h15_hit_effect_se_id1", "i32"),
+        ("h15_hit_effect_se_id2", "i32"),
+        ("h15_hit_effect_se_id3", "i32"),
+        ("h15_hit_effect_se_id4", "i32"),
+        ("h15_hit_effect_se_id5", "i32"),
+        ("h15_hit_effect_se_id6", "i32"),
+        ("h15_hit_effect_se_id7", "i32"),
+        ("h16_hit_effect_se_id0", "i32"),
+        ("h16_hit_effect_se_id1", "i32"),
+        ("h16_hit_effect_se_id2", "i32"),
+        ("h16_hit_effect_se_id3", "i32"),
+        ("h16_hit_effect_se_id4", "i32"),
+        ("h16_hit_effect_se_id5", "i32"),
+        ("h16_hit_effect_se_id6", "i32"),
+        ("h16_hit_effect_se_id7", "i32"),
+        ("h17_hit_effect_se_id0", "i32"),
+        ("h17_hit_effect_se_id1", "i32"),
+        ("h17_hit_effect_se_id2", "i32"),
+        ("h17_hit_effect_se_id3", "i32"),
+        ("h17_hit_effect_se_id4", "i32"),
+        ("h17_hit_effect_se_id5", "i32"),
+        ("h17_hit_effect_se_id6", "i32"),
+        ("h17_hit_effect_se_id7", "i32"),
+        ("h18_hit_effect_se_id0", "i32"),
+        ("h18_hit_effect_se_id1", "i32"),
+        ("h18_hit_effect_se_id2", "i32"),
+        ("h18_hit_effect_se_id3", "i32"),
+        ("h18_hit_effect_se_id4", "i32"),
+        ("h18_hit_effect_se_id5", "i32"),
+        ("h18_hit_effect_se_id6", "i32"),
+        ("h18_hit_effect_se_id7", "i32"),
+        ("h19_hit_effect_se_id0", "i32"),
+        ("h19_hit_effect_se_id1", "i32"),
+        ("h19_hit_effect_se_id2", "i32"),
+        ("h19_hit_effect_se_id3", "i32"),
+        ("h19_hit_effect_se_id4", "i32"),
+        ("h19_hit_effect_se_id5", "i32"),
+        ("h19_hit_effect_se_id6", "i32"),
+        ("h19_hit_effect_se_id7", "i32"),
+    ]),
+    ("HitEffectSfxConceptParam", &[
+        ("sfx_concept_id0", "i16"),
+        ("sfx_concept_id1", "i16"),
+        ("sfx_concept_id2", "i16"),
+        ("sfx_concept_id3", "i16"),
+        ("sfx_concept_id4", "i16"),
+        ("sfx_concept_id5", "i16"),
+        ("sfx_concept_id6", "i16"),
+        ("sfx_concept_id7", "i16"),
+        ("sfx_concept_id8", "i16"),
+        ("sfx_concept_id9", "i16"),
+        ("sfx_concept_id10", "i16"),
+        ("sfx_concept_id11", "i16"),
+        ("sfx_concept_id12", "i16"),
+        ("sfx_concept_id13", "i16"),
+        ("sfx_concept_id14", "i16"),
+        ("sfx_concept_id15", "i16"),
+        ("sfx_concept_id16", "i16"),
+        ("sfx_concept_id17", "i16"),
+        ("sfx_concept_id18", "i16"),
+        ("sfx_concept_id19", "i16"),
+        ("sfx_concept_id20", "i16"),
+        ("sfx_concept_id21", "i16"),
+        ("sfx_concept_id22", "i16"),
+        ("sfx_concept_id23", "i16"),
+        ("sfx_concept_id24", "i16"),
+        ("sfx_concept_id25", "i16"),
+        ("sfx_concept_id26", "i16"),
+        ("sfx_concept_id27", "i16"),
+        ("sfx_concept_id28", "i16"),
+        ("sfx_concept_id29", "i16"),
+    ]),
+    ("HitEffectSfxParam", &[
+        ("hit_sfx_id0", "i32"),
+        ("hit_sfx_id1", "i32"),
+        ("hit_sfx_id2", "i32"),
+        ("hit_sfx_id3", "i32"),
+        ("hit_sfx_id4", "i32"),
+        ("hit_sfx_id5", "i32"),
+        ("hit_sfx_id6", "i32"),
+        ("hit_sfx_id7", "i32"),
+        ("hit_sfx_id8", "i32"),
+        ("hit_sfx_id9", "i32"),
+        ("hit_sfx_id10", "i32"),
+        ("hit_sfx_id11", "i32"),
+        ("hit_sfx_id12", "i32"),
+        ("hit_sfx_id13", "i32"),
+        ("hit_sfx_id14", "i32"),
+        ("hit_sfx_id15", "i32"),
+    ]),
+    ("HitMtrlParam", &[
+        ("ai_volume_rate", "f32"),
+        ("sp_effect_id0", "i32"),
+        ("sp_effect_id1", "i32"),
+        ("FootEffectHeightType0", "bool"),
+        ("FootEffectHeightType1", "bool"),
+        ("footEffectDirType0", "bool"),
+        ("footEffectDirType1", "bool"),
+        ("newSpType0", "bool"),
+        ("newSpType1", "bool"),
+        ("unkb1", "bool"),
+        ("unkb2", "bool"),
+        ("hit_mtrl_type0", "u8"),
+        ("hit_mtrl_type1", "u8"),
+        ("hit_mtrl_type2", "u8"),
+        ("sp_effect_id2", "i32"),
+        ("sp_effect_id3", "i32"),
+        ("sp_effect_id4", "i32"),
+        ("sp_effect_id5", "i32"),
+        ("sp_effect_id6", "i32"),
+        ("sp_effect_id7", "i32"),
+        ("sp_effect_id8", "i32"),
+        ("sp_effect_id9", "i32"),
+        ("sp_effect_id10", "i32"),
+        ("sp_effect_id11", "i32"),
+        ("sp_effect_id12", "i32"),
+        ("sp_effect_id13", "i32"),
+        ("sp_effect_id14", "i32"),
+        ("sp_effect_id15", "i32"),
+        ("sp_effect_id16", "i32"),
+    ]),
+    ("ItemLotParam", &[
+        ("item_lot_id1", "i32"),
+        ("item_lot_id2", "i32"),
+        ("item_lot_id3", "i32"),
+        ("item_lot_id4", "i32"),
+        ("item_lot_id5", "i32"),
+        ("item_lot_id6", "i32"),
+        ("item_lot_id7", "i32"),
+        ("item_lot_id8", "i32"),
+        ("lot_item_category01", "u32"),
+        ("lot_item_category02", "u32"),
+        ("lot_item_category03", "u32"),
+        ("lot_item_category04", "u32"),
+        ("lot_item_category05", "u32"),
+        ("lot_item_category06", "u32"),
+        ("lot_item_category07", "u32"),
+        ("lot_item_category08", "u32"),
+        ("lot_item_base_point01", "i16"),
+        ("lot_item_base_point02", "i16"),
+        ("lot_item_base_point03", "i16"),
+        ("lot_item_base_point04", "i16"),
+        ("lot_item_base_point05", "i16"),
+        ("lot_item_base_point06", "i16"),
+        ("lot_item_base_point07", "i16"),
+        ("lot_item_base_point08", "i16"),
+        ("cumulate_lot_point01", "i16"),
+        ("cumulate_lot_point02", "i16"),
+        ("cumulate_lot_point03", "i16"),
+        ("cumulate_lot_point04", "i16"),
+        ("cumulate_lot_point05", "i16"),
+        ("cumulate_lot_point06", "i16"),
+        ("cumulate_lot_point07", "i16"),
+        ("cumulate_lot_point08", "i16"),
+        ("get_item_flag_id01", "i32"),
+        ("get_item_flag_id02", "i32"),
+        ("get_item_flag_id03", "i32"),
+        ("get_item_flag_id04", "i32"),
+        ("get_item_flag_id05", "i32"),
+        ("get_item_flag_id06", "i32"),
+        ("get_item_flag_id07", "i32"),
+        ("get_item_flag_id08", "i32"),
+        ("get_item_flag_id", "i32"),
+        ("cumulate_num_flag_id", "i32"),
+        ("cumulate_num_max", "u8"),
+        ("lot_item_rarity", "u8"),
+        ("lot_item_num1", "u8"),
+        ("lot_item_num2", "u8"),
+        ("lot_item_num3", "u8"),
+        ("lot_item_num4", "u8"),
+        ("lot_item_num5", "u8"),
+        ("lot_item_num6", "u8"),
+        ("lot_item_num7", "u8"),
+        ("lot_item_num8", "u8"),
+        ("EnableLuck01", "bool"),
+        ("EnableLuck02", "bool"),
+        ("EnableLuck03", "bool"),
+        ("EnableLuck04", "bool"),
+        ("EnableLuck05", "bool"),
+        ("EnableLuck06", "bool"),
+        ("EnableLuck07", "bool"),
+        ("EnableLuck08", "bool"),
+        ("cumulateReset01", "bool"),
+        ("cumulateReset02", "bool"),
+        ("cumulateReset03", "bool"),
+        ("cumulateReset04", "bool"),
+        ("cumulateReset05", "bool"),
+        ("cumulateReset06", "bool"),
+        ("cumulateReset07", "bool"),
+        ("cumulateReset08", "bool"),
+        ("clear_count", "i8"),
+    ]),
+    ("KnockBackParam", &[
+        ("damage_min_cont_time", "f32"),
+        ("damage_s_cont_time", "f32"),
+        ("damage_m_cont_time", "f32"),
+        ("damage_l_cont_time", "f32"),
+        ("damage_blow_s_cont_time", "f32"),
+        ("damage_blow_m_cont_time", "f32"),
+        ("damage_strike_cont_time", "f32"),
+        ("damage_uppercut_cont_time", "f32"),
+        ("damage_push_cont_time", "f32"),
+        ("damage_breath_cont_time", "f32"),
+        ("damage_head_shot_cont_time", "f32"),
+        ("guard_s_cont_time", "f32"),
+        ("guard_l_cont_time", "f32"),
+        ("guard_ll_cont_time", "f32"),
+        ("guard_brake_cont_time", "f32"),
+        ("damage_min_dec_time", "f32"),
+        ("damage_s_dec_time", "f32"),
+        ("damage_m_dec_time", "f32"),
+        ("damage_l_dec_time", "f32"),
+        ("damage_blow_s_dec_time", "f32"),
+        ("damage_blow_m_dec_time", "f32"),
+        ("damage_strike_dec_time", "f32"),
+        ("damage_uppercut_dec_time", "f32"),
+        ("damage_push_dec_time", "f32"),
+        ("damage_breath_dec_time", "f32"),
+        ("damage_head_shot_dec_time", "f32"),
+        ("guard_s_dec_time", "f32"),
+        ("guard_l_dec_time", "f32"),
+        ("guard_ll_dec_time", "f32"),
+        ("guard_brake_dec_time", "f32"),
+    ]),
+    ("KnowledgeLoadScreenItemParam", &[("loadscreen_category_id", "u32"), ("knowledge_id", "i32")]),
+    ("LoadBalancerDrawDistScaleParam", &[
+        ("lod_dist_draw_scale0", "f32"),
+        ("lod_dist_draw_scale1", "f32"),
+        ("lod_dist_draw_scale2", "f32"),
+        ("lod_dist_draw_scale3", "f32"),
+        ("lod_dist_draw_scale4", "f32"),
+        ("lod_dist_draw_scale5", "f32"),
+        ("lod_dist_draw_scale6", "f32"),
+        ("lod_dist_draw_scale7", "f32"),
+        ("lod_dist_draw_scale8", "f32"),
+        ("lod_dist_draw_scale9", "f32"),
+        ("lod_dist_draw_scale10", "f32"),
+        ("lod_dist_draw_scale11", "f32"),
+        ("lod_dist_draw_scale12", "f32"),
+        ("lod_dist_draw_scale13", "f32"),
+        ("lod_dist_draw_scale14", "f32"),
+        ("lod_dist_draw_scale15", "f32"),
+        ("lod_dist_draw_scale16", "f32"),
+        ("lod_dist_draw_scale17", "f32"),
+        ("lod_dist_draw_scale18", "f32"),
+        ("lod_dist_draw_scale19", "f32"),
+        ("lod_dist_draw_scale20", "f32"),
+    ]),
+    ("LoadBalancerParam", &[
+        ("unk1", "f32"),
+        ("unk2", "f32"),
+        ("unk3", "i32"),
+        ("unk4", "i32"),
+        ("unk5", "i32"),
+        ("unk6", "i32"),
+        ("load_balancer_val0", "u8"),
+        ("load_balancer_val1", "u8"),
+        ("load_balancer_val2", "u8"),
+        ("load_balancer_val3", "u8"),
+        ("load_balancer_val4", "u8"),
+        ("load_balancer_val5", "u8"),
+        ("load_balancer_val6", "u8"),
+        ("load_balancer_val7", "u8"),
+        ("load_balancer_val8", "u8"),
+        ("load_balancer_val9", "u8"),
+        ("load_balancer_val10", "u8"),
+        ("load_balancer_val11", "u8"),
+        ("load_balancer_val12", "u8"),
+        ("load_balancer_val13", "u8"),
+        ("load_balancer_val14", "u8"),
+        ("load_balancer_val15", "u8"),
+        ("load_balancer_val16", "u8"),
+        ("load_balancer_val17", "u8"),
+    ]),
+    ("LockCamParam", &[
+        ("cam_dist_target", "f32"),
+        ("rot_range_min_x", "f32"),
+        ("lock_rot_xshift_ratio", "f32"),
+        ("chr_org_offset_z", "f32"),
+        ("chr_lock_range_max_radius", "f32"),
+        ("fov_ychange", "f32"),
+        ("chr_lock_range_max_radius_for_dark", "f32"),
+        ("chr_lock_range_max_radius_for_pitch_dark", "f32"),
+        ("melee_attack_capture_upper_limit_height", "f32"),
+        ("attack_capture_lower_limit_height", "f32"),
+        ("attack_auto_acquisition_angle_range_left", "f32"),
+        ("melee_attack_auto_acquisition_character_range_maximum_radius", "f32"),
+        ("melee_attack_auto_acquisition_dark_character_range_maximum_radius", "f32"),
+        (
+            "proximity_attack_auto_acquisition_character_range_for_pure_darkness_maximum_radius",
+            "f32",
+        ),
+        ("bullet_auto_capture_character_range_maximum_radius", "f32"),
+        ("bullet_auto_capture_dark_character_range_maximum_radius", "f32"),
+        ("bullet_automatic_capture_character_range_ror_pure_darkness_maximum_radius", "f32"),
+        ("bullet_auto_capturing_angle_range_left_and_right", "f32"),
+    ]),
+    ("LodParam", &[
+        ("lv01_border_dist", "f32"),
+        ("lv01_play_dist", "f32"),
+        ("lv12_border_dist", "f32"),
+        ("lv12_play_dist", "f32"),
+        ("texture_lod", "u8"),
+        ("lv23_border_dist", "f32"),
+        ("lv23_play_dist", "f32"),
+        ("lv34_border_dist", "f32"),
+        ("lv34_play_dist", "f32"),
+        ("lv45_border_dist", "f32"),
+        ("lv45_play_dist", "f32"),
+        ("distance_scale_id", "u8"),
+    ]),
+    ("LodParam_ps4", &[
+        ("lv01_border_dist", "f32"),
+        ("lv01_play_dist", "f32"),
+        ("lv12_border_dist", "f32"),
+        ("lv12_play_dist", "f32"),
+        ("texture_lod", "u8"),
+        ("lv23_border_dist", "f32"),
+        ("lv23_play_dist", "f32"),
+        ("lv34_border_dist", "f32"),
+        ("lv34_play_dist", "f32"),
+        ("lv45_border_dist", "f32"),
+        ("lv45_play_dist", "f32"),
+        ("distance_scale_id", "u8"),
+    ]),
+    ("LodParam_xb1", &[
+        ("lv01_border_dist", "f32"),
+        ("lv01_play_dist", "f32"),
+        ("lv12_border_dist", "f32"),
+        ("lv12_play_dist", "f32"),
+        ("texture_lod", "u8"),
+        ("lv23_border_dist", "f32"),
+        ("lv23_play_dist", "f32"),
+        ("lv34_border_dist", "f32"),
+        ("lv34_play_dist", "f32"),
+        ("lv45_border_dist", "f32"),
+        ("lv45_play_dist", "f32"),
+        ("distance_scale_id", "u8"),
+    ]),
+    ("MPEstusFlaskRecoveryParam", &[
+        ("recovery_count0", "u8"),
+        ("recovery_count1", "u8"),
+        ("recovery_count2", "u8"),
+        ("recovery_count3", "u8"),
+        ("recovery_count4", "u8"),
+        ("recovery_count5", "u8"),
+        ("recovery_count6", "u8"),
+        ("recovery_count7", "u8"),
+        ("recovery_count8", "u8"),
+        ("recovery_count9", "u8"),
+        ("recovery_count10", "u8"),
+        ("recovery_count11", "u8"),
+        ("recovery_count12", "u8"),
+        ("recovery_count13", "u8"),
+        ("recovery_count14", "u8"),
+        ("recovery_count15", "u8"),
+        ("recovery_count16", "u8"),
+        ("recovery_count17", "u8"),
+        ("recovery_count18", "u8"),
+        ("recovery_count19", "u8"),
+        ("recovery_count20", "u8"),
+        ("recovery_count21", "u8"),
+        ("recovery_count22", "u8"),
+        ("recovery_count23", "u8"),
+    ]),
+    ("Magic", &[
+        ("yes_no_dialog_message_id", "i32"),
+        ("limit_cancel_sp_effect_id", "i32"),
+        ("sort_id", "i16"),
+        ("ref_id", "i16"),
+        ("ref_id_fp_cost1", "i16"),
+        ("ref_id_sp_cost1", "i16"),
+        ("icon_id", "i16"),
+        ("behavior_id", "i16"),
+        ("mtrl_item_id", "i16"),
+        ("replace_magic_id", "i16"),
+        ("max_quantity", "i16"),
+        ("hero_point", "u8"),
+        ("over_dexterity", "u8"),
+        ("sfx_variation_id", "u8"),
+        ("slot_length", "u8"),
+        ("requirement_intellect", "u8"),
+        ("requirement_faith", "u8"),
+        ("analog_dexterity_min", "u8"),
+        ("analog_dexterity_max", "u8"),
+        ("ez_state_behavior_type", "u8"),
+        ("ref_category1", "u8"),
+        ("sp_effect_category", "u8"),
+        ("ref_type", "u8"),
+        ("menu_type", "u8"),
+        ("ref_category4", "u8"),
+        ("has_sp_effect_type", "i16"),
+        ("VowType0", "bool"),
+        ("VowType1", "bool"),
+        ("VowType2", "bool"),
+        ("VowType3", "bool"),
+        ("VowType4", "bool"),
+        ("VowType5", "bool"),
+        ("VowType6", "bool"),
+        ("VowType7", "bool"),
+        ("enableMulti", "bool"),
+        ("enableMultOnly", "bool"),
+        ("isEnchant", "bool"),
+        ("isShieldEnchant", "bool"),
+        ("enable_live", "bool"),
+        ("enable_gray", "bool"),
+        ("enable_white", "bool"),
+        ("enable_black", "bool"),
+        ("disable_offline", "bool"),
+        ("castResonanceMagic", "bool"),
+        ("Unk1", "bool"),
+        ("Unk2", "bool"),
+        ("Unk3", "bool"),
+        ("Unk4", "bool"),
+        ("Unk5", "bool"),
+        ("Unk6", "bool"),
+        ("VowType8", "bool"),
+        ("VowType9", "bool"),
+        ("VowType10", "bool"),
+        ("VowType11", "bool"),
+        ("VowType12", "bool"),
+        ("VowType13", "bool"),
+        ("VowType14", "bool"),
+        ("VowType15", "bool"),
+        ("unk7", "u8"),
+        ("unk8", "u8"),
+        ("cast_sfx1", "i32"),
+        ("cast_sfx2", "i32"),
+        ("cast_sfx3", "i32"),
+        ("unk9", "i32"),
+        ("attribute_type", "u8"),
+        ("attribute_val0", "i8"),
+        ("attribute_val1", "i8"),
+        ("attribute_val2", "i8"),
+        ("attribute_val3", "i8"),
+        ("ref_category2", "u8"),
+        ("ref_id_sp_cost4", "i16"),
+        ("magic_id0", "i32"),
+        ("magic_id1", "i32"),
+        ("magic_id2", "i32"),
+        ("magic_id3", "i32"),
+        ("ref_id_fp_cost2", "i16"),
+        ("ref_id_sp_cost2", "i16"),
+        ("unk10", "u8"),
+        ("ref_category3", "u8"),
+        ("ref_id_fp_cost4", "i16"),
+        ("ref_id_fp_cost3", "i16"),
+        ("ref_id_sp_cost3", "i16"),
+        ("ref_id1", "i32"),
+        ("ref_id2", "i32"),
+        ("ref_id3", "i32"),
+        ("ref_id4", "i32"),
+    ]),
+    ("MapMimicryEstablishmentParam", &[
+        ("randomizer_coefficient0", "f32"),
+        ("randomizer_coefficient1", "f32"),
+        ("randomizer_coefficient2", "f32"),
+        ("transform_vfx_id0", "i32"),
+        ("loop_vfx_id0", "i32"),
+        ("destroy_vfx_id0", "i32"),
+        ("transform_vfx_id1", "i32"),
+        ("loop_vfx_id1", "i32"),
+        ("destroy_vfx_id1", "i32"),
+        ("transform_vfx_id2", "i32"),
+        ("loop_vfx_id2", "i32"),
+        ("destroy_vfx_id2", "i32"),
+    ]),
+    ("MenuOffscrRendParam", &[
+        ("menu_content0", "f32"),
+        ("menu_content0_0", "f32"),
+        ("menu_content0_1", "f32"),
+        ("menu_content1", "f32"),
+        ("menu_content1_2", "f32"),
+        ("menu_content1_3", "f32"),
+        ("menu_content1_4", "f32"),
+        ("screen_rend_id", "i32"),
+    ]),
+    ("MenuPropertyLayoutParam", &[
+        ("property_id", "i32"),
+        ("caption_text_id", "i32"),
+        ("help_text_id", "i32"),
+    ]),
+    ("MenuPropertySpecParam", &[
+        ("caption_text_id", "i32"),
+        ("icon_id", "i32"),
+        ("required_property_id", "i32"),
+        ("compare_type", "u8"),
+        ("required_property_format_id", "u8"),
+    ]),
+    ("MenuValueTableParam", &[("value", "i32"), ("text_id", "i32"), ("compare_type", "u8")]),
+    ("ModelSfxParam", &[
+        ("vfx_id1", "i32"),
+        ("dummy_poly_id1", "i32"),
+        ("vfx_id2", "i32"),
+        ("dummy_poly_id2", "i32"),
+        ("vfx_id3", "i32"),
+        ("dummy_poly_id3", "i32"),
+        ("vfx_id4", "i32"),
+        ("dummy_poly_id4", "i32"),
+        ("vfx_id5", "i32"),
+        ("dummy_poly_id5", "i32"),
+        ("vfx_id6", "i32"),
+        ("dummy_poly_id6", "i32"),
+        ("vfx_id7", "i32"),
+        ("dummy_poly_id7", "i32"),
+        ("vfx_id8", "i32"),
+        ("dummy_poly_id8", "i32"),
+        ("vfx_id9", "i32"),
+        ("dummy_poly_id9", "i32"),
+        ("vfx_id10", "i32"),
+        ("dummy_poly_id10", "i32"),
+    ]),
+    ("MoveParam", &[
+        ("stay_id", "i32"),
+        ("walk_f", "i32"),
+        ("walk_b", "i32"),
+        ("walk_l", "i32"),
+        ("walk_r", "i32"),
+        ("dash_f", "i32"),
+        ("dash_b", "i32"),
+        ("dash_l", "i32"),
+        ("dash_r", "i32"),
+        ("super_dash", "i32"),
+        ("escape_f", "i32"),
+        ("escape_b", "i32"),
+        ("escape_l", "i32"),
+        ("escape_r", "i32"),
+        ("turn_l", "i32"),
+        ("turn_r", "i32"),
+        ("large_turn_l", "i32"),
+        ("large_turn_r", "i32"),
+        ("step_move", "i32"),
+        ("fly_stay", "i32"),
+        ("fly_walk_f", "i32"),
+        ("fly_walk_fl", "i32"),
+        ("fly_walk_fr", "i32"),
+        ("fly_walk_fl2", "i32"),
+        ("fly_walk_fr2", "i32"),
+        ("fly_dash_f", "i32"),
+        ("fly_dash_fl", "i32"),
+        ("fly_dash_fr", "i32"),
+        ("fly_dash_fl2", "i32"),
+        ("fly_dash_fr2", "i32"),
+        ("dash_escape_f", "i32"),
+        ("dash_escape_b", "i32"),
+        ("dash_escape_l", "i32"),
+        ("dash_escape_r", "i32"),
+        ("analog_move_param_id", "i32"),
+        ("turn_no_anim_angle", "u8"),
+        ("turn45_angle", "u8"),
+        ("turn90_angle", "u8"),
+        ("turn_wait_no_anim_angle", "u8"),
+    ]),
+    ("MultiHPEstusFlaskBonusParam", &[
+        ("estus_flask_restore_count0", "u8"),
+        ("estus_flask_restore_count1", "u8"),
+        ("estus_flask_restore_count2", "u8"),
+        ("estus_flask_restore_count3", "u8"),
+        ("estus_flask_restore_count4", "u8"),
+        ("estus_flask_restore_count5", "u8"),
+        ("estus_flask_restore_count6", "u8"),
+        ("estus_flask_restore_count7", "u8"),
+        ("estus_flask_restore_count8", "u8"),
+        ("estus_flask_restore_count9", "u8"),
+        ("estus_flask_restore_count10", "u8"),
+        ("estus_flask_restore_count11", "u8"),
+        ("estus_flask_restore_count12", "u8"),
+        ("estus_flask_restore_count13", "u8"),
+        ("estus_flask_restore_count14", "u8"),
+        ("estus_flask_restore_count15", "u8"),
+        ("estus_flask_restore_count16", "u8"),
+        ("estus_flask_restore_count17", "u8"),
+        ("estus_flask_restore_count18", "u8"),
+        ("estus_flask_restore_count19", "u8"),
+    ]),
+    ("MultiMPEstusFlaskBonusParam", &[
+        ("estus_flask_restore_count0", "u8"),
+        ("estus_flask_restore_count1", "u8"),
+        ("estus_flask_restore_count2", "u8"),
+        ("estus_flask_restore_count3", "u8"),
+        ("estus_flask_restore_count4", "u8"),
+        ("estus_flask_restore_count5", "u8"),
+        ("estus_flask_restore_count6", "u8"),
+        ("estus_flask_restore_count7", "u8"),
+        ("estus_flask_restore_count8", "u8"),
+        ("estus_flask_restore_count9", "u8"),
+        ("estus_flask_restore_count10", "u8"),
+        ("estus_flask_restore_count11", "u8"),
+        ("estus_flask_restore_count12", "u8"),
+        ("estus_flask_restore_count13", "u8"),
+        ("estus_flask_restore_count14", "u8"),
+        ("estus_flask_restore_count15", "u8"),
+        ("estus_flask_restore_count16", "u8"),
+        ("estus_flask_restore_count17", "u8"),
+        ("estus_flask_restore_count18", "u8"),
+        ("estus_flask_restore_count19", "u8"),
+    ]),
+    ("MultiPlayCorrectionParam", &[
+        ("correction_val0", "i32"),
+        ("correction_val1", "i32"),
+        ("correction_val2", "i32"),
+        ("correction_val3", "i32"),
+    ]),
+    ("MultiSoulBonusRateParam", &[
+        ("soul_multiplier_rate0", "f32"),
+        ("soul_multiplier_rate1", "f32"),
+        ("soul_multiplier_rate2", "f32"),
+        ("soul_multiplier_rate3", "f32"),
+        ("soul_multiplier_rate4", "f32"),
+        ("soul_multiplier_rate5", "f32"),
+        ("soul_multiplier_rate6", "f32"),
+        ("soul_multiplier_rate7", "f32"),
+        ("soul_multiplier_rate8", "f32"),
+        ("soul_multiplier_rate9", "f32"),
+        ("soul_multiplier_rate10", "f32"),
+        ("soul_multiplier_rate11", "f32"),
+        ("soul_multiplier_rate12", "f32"),
+        ("soul_multiplier_rate13", "f32"),
+        ("soul_multiplier_rate14", "f32"),
+        ("soul_multiplier_rate15", "f32"),
+    ]),
+    ("NetworkAreaParam", &[
+        ("limitation_time0", "f32"),
+        ("limitation_time1", "f32"),
+        ("limitation_time2", "f32"),
+        ("isEnable00", "bool"),
+        ("isEnable01", "bool"),
+        ("isEnable02", "bool"),
+        ("unkb1", "bool"),
+        ("unkb2", "bool"),
+        ("unkb3", "bool"),
+        ("unkb4", "bool"),
+        ("unkb5", "bool"),
+    ]),
+    ("NetworkMsgParam", &[
+        ("msg_type0", "u8"),
+        ("msg_type1", "u8"),
+        ("msg_type2", "u8"),
+        ("msg_type3", "u8"),
+        ("msg_id0", "i32"),
+        ("msg_id1", "i32"),
+        ("msg_id2", "i32"),
+        ("msg_id3", "i32"),
+        ("msg_id4", "i32"),
+        ("msg_id5", "i32"),
+        ("msg_id6", "i32"),
+        ("msg_id7", "i32"),
+        ("msg_id8", "i32"),
+        ("msg_id9", "i32"),
+        ("msg_id10", "i32"),
+        ("msg_id11", "i32"),
+        ("msg_id12", "i32"),
+        ("msg_id13", "i32"),
+        ("msg_id14", "i32"),
+        ("msg_id15", "i32"),
+        ("msg_id16", "i32"),
+        ("msg_id17", "i32"),
+        ("msg_id18", "i32"),
+        ("msg_id19", "i32"),
+        ("msg_id20", "i32"),
+        ("msg_id21", "i32"),
+        ("msg_id22", "i32"),
+    ]),
+    ("NetworkParam", &[]),
+    ("NewMenuColorTableParam", &[("r", "u8"), ("g", "u8"), ("b", "u8"), ("a", "u8")]),
+    ("NpcAiActionParam", &[
+        ("direction_movement_id", "u8"),
+        ("act_id0", "u8"),
+        ("act_id1", "u8"),
+        ("act_id2", "u8"),
+        ("is_disable_direction_movement", "u8"),
+        ("is_disable_act0", "u8"),
+        ("is_disable_act1", "u8"),
+        ("is_disable_act2", "u8"),
+        ("act_type", "i32"),
+        ("is_disable_ai_check", "u8"),
+    ]),
+    ("NpcParam", &[
+        ("behavior_variation_id", "i32"),
+        ("ai_think_id", "i32"),
+        ("name_id", "i32"),
+        ("turn_vellocity", "f32"),
+        ("hit_height", "f32"),
+        ("hit_radius", "f32"),
+        ("weight", "i32"),
+        ("hit_offset", "f32"),
+        ("hp", "i32"),
+        ("mp", "i32"),
+        ("get_soul", "i32"),
+        ("item_lot_id1", "i32"),
+        ("item_lot_id2", "i32"),
+        ("item_lot_id3", "i32"),
+        ("item_lot_id4", "i32"),
+        ("item_lot_id5", "i32"),
+        ("item_lot_id6", "i32"),
+        ("humanity_lot_id", "i32"),
+        ("sp_effect_id0", "i32"),
+        ("sp_effect_id1", "i32"),
+        ("sp_effect_id2", "i32"),
+        ("sp_effect_id3", "i32"),
+        ("sp_effect_id4", "i32"),
+        ("sp_effect_id5", "i32"),
+        ("sp_effect_id6", "i32"),
+        ("sp_effect_id7", "i32"),
+        ("game_clear_sp_effect_id", "i32"),
+        ("phys_guard_cut_rate", "f32"),
+        ("mag_guard_cut_rate", "f32"),
+        ("fire_guard_cut_rate", "f32"),
+        ("thun_guard_cut_rate", "f32"),
+        ("animid_offset", "i32"),
+        ("move_anim_id", "i32"),
+        ("sp_move_anim_id1", "i32"),
+        ("sp_move_anim_id2", "i32"),
+        ("network_warp_disp", "f32"),
+        ("dbg_behavior_r1", "i32"),
+        ("dbg_behavior_l1", "i32"),
+        ("dbg_behavior_r2", "i32"),
+        ("dbg_behavior_l2", "i32"),
+        ("dbg_behavior_rl", "i32"),
+        ("dbg_behavior_rr", "i32"),
+        ("dbg_behavior_rd", "i32"),
+        ("dbg_behavior_ru", "i32"),
+        ("dbg_behavior_ll", "i32"),
+        ("dbg_behavior_lr", "i32"),
+        ("dbg_behavior_ld", "i32"),
+        ("dbg_behavior_lu", "i32"),
+        ("anim_id_offset", "i32"),
+        ("parts_damage_rate1", "f32"),
+        ("parts_damage_rate2", "f32"),
+        ("parts_damage_rate3", "f32"),
+        ("parts_damage_rate4", "f32"),
+        ("parts_damage_rate5", "f32"),
+        ("parts_damage_rate6", "f32"),
+        ("parts_damage_rate7", "f32"),
+        ("parts_damage_rate8", "f32"),
+        ("weak_parts_damage_rate", "f32"),
+        ("super_armor_recover_correction", "f32"),
+        ("super_armor_brake_knockback_dist", "f32"),
+        ("stamina", "i16"),
+        ("stamina_recover_base_val", "i16"),
+        ("def_phys", "i16"),
+        ("def_slash", "i16"),
+        ("def_blow", "i16"),
+        ("def_thrust", "i16"),
+        ("def_mag", "i16"),
+        ("def_fire", "i16"),
+        ("def_thunder", "i16"),
+        ("def_flick_power", "i16"),
+        ("resist_poison", "i16"),
+        ("resist_toxic", "i16"),
+        ("resist_blood", "i16"),
+        ("resist_curse", "i16"),
+        ("ghost_model_id", "i16"),
+        ("normal_change_resource_id", "i16"),
+        ("guard_angle", "i16"),
+        ("slash_guard_cut_rate", "i16"),
+        ("blow_guard_cut_rate", "i16"),
+        ("thrust_guard_cut_rate", "i16"),
+        ("super_armor_durability", "i16"),
+        ("normal_change_tex_chr_id", "i16"),
+        ("drop_type", "i16"),
+        ("knockback_rate", "u8"),
+        ("knockback_param_id", "u8"),
+        ("fall_damage_damp", "u8"),
+        ("stamina_guard_def", "u8"),
+        ("pc_attr_b", "u8"),
+        ("pc_attr_w", "u8"),
+        ("pc_attr_l", "u8"),
+        ("pc_attr_r", "u8"),
+        ("area_attr_b", "u8"),
+        ("area_attr_w", "u8"),
+        ("area_attr_l", "u8"),
+        ("area_attr_r", "u8"),
+        ("mp_recover_base_val", "u8"),
+        ("flick_damage_cut_rate", "u8"),
+        ("default_lod_param_id", "i8"),
+        ("draw_type", "u8"),
+        ("npc_type", "u8"),
+        ("team_type", "u8"),
+        ("move_type", "u8"),
+        ("lock_dist", "u8"),
+        ("material", "i16"),
+        ("material_sfx", "i16"),
+        ("parts_damage_type", "u8"),
+        ("max_unduration_ang", "u8"),
+        ("guard_level", "u8"),
+        ("burnsfx_type", "u8"),
+        ("poison_guard_resist", "u8"),
+        ("toxic_guard_resist", "u8"),
+        ("blood_guard_resist", "u8"),
+        ("curse_guard_resist", "u8"),
+        ("parry_attack", "u8"),
+        ("parry_defense", "u8"),
+        ("sfx_size", "u8"),
+        ("push_out_cam_region_radius", "u8"),
+        ("hit_stop_type", "u8"),
+        ("ladder_end_chk_offset_top", "u8"),
+        ("ladder_end_chk_offset_low", "u8"),
+        ("UseRagdollCamHit", "bool"),
+        ("disableClothRigidHit", "bool"),
+        ("UseRagdoll", "bool"),
+        ("isDemon", "bool"),
+        ("isGhost", "bool"),
+        ("isNoDamageMotion", "bool"),
+        ("isUnduration", "bool"),
+        ("isChangeWanderGhost", "bool"),
+        ("ModelDispMask0", "bool"),
+        ("ModelDispMask1", "bool"),
+        ("ModelDispMask2", "bool"),
+        ("ModelDispMask3", "bool"),
+        ("ModelDispMask4", "bool"),
+        ("ModelDispMask5", "bool"),
+        ("ModelDispMask6", "bool"),
+        ("ModelDispMask7", "bool"),
+        ("ModelDispMask8", "bool"),
+        ("ModelDispMask9", "bool"),
+        ("ModelDispMask10", "bool"),
+        ("ModelDispMask11", "bool"),
+        ("ModelDispMask12", "bool"),
+        ("ModelDispMask13", "bool"),
+        ("ModelDispMask14", "bool"),
+        ("ModelDispMask15", "bool"),
+        ("isEnableNeckTurn", "bool"),
+        ("disableRespawn", "bool"),
+        ("isMoveAnimWait", "bool"),
+        ("isCrowd", "bool"),
+        ("isWeakSaint", "bool"),
+        ("isWeakA", "bool"),
+        ("isWeakB", "bool"),
+        ("isEnableDropSoulCapture", "bool"),
+        ("VowType0", "bool"),
+        ("VowType1", "bool"),
+        ("VowType2", "bool"),
+        ("disableIntiliazeDead", "bool"),
+        ("isSoulGetByBoss", "bool"),
+        ("isMultilingual", "bool"),
+        ("Unk1", "bool"),
+        ("Unk2", "bool"),
+        ("ModelDispMask16", "bool"),
+        ("ModelDispMask17", "bool"),
+        ("ModelDispMask18", "bool"),
+        ("ModelDispMask19", "bool"),
+        ("ModelDispMask20", "bool"),
+        ("ModelDispMask21", "bool"),
+        ("ModelDispMask22", "bool"),
+        ("ModelDispMask23", "bool"),
+        ("ModelDispMask24", "bool"),
+        ("ModelDispMask25", "bool"),
+        ("ModelDispMask26", "bool"),
+        ("ModelDispMask27", "bool"),
+        ("ModelDispMask28", "bool"),
+        ("ModelDispMask29", "bool"),
+        ("ModelDispMask30", "bool"),
+        ("ModelDispMask31", "bool"),
+        ("item_search_radius", "f32"),
+        ("sub_hit_height", "f32"),
+        ("sub_hit_radius", "f32"),
+        ("turn_velocity_type", "u8"),
+        ("isMultModeBoss", "bool"),
+        ("Unk3", "bool"),
+        ("behMemSize", "bool"),
+        ("Unk4", "bool"),
+        ("isUseFeetData", "bool"),
+        ("Unk5", "bool"),
+        ("Unk6", "bool"),
+        ("Unk7", "bool"),
+        ("def_dark", "i16"),
+        ("sub_turn_velocity", "f32"),
+        ("foot_step_id", "i32"),
+        ("sub_material", "i16"),
+        ("sub_material_sfx", "i16"),
+        ("material_weak", "i16"),
+        ("material_sfx_weak", "i16"),
+        ("sub_material_weak", "i16"),
+        ("sub_material_sfx_weak", "i16"),
+        ("sp_effect_id8", "i32"),
+        ("sp_effect_id9", "i32"),
+        ("sp_effect_id10", "i32"),
+        ("sp_effect_id11", "i32"),
+        ("sp_effect_id12", "i32"),
+        ("sp_effect_id13", "i32"),
+        ("sp_effect_id14", "i32"),
+        ("sp_effect_id15", "i32"),
+        ("tentative_player_id", "i32"),
+        ("basic_toughness_value", "i32"),
+        ("game_system_param_correction", "f32"),
+        ("regain_rate_phys_neutral", "f32"),
+        ("regain_rate_phys_slash", "f32"),
+        ("regain_rate_phys_blow", "f32"),
+        ("regain_rate_phys_thrust", "f32"),
+        ("regain_rate_magic", "f32"),
+        ("regain_rate_fire", "f32"),
+        ("regain_rate_thunder", "f32"),
+        ("regain_rate_dark", "f32"),
+        ("max_ankle_angle", "f32"),
+        ("cloth_update_offset", "i8"),
+        ("sp_npc_type", "u8"),
+        ("normal_change_model_id", "i16"),
+        ("normal_change_anim_chr_id", "i16"),
+        ("stamina_guard_def_val", "i16"),
+        ("cult_setting_id", "i32"),
+        ("phantom_param_id", "i32"),
+        ("multi_play_correction_id", "i32"),
+        ("foot_ankle", "f32"),
+        ("resist_frost", "i16"),
+        ("sub_npc_type", "u8"),
+        ("sub_team_type", "u8"),
+        ("lock_cam_param_id", "i32"),
+        ("sp_effect_id16", "i32"),
+        ("sp_effect_id17", "i32"),
+        ("sp_effect_id18", "i32"),
+        ("sp_effect_id19", "i32"),
+        ("sp_effect_id20", "i32"),
+        ("sp_effect_id21", "i32"),
+        ("sp_effect_id22", "i32"),
+        ("sp_effect_id23", "i32"),
+        ("sp_effect_id24", "i32"),
+        ("sp_effect_id25", "i32"),
+        ("sp_effect_id26", "i32"),
+        ("sp_effect_id27", "i32"),
+        ("sp_effect_id28", "i32"),
+        ("sp_effect_id29", "i32"),
+        ("sp_effect_id30", "i32"),
+        ("sp_effect_id31", "i32"),
+        ("lock_correction", "f32"),
+        ("sub_cloth_update_offset", "i8"),
+        ("estus_flask_param_id", "i16"),
+        ("text_id", "i32"),
+        ("h_p", "i16"),
+        ("h_p_restore_id0", "i16"),
+        ("m_p_restore_id0", "i16"),
+        ("h_p_0", "i16"),
+        ("h_p_restore_id1", "i16"),
+        ("m_p_restore_id1", "i16"),
+        ("sub_phantom_param_id", "i32"),
+        ("activate_distance", "i16"),
+        ("deactivate_distance", "i16"),
+    ]),
+    ("NpcThinkParam", &[
+        ("logic_id", "i32"),
+        ("battle_goal_id", "i32"),
+        ("near_dist", "f32"),
+        ("mid_dist", "f32"),
+        ("far_dist", "f32"),
+        ("out_dist", "f32"),
+        ("back_home_life_on_hit_ene_wal", "f32"),
+        ("goal_id_to_caution", "f32"),
+        ("id_attack_cannot_move", "i32"),
+        ("goal_id_to_find", "f32"),
+        ("call_help_action_anim_id", "i32"),
+        ("call_help_call_action_id", "i32"),
+        ("eye_dist", "i16"),
+        ("ear_dist", "i16"),
+        ("ear_soundcut_dist", "i16"),
+        ("nose_dist", "i16"),
+        ("max_backhome_dist", "i16"),
+        ("backhome_dist", "i16"),
+        ("backhome_battle_dist", "i16"),
+        ("non_battle_act_life", "i16"),
+        ("back_home_look_target_time", "i16"),
+        ("back_home_look_target_dist", "i16"),
+        ("sight_target_forget_time", "i16"),
+        ("sound_target_forget_time", "i16"),
+        ("battle_start_dist", "i16"),
+        ("call_help_my_peer_id", "i16"),
+        ("call_help_call_peer_id", "i16"),
+        ("target_sys_dmg_effect_rate", "i16"),
+        ("team_attack_effectivity", "u8"),
+        ("eye_ang_x", "u8"),
+        ("eye_ang_y", "u8"),
+        ("state0", "u8"),
+        ("state1", "u8"),
+        ("call_help_call_valid_min_dist_target", "u8"),
+        ("call_help_call_valid_range", "u8"),
+        ("call_help_forget_time_by_arrival", "u8"),
+        ("call_help_min_wait_time", "u8"),
+        ("call_help_max_wait_time", "u8"),
+        ("goal_action_to_caution", "u8"),
+        ("goal_action_to_find", "u8"),
+        ("call_help_reply_behavior_type", "u8"),
+        ("disable_path_move", "u8"),
+        ("skip_arrival_visible_check", "u8"),
+        ("think_attr_do_admirer", "u8"),
+        ("enableNaviFlg_Edge", "bool"),
+        ("enableNaviFlg_LargeSpace", "bool"),
+        ("enableNaviFlg_Ladder", "bool"),
+        ("enableNaviFlg_Hole", "bool"),
+        ("enableNaviFlg_Door", "bool"),
+        ("enableNaviFlg_InSideWall", "bool"),
+        ("enableNaviFlg_EdgeOrdinary", "bool"),
+        ("enableNaviFlg_reserve0", "bool"),
+        ("eye_dist_for_dark", "i16"),
+        ("battle_start_dist_for_dark", "i16"),
+        ("eye_dist_for_pitch_dark", "i16"),
+        ("battle_start_dist_for_pitch_dark", "i16"),
+        ("platoon_reply_time", "f32"),
+        ("platoon_reply_add_random_time", "f32"),
+        ("eye_back_offset_dist", "i16"),
+        ("eye_begin_dist", "i16"),
+        ("target_arrive_dist", "f32"),
+        ("point_arrive_dist", "f32"),
+        ("change_state_action_to_find", "u8"),
+        ("change_state_action_to_caution", "u8"),
+        ("change_state_action_to_battle", "u8"),
+        ("goal_action_to_disappear", "u8"),
+        ("disable_local_steering", "u8"),
+        ("act_type_on_failed_path", "u8"),
+        ("interest_category", "u8"),
+        ("goal_action_to_interest", "u8"),
+        ("unk1", "i16"),
+        ("ear_ang_x", "i16"),
+        ("ear_ang_y", "i16"),
+        ("change_state_action_to_interest", "u8"),
+        ("change_state_action_to_normal", "u8"),
+        ("unk2", "f32"),
+        ("unk3", "f32"),
+        ("unk4", "i16"),
+        ("unk5", "u8"),
+        ("unk6", "u8"),
+        ("unk7", "f32"),
+    ]),
+    ("ObjActParam", &[
+        ("action_enable_msg_id", "i32"),
+        ("action_failed_msg_id", "i32"),
+        ("sp_qualified_pass_event_flag", "i32"),
+        ("player_anim_id", "i32"),
+        ("chr_anim_id", "i32"),
+        ("valid_dist", "i16"),
+        ("sp_qualified_id", "i16"),
+        ("sp_qualified_id2", "i16"),
+        ("obj_dummy_id", "u8"),
+        ("obj_anim_id", "i32"),
+        ("valid_player_angle", "u8"),
+        ("sp_qualified_type", "u8"),
+        ("sp_qualified_type2", "u8"),
+        ("valid_obj_angle", "u8"),
+        ("chr_sorb_type", "u8"),
+        ("event_kick_timing", "u8"),
+        ("action_button_param_id", "i32"),
+        ("action_success_msg_id", "i32"),
+    ]),
+    ("ObjectMaterialSfxParam", &[
+        ("mtrl_vfx_id0", "i32"),
+        ("mtrl_vfx_id1", "i32"),
+        ("mtrl_vfx_id2", "i32"),
+        ("mtrl_vfx_id3", "i32"),
+        ("mtrl_vfx_id4", "i32"),
+        ("mtrl_vfx_id5", "i32"),
+        ("mtrl_vfx_id6", "i32"),
+        ("mtrl_vfx_id7", "i32"),
+        ("mtrl_vfx_id8", "i32"),
+        ("mtrl_vfx_id9", "i32"),
+        ("mtrl_vfx_id10", "i32"),
+        ("mtrl_vfx_id11", "i32"),
+        ("mtrl_vfx_id12", "i32"),
+        ("mtrl_vfx_id13", "i32"),
+        ("mtrl_vfx_id14", "i32"),
+        ("mtrl_vfx_id15", "i32"),
+        ("mtrl_vfx_id16", "i32"),
+        ("mtrl_vfx_id17", "i32"),
+        ("mtrl_vfx_id18", "i32"),
+        ("mtrl_vfx_id19", "i32"),
+        ("mtrl_vfx_id20", "i32"),
+        ("mtrl_vfx_id21", "i32"),
+        ("mtrl_vfx_id22", "i32"),
+        ("mtrl_vfx_id23", "i32"),
+        ("mtrl_vfx_id24", "i32"),
+        ("mtrl_vfx_id25", "i32"),
+        ("mtrl_vfx_id26", "i32"),
+        ("mtrl_vfx_id27", "i32"),
+        ("mtrl_vfx_id28", "i32"),
+        ("mtrl_vfx_id29", "i32"),
+        ("mtrl_vfx_id30", "i32"),
+        ("mtrl_vfx_id31", "i32"),
+    ]),
+    ("ObjectParam", &[
+        ("h_p", "i16"),
+        ("defense", "u16"),
+        ("ext_ref_tex_id", "i16"),
+        ("material_id", "i16"),
+        ("anim_break_id_max", "u8"),
+        ("isCamHit", "bool"),
+        ("isBreakByPlayerCollide", "bool"),
+        ("isAnimBreak", "bool"),
+        ("isPenetrationBulletHit", "bool"),
+        ("isChrHit", "bool"),
+        ("isAttackBacklash", "bool"),
+        ("isDisableBreakForFirstAppear", "bool"),
+        ("isLadder", "bool"),
+        ("isAnimPauseOnRemoPlay", "bool"),
+        ("isDamageNoHit", "bool"),
+        ("isMoveObj", "bool"),
+        ("UnkBool1", "bool"),
+        ("UnkBool2", "bool"),
+        ("UnkBool3", "bool"),
+        ("mapRelated", "bool"),
+        ("IsBreakByCollide2", "bool"),
+        ("default_lod_param_id", "i8"),
+        ("break_sfx_id", "i32"),
+        ("beh_param_id0", "i32"),
+        ("beh_param_id1", "i32"),
+        ("beh_param_id2", "i32"),
+        ("unk2", "u8"),
+        ("havok_system_wind", "u8"),
+        ("havok_system_break_obj", "u8"),
+        ("unk5", "u8"),
+        ("wind_effect_rate", "f32"),
+        ("unk7", "f32"),
+        ("break_obj_rate", "f32"),
+        ("burn_life_time", "f32"),
+        ("break_update", "f32"),
+        ("burn_vfx_id0", "i32"),
+        ("burn_vfx_id1", "i32"),
+        ("burn_vfx_id2", "i32"),
+        ("burn_vfx_id3", "i32"),
+        ("burn_behavior_id0", "i32"),
+        ("burn_behavior_id1", "i32"),
+        ("burn_behavior_id2", "i32"),
+        ("burn_behavior_id3", "i32"),
+        ("burn_interval", "u16"),
+        ("unk20", "u8"),
+        ("unk22", "u8"),
+        ("burn_behavior_time", "f32"),
+        ("burn_time_begin0", "f32"),
+        ("burn_time_begin1", "f32"),
+        ("burn_time_begin2", "f32"),
+        ("burn_time_begin3", "f32"),
+        ("burn_time_end0", "f32"),
+        ("burn_time_end1", "f32"),
+        ("burn_time_end2", "f32"),
+        ("burn_time_end3", "f32"),
+        ("ai_sound_param_id", "i32"),
+        ("unk32", "f32"),
+        ("unk33", "f32"),
+        ("unk34", "f32"),
+        ("unk35", "f32"),
+        ("unk36", "f32"),
+        ("unk37", "f32"),
+        ("unk38", "f32"),
+        ("unk39", "f32"),
+        ("unk40", "f32"),
+        ("unk41", "i32"),
+        ("unk42", "i16"),
+        ("spawn_param", "i16"),
+        ("auto_destroy_timer", "f32"),
+        ("unk44", "f32"),
+        ("sound_id", "i32"),
+        ("object_material_sfx_index", "i32"),
+    ]),
+    ("PhantomParam", &[
+        ("alpha1", "f32"),
+        ("alpha2", "f32"),
+        ("alpha3", "f32"),
+        ("alpha4", "f32"),
+        ("alpha5", "f32"),
+        ("r1", "u8"),
+        ("g1", "u8"),
+        ("b1", "u8"),
+        ("r2", "u8"),
+        ("g2", "u8"),
+        ("b2", "u8"),
+        ("r3", "u8"),
+        ("g3", "u8"),
+        ("b3", "u8"),
+        ("r4", "u8"),
+        ("g4", "u8"),
+        ("b4", "u8"),
+        ("r5", "u8"),
+        ("g5", "u8"),
+        ("b5", "u8"),
+        ("unk1", "u8"),
+        ("ghost_alpha1", "f32"),
+        ("ghost_alpha2", "f32"),
+        ("ghost_type", "u8"),
+    ]),
+    ("PlayRegionParam", &[
+        ("play_region_sp_id", "i32"),
+        ("event_flag_id0", "i32"),
+        ("limitation_time", "f32"),
+        ("event_flag_id1", "i32"),
+        ("event_flag_id2", "i32"),
+        ("disolved_event_flag", "i16"),
+        ("load_of_cinder", "u8"),
+        ("IsEnableEvent", "bool"),
+        ("Unk1", "bool"),
+        ("Unk2", "bool"),
+        ("Unk3", "bool"),
+        ("Unk4", "bool"),
+        ("Unk5", "bool"),
+        ("Unk6", "bool"),
+        ("Unk7", "bool"),
+        ("bonfire_event_id0", "i32"),
+        ("bonfire_event_id1", "i32"),
+        ("bonfire_event_id2", "i32"),
+        ("bonfire_event_id3", "i32"),
+        ("bonfire_event_id4", "i32"),
+        ("bonfire_event_id5", "i32"),
+        ("bonfire_event_id6", "i32"),
+        ("bonfire_event_id7", "i32"),
+        ("bonfire_event_id8", "i32"),
+        ("bonfire_event_id9", "i32"),
+        ("Unk8", "bool"),
+        ("Unk9", "bool"),
+        ("Unk10", "bool"),
+        ("Unk11", "bool"),
+        ("Unk12", "bool"),
+        ("Unk13", "bool"),
+        ("Unk14", "bool"),
+        ("Unk15", "bool"),
+    ]),
+    ("ProtectorGenParam", &[
+        ("pro_param_id", "i32"),
+        ("gem_slot_type_0", "u32"),
+        ("gem_gen_id_0", "i32"),
+        ("gem_slot_type_1", "u32"),
+        ("gem_gen_id_1", "i32"),
+        ("gem_slot_type_2", "u32"),
+        ("gem_gen_id_2", "i32"),
+        ("gem_slot_type_3", "u32"),
+        ("gem_gen_id_3", "i32"),
+        ("gem_slot_type_4", "u32"),
+        ("gem_gen_id_4", "i32"),
+    ]),
+    ("RagdollParam", &[
+        ("hierarch_gain", "f32"),
+        ("velocity_damping", "f32"),
+        ("accel_gain", "f32"),
+        ("velocity_gain", "f32"),
+        ("position_gain", "f32"),
+        ("max_liner_velocity", "f32"),
+        ("max_angular_velocity", "f32"),
+        ("snap_gain", "f32"),
+        ("enable", "u8"),
+        ("parts_hit_mask_no", "i8"),
+    ]),
+    ("ReinforceParamProtector", &[
+        ("physic_def_rate", "f32"),
+        ("magic_def_rate", "f32"),
+        ("fire_def_rate", "f32"),
+        ("thunder_def_rate", "f32"),
+        ("slash_def_rate", "f32"),
+        ("blow_def_rate", "f32"),
+        ("thrust_def_rate", "f32"),
+        ("resist_poison_rate", "f32"),
+        ("resist_toxic_rate", "f32"),
+        ("resist_blood_rate", "f32"),
+        ("resist_curse_rate", "f32"),
+        ("resident_sp_effect_id1", "u8"),
+        ("resident_sp_effect_id2", "u8"),
+        ("resident_sp_effect_id3", "u8"),
+        ("material_set_id", "u8"),
+        ("dark_def_rate", "f32"),
+        ("resist_frost", "f32"),
+    ]),
+    ("ReinforceParamWeapon", &[
+        ("physics_atk_rate", "f32"),
+        ("magic_atk_rate", "f32"),
+        ("fire_atk_rate", "f32"),
+        ("thunder_atk_rate", "f32"),
+        ("stamina_atk_rate", "f32"),
+        ("sa_weapon_atk_rate", "f32"),
+        ("sa_durability_rate", "f32"),
+        ("correct_strength_rate", "f32"),
+        ("correct_agility_rate", "f32"),
+        ("correct_magic_rate", "f32"),
+        ("correct_faith_rate", "f32"),
+        ("physics_guard_cut_rate", "f32"),
+        ("magic_guard_cut_rate", "f32"),
+        ("fire_guard_cut_rate", "f32"),
+        ("thunder_guard_cut_rate", "f32"),
+        ("poison_guard_resist_rate", "f32"),
+        ("toxic_guard_resist_rate", "f32"),
+        ("bleed_guard_resist_rate", "f32"),
+        ("curse_guard_resist_rate", "f32"),
+        ("stamina_guard_resist_rate", "f32"),
+        ("sp_effect_id1", "u8"),
+        ("sp_effect_id2", "u8"),
+        ("sp_effect_id3", "u8"),
+        ("resident_sp_effect_id1", "u8"),
+        ("resident_sp_effect_id2", "u8"),
+        ("resident_sp_effect_id3", "u8"),
+        ("material_set_id", "u8"),
+        ("dark_atk_rate", "f32"),
+        ("dark_cut_rate", "f32"),
+        ("stability_atk_rate", "f32"),
+        ("stability_cut_rate", "f32"),
+        ("frost_guard_resist_rate", "f32"),
+        ("unk1", "f32"),
+    ]),
+    ("RoleParam", &[
+        ("team_type", "u8"),
+        ("phantom_param_id0", "i32"),
+        ("sp_effect_id0", "i32"),
+        ("sp_effect_id1", "i32"),
+        ("sp_effect_id2", "i32"),
+        ("sp_effect_id3", "i32"),
+        ("sp_effect_id4", "i32"),
+        ("sp_effect_id5", "i32"),
+        ("sp_effect_id6", "i32"),
+        ("sp_effect_id7", "i32"),
+        ("sp_effect_id8", "i32"),
+        ("sp_effect_id9", "i32"),
+        ("sfx_id0", "i32"),
+        ("sfx_id1", "i32"),
+        ("stay_anim_id", "i32"),
+        ("item_lot_id", "i32"),
+        ("sp_effect_condition", "u8"),
+        ("is_display_team_name", "u8"),
+        ("text_id", "i32"),
+        ("sub_team_type", "i32"),
+        ("phantom_param_id1", "i32"),
+        ("phantom_param_id2", "i32"),
+        ("phantom_param_id3", "i32"),
+        ("sp_effect10", "i32"),
+        ("sp_effect11", "i32"),
+        ("sp_effect12", "i32"),
+        ("sp_effect13", "i32"),
+        ("phantom_param_id_for_debug", "i32"),
+    ]),
+    ("SeMaterialConvertParam", &[("material_id", "i32")]),
+    ("ShopLineupParam", &[
+        ("equip_id", "i32"),
+        ("value", "i32"),
+        ("mtrl_id", "i32"),
+        ("event_flag", "i32"),
+        ("qwc_id", "i32"),
+        ("sell_quantity", "i16"),
+        ("shop_type", "u8"),
+        ("equip_type", "u8"),
+        ("value_san", "i16"),
+        ("price_rate", "f32"),
+    ]),
+    ("SkeletonParam", &[
+        ("neck_turn_gain", "f32"),
+        ("original_ground_height_ms", "i16"),
+        ("min_ankle_height_ms", "i16"),
+        ("max_ankle_height_ms", "i16"),
+        ("cosine_max_knee_angle", "i16"),
+        ("cosine_min_knee_angle", "i16"),
+        ("foot_planted_ankle_height_ms", "i16"),
+        ("foot_raised_ankle_height_ms", "i16"),
+        ("raycast_distance_up", "i16"),
+        ("raycast_distance_down", "i16"),
+        ("foot_end_ls_x", "i16"),
+        ("foot_end_ls_y", "i16"),
+        ("foot_end_ls_z", "i16"),
+        ("on_off_gain", "i16"),
+        ("ground_acsending_gain", "i16"),
+        ("ground_descending_gain", "i16"),
+        ("foot_raised_gain", "i16"),
+        ("foot_planted_gain", "i16"),
+        ("foot_unlock_gain", "i16"),
+        ("knee_axis_type", "u8"),
+        ("use_foot_locking", "u8"),
+        ("foot_placement_on", "u8"),
+        ("twist_knee_axis_type", "u8"),
+        ("neck_turn_priority", "u8"),
+        ("neck_turn_max_angle", "u8"),
+    ]),
+    ("SpEffectParam", &[
+        ("icon_id", "i32"),
+        ("condition_hp", "f32"),
+        ("effect_endurance", "f32"),
+        ("motion_interval", "f32"),
+        ("max_hp_rate", "f32"),
+        ("max_mp_rate", "f32"),
+        ("max_stamina_cut_rate", "f32"),
+        ("slash_damage_cut_rate", "f32"),
+        ("blow_damage_cut_rate", "f32"),
+        ("thrust_damage_cut_rate", "f32"),
+        ("neutral_damage_cut_rate", "f32"),
+        ("magic_damage_cut_rate", "f32"),
+        ("fire_damage_cut_rate", "f32"),
+        ("thunder_damage_cut_rate", "f32"),
+        ("phys_atk_rate", "f32"),
+        ("magic_atk_rate", "f32"),
+        ("fire_atk_rate", "f32"),
+        ("thunder_atk_rate", "f32"),
+        ("phys_atk_power_rate", "f32"),
+        ("magic_atk_power_rate", "f32"),
+        ("fire_atk_power_rate", "f32"),
+        ("thunder_atk_power_rate", "f32"),
+        ("phys_atk_power", "i32"),
+        ("magic_atk_power", "i32"),
+        ("fire_atk_power", "i32"),
+        ("thunder_atk_power", "i32"),
+        ("phys_def_rate", "f32"),
+        ("magic_def_rate", "f32"),
+        ("fire_def_rate", "f32"),
+        ("thunder_def_rate", "f32"),
+        ("phys_def", "i32"),
+        ("magic_def", "i32"),
+        ("fire_def", "i32"),
+        ("thunder_def", "i32"),
+        ("no_guard_damage_rate", "f32"),
+        ("vital_spot_change_rate", "f32"),
+        ("normal_spot_change_rate", "f32"),
+        ("max_hp_change_rate", "f32"),
+        ("behavior_id", "i32"),
+        ("change_hp_rate", "f32"),
+        ("change_hp_point", "i32"),
+        ("change_mp_rate", "f32"),
+        ("change_mp_point", "i32"),
+        ("mp_recover_change_speed", "i32"),
+        ("change_stamina_rate", "f32"),
+        ("change_stamina_point", "i32"),
+        ("stamina_recover_change_speed", "i32"),
+        ("magic_effect_time_change", "f32"),
+        ("inside_durability", "i32"),
+        ("max_durability", "i32"),
+        ("stamina_attack_rate", "f32"),
+        ("regist_poison", "i32"),
+        ("regist_toxic", "i32"),
+        ("regist_blood", "i32"),
+        ("regist_curse", "i32"),
+        ("fall_damage_rate", "f32"),
+        ("soul_rate", "f32"),
+        ("equip_weight_change_rate", "f32"),
+        ("all_item_weight_change_rate", "f32"),
+        ("soul", "i32"),
+        ("anim_id_offset", "i32"),
+        ("have_soul_rate", "f32"),
+        ("target_priority", "f32"),
+        ("sight_search_enemy_cut", "i32"),
+        ("hearing_search_enemy_cut", "f32"),
+        ("gravity_rate", "f32"),
+        ("regist_poison_change_rate", "f32"),
+        ("regist_toxic_change_rate", "f32"),
+        ("regist_blood_change_rate", "f32"),
+        ("regist_curse_change_rate", "f32"),
+        ("soul_steal_rate", "f32"),
+        ("life_reduction_rate", "f32"),
+        ("hp_recover_rate", "f32"),
+        ("replace_sp_effect_id", "i32"),
+        ("cycle_occurence_sp_effect_id", "i32"),
+        ("atk_occurence_sp_effect_id", "i32"),
+        ("guard_def_flick_power_rate", "f32"),
+        ("guard_stamina_cut_rate", "f32"),
+        ("ray_cast_passed_time", "i16"),
+        ("change_super_armor_point", "i16"),
+        ("bow_dist_rate", "i16"),
+        ("sp_category", "i16"),
+        ("category_priority", "i8"),
+        ("save_category", "i8"),
+        ("change_magic_slot", "u8"),
+        ("change_miracle_slot", "u8"),
+        ("hero_point_damage", "u8"),
+        ("def_flick_power", "u8"),
+        ("flick_damage_cut_rate", "u8"),
+        ("blood_damage_rate", "u8"),
+        ("dmg_lv_none", "u8"),
+        ("dmg_lv_s", "u8"),
+        ("dmg_lv_m", "u8"),
+        ("dmg_lv_l", "u8"),
+        ("dmg_lv_blow_m", "u8"),
+        ("dmg_lv_push", "u8"),
+        ("dmg_lv_strike", "u8"),
+        ("dmg_lv_blow_s", "u8"),
+        ("dmg_lv_min", "u8"),
+        ("dmg_lv_uppercut", "u8"),
+        ("dmg_lv_blow_ll", "u8"),
+        ("dmg_lv_breath", "u8"),
+        ("atk_attribute", "u8"),
+        ("sp_attribute", "u8"),
+        ("state_info", "i16"),
+        ("wep_param_change", "u8"),
+        ("move_type", "u8"),
+        ("life_reduction_type", "i16"),
+        ("throw_condition", "u8"),
+        ("add_behavior_judge_id_condition", "i8"),
+        ("add_behavior_judge_id_add", "i8"),
+        ("effectTargetSelf", "bool"),
+        ("effectTargetFriend", "bool"),
+        ("effectTargetEnemy", "bool"),
+        ("effectTargetPlayer", "bool"),
+        ("effectTargetAI", "bool"),
+        ("effectTargetLive", "bool"),
+        ("effectTargetGhost", "bool"),
+        ("effectTargetWhiteGhost", "bool"),
+        ("effectTargetBlackGhost", "bool"),
+        ("effectTargetAttacker", "bool"),
+        ("dispIconNonactive", "bool"),
+        ("useSpEffectEffect", "bool"),
+        ("bAdjustMagicAbility", "bool"),
+        ("bAdjustFaithAbility", "bool"),
+        ("bGameClearBonus", "bool"),
+        ("magParamChange", "bool"),
+        ("miracleParamChange", "bool"),
+        ("clearSoul", "bool"),
+        ("requestSos", "bool"),
+        ("requestBlackSos", "bool"),
+        ("requestForceJoinBlackSos", "bool"),
+        ("requestKickSession", "bool"),
+        ("requestLeaveSession", "bool"),
+        ("requestNpcInvade", "bool"),
+        ("noDead", "bool"),
+        ("bCurrHPIndependeMaxHP", "bool"),
+        ("corrosionIgnore", "bool"),
+        ("sightSearchCutIgnore", "bool"),
+        ("hearingSearchCutIgnore", "bool"),
+        ("antiMagicIgnore", "bool"),
+        ("fakeTargetIgnore", "bool"),
+        ("fakeTargetIgnoreUndead", "bool"),
+        ("fakeTargetIgnoreAnimal", "bool"),
+        ("gravityIgnore", "bool"),
+        ("disablePoison", "bool"),
+        ("disableToxic", "bool"),
+        ("disableBlood", "bool"),
+        ("disableCurse", "bool"),
+        ("enableCharm", "bool"),
+        ("enableLifeTime", "bool"),
+        ("hasTarget", "bool"),
+        ("isFireDamageCancel", "bool"),
+        ("isExtendSpEffectLife", "bool"),
+        ("requestLeaveColiseumSession", "bool"),
+        ("disableFrost", "bool"),
+        ("chargeAttackParamChange", "bool"),
+        ("throwAttackParamChange", "bool"),
+        ("enableEquipSlotCheck", "bool"),
+        ("vowType0", "bool"),
+        ("vowType1", "bool"),
+        ("vowType2", "bool"),
+        ("vowType3", "bool"),
+        ("vowType4", "bool"),
+        ("vowType5", "bool"),
+        ("vowType6", "bool"),
+        ("vowType7", "bool"),
+        ("vowType8", "bool"),
+        ("vowType9", "bool"),
+        ("vowType10", "bool"),
+        ("vowType11", "bool"),
+        ("vowType12", "bool"),
+        ("vowType13", "bool"),
+        ("vowType14", "bool"),
+        ("vowType15", "bool"),
+        ("damage_change_state", "u8"),
+        ("unk1", "f32"),
+        ("effectTargetOpposeTarget", "bool"),
+        ("effectTargetFriendlyTarget", "bool"),
+        ("effectTargetSelfTarget", "bool"),
+        ("Unk2", "bool"),
+        ("Unk3", "bool"),
+        ("Unk4", "bool"),
+        ("Unk5", "bool"),
+        ("Unk6", "bool"),
+        ("unk7", "i8"),
+        ("unk8", "i16"),
+        ("effect_vfx0", "i32"),
+        ("state_sp_effect0", "i32"),
+        ("state_sp_effect1", "i32"),
+        ("state_sp_effect2", "i32"),
+        ("state_sp_effect3", "i32"),
+        ("stability_val", "i32"),
+        ("unk9", "i32"),
+        ("unk10", "i16"),
+        ("unk11", "i16"),
+        ("effect_vfx1", "i32"),
+        ("effect_vfx2", "i32"),
+        ("effect_vfx3", "i32"),
+        ("effect_vfx4", "i32"),
+        ("effect_vfx5", "i32"),
+        ("effect_vfx6", "i32"),
+        ("effect_vfx7", "i32"),
+        ("regist_frost", "i32"),
+        ("unk12", "i32"),
+        ("unk13", "i16"),
+        ("unk14", "u8"),
+        ("unk15", "u8"),
+        ("poise_rate", "f32"),
+        ("phys_rate", "f32"),
+        ("magic_rate", "f32"),
+        ("fire_rate", "f32"),
+        ("thunder_rate", "f32"),
+        ("dark_rate", "f32"),
+        ("stamina_rate", "f32"),
+        ("dark_damage_cut_rate", "f32"),
+        ("dark_def_rate", "f32"),
+        ("unk16", "f32"),
+        ("unk17", "f32"),
+        ("dark_attack_power_rate", "f32"),
+        ("dark_atk_power", "i32"),
+        ("unk18", "f32"),
+        ("unk19", "i32"),
+        ("condition_max_hp", "f32"),
+        ("unk20", "f32"),
+        ("drop_rate", "f32"),
+        ("unk21", "f32"),
+        ("poison_resist_change", "f32"),
+        ("toxic_resist_change", "f32"),
+        ("bleed_resist_change", "f32"),
+        ("curse_resist_change", "f32"),
+        ("frost_resist_change", "f32"),
+        ("unk_damage_rate0", "f32"),
+        ("unk_damage_rate1", "f32"),
+        ("unk_damage_rate2", "f32"),
+        ("unk_damage_rate3", "f32"),
+        ("poison_atk_rate", "f32"),
+        ("toxic_atk_rate", "f32"),
+        ("bleed_atk_rate", "f32"),
+        ("toxic_atk_rate_0", "f32"),
+        ("unk22", "f32"),
+        ("slash_sp_attack_rate", "f32"),
+        ("blow_sp_attack_rate", "f32"),
+        ("thrust_sp_attack_rate", "f32"),
+        ("phys_sp_attack_rate", "f32"),
+        ("magic_sp_attack_rate", "f32"),
+        ("fire_sp_attack_rate", "f32"),
+        ("thunder_sp_attack_rate", "f32"),
+        ("dark_sp_attack_rate", "f32"),
+        ("player_base_val0", "u8"),
+        ("player_base_val1", "u8"),
+        ("player_base_val2", "u8"),
+        ("player_base_val3", "u8"),
+        ("unk23", "u8"),
+        ("Unk24", "bool"),
+        ("useSpValCorrection", "bool"),
+        ("useDifferentVal", "bool"),
+        ("instaDeath", "bool"),
+        ("Unk25", "bool"),
+        ("Unk26", "bool"),
+        ("Unk27", "bool"),
+        ("DisableMultiPlayUse", "bool"),
+        ("unk28", "u8"),
+        ("unk29", "u8"),
+        ("unk30", "f32"),
+        ("phys_def_cut_rate_mp", "f32"),
+        ("magic_def_cut_rate_mp", "f32"),
+        ("fire_def_cut_rate_mp", "f32"),
+        ("thunder_def_cut_rate_mp", "f32"),
+        ("dark_def_cut_rate_mp", "f32"),
+        ("phys_def_cut_rate2", "f32"),
+        ("magic_def_cut_rate2", "f32"),
+        ("fire_def_cut_rate2", "f32"),
+        ("thunder_def_cut_rate2", "f32"),
+        ("dark_def_cut_rate2", "f32"),
+        ("unk36", "f32"),
+        ("phys_unk_rate", "f32"),
+        ("magic_unk_rate", "f32"),
+        ("fire_unk_rate", "f32"),
+        ("thunder_unk_rate", "f32"),
+        ("dark_unk_rate", "f32"),
+        ("phys_damage_rate2", "f32"),
+        ("magic_damage_rate2", "f32"),
+        ("fire_damage_rate2", "f32"),
+        ("thunder_damage_rate2", "f32"),
+        ("dark_damage_rate2", "f32"),
+        ("regist_frost_change_rate", "f32"),
+        ("condition_hp_change0", "i16"),
+        ("condition_hp_change1", "i16"),
+        ("condition_hp_change2", "i16"),
+        ("unk37", "u8"),
+        ("fake_dexterity_cast_speed", "u8"),
+        ("unk38", "f32"),
+        ("sword_art_mp_change_rate", "f32"),
+        ("magic_mp_change_rate", "f32"),
+        ("pyro_mp_change_rate", "f32"),
+        ("miracle_mp_change_rate", "f32"),
+        ("sub_change_hp_rate", "f32"),
+        ("sub_hp_point", "i32"),
+        ("sub_change_mp_rate", "f32"),
+        ("sub_mp_point", "i32"),
+        ("sub_hp_restore_rate", "f32"),
+        ("sub_mp_restore_rate", "f32"),
+        ("death_occurence_sp_effect_id", "i32"),
+        ("effect_endurance_rate", "f32"),
+        ("unk39", "f32"),
+        ("unk40", "u8"),
+        ("unk41", "u8"),
+        ("sub_sp_category", "i16"),
+        ("unk42", "f32"),
+        ("vigor", "i8"),
+        ("attunement", "i8"),
+        ("endurance", "i8"),
+        ("vitality", "i8"),
+        ("strength", "i8"),
+        ("dexterity", "i8"),
+        ("intelligence", "i8"),
+        ("faith", "i8"),
+        ("luck", "i8"),
+        ("human_point", "i8"),
+    ]),
+    ("SpEffectVfxParam", &[
+        ("midst_sfx_id", "i32"),
+        ("midst_se_id", "i32"),
+        ("init_sfx_id", "i32"),
+        ("init_se_id", "i32"),
+        ("finish_sfx_id", "i32"),
+        ("finish_se_id", "i32"),
+        ("camouflage_begin_dist", "f32"),
+        ("camouflage_end_dist", "f32"),
+        ("transform_protector_id", "i32"),
+        ("midst_dmy_id", "i16"),
+        ("init_dmy_id", "i16"),
+        ("finish_dmy_id", "i16"),
+        ("effect_type", "u8"),
+        ("soul_param_id_for_wep_enchant1", "u8"),
+        ("play_category", "u8"),
+        ("play_priority", "u8"),
+        ("existEffectForLarge", "bool"),
+        ("existEffectForSoul", "bool"),
+        ("existInvisibleAtCamouflage", "bool"),
+        ("useCamouflage", "bool"),
+        ("invisibleAtFriendCamouflage", "bool"),
+        ("addMapAreaBlockOffset", "bool"),
+        ("halfCamouflage", "bool"),
+        ("isFullBodyProtectorId", "bool"),
+        ("isInvisibleWeapon", "bool"),
+        ("isSilence", "bool"),
+        ("isGauntletProtectorId", "bool"),
+        ("Unk1", "bool"),
+        ("Unk2", "bool"),
+        ("Unk3", "bool"),
+        ("Unk4", "bool"),
+        ("Unk5", "bool"),
+        ("decal_id0", "i32"),
+        ("decal_id1", "i32"),
+        ("soul_param_id_for_wep_enchant2", "u8"),
+        ("sp_effect_sp0", "u8"),
+        ("sp_effect_sp1", "u8"),
+        ("body_protector_type", "u8"),
+        ("sp_sfx_id0", "i32"),
+        ("sp_sfx_id1", "i32"),
+        ("sp_sfx_id2", "i32"),
+        ("sp_sfx_id3", "i32"),
+        ("sp_sfx_id4", "i32"),
+        ("sp_sfx_id5", "i32"),
+        ("sp_sfx_id6", "i32"),
+        ("sp_sfx_id7", "i32"),
+        ("sp_sfx_id8", "i32"),
+        ("sp_sfx_id9", "i32"),
+        ("sp_sfx_id10", "i32"),
+        ("sp_sfx_id11", "i32"),
+        ("sp_sfx_id12", "i32"),
+        ("sp_sfx_id13", "i32"),
+        ("sp_sfx_id14", "i32"),
+        ("sp_sfx_id15", "i32"),
+        ("unk10", "u8"),
+        ("phantom_type", "u8"),
+        ("camouflage_ghost", "u8"),
+        ("unk12", "u8"),
+        ("phantom_param_id", "i32"),
+        ("emissive_intensity_scale", "f32"),
+        ("unk13", "f32"),
+        ("body_protector_val", "i16"),
+        ("unk14", "i16"),
+        ("cinder_intensity_scale", "f32"),
+    ]),
+    ("SwordArtsParam", &[
+        ("action_id", "u8"),
+        ("action_correction", "u8"),
+        ("reserve_art_point_type", "u8"),
+        ("unused_field", "u8"),
+        ("reserve_arts_point0", "i8"),
+        ("reserve_arts_point1", "i8"),
+        ("reserve_arts_point2", "i8"),
+        ("reserve_arts_point3", "i8"),
+        ("debug_text_id", "i32"),
+        ("sub_fpcost", "i16"),
+        ("f_pcost", "i16"),
+        ("f_pcost_light", "i16"),
+        ("fp_cost_strong", "i16"),
+        ("shield_category", "u8"),
+    ]),
+    ("TalkParam", &[
+        ("pc_gender_female1", "i32"),
+        ("pc_gender_male1", "i32"),
+        ("sp_effect_id0", "i32"),
+        ("animation_id0", "i32"),
+        ("sp_effect_id1", "i32"),
+        ("animation_id1", "i32"),
+        ("sp_effect_id2", "i32"),
+        ("animation_id2", "i32"),
+        ("event_flag_id", "i32"),
+        ("event_id_female", "i32"),
+        ("event_id_male", "i32"),
+        ("talk_time", "f32"),
+    ]),
+    ("ThrowDirectionSfxParam", &[
+        ("sfx_id1", "i32"),
+        ("sfx_id2", "i32"),
+        ("sfx_id3", "i32"),
+        ("sfx_id4", "i32"),
+        ("sfx_id5", "i32"),
+        ("sfx_id6", "i32"),
+        ("sfx_id7", "i32"),
+        ("sfx_id8", "i32"),
+        ("sfx_id9", "i32"),
+        ("sfx_id10", "i32"),
+        ("sfx_id11", "i32"),
+        ("sfx_id12", "i32"),
+        ("sfx_id13", "i32"),
+        ("sfx_id14", "i32"),
+        ("sfx_id15", "i32"),
+        ("sfx_id16", "i32"),
+        ("sfx_id17", "i32"),
+        ("sfx_id18", "i32"),
+        ("sfx_id19", "i32"),
+        ("sfx_id20", "i32"),
+        ("sfx_id21", "i32"),
+        ("sfx_id22", "i32"),
+        ("sfx_id23", "i32"),
+        ("sfx_id24", "i32"),
+        ("sfx_id25", "i32"),
+        ("sfx_id26", "i32"),
+        ("sfx_id27", "i32"),
+        ("sfx_id28", "i32"),
+        ("sfx_id29", "i32"),
+        ("sfx_id30", "i32"),
+    ]),
+    ("ThrowParam", &[
+        ("atk_chr_id", "i32"),
+        ("def_chr_id", "i32"),
+        ("dist", "f32"),
+        ("diff_ang_min", "f32"),
+        ("diff_ang_max", "f32"),
+        ("upper_yrange", "f32"),
+        ("lower_yrange", "f32"),
+        ("diff_ang_my_to_def", "f32"),
+        ("throw_type_id", "i32"),
+        ("atk_animid", "i32"),
+        ("def_anim_id", "i32"),
+        ("esc_hp", "i16"),
+        ("self_esc_cycle_time", "i16"),
+        ("sphere_cast_radius_rate_top", "i16"),
+        ("sphere_cast_radius_rate_low", "i16"),
+        ("pad_type", "u8"),
+        ("atk_enable_state", "u8"),
+        ("atk_sorb_dmy_id", "u8"),
+        ("def_sorb_dmy_id", "u8"),
+        ("throw_type", "u8"),
+        ("self_esc_cycle_int", "u8"),
+        ("dmy_has_chr_dir_type", "u8"),
+        ("isTurnAtker", "bool"),
+        ("isSkipWepCate", "bool"),
+        ("isSkipSphereCast", "bool"),
+        ("Unk1", "bool"),
+        ("Unk2", "bool"),
+        ("Unk3", "bool"),
+        ("Unk4", "bool"),
+        ("Unk5", "bool"),
+        ("sub_atk_sorb_dmy_id", "i16"),
+        ("sub_def_sorb_dmy_id", "i16"),
+        ("dist2", "f32"),
+        ("diff_ang_min2", "f32"),
+        ("diff_ang_max2", "f32"),
+        ("upper_yrange2", "f32"),
+        ("lower_yrange2", "f32"),
+        ("diff_ang_my_to_def2", "f32"),
+        ("perform_dmy_id0", "i32"),
+        ("perform_dmy_id1", "i32"),
+    ]),
+    ("ToughnessParam", &[
+        ("toughness", "f32"),
+        ("damage_lvl_threshold", "i16"),
+        ("is_toughness_effective", "u8"),
+        ("sp_effect_id", "i32"),
+    ]),
+    ("UpperArmParam", &[
+        ("arm_z0", "f32"),
+        ("arm_xy0", "f32"),
+        ("arm_z1", "f32"),
+        ("arm_xy1", "f32"),
+        ("arm_z2", "f32"),
+        ("arm_xy2", "f32"),
+        ("arm_z3", "f32"),
+        ("arm_xy3", "f32"),
+        ("arm_z4", "f32"),
+        ("arm_xy4", "f32"),
+        ("arm_z5", "f32"),
+        ("arm_xy5", "f32"),
+        ("arm_z6", "f32"),
+        ("arm_xy6", "f32"),
+        ("arm_z7", "f32"),
+        ("arm_xy7", "f32"),
+        ("arm_z8", "f32"),
+        ("arm_xy8", "f32"),
+        ("arm_z9", "f32"),
+        ("arm_xy9", "f32"),
+    ]),
+    ("WeaponGenParam", &[
+        ("wep_param_id", "i32"),
+        ("gem_slot_type_0", "i32"),
+        ("gem_gen_id0", "i32"),
+        ("gem_slot_type_1", "i32"),
+        ("gem_gen_id1", "i32"),
+        ("gem_slot_type_2", "i32"),
+        ("gem_gen_id2", "i32"),
+        ("gem_slot_type_3", "i32"),
+        ("gem_gen_id3", "i32"),
+        ("gem_slot_type_4", "i32"),
+        ("gem_gen_id4", "i32"),
+    ]),
+    ("WepAbsorpPosParam", &[
+        ("shealth_time", "u8"),
+        ("one_hand_damipoly_id0", "u16"),
+        ("one_hand_damipoly_id1", "u16"),
+        ("both_hand_damipoly_id0", "u16"),
+        ("shealth_damipoly_id0", "u16"),
+        ("shealth_damipoly_id1", "u16"),
+        ("one_hand_damipoly_id2", "u16"),
+        ("one_hand_damipoly_id3", "u16"),
+        ("both_hand_damipoly_id1", "u16"),
+        ("shealth_damipoly_id2", "u16"),
+        ("shealth_damipoly_id3", "u16"),
+        ("one_hand_damipoly_id4", "u16"),
+        ("one_hand_damipoly_id5", "u16"),
+        ("both_hand_damipoly_id2", "u16"),
+        ("shealth_damipoly_id4", "u16"),
+        ("shealth_damipoly_id5", "u16"),
+        ("one_hand_damipoly_id6", "u16"),
+        ("one_hand_damipoly_id7", "u16"),
+        ("both_hand_damipoly_id3", "u16"),
+        ("shealth_damipoly_id6", "u16"),
+        ("shealth_damipoly_id7", "u16"),
+        ("unk22", "u8"),
+        ("unk23", "u8"),
+        ("unk24", "u8"),
+        ("unk25", "u8"),
+        ("both_hand_damipoly_id4", "i16"),
+        ("both_hand_damipoly_id5", "i16"),
+        ("both_hand_damipoly_id6", "i16"),
+        ("both_hand_damipoly_id7", "i16"),
+        ("unk30", "u8"),
+        ("unk31", "u8"),
+        ("unk32", "u8"),
+        ("unk33", "u8"),
+        ("unk34", "u8"),
+        ("unk35", "u8"),
+        ("unk36", "u8"),
+        ("unk37", "u8"),
+        ("unk38", "u8"),
+        ("unk39", "u8"),
+        ("unk40", "u8"),
+        ("unk41", "u8"),
+        ("unk42", "u8"),
+        ("unk43", "u8"),
+        ("unk44", "u8"),
+        ("unk45", "u8"),
+        ("unk46", "u8"),
+        ("unk47", "u8"),
+        ("unk48", "u8"),
+        ("unk49", "u8"),
+        ("unk50", "u8"),
+        ("unk51", "u8"),
+        ("unk52", "u8"),
+        ("unk53", "u8"),
+    ]),
+    ("WetAspectParam", &[
+        ("r1", "u8"),
+        ("g1", "u8"),
+        ("b1", "u8"),
+        ("alpha1", "f32"),
+        ("r2", "u8"),
+        ("g2", "u8"),
+        ("b2", "u8"),
+        ("alpha2", "f32"),
+        ("wet_rate", "f32"),
+        ("wet_correction", "u8"),
+    ]),
+    ("WhiteSignCoolTimeParam", &[
+        ("time_limit0", "f32"),
+        ("time_limit1", "f32"),
+        ("time_limit2", "f32"),
+        ("time_limit3", "f32"),
+    ]),
+    ("Wind", &[
+        ("common_capsule_begin_dmy_id", "i16"),
+        ("common_capsule_end_dmy_id", "i16"),
+        ("common_capsule_radius", "f32"),
+        ("sfx_dir_pitch_min", "f32"),
+        ("sfx_dir_pitch_max", "f32"),
+        ("sfx_dir_yaw_min", "f32"),
+        ("sfx_dir_yaw_max", "f32"),
+        ("sfx_cycle_min", "f32"),
+        ("sfx_cycle_max", "f32"),
+        ("sfx_speed_min", "f32"),
+        ("sfx_speed_max", "f32"),
+        ("sfx_maximum_drag", "f32"),
+        ("cloth_dir_pitch_min", "f32"),
+        ("cloth_dir_pitch_max", "f32"),
+        ("cloth_dir_yaw_min", "f32"),
+        ("cloth_dir_yaw_max", "f32"),
+        ("cloth_cycle_min", "f32"),
+        ("cloth_cycle_max", "f32"),
+        ("cloth_speed_min", "f32"),
+        ("cloth_speed_max", "f32"),
+        ("cloth_maximum_drag", "f32"),
+    ]),
+];
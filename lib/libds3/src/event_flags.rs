@@ -0,0 +1,159 @@
+//! Event flag access.
+//!
+//! Event flags are stored as bitfields owned by `SprjEventFlagMan`. Flags
+//! belonging to a map block are stored in a per-block section whose index is
+//! looked up in `FieldArea`'s world info; global flags (areas 0 and 90+) live
+//! in the first section. The layout follows the research done by the
+//! speedrunning community for the DS3 autosplitters.
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::aob::aob_scan_rip_relative;
+use crate::memedit::PointerChain;
+
+static SPRJ_EVENT_FLAG_MAN: Lazy<Option<usize>> = Lazy::new(|| {
+    aob_scan_rip_relative(
+        "48 C7 05 ?? ?? ?? ?? 00 00 00 00 48 8B 7C 24 38 C7 46 54 FF FF FF FF 48 83 C4 20 5E C3",
+        3,
+        11,
+    )
+});
+
+static FIELD_AREA: Lazy<Option<usize>> = Lazy::new(|| {
+    aob_scan_rip_relative(
+        "4C 8B 3D ?? ?? ?? ?? 8B 45 87 83 F8 FF 74 69 48 8D 4D 8F 48 89 4D 9F 89 45 8F 48 8D 55 \
+         8F 49 8B 4F 10",
+        3,
+        7,
+    )
+});
+
+/// Address of the static pointer to `FieldArea`, or zero if it wasn't found.
+pub(crate) fn field_area() -> usize {
+    FIELD_AREA.unwrap_or(0)
+}
+
+/// Boss defeated event flags, in rough route order.
+pub static BOSS_FLAGS: [(u32, &str); 25] = [
+    (14000800, "Iudex Gundyr"),
+    (13000800, "Vordt of the Boreal Valley"),
+    (13100800, "Curse-Rotted Greatwood"),
+    (13300850, "Crystal Sage"),
+    (13500800, "Deacons of the Deep"),
+    (13300800, "Abyss Watchers"),
+    (13800800, "High Lord Wolnir"),
+    (13800830, "Old Demon King"),
+    (13700850, "Pontiff Sulyvahn"),
+    (13900800, "Yhorm the Giant"),
+    (13700800, "Aldrich, Devourer of Gods"),
+    (13000890, "Dancer of the Boreal Valley"),
+    (13010800, "Dragonslayer Armour"),
+    (13000830, "Oceiros, the Consumed King"),
+    (14000830, "Champion Gundyr"),
+    (13200800, "Ancient Wyvern"),
+    (13200850, "Nameless King"),
+    (13410830, "Lothric, Younger Prince"),
+    (14100800, "Soul of Cinder"),
+    (14500860, "Champion's Gravetender"),
+    (14500800, "Sister Friede"),
+    (15000800, "Demon Prince"),
+    (15100800, "Halflight, Spear of the Church"),
+    (15100850, "Darkeater Midir"),
+    (15110800, "Slave Knight Gael"),
+];
+
+/// Sections found by [`world_block_section`], keyed by area and block, along
+/// with the world info they were found in. Looking a section up walks every
+/// map block, which is too slow to do for each flag read.
+static SECTIONS: Lazy<Mutex<(usize, HashMap<(u32, u32), usize>)>> = Lazy::new(Default::default);
+
+fn read<T>(addr: usize) -> Option<T> {
+    PointerChain::<T>::new(&[addr]).read()
+}
+
+/// Finds the event flag section index of a map block in `FieldArea`'s world
+/// info.
+fn world_block_section(area: u32, block: u32) -> Option<usize> {
+    let field_area: usize = read((*FIELD_AREA)?)?;
+    let world_info_owner: usize = read(field_area + 0x10)?;
+
+    let mut sections = SECTIONS.lock();
+    if sections.0 != world_info_owner {
+        *sections = (world_info_owner, HashMap::new());
+    }
+    if let Some(&section) = sections.1.get(&(area, block)) {
+        return Some(section);
+    }
+
+    let section = find_block_section(world_info_owner, area, block)?;
+    sections.1.insert((area, block), section);
+    Some(section)
+}
+
+fn find_block_section(world_info_owner: usize, area: u32, block: u32) -> Option<usize> {
+    let world_info_count: u32 = read(world_info_owner + 0x8)?;
+    let world_info = world_info_owner + 0x10;
+
+    for i in 0..world_info_count as usize {
+        let entry = world_info + i * 0x38;
+        if read::<u8>(entry + 0xb)? as u32 != area {
+            continue;
+        }
+
+        let block_count: u8 = read(entry + 0x20)?;
+        let blocks: usize = read(entry + 0x28)?;
+
+        for j in 0..block_count as usize {
+            let block_id: u32 = read(blocks + j * 0x70 + 0x8)?;
+            if (block_id >> 16) & 0xff == block && block_id >> 24 == area {
+                return read::<u32>(blocks + j * 0x70 + 0x20).map(|section| section as usize);
+            }
+        }
+    }
+
+    None
+}
+
+/// An event flag, identified by its numeric ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventFlag(pub u32);
+
+impl EventFlag {
+    /// Resolves the address of the 32-bit word holding the flag and its mask.
+    fn eval(&self) -> Option<(usize, u32)> {
+        let id = self.0;
+        let group = (id / 10_000_000 % 10) as usize;
+        let area = id / 100_000 % 100;
+        let block = id / 10_000 % 10;
+        let category = (id / 1_000 % 10) as usize;
+        let index = id % 1_000;
+
+        let section =
+            if area >= 90 || area + block == 0 { 0 } else { world_block_section(area, block)? + 1 };
+
+        let groups = PointerChain::<usize>::new(&[(*SPRJ_EVENT_FLAG_MAN)?, 0x218, group * 0x18, 0])
+            .eval()? as usize;
+        let words: usize = read(groups + (category << 4) + section * 0xa8)?;
+
+        Some((words + (index >> 5) as usize * 4, 1 << (31 - (index & 0x1f))))
+    }
+
+    /// Returns the current value of the flag, or `None` if the event flag
+    /// manager is not available (e.g. not in game).
+    pub fn get(&self) -> Option<bool> {
+        let (addr, mask) = self.eval()?;
+        read::<u32>(addr).map(|word| word & mask != 0)
+    }
+
+    /// Sets the value of the flag. Returns `None` if the flag could not be
+    /// written.
+    pub fn set(&self, value: bool) -> Option<()> {
+        let (addr, mask) = self.eval()?;
+        let word = PointerChain::<u32>::new(&[addr]);
+        let current = word.read()?;
+        word.write(if value { current | mask } else { current & !mask })
+    }
+}
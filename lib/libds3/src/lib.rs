@@ -1,14 +1,22 @@
+pub mod aob;
 pub mod codegen;
+pub mod event_flags;
 pub mod memedit;
+pub mod offsets;
 pub mod params;
 pub mod pointers;
+pub mod signatures;
 pub mod version;
 
 pub mod prelude {
+    pub use crate::aob::*;
     pub use crate::codegen::*;
+    pub use crate::event_flags::*;
     pub use crate::memedit::*;
+    pub use crate::offsets::*;
     pub use crate::params::*;
     pub use crate::pointers::*;
+    pub use crate::signatures::*;
     pub use crate::version::*;
     pub use crate::{wait_option, ParamStruct, ParamVisitor};
 }
@@ -32,6 +32,14 @@ pub struct PointerChain<T> {
 unsafe impl<T> Send for PointerChain<T> {}
 unsafe impl<T> Sync for PointerChain<T> {}
 
+/// Chains are equal when they start from the same base and follow the same
+/// offsets, regardless of what they currently evaluate to.
+impl<T> PartialEq for PointerChain<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base && self.offsets == other.offsets
+    }
+}
+
 impl<T> PointerChain<T> {
     /// Creates a new pointer chain given an array of addresses.
     pub fn new(chain: &[usize]) -> PointerChain<T> {
@@ -109,7 +117,7 @@ impl<T> PointerChain<T> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Bitflag<T>(PointerChain<T>, T);
 
 impl<T> Bitflag<T>
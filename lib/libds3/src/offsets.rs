@@ -0,0 +1,82 @@
+//! Per-version offset tables.
+//!
+//! Base addresses are generated per version by `cargo xtask codegen`; this
+//! table holds the struct field offsets that moved between patches. Adding
+//! support for a new version means adding a row here.
+
+use crate::version::Version;
+
+/// Struct field offsets that differ between game versions.
+#[derive(Debug, Clone, Copy)]
+pub struct Offsets {
+    /// `PlayerIns` infinite consumables flags.
+    pub no_goods_consume: usize,
+    /// `WorldChrMan` deathcam flag.
+    pub deathcam: usize,
+    /// `WorldChrMan` bloodstain drawing flag.
+    pub bloodstain_draw: usize,
+    /// `ChrIns` animation speed.
+    pub speed: usize,
+    /// `GameDataMan` in-game time.
+    pub igt: usize,
+    /// `WorldChrManDbg` debug draw flag.
+    pub debug_draw: usize,
+    /// `WorldChrManDbg` IK foot ray flag.
+    pub ik_foot_ray: usize,
+    /// `PlayerIns` current animation data.
+    pub anim: usize,
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn row(
+    no_goods_consume: usize,
+    deathcam: usize,
+    bloodstain_draw: usize,
+    speed: usize,
+    igt: usize,
+    debug_draw: usize,
+    ik_foot_ray: usize,
+    anim: usize,
+) -> Offsets {
+    Offsets {
+        no_goods_consume,
+        deathcam,
+        bloodstain_draw,
+        speed,
+        igt,
+        debug_draw,
+        ik_foot_ray,
+        anim,
+    }
+}
+
+impl From<Version> for Offsets {
+    fn from(v: Version) -> Self {
+        // Columns: no_goods_consume, deathcam, bloodstain_draw, speed, igt,
+        // debug_draw, ik_foot_ray, anim.
+        #[rustfmt::skip]
+        let offsets = match v {
+            Version::V1_01_1 => row(0x1eca, 0x88, 0x2155, 0xa38, 0x9c, 0x55, 0x5b, 0x1f70),
+            Version::V1_03_1 => row(0x1eca, 0x88, 0x2155, 0xa38, 0x9c, 0x55, 0x5b, 0x1f70),
+            Version::V1_03_2 => row(0x1eca, 0x88, 0x2155, 0xa38, 0x9c, 0x55, 0x5b, 0x1f70),
+            Version::V1_04_1 => row(0x1eca, 0x88, 0x2155, 0xa38, 0x9c, 0x55, 0x5b, 0x1f70),
+            Version::V1_04_2 => row(0x1eca, 0x88, 0x2155, 0xa38, 0x9c, 0x55, 0x5b, 0x1f70),
+            Version::V1_04_3 => row(0x1eca, 0x88, 0x2155, 0xa38, 0x9c, 0x55, 0x5b, 0x1f70),
+            Version::V1_05_0 => row(0x1eca, 0x88, 0x2165, 0xa38, 0x9c, 0x55, 0x5b, 0x1f80),
+            Version::V1_05_1 => row(0x1eca, 0x88, 0x2165, 0xa38, 0x9c, 0x55, 0x5b, 0x1f80),
+            Version::V1_06_0 => row(0x1eda, 0x88, 0x2165, 0xa38, 0x9c, 0x55, 0x5b, 0x1f80),
+            Version::V1_07_0 => row(0x1eda, 0x88, 0x2165, 0xa38, 0x9c, 0x55, 0x5b, 0x1f80),
+            Version::V1_08_0 => row(0x1eda, 0x88, 0x2185, 0xa38, 0xa4, 0x65, 0x6b, 0x1f80),
+            Version::V1_09_0 => row(0x1eda, 0x88, 0x2185, 0xa58, 0xa4, 0x65, 0x6b, 0x1f80),
+            Version::V1_10_0 => row(0x1eda, 0x88, 0x2185, 0xa58, 0xa4, 0x65, 0x6b, 0x1f80),
+            Version::V1_11_0 => row(0x1eda, 0x88, 0x2185, 0xa58, 0xa4, 0x65, 0x6b, 0x1f80),
+            Version::V1_12_0 => row(0x1ee2, 0x90, 0x2185, 0xa58, 0xa4, 0x65, 0x6b, 0x1f80),
+            Version::V1_13_0 => row(0x1ee2, 0x90, 0x2195, 0xa58, 0xa4, 0x65, 0x6b, 0x1f90),
+            Version::V1_14_0 => row(0x1ee2, 0x90, 0x2195, 0xa58, 0xa4, 0x65, 0x6b, 0x1f90),
+            Version::V1_15_0 => row(0x1eea, 0x90, 0x2195, 0xa58, 0xa4, 0x65, 0x6b, 0x1f90),
+            Version::V1_15_1 => row(0x1eea, 0x90, 0x2195, 0xa58, 0xa4, 0x65, 0x6b, 0x1f90),
+            Version::V1_15_2 => row(0x1eea, 0x90, 0x2195, 0xa58, 0xa4, 0x65, 0x6b, 0x1f90),
+        };
+        offsets
+    }
+}
@@ -9,11 +9,8 @@ use once_cell::sync::Lazy;
 pub use param_data::*;
 use parking_lot::RwLock;
 use widestring::U16CStr;
-use windows::Win32::System::LibraryLoader::GetModuleHandleA;
 use windows::Win32::System::Memory::{VirtualQuery, MEMORY_BASIC_INFORMATION, PAGE_READWRITE};
 
-use crate::prelude::base_addresses::*;
-use crate::version::VERSION;
 use crate::{wait_option, ParamVisitor};
 
 pub static PARAMS: Lazy<RwLock<Params>> = Lazy::new(|| unsafe {
@@ -26,6 +23,12 @@ pub static PARAMS: Lazy<RwLock<Params>> = Lazy::new(|| unsafe {
     })
 });
 
+/// The params, if they have been loaded already. Unlike [`PARAMS`], never
+/// waits for the game to load them.
+pub fn loaded_params() -> Option<&'static RwLock<Params>> {
+    Lazy::get(&PARAMS)
+}
+
 pub static PARAM_NAMES: Lazy<HashMap<String, HashMap<usize, String>>> =
     Lazy::new(|| serde_json::from_str(include_str!("param_names.json")).unwrap());
 
@@ -81,9 +84,10 @@ impl Params {
     /// Accesses raw pointers. Should never crash as the param pointers are
     /// static.
     pub unsafe fn refresh(&mut self) -> Result<(), String> {
-        let addresses: BaseAddresses = (*VERSION).into();
-        let module_base_addr = GetModuleHandleA(None).map_err(|e| e.to_string())?.0 as usize;
-        let base_ptr = addresses.param + module_base_addr;
+        let base_ptr = crate::signatures::base_addresses().param;
+        if base_ptr == 0 {
+            return Err("Param base address not found".to_string());
+        }
         let base_ptr = loop {
             let base_ptr = *(base_ptr as *const *const c_void) as usize;
             let mut memory_basic_info = MEMORY_BASIC_INFORMATION::default();
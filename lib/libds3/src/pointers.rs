@@ -2,15 +2,32 @@ use std::fmt::Display;
 use std::mem::size_of;
 
 use log::debug;
-use windows::Win32::System::LibraryLoader::GetModuleHandleA;
 
 use crate::memedit::*;
+use crate::offsets::Offsets;
 use crate::prelude::base_addresses::BaseAddresses;
-use crate::prelude::{Version, VERSION};
+use crate::prelude::map_names::MAP_NAMES;
+use crate::version::VERSION;
 
 // Character stats
 //
 
+/// Status effect buildup of a character, as stored in its resist module.
+#[derive(Debug, Clone, Default)]
+#[repr(C)]
+pub struct StatusBuildup {
+    pub poison: u32,
+    pub toxic: u32,
+    pub bleed: u32,
+    pub curse: u32,
+    pub frost: u32,
+    pub poison_max: u32,
+    pub toxic_max: u32,
+    pub bleed_max: u32,
+    pub curse_max: u32,
+    pub frost_max: u32,
+}
+
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct CharacterStats {
@@ -35,6 +52,89 @@ impl Display for CharacterStats {
     }
 }
 
+// Attunement
+//
+
+/// Number of attunement slots.
+pub const ATTUNEMENT_SLOTS: usize = 14;
+
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(C)]
+pub struct AttunementSlot {
+    /// `Magic` param ID of the spell, or -1 for an empty slot.
+    pub magic_id: i32,
+    pub charges: i32,
+}
+
+/// Number of slots in the goods part of the inventory.
+pub const INVENTORY_SLOTS: usize = 1024;
+
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(C)]
+pub struct InventoryItem {
+    pub handle: u32,
+    /// Item ID, category included. Empty slots have an ID of -1.
+    pub id: u32,
+    pub quantity: u32,
+    pub unk: u32,
+}
+
+// Equipment
+//
+
+/// Equipped item IDs, in the game's `ChrAsm` order. Weapon IDs include the
+/// infusion and upgrade level.
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(C)]
+pub struct Equipment {
+    /// Left and right hand weapons for each of the three slots.
+    pub weapons: [[u32; 2]; 3],
+    /// Arrow 1, bolt 1, arrow 2, bolt 2.
+    pub ammo: [u32; 4],
+    pub _unk1: [u32; 2],
+    /// Head, body, arms, legs.
+    pub armor: [u32; 4],
+    pub _unk2: u32,
+    pub rings: [u32; 4],
+}
+
+// Map
+//
+
+/// Map the player is in, packed as `0xAABBCCDD` for map `mAA_BB_CC_DD`. The
+/// area is e.g. 30 for the High Wall of Lothric, and blocks split areas.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct MapId(pub u32);
+
+impl MapId {
+    pub fn area(self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    pub fn block(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Name of the area the map belongs to. Maps without an entry of their
+    /// own fall back to the first entry with the same area and block.
+    pub fn name(self) -> Option<&'static str> {
+        let block = self.0 & 0xffff0000;
+        MAP_NAMES
+            .iter()
+            .find(|(id, _)| *id == self.0)
+            .or_else(|| MAP_NAMES.iter().find(|(id, _)| *id & 0xffff0000 == block))
+            .map(|(_, name)| *name)
+    }
+}
+
+impl Display for MapId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [aa, bb, cc, dd] = self.0.to_be_bytes();
+        write!(f, "m{aa:02}_{bb:02}_{cc:02}_{dd:02}")
+    }
+}
+
 // Pointer chains
 //
 
@@ -50,8 +150,11 @@ pub struct PointerChains {
     pub bloodstain_draw: Bitflag<u8>,
     pub evt_disable: Bitflag<u8>,
     pub ai_disable: Bitflag<u8>,
+    pub no_aggro: Bitflag<u8>,
+    pub no_fog_walls: Bitflag<u8>,
     pub ember: Bitflag<u8>,
     pub rend_chr: Bitflag<u8>,
+    pub hide_player: Bitflag<u8>,
     pub rend_obj: Bitflag<u8>,
     pub rend_map: Bitflag<u8>,
     pub rend_mesh_hi: Bitflag<u8>,
@@ -66,9 +169,25 @@ pub struct PointerChains {
     pub gravity: Bitflag<u8>,
     pub collision: Bitflag<u8>,
     pub speed: PointerChain<f32>,
+    pub world_speed: PointerChain<f32>,
     pub position: (PointerChain<f32>, PointerChain<[f32; 3]>),
+    pub map_id: PointerChain<MapId>,
+    pub hp: PointerChain<[u32; 3]>,
+    pub fp: PointerChain<[u32; 3]>,
+    pub sp: PointerChain<[u32; 3]>,
+    pub status_buildup: PointerChain<StatusBuildup>,
     pub character_stats: PointerChain<CharacterStats>,
+    pub attunement: PointerChain<[AttunementSlot; ATTUNEMENT_SLOTS]>,
+    pub equipment: PointerChain<Equipment>,
+    pub inventory: PointerChain<[InventoryItem; INVENTORY_SLOTS]>,
+    /// Number of flasks allocated to the Estus Flask and the Ashen Estus Flask.
+    pub estus_allocation: PointerChain<[u8; 2]>,
+    pub covenant: PointerChain<u8>,
     pub souls: PointerChain<u32>,
+    /// World matrix of the gameplay camera: its rotation, then its position.
+    pub camera: PointerChain<[f32; 16]>,
+    /// Vertical field of view of the gameplay camera, in radians.
+    pub fov: PointerChain<f32>,
     pub quitout: PointerChain<u8>,
     pub cursor_show: Bitflag<u8>,
     pub igt: PointerChain<u32>,
@@ -76,6 +195,8 @@ pub struct PointerChains {
     pub cur_anim: PointerChain<u32>,
     pub cur_anim_time: PointerChain<f32>,
     pub cur_anim_length: PointerChain<f32>,
+    /// Address of the current animation's TimeAct data.
+    pub cur_anim_tae: PointerChain<usize>,
     pub no_logo: PointerChain<[u8; 20]>,
     pub current_target: PointerChain<u64>,
     pub map_item_man: u64,
@@ -114,166 +235,19 @@ impl From<BaseAddresses> for PointerChains {
 
         let offs_all_no_damage = 9;
         let offs_player_exterminate = 1;
-        let offs_no_goods_consume = match *VERSION {
-            Version::V1_01_1
-            | Version::V1_03_1
-            | Version::V1_03_2
-            | Version::V1_04_1
-            | Version::V1_04_2
-            | Version::V1_04_3
-            | Version::V1_05_0
-            | Version::V1_05_1 => 0x1ECA,
-
-            Version::V1_06_0
-            | Version::V1_07_0
-            | Version::V1_08_0
-            | Version::V1_09_0
-            | Version::V1_10_0
-            | Version::V1_11_0 => 0x1EDA,
-
-            Version::V1_12_0 | Version::V1_13_0 | Version::V1_14_0 => 0x1EE2,
-
-            Version::V1_15_0 | Version::V1_15_1 | Version::V1_15_2 => 0x1EEA,
-        };
-        let offs_deathcam = match *VERSION {
-            Version::V1_01_1
-            | Version::V1_03_1
-            | Version::V1_03_2
-            | Version::V1_04_1
-            | Version::V1_04_2
-            | Version::V1_04_3
-            | Version::V1_05_0
-            | Version::V1_05_1
-            | Version::V1_06_0
-            | Version::V1_07_0
-            | Version::V1_08_0
-            | Version::V1_09_0
-            | Version::V1_10_0
-            | Version::V1_11_0 => 0x88,
-
-            Version::V1_12_0
-            | Version::V1_13_0
-            | Version::V1_14_0
-            | Version::V1_15_0
-            | Version::V1_15_1
-            | Version::V1_15_2 => 0x90,
-        };
-        let offs_bloodstain_draw = match *VERSION {
-            Version::V1_01_1
-            | Version::V1_03_1
-            | Version::V1_03_2
-            | Version::V1_04_1
-            | Version::V1_04_2
-            | Version::V1_04_3 => 0x2155,
-
-            Version::V1_05_0 | Version::V1_05_1 | Version::V1_06_0 | Version::V1_07_0 => 0x2165,
-
-            Version::V1_08_0
-            | Version::V1_09_0
-            | Version::V1_10_0
-            | Version::V1_11_0
-            | Version::V1_12_0 => 0x2185,
-
-            Version::V1_13_0
-            | Version::V1_14_0
-            | Version::V1_15_0
-            | Version::V1_15_1
-            | Version::V1_15_2 => 0x2195,
-        };
-        let offs_speed = match *VERSION {
-            Version::V1_01_1
-            | Version::V1_03_1
-            | Version::V1_03_2
-            | Version::V1_04_1
-            | Version::V1_04_2
-            | Version::V1_04_3
-            | Version::V1_05_0
-            | Version::V1_05_1
-            | Version::V1_06_0
-            | Version::V1_07_0
-            | Version::V1_08_0 => 0xa38,
-
-            Version::V1_09_0
-            | Version::V1_10_0
-            | Version::V1_11_0
-            | Version::V1_12_0
-            | Version::V1_13_0
-            | Version::V1_14_0
-            | Version::V1_15_0
-            | Version::V1_15_1
-            | Version::V1_15_2 => 0xa58,
-        };
-        let offs_igt = match *VERSION {
-            Version::V1_01_1
-            | Version::V1_03_1
-            | Version::V1_03_2
-            | Version::V1_04_1
-            | Version::V1_04_2
-            | Version::V1_04_3
-            | Version::V1_05_0
-            | Version::V1_05_1
-            | Version::V1_06_0
-            | Version::V1_07_0 => 0x9c,
-
-            Version::V1_08_0
-            | Version::V1_09_0
-            | Version::V1_10_0
-            | Version::V1_11_0
-            | Version::V1_12_0
-            | Version::V1_13_0
-            | Version::V1_14_0
-            | Version::V1_15_0
-            | Version::V1_15_1
-            | Version::V1_15_2 => 0xa4,
-        };
+        let offs_player_hide = 4;
+        let offs_no_obj_hit = 0x10;
+        let Offsets {
+            no_goods_consume: offs_no_goods_consume,
+            deathcam: offs_deathcam,
+            bloodstain_draw: offs_bloodstain_draw,
+            speed: offs_speed,
+            igt: offs_igt,
+            debug_draw: offs_debug_draw,
+            ik_foot_ray: offs_ik_foot_ray,
+            anim: offs_anim,
+        } = Offsets::from(*VERSION);
         let offs_fps = 0x08;
-        let offs_debug_draw = match *VERSION {
-            Version::V1_01_1
-            | Version::V1_03_1
-            | Version::V1_03_2
-            | Version::V1_04_1
-            | Version::V1_04_2
-            | Version::V1_04_3
-            | Version::V1_05_0
-            | Version::V1_05_1
-            | Version::V1_06_0
-            | Version::V1_07_0 => 0x55,
-
-            Version::V1_08_0
-            | Version::V1_09_0
-            | Version::V1_10_0
-            | Version::V1_11_0
-            | Version::V1_12_0
-            | Version::V1_13_0
-            | Version::V1_14_0
-            | Version::V1_15_0
-            | Version::V1_15_1
-            | Version::V1_15_2 => 0x65,
-        };
-
-        let offs_ik_foot_ray = match *VERSION {
-            Version::V1_01_1
-            | Version::V1_03_1
-            | Version::V1_03_2
-            | Version::V1_04_1
-            | Version::V1_04_2
-            | Version::V1_04_3
-            | Version::V1_05_0
-            | Version::V1_05_1
-            | Version::V1_06_0
-            | Version::V1_07_0 => 0x5B,
-
-            Version::V1_08_0
-            | Version::V1_09_0
-            | Version::V1_10_0
-            | Version::V1_11_0
-            | Version::V1_12_0
-            | Version::V1_13_0
-            | Version::V1_14_0
-            | Version::V1_15_0
-            | Version::V1_15_1
-            | Version::V1_15_2 => 0x6B,
-        };
 
         let offs_no_update_ai = 0xD;
         let mesh_hi = 0xEC;
@@ -281,31 +255,7 @@ impl From<BaseAddresses> for PointerChains {
         let hurtbox = 0xEF;
         let mesh_hit = 0xF1;
         let mouse_enable_offs = 0x54;
-
-        let offs_anim = match *VERSION {
-            Version::V1_01_1
-            | Version::V1_03_1
-            | Version::V1_03_2
-            | Version::V1_04_1
-            | Version::V1_04_2
-            | Version::V1_04_3 => 0x1F70,
-
-            Version::V1_05_0
-            | Version::V1_05_1
-            | Version::V1_06_0
-            | Version::V1_07_0
-            | Version::V1_08_0
-            | Version::V1_09_0
-            | Version::V1_10_0
-            | Version::V1_11_0
-            | Version::V1_12_0 => 0x1F80,
-
-            Version::V1_13_0
-            | Version::V1_14_0
-            | Version::V1_15_0
-            | Version::V1_15_1
-            | Version::V1_15_2 => 0x1F90,
-        };
+        let field_area = crate::event_flags::field_area();
 
         PointerChains {
             all_no_damage: bitflag!(0b1; debug + offs_all_no_damage as usize),
@@ -319,8 +269,14 @@ impl From<BaseAddresses> for PointerChains {
             bloodstain_draw: bitflag!(0b1; world_chr_man, 0x40, 0x0, offs_bloodstain_draw as _),
             evt_disable: bitflag!(0b1; sprj_debug_event, 0xd4),
             ai_disable: bitflag!(0b1; debug + offs_no_update_ai as usize),
+            // Enemies don't notice the player, but otherwise keep acting.
+            no_aggro: bitflag!(0b1; debug + offs_player_hide as usize),
+            // Objects have no collision: fog walls and invisible barriers included.
+            no_fog_walls: bitflag!(0b1; debug + offs_no_obj_hit as usize),
             ember: bitflag!(0b1; base_a, 0x10, 0x100),
             rend_chr: bitflag!(0b1; grend + 2),
+            // PlayerIns draw flags: only the player's model stops rendering.
+            hide_player: bitflag!(0b1; world_chr_man, 0x80, 0x1a09),
             rend_obj: bitflag!(0b1; grend + 1),
             rend_map: bitflag!(0b1; grend),
             rend_mesh_hi: bitflag!(0b1; base_hbd + mesh_hi as usize),
@@ -335,17 +291,38 @@ impl From<BaseAddresses> for PointerChains {
             gravity: bitflag!(0b1000000; world_chr_man, 0x80, 0x1a08),
             collision: bitflag!(0b1; world_chr_man, 0x40, 0x0, 0x50, 0x187),
             speed: pointer_chain!(world_chr_man, 0x80, xa as _, 0x28, offs_speed as _),
+            // SprjFlipper game speed multiplier: scales everything, the player included.
+            world_speed: pointer_chain!(base_fps as _, 0x2cc),
             position: (
                 pointer_chain!(world_chr_man, 0x40, 0x28, 0x74),
                 pointer_chain!(world_chr_man, 0x40, 0x28, 0x80),
             ),
+            // PlayerIns: map block the player is standing in
+            map_id: pointer_chain!(world_chr_man, 0x80, 0x1abc),
+            // SprjChrDataModule: hp, base max hp, max hp
+            hp: pointer_chain!(world_chr_man, 0x80, xa as _, 0x18, 0xd8),
+            fp: pointer_chain!(world_chr_man, 0x80, xa as _, 0x18, 0xe4),
+            sp: pointer_chain!(world_chr_man, 0x80, xa as _, 0x18, 0xf0),
+            status_buildup: pointer_chain!(world_chr_man, 0x80, xa as _, 0x20, 0x10),
             character_stats: pointer_chain!(base_a, 0x10, 0x44),
+            // PlayerGameData -> EquipMagicData
+            attunement: pointer_chain!(base_a, 0x10, 0x530, 0x18),
+            // PlayerGameData -> ChrAsm
+            equipment: pointer_chain!(base_a, 0x10, 0x32c),
+            // EquipInventoryData
+            inventory: pointer_chain!(base_a, 0x10, 0x470, 0x10, 0x0),
+            estus_allocation: pointer_chain!(base_a, 0x10, 0x1a9),
+            // PlayerGameData: currently joined covenant
+            covenant: pointer_chain!(base_a, 0x10, 0xf7),
             // souls was previously pointer_chain!(sprj_debug_event as _, 0x3d0, 0x74),
             souls: pointer_chain!(base_a, 0x10, 0x44 + 12 * size_of::<i32>()),
+            // FieldArea -> ChrCam, an FD4PersCam: view matrix at 0x10, then fov
+            camera: pointer_chain!(field_area, 0x20, 0x10),
+            fov: pointer_chain!(field_area, 0x20, 0x50),
             map_item_man: map_item_man as _,
             spawn_item_func_ptr: spawn_item_func_ptr as _,
             travel_ptr: menu_travel,
-            attune_ptr: menu_attune - 0x39,
+            attune_ptr: menu_attune.saturating_sub(0x39),
             world_chr_man,
             cursor_show: bitflag!(0b1; menu_man as _, mouse_enable_offs as _),
             igt: pointer_chain!(base_a as _, offs_igt),
@@ -353,6 +330,7 @@ impl From<BaseAddresses> for PointerChains {
             cur_anim: pointer_chain!(world_chr_man as _, 0x80, offs_anim as _, 0x80, 0xC8),
             cur_anim_time: pointer_chain!(world_chr_man as _, 0x80, offs_anim as _, 0x10, 0x24),
             cur_anim_length: pointer_chain!(world_chr_man as _, 0x80, offs_anim as _, 0x10, 0x2C),
+            cur_anim_tae: pointer_chain!(world_chr_man as _, 0x80, offs_anim as _, 0x10, 0x18),
             quitout: pointer_chain!(menu_man as _, 0x250),
             current_target: pointer_chain!(current_target),
             no_logo: pointer_chain!(no_logo as _),
@@ -369,10 +347,57 @@ impl Default for PointerChains {
 
 impl PointerChains {
     pub fn new() -> Self {
-        let base_module_address = unsafe { GetModuleHandleA(None) }.unwrap().0 as usize;
-        let base_addresses = BaseAddresses::from(*crate::version::VERSION)
-            .with_module_base_addr(base_module_address);
+        crate::signatures::base_addresses().into()
+    }
 
-        base_addresses.into()
+    /// Reports, for each pointer chain, whether it currently resolves. Most
+    /// chains only resolve while a character is loaded.
+    pub fn diagnostics(&self) -> Vec<(&'static str, bool)> {
+        vec![
+            ("all_no_damage", self.all_no_damage.get().is_some()),
+            ("no_death", self.no_death.get().is_some()),
+            ("one_shot", self.one_shot.get().is_some()),
+            ("inf_consumables", self.inf_consumables.get().is_some()),
+            ("deathcam", self.deathcam.get().is_some()),
+            ("evt_draw", self.evt_draw.get().is_some()),
+            ("bloodstain_draw", self.bloodstain_draw.get().is_some()),
+            ("ai_disable", self.ai_disable.get().is_some()),
+            ("no_aggro", self.no_aggro.get().is_some()),
+            ("no_fog_walls", self.no_fog_walls.get().is_some()),
+            ("ember", self.ember.get().is_some()),
+            ("rend_chr", self.rend_chr.get().is_some()),
+            ("hide_player", self.hide_player.get().is_some()),
+            ("rend_mesh_hi", self.rend_mesh_hi.get().is_some()),
+            ("debug_draw", self.debug_draw.get().is_some()),
+            ("gravity", self.gravity.get().is_some()),
+            ("collision", self.collision.get().is_some()),
+            ("speed", self.speed.eval().is_some()),
+            ("world_speed", self.world_speed.eval().is_some()),
+            ("position", self.position.1.eval().is_some()),
+            ("map_id", self.map_id.eval().is_some()),
+            ("hp", self.hp.eval().is_some()),
+            ("fp", self.fp.eval().is_some()),
+            ("sp", self.sp.eval().is_some()),
+            ("status_buildup", self.status_buildup.eval().is_some()),
+            ("character_stats", self.character_stats.eval().is_some()),
+            ("attunement", self.attunement.eval().is_some()),
+            ("equipment", self.equipment.eval().is_some()),
+            ("inventory", self.inventory.eval().is_some()),
+            ("estus_allocation", self.estus_allocation.eval().is_some()),
+            ("covenant", self.covenant.eval().is_some()),
+            ("souls", self.souls.eval().is_some()),
+            ("camera", self.camera.eval().is_some()),
+            ("fov", self.fov.eval().is_some()),
+            ("quitout", self.quitout.eval().is_some()),
+            ("cursor_show", self.cursor_show.get().is_some()),
+            ("igt", self.igt.eval().is_some()),
+            ("fps", self.fps.eval().is_some()),
+            ("cur_anim", self.cur_anim.eval().is_some()),
+            ("no_logo", self.no_logo.eval().is_some()),
+            ("current_target", self.current_target.eval().is_some()),
+            ("spawn_item", self.spawn_item_func_ptr != 0 && self.map_item_man != 0),
+            ("travel_menu", self.travel_ptr != 0),
+            ("attune_menu", self.attune_ptr != 0),
+        ]
     }
 }
@@ -0,0 +1,89 @@
+//! Signature-scanning fallback for the base addresses.
+//!
+//! The base addresses in [`crate::codegen::base_addresses`] only cover the game
+//! versions that were known when `cargo xtask codegen` last ran. On any other
+//! version, the same signatures are scanned at runtime instead, so that
+//! everything that can still be found keeps working after a game patch.
+
+use log::*;
+use once_cell::sync::Lazy;
+use windows::Win32::System::LibraryLoader::GetModuleHandleA;
+
+use crate::aob::{aob_scan, aob_scan_rip_relative};
+use crate::codegen::base_addresses::BaseAddresses;
+use crate::codegen::base_signatures::{scanned_base_addresses, SIGNATURES};
+use crate::version::{VERSION, VERSION_KNOWN};
+
+/// How the address is read from a signature's match.
+pub(crate) enum Resolve {
+    /// The address of the match.
+    Direct,
+    /// A RIP-relative address: displacement offset and instruction length.
+    RipRelative(usize, usize),
+    /// A 32-bit immediate at the given offset into the match.
+    Immediate(usize),
+}
+
+/// A signature of a base address, as listed in
+/// `xtask/src/codegen/aob_scans.rs`.
+pub(crate) struct Signature {
+    pub(crate) name: &'static str,
+    pub(crate) pattern: &'static str,
+    pub(crate) resolve: Resolve,
+}
+
+/// Outcome of scanning for a single base address.
+#[derive(Debug, Clone, Copy)]
+pub struct ScanResult {
+    pub name: &'static str,
+    pub address: Option<usize>,
+}
+
+static SCAN_RESULTS: Lazy<Vec<ScanResult>> = Lazy::new(|| {
+    SIGNATURES
+        .iter()
+        .map(|sig| {
+            let address = match sig.resolve {
+                Resolve::Direct => aob_scan(sig.pattern),
+                Resolve::RipRelative(offset, len) => {
+                    aob_scan_rip_relative(sig.pattern, offset, len)
+                },
+                Resolve::Immediate(offset) => aob_scan(sig.pattern).map(|addr| unsafe {
+                    std::ptr::read_unaligned((addr + offset) as *const u32) as usize
+                }),
+            };
+
+            match address {
+                Some(address) => info!("{}: found at {address:#x}", sig.name),
+                None => warn!("{}: signature not found", sig.name),
+            }
+
+            ScanResult { name: sig.name, address }
+        })
+        .collect()
+});
+
+/// Returns the results of the signature scan, or `None` if the game version is
+/// known and the hardcoded base addresses are used instead.
+pub fn scan_results() -> Option<&'static [ScanResult]> {
+    if *VERSION_KNOWN {
+        None
+    } else {
+        Some(SCAN_RESULTS.as_slice())
+    }
+}
+
+fn scanned(name: &str) -> usize {
+    SCAN_RESULTS.iter().find(|r| r.name == name).and_then(|r| r.address).unwrap_or(0)
+}
+
+/// Returns the absolute base addresses for the running executable. Addresses
+/// that couldn't be found by the signature scan are zero.
+pub fn base_addresses() -> BaseAddresses {
+    if *VERSION_KNOWN {
+        let base_module_address = unsafe { GetModuleHandleA(None) }.unwrap().0 as usize;
+        return BaseAddresses::from(*VERSION).with_module_base_addr(base_module_address);
+    }
+
+    scanned_base_addresses(scanned)
+}
@@ -12,9 +12,50 @@ use windows::Win32::System::LibraryLoader::{GetModuleFileNameW, GetModuleHandleW
 
 pub use crate::prelude::base_addresses::Version;
 
-pub static VERSION: Lazy<Version> = Lazy::new(get_version);
+/// Every version the hardcoded base addresses were generated for.
+const KNOWN_VERSIONS: [Version; 20] = [
+    Version::V1_01_1,
+    Version::V1_03_1,
+    Version::V1_03_2,
+    Version::V1_04_1,
+    Version::V1_04_2,
+    Version::V1_04_3,
+    Version::V1_05_0,
+    Version::V1_05_1,
+    Version::V1_06_0,
+    Version::V1_07_0,
+    Version::V1_08_0,
+    Version::V1_09_0,
+    Version::V1_10_0,
+    Version::V1_11_0,
+    Version::V1_12_0,
+    Version::V1_13_0,
+    Version::V1_14_0,
+    Version::V1_15_0,
+    Version::V1_15_1,
+    Version::V1_15_2,
+];
 
-fn get_version() -> Version {
+/// The file version of the running executable.
+pub static FILE_VERSION: Lazy<(u32, u32, u32)> = Lazy::new(get_file_version);
+
+/// Whether [`FILE_VERSION`] is one of the known versions.
+pub static VERSION_KNOWN: Lazy<bool> =
+    Lazy::new(|| KNOWN_VERSIONS.iter().any(|v| <(u32, u32, u32)>::from(*v) == *FILE_VERSION));
+
+/// The game version. Unknown versions are treated as the latest known one, and
+/// their base addresses are found by signature scanning.
+pub static VERSION: Lazy<Version> = Lazy::new(|| {
+    if *VERSION_KNOWN {
+        Version::from(*FILE_VERSION)
+    } else {
+        let (major, minor, patch) = *FILE_VERSION;
+        warn!("Unknown version {major}.{minor:02}.{patch}, falling back to signature scanning");
+        KNOWN_VERSIONS[KNOWN_VERSIONS.len() - 1]
+    }
+});
+
+fn get_file_version() -> (u32, u32, u32) {
     let file_path = {
         let mut buf = vec![0u16; MAX_PATH as usize];
         unsafe { GetModuleFileNameW(GetModuleHandleW(None).unwrap(), &mut buf) };
@@ -49,5 +90,5 @@ fn get_version() -> Version {
     let patch = (version_info.dwFileVersionLS >> 16) & 0xffff;
 
     info!("Version {} {} {}", major, minor, patch);
-    Version::from((major, minor, patch))
+    (major, minor, patch)
 }
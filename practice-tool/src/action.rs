@@ -0,0 +1,51 @@
+//! Actions bound to gamepad input: either one of the remote control commands,
+//! or a key combination pressed as if on the keyboard.
+
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use serde::Deserialize;
+
+use crate::remote::RemoteCommand;
+use crate::util;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub(crate) enum Action {
+    Key { key: KeyCombo },
+    Command(RemoteCommand),
+}
+
+impl Action {
+    /// Runs the action. Commands are queued to be executed by the remote
+    /// control on the render thread.
+    pub(crate) fn run(&self, commands: &Sender<RemoteCommand>) {
+        match self {
+            Action::Key { key } => key.press(),
+            Action::Command(command) => {
+                commands.send(command.clone()).ok();
+            },
+        }
+    }
+}
+
+/// Key combination such as `"ctrl+f4"`, written like hotkeys.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(try_from = "Key")]
+pub(crate) struct KeyCombo(Vec<u16>);
+
+impl KeyCombo {
+    /// Presses and releases the combination, as if typed on the keyboard.
+    pub(crate) fn press(&self) {
+        util::press_key_combo(&self.0);
+    }
+}
+
+impl TryFrom<Key> for KeyCombo {
+    type Error = String;
+
+    fn try_from(key: Key) -> Result<Self, Self::Error> {
+        util::virtual_keys(&key)
+            .map(KeyCombo)
+            .ok_or_else(|| format!("Key combination {key} can't be pressed"))
+    }
+}
@@ -0,0 +1,180 @@
+//! Short sounds played on events, as feedback that doesn't need looking at
+//! the screen.
+//!
+//! Sounds are either tones synthesized at startup or WAV files, and are
+//! played asynchronously from memory through `PlaySound`. Only one sound plays
+//! at a time: a new cue cuts the previous one short, except for the `command`
+//! cue, which doesn't cut the others.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::HMODULE;
+use windows::Win32::Media::Audio::{PlaySoundW, SND_ASYNC, SND_MEMORY, SND_NODEFAULT};
+
+use crate::util;
+
+const SAMPLE_RATE: u32 = 22050;
+
+/// Length of a tone given by its frequency only, in milliseconds.
+const DEFAULT_TONE_DURATION: u32 = 80;
+
+/// How long the `command` cue is held back after another cue, so that it
+/// doesn't cut short the sound of an event, such as a gold split, that
+/// happens along with a command.
+const COMMAND_HOLDOFF: Duration = Duration::from_millis(300);
+
+/// Sounds of the configured cues. The buffers must stay alive while they
+/// play, so they are only dropped after stopping playback.
+static CUES: Lazy<Mutex<Vec<(Cue, Vec<u8>)>>> = Lazy::new(Default::default);
+
+/// When a cue other than `command` last played.
+static LAST_EVENT_CUE: Lazy<Mutex<Option<Instant>>> = Lazy::new(Default::default);
+
+/// Event that can play a sound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Cue {
+    /// A command ran.
+    Command,
+    /// The locked on target's HP crossed a configured threshold.
+    BossHp,
+    /// An invulnerability window started.
+    IFrames,
+    /// A split segment was completed in its best time.
+    Gold,
+}
+
+/// A tone, given by its frequency in Hz and optionally its length in
+/// milliseconds, or a WAV file.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub(crate) enum Sound {
+    Frequency(f32),
+    Tone { frequency: f32, duration: u32 },
+    File { file: PathBuf },
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct AudioConfig {
+    /// Volume of the tones, from 0 to 1. WAV files play at their own volume.
+    #[serde(default = "AudioConfig::default_volume")]
+    volume: f32,
+    command: Option<Sound>,
+    boss_hp: Option<Sound>,
+    iframes: Option<Sound>,
+    gold: Option<Sound>,
+}
+
+impl AudioConfig {
+    fn default_volume() -> f32 {
+        0.5
+    }
+}
+
+/// Encodes a sine tone as a 16-bit mono WAV file. The tone fades in and out
+/// to avoid clicks.
+fn tone(frequency: f32, duration: u32, volume: f32) -> Vec<u8> {
+    let count = SAMPLE_RATE * duration / 1000;
+    let fade = (SAMPLE_RATE / 200).min(count / 2).max(1);
+    let amplitude = volume.clamp(0., 1.) * i16::MAX as f32;
+
+    let mut buf = Vec::with_capacity(44 + count as usize * 2);
+    buf.extend_from_slice(b"RIFF");
+    buf.extend_from_slice(&(36 + count * 2).to_le_bytes());
+    buf.extend_from_slice(b"WAVEfmt ");
+    buf.extend_from_slice(&16u32.to_le_bytes());
+    buf.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    buf.extend_from_slice(&1u16.to_le_bytes()); // Mono
+    buf.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    buf.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    buf.extend_from_slice(&2u16.to_le_bytes());
+    buf.extend_from_slice(&16u16.to_le_bytes());
+    buf.extend_from_slice(b"data");
+    buf.extend_from_slice(&(count * 2).to_le_bytes());
+
+    for i in 0..count {
+        let t = i as f32 / SAMPLE_RATE as f32;
+        let envelope = (i.min(count - 1 - i) as f32 / fade as f32).min(1.);
+        let sample = (t * frequency * std::f32::consts::TAU).sin() * amplitude * envelope;
+        buf.extend_from_slice(&(sample as i16).to_le_bytes());
+    }
+
+    buf
+}
+
+fn load(sound: &Sound, volume: f32) -> Result<Vec<u8>, String> {
+    match sound {
+        Sound::Frequency(frequency) => Ok(tone(*frequency, DEFAULT_TONE_DURATION, volume)),
+        Sound::Tone { frequency, duration } => Ok(tone(*frequency, *duration, volume)),
+        Sound::File { file } => {
+            let path = match util::get_dll_path() {
+                Some(mut dll_path) if file.is_relative() => {
+                    dll_path.pop();
+                    dll_path.join(file)
+                },
+                _ => file.clone(),
+            };
+            fs::read(&path).map_err(|e| format!("Couldn't read sound {}: {e}", path.display()))
+        },
+    }
+}
+
+fn stop() {
+    unsafe { PlaySoundW(PCWSTR::null(), HMODULE::default(), SND_ASYNC) };
+}
+
+/// Replaces the sounds of every cue. Returns the sounds that couldn't be
+/// loaded; their cues stay silent.
+pub(crate) fn configure(config: Option<&AudioConfig>) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut cues = Vec::new();
+
+    if let Some(config) = config {
+        let sounds = [
+            (Cue::Command, &config.command),
+            (Cue::BossHp, &config.boss_hp),
+            (Cue::IFrames, &config.iframes),
+            (Cue::Gold, &config.gold),
+        ];
+
+        for (cue, sound) in sounds {
+            match sound.as_ref().map(|s| load(s, config.volume)) {
+                Some(Ok(buf)) => cues.push((cue, buf)),
+                Some(Err(e)) => errors.push(e),
+                None => {},
+            }
+        }
+    }
+
+    let mut current = CUES.lock();
+    stop();
+    *current = cues;
+
+    errors
+}
+
+/// Plays the sound of a cue, if it has one.
+pub(crate) fn play(cue: Cue) {
+    let mut last_event_cue = LAST_EVENT_CUE.lock();
+    if cue != Cue::Command {
+        *last_event_cue = Some(Instant::now());
+    } else if last_event_cue.is_some_and(|t| t.elapsed() < COMMAND_HOLDOFF) {
+        return;
+    }
+
+    let cues = CUES.lock();
+    if let Some((_, buf)) = cues.iter().find(|(c, _)| *c == cue) {
+        unsafe {
+            PlaySoundW(
+                PCWSTR(buf.as_ptr() as *const u16),
+                HMODULE::default(),
+                SND_MEMORY | SND_ASYNC | SND_NODEFAULT,
+            )
+        };
+    }
+}
@@ -0,0 +1,49 @@
+// Generated by `cargo xtask codegen` from `practice-tool/src/config.rs`.
+
+/// Command fields holding a hotkey.
+pub(crate) const HOTKEY_FIELDS: [&str; 40] = [
+    "savefile_manager",
+    "next",
+    "previous",
+    "item_spawner",
+    "hotkey",
+    "position",
+    "save",
+    "minimap",
+    "place",
+    "clear",
+    "fov_up",
+    "fov_down",
+    "speed_up",
+    "speed_down",
+    "character_stats",
+    "attunement",
+    "param_editor",
+    "memory_viewer",
+    "boss_practice",
+    "covenant",
+    "unlock_bonfires",
+    "estus",
+    "animation",
+    "restart",
+    "souls_input",
+    "quitout",
+    "restore_position",
+    "hit_counter",
+    "heal",
+    "refill",
+    "resurrect_boss",
+    "target",
+    "target_stats",
+    "record",
+    "play",
+    "nudge_up",
+    "nudge_down",
+    "rotate_left",
+    "rotate_right",
+    "snap",
+];
+
+/// Settings fields holding a hotkey.
+pub(crate) const SETTINGS_FIELDS: [&str; 4] =
+    ["display", "hide", "switch_profile", "command_palette"];
@@ -0,0 +1,178 @@
+//! Command palette.
+//!
+//! Opened with its hotkey, it lists the configured commands that can be run
+//! from outside their widget: the ones bound to a hotkey, which is pressed as
+//! if on the keyboard, and the flag and quitout commands without one, which
+//! are run through the remote control. Typing filters the list with a fuzzy
+//! match, the arrow keys move the selection and Enter runs it.
+
+use imgui::{Condition, WindowFlags};
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::widgets::scaling_factor;
+
+use crate::action::Action;
+use crate::remote::RemoteCommand;
+
+/// Most entries shown at once.
+const MAX_SHOWN: usize = 12;
+
+#[derive(Debug, Clone)]
+pub(crate) struct PaletteEntry {
+    pub(crate) label: String,
+    pub(crate) hotkey: Option<String>,
+    pub(crate) action: Action,
+}
+
+/// Scores how well `query` matches `text`, ignoring case and whitespace in
+/// the query. Every character of the query must appear in order in the text;
+/// consecutive characters and characters starting a word score higher.
+fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut last = None;
+
+    for q in query.chars().filter(|c| !c.is_whitespace()).map(|c| c.to_ascii_lowercase()) {
+        let i = pos + text[pos..].iter().position(|&c| c == q)?;
+        score += 1;
+        if i > 0 && last == Some(i - 1) {
+            score += 5;
+        }
+        if i == 0 || !text[i - 1].is_alphanumeric() {
+            score += 3;
+        }
+        last = Some(i);
+        pos = i + 1;
+    }
+
+    Some(score)
+}
+
+pub(crate) struct CommandPalette {
+    entries: Vec<PaletteEntry>,
+    commands: Sender<RemoteCommand>,
+    open: bool,
+    /// Whether the query field should grab the keyboard on the next frame.
+    focus: bool,
+    query: String,
+    selected: usize,
+}
+
+impl CommandPalette {
+    pub(crate) fn new(entries: Vec<PaletteEntry>, commands: Sender<RemoteCommand>) -> Self {
+        CommandPalette {
+            entries,
+            commands,
+            open: false,
+            focus: false,
+            query: String::new(),
+            selected: 0,
+        }
+    }
+
+    pub(crate) fn set_entries(&mut self, entries: Vec<PaletteEntry>) {
+        self.entries = entries;
+        self.selected = 0;
+    }
+
+    pub(crate) fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub(crate) fn toggle(&mut self) {
+        self.open = !self.open;
+        self.focus = self.open;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Indices of the entries matching the query, best first.
+    fn matches(&self) -> Vec<usize> {
+        let mut matches: Vec<(i32, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| Some((fuzzy_score(&self.query, &e.label)?, i)))
+            .collect();
+        matches.sort_by_key(|(score, _)| -score);
+        matches.into_iter().map(|(_, i)| i).collect()
+    }
+
+    pub(crate) fn render(&mut self, ui: &imgui::Ui) {
+        if !self.open {
+            return;
+        }
+
+        let [w, h] = ui.io().display_size;
+        let mut run = None;
+        let mut close = false;
+
+        ui.window("##command-palette")
+            .position([w / 2., h * 0.2], Condition::Always)
+            .position_pivot([0.5, 0.])
+            .size([400. * scaling_factor(ui), 0.], Condition::Always)
+            .flags(
+                WindowFlags::NO_TITLE_BAR
+                    | WindowFlags::NO_RESIZE
+                    | WindowFlags::NO_MOVE
+                    | WindowFlags::NO_SCROLLBAR
+                    | WindowFlags::NO_SAVED_SETTINGS,
+            )
+            .build(|| {
+                if self.focus {
+                    ui.set_keyboard_focus_here();
+                    self.focus = false;
+                }
+                ui.set_next_item_width(-1.);
+                if ui.input_text("##command-palette-query", &mut self.query).build() {
+                    self.selected = 0;
+                }
+
+                let matches = self.matches();
+                let shown = matches.len().min(MAX_SHOWN);
+                if ui.is_key_pressed(imgui::Key::DownArrow) {
+                    self.selected = (self.selected + 1).min(shown.saturating_sub(1));
+                }
+                if ui.is_key_pressed(imgui::Key::UpArrow) {
+                    self.selected = self.selected.saturating_sub(1);
+                }
+                if ui.is_key_pressed(imgui::Key::Enter)
+                    || ui.is_key_pressed(imgui::Key::KeypadEnter)
+                {
+                    run = matches.get(self.selected).map(|&i| self.entries[i].action.clone());
+                    close = true;
+                }
+                if ui.is_key_pressed(imgui::Key::Escape) {
+                    close = true;
+                }
+
+                if matches.is_empty() {
+                    ui.text_disabled("No matching command");
+                }
+
+                for (i, entry) in
+                    matches.iter().map(|&i| &self.entries[i]).enumerate().take(MAX_SHOWN)
+                {
+                    let label = match &entry.hotkey {
+                        Some(key) => format!("{} ({key})##{i}", entry.label),
+                        None => format!("{}##{i}", entry.label),
+                    };
+                    if ui.selectable_config(&label).selected(i == self.selected).build() {
+                        run = Some(entry.action.clone());
+                        close = true;
+                    }
+                }
+                if matches.len() > MAX_SHOWN {
+                    ui.text_disabled(format!("{} more", matches.len() - MAX_SHOWN));
+                }
+            });
+
+        if close {
+            self.toggle();
+        }
+
+        if let Some(action) = run {
+            action.run(&self.commands);
+        }
+    }
+}
@@ -1,43 +1,298 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use libds3::prelude::*;
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::Widget;
 use serde::Deserialize;
+use toml_edit::{Array, DocumentMut};
 use tracing_subscriber::filter::LevelFilter;
 
+use crate::action::{Action, KeyCombo};
+use crate::audio::AudioConfig;
+use crate::command_palette::PaletteEntry;
+use crate::gamepad::{GamepadHotkey, RumbleConfig};
+use crate::hotkey_editor::{command_label, hotkey_field};
+use crate::overlay::OverlayConfig;
+use crate::radial_menu::RadialMenuConfig;
+use crate::remote::RemoteCommand;
+use crate::util;
+use crate::widgets::animation::Animation;
+use crate::widgets::area::{in_area, AreaSpec, NamedPoint};
+use crate::widgets::attunement::Attunement;
+use crate::widgets::boss_practice::{boss_practice, BossPreset};
 use crate::widgets::character_stats::character_stats_edit;
-use crate::widgets::cycle_speed::cycle_speed;
+use crate::widgets::covenant::Covenant;
+use crate::widgets::cycle_speed::{cycle_speed, step_speed, SpeedTarget};
+use crate::widgets::estus::Estus;
 use crate::widgets::flag::flag_widget;
+use crate::widgets::flag_watch::{flag_watch, WatchedFlags};
+use crate::widgets::fov::fov;
+use crate::widgets::ghost::Ghost;
 use crate::widgets::group::group;
+use crate::widgets::heal::{heal, set_hp};
+use crate::widgets::hit_counter::HitCounter;
+use crate::widgets::input_macro::InputMacro;
+use crate::widgets::interlock::interlocked;
+use crate::widgets::item_preset::{item_preset, PresetItem};
 use crate::widgets::item_spawn::ItemSpawner;
 use crate::widgets::label::label_widget;
-use crate::widgets::nudge_pos::nudge_position;
+use crate::widgets::loadout::{loadout, LoadoutSpec};
+use crate::widgets::memory_viewer::MemoryViewer;
+use crate::widgets::minimap::minimap;
+use crate::widgets::nudge_pos::{nudge_position, NudgeRotation};
 use crate::widgets::open_menu::{open_menu, OpenMenuKind};
+use crate::widgets::param_editor::ParamEditor;
 use crate::widgets::position::save_position;
-use crate::widgets::quitout::quitout;
-use crate::widgets::savefile_manager::savefile_manager;
-use crate::widgets::souls::souls;
+use crate::widgets::quitout::{quitout, QuitoutPosition};
+use crate::widgets::refill::refill;
+use crate::widgets::resurrect_boss::ResurrectBoss;
+use crate::widgets::savefile_manager::{savefile_manager, SavefileCycle};
+use crate::widgets::scenario::{scenario, ScenarioConfig};
+use crate::widgets::script::Script;
+use crate::widgets::sequence::{sequence, SequenceStep};
+use crate::widgets::souls::{souls, SoulsInput, SoulsMode};
+use crate::widgets::splits::{SegmentConfig, Splits};
+use crate::widgets::switchable::switchable;
 use crate::widgets::target::Target;
+use crate::widgets::target_hp::{HpAlerts, TargetHp};
+use crate::widgets::target_stats::TargetStats;
+use crate::widgets::tooltip::described;
+use crate::widgets::trail::trail;
+use crate::widgets::unlock_bonfires::unlock_bonfires;
+use crate::widgets::waypoints::waypoints;
 
 #[derive(Debug, Deserialize)]
 pub(crate) struct Config {
     pub(crate) settings: Settings,
-    commands: Vec<CfgCommand>,
+    #[serde(default)]
+    pub(crate) theme: Theme,
+    #[serde(default)]
+    pub(crate) font: FontConfig,
+    commands: Vec<CommandSpec>,
+    #[serde(default)]
+    profiles: Vec<Profile>,
+    #[serde(default, rename = "scenario")]
+    scenarios: Vec<ScenarioConfig>,
+    /// Names of the top level commands, by profile.
+    #[serde(skip)]
+    names: Vec<Vec<String>>,
+    /// Command palette entries, by profile.
+    #[serde(skip)]
+    palette: Vec<Vec<PaletteEntry>>,
+}
+
+/// Alternative set of commands that can be switched to at runtime.
+#[derive(Debug, Deserialize)]
+struct Profile {
+    name: String,
+    commands: Vec<CommandSpec>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub(crate) struct Settings {
     pub(crate) log_level: LevelFilterSerde,
+    #[serde(default)]
+    pub(crate) log_format: LogFormat,
     pub(crate) display: Key,
     pub(crate) hide: Option<Key>,
     #[serde(default)]
     pub(crate) show_console: bool,
     #[serde(default = "Indicator::default_set")]
     pub(crate) indicators: Vec<Indicator>,
+    #[serde(default)]
+    pub(crate) remote_port: Option<u16>,
+    #[serde(default)]
+    pub(crate) command_pipe: bool,
+    #[serde(default)]
+    pub(crate) log_position: Option<Placement>,
+    #[serde(default)]
+    pub(crate) log_panel: bool,
+    #[serde(default)]
+    pub(crate) telemetry: Option<TelemetryConfig>,
+    #[serde(default)]
+    pub(crate) overlay: Option<OverlayConfig>,
+    #[serde(default)]
+    pub(crate) switch_profile: Option<Key>,
+    #[serde(default)]
+    pub(crate) command_palette: Option<Key>,
+    #[serde(default)]
+    pub(crate) radial_menu: Option<RadialMenuConfig>,
+    #[serde(default)]
+    pub(crate) gamepad_hotkeys: Vec<GamepadHotkey>,
+    #[serde(default)]
+    pub(crate) rumble: Option<RumbleConfig>,
+    #[serde(default)]
+    pub(crate) audio: Option<AudioConfig>,
+    #[serde(default)]
+    pub(crate) boss_presets: Vec<BossPreset>,
+    #[serde(default)]
+    pub(crate) check_updates: bool,
+    /// Only keep the commands that don't change the game.
+    #[serde(default)]
+    pub(crate) safe_mode: bool,
+    /// Only keep what leaderboard runs are allowed to use, and say so on
+    /// screen.
+    #[serde(default)]
+    pub(crate) speedrun_mode: bool,
+    /// Disable the commands that change the game while it is online.
+    #[serde(default = "Settings::default_online_interlock")]
+    pub(crate) online_interlock: bool,
+    /// Graphics API to hook, instead of detecting it.
+    #[serde(default)]
+    pub(crate) render_backend: Option<RenderBackend>,
+}
+
+/// Graphics API the tool draws with.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RenderBackend {
+    Dx11,
+    Dx12,
+}
+
+impl Settings {
+    fn default_online_interlock() -> bool {
+        true
+    }
+
+    /// Whether the tool must not write to the game's memory.
+    pub(crate) fn read_only(&self) -> bool {
+        self.safe_mode || self.speedrun_mode
+    }
+
+    /// Keeps the restricted modes of `previous`, the settings in use so far:
+    /// once on, they stay on until the tool is ejected, whatever the edited
+    /// configuration says.
+    pub(crate) fn keep_restrictions(&mut self, previous: &Settings) {
+        self.safe_mode |= previous.safe_mode;
+        self.speedrun_mode |= previous.speedrun_mode;
+        self.retain_indicators();
+    }
+
+    /// Drops the indicators that aren't allowed in speedrun mode.
+    fn retain_indicators(&mut self) {
+        if self.speedrun_mode {
+            self.indicators.retain(|i| i.indicator.is_speedrun_legal());
+            if let Some(overlay) = &mut self.overlay {
+                overlay.indicators.retain(|i| i.is_speedrun_legal());
+            }
+        }
+    }
+
+    /// Which commands are kept, if the tool runs in a restricted mode.
+    fn command_filter(&self) -> Option<CommandFilter> {
+        if self.speedrun_mode {
+            Some(CfgCommand::is_speedrun_legal)
+        } else if self.safe_mode {
+            Some(CfgCommand::is_read_only)
+        } else {
+            None
+        }
+    }
+}
+
+/// Format of the log file.
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum LogFormat {
+    #[default]
+    Text,
+    /// One JSON object per line, for other tools to parse.
+    Json,
 }
 
+/// Opt-in per-frame telemetry export to CSV.
 #[derive(Debug, Deserialize, Clone)]
+pub(crate) struct TelemetryConfig {
+    /// Write a row every this many frames.
+    #[serde(default = "TelemetryConfig::default_every")]
+    pub(crate) every: u32,
+}
+
+impl TelemetryConfig {
+    fn default_every() -> u32 {
+        1
+    }
+}
+
+/// Look of the tool's windows, applied to the imgui style at init.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct Theme {
+    pub(crate) window_alpha: f32,
+    pub(crate) accent: Option<Color>,
+    pub(crate) accent_hovered: Option<Color>,
+    pub(crate) accent_active: Option<Color>,
+    pub(crate) position_x: Color,
+    pub(crate) position_y: Color,
+    pub(crate) position_z: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            window_alpha: 0.8,
+            accent: None,
+            accent_hovered: None,
+            accent_active: None,
+            position_x: Color([0.7048, 0.1228, 0.1734, 1.]),
+            position_y: Color([0.1161, 0.5327, 0.3512, 1.]),
+            position_z: Color([0.1445, 0.2852, 0.5703, 1.]),
+        }
+    }
+}
+
+/// Font files to try in order, and the pixel sizes used depending on the
+/// screen resolution. The embedded font is used if none of the files can be
+/// loaded.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct FontConfig {
+    pub(crate) paths: Vec<PathBuf>,
+    pub(crate) small: f32,
+    pub(crate) normal: f32,
+    pub(crate) big: f32,
+}
+
+impl Default for FontConfig {
+    fn default() -> Self {
+        FontConfig { paths: Vec::new(), small: 11., normal: 18., big: 24. }
+    }
+}
+
+/// An RGBA color, written as `"#rrggbb"` or `"#rrggbbaa"`.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(try_from = "String")]
+pub(crate) struct Color(pub(crate) [f32; 4]);
+
+impl TryFrom<String> for Color {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let hex = value.strip_prefix('#').unwrap_or(&value);
+        let component = |i: usize| {
+            hex.get(i * 2..i * 2 + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .map(|c| c as f32 / 255.)
+        };
+
+        let color = match hex.len() {
+            6 => [component(0), component(1), component(2), Some(1.)],
+            8 => [component(0), component(1), component(2), component(3)],
+            _ => [None; 4],
+        };
+
+        match color {
+            [Some(r), Some(g), Some(b), Some(a)] => Ok(Color([r, g, b, a])),
+            _ => Err(format!("Invalid color: {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
 pub(crate) enum IndicatorType {
     Igt,
     Position,
@@ -46,25 +301,113 @@ pub(crate) enum IndicatorType {
     Fps,
     FrameCount,
     Animation,
+    InputDisplay,
+    Deaths,
+    IFrames,
+    StatusBuildup,
+    Vitals,
+    Compass,
+    SpeedGraph,
+    TargetDistance,
+    Area,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+impl IndicatorType {
+    /// Whether the indicator is allowed in speedrun mode: it only shows what
+    /// the game shows, or timing.
+    fn is_speedrun_legal(&self) -> bool {
+        match self {
+            IndicatorType::Igt
+            | IndicatorType::GameVersion
+            | IndicatorType::ImguiDebug
+            | IndicatorType::Fps
+            | IndicatorType::FrameCount
+            | IndicatorType::InputDisplay
+            | IndicatorType::Deaths
+            | IndicatorType::Compass
+            | IndicatorType::Area
+            | IndicatorType::Animation => true,
+            IndicatorType::Position
+            | IndicatorType::IFrames
+            | IndicatorType::StatusBuildup
+            | IndicatorType::TargetDistance
+            | IndicatorType::Vitals
+            | IndicatorType::SpeedGraph => false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
 #[serde(try_from = "IndicatorConfig")]
 pub(crate) struct Indicator {
     pub(crate) indicator: IndicatorType,
     pub(crate) enabled: bool,
+    /// Where to draw the indicator on its own. Indicators without a placement
+    /// are listed below the tool's header.
+    pub(crate) placement: Option<Placement>,
+    /// How long the indicator's text is kept before being formatted again.
+    /// Refreshed every frame when `None`.
+    pub(crate) refresh_interval: Option<Duration>,
+}
+
+/// Screen corner a window is anchored to.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Anchor {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Position of a window relative to a screen corner. The offset is in pixels,
+/// measured from the corner towards the center of the screen.
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub(crate) struct Placement {
+    pub(crate) anchor: Anchor,
+    #[serde(default)]
+    pub(crate) offset: [f32; 2],
+}
+
+impl Placement {
+    /// Returns the window position and pivot for the given display size.
+    pub(crate) fn position(&self, [dw, dh]: [f32; 2]) -> ([f32; 2], [f32; 2]) {
+        let [ox, oy] = self.offset;
+        match self.anchor {
+            Anchor::TopLeft => ([ox, oy], [0., 0.]),
+            Anchor::TopRight => ([dw - ox, oy], [1., 0.]),
+            Anchor::BottomLeft => ([ox, dh - oy], [0., 1.]),
+            Anchor::BottomRight => ([dw - ox, dh - oy], [1., 1.]),
+        }
+    }
 }
 
 impl Indicator {
     fn default_set() -> Vec<Indicator> {
+        let indicator = |indicator, enabled| Indicator {
+            indicator,
+            enabled,
+            placement: None,
+            refresh_interval: None,
+        };
+
         vec![
-            Indicator { indicator: IndicatorType::GameVersion, enabled: true },
-            Indicator { indicator: IndicatorType::Igt, enabled: true },
-            Indicator { indicator: IndicatorType::Position, enabled: false },
-            Indicator { indicator: IndicatorType::Animation, enabled: false },
-            Indicator { indicator: IndicatorType::Fps, enabled: false },
-            Indicator { indicator: IndicatorType::FrameCount, enabled: false },
-            Indicator { indicator: IndicatorType::ImguiDebug, enabled: false },
+            indicator(IndicatorType::GameVersion, true),
+            indicator(IndicatorType::Igt, true),
+            indicator(IndicatorType::Position, false),
+            indicator(IndicatorType::Animation, false),
+            indicator(IndicatorType::Fps, false),
+            indicator(IndicatorType::FrameCount, false),
+            indicator(IndicatorType::InputDisplay, false),
+            indicator(IndicatorType::Deaths, false),
+            indicator(IndicatorType::IFrames, false),
+            indicator(IndicatorType::StatusBuildup, false),
+            indicator(IndicatorType::Vitals, false),
+            indicator(IndicatorType::Compass, false),
+            indicator(IndicatorType::SpeedGraph, false),
+            indicator(IndicatorType::TargetDistance, false),
+            indicator(IndicatorType::Area, false),
+            indicator(IndicatorType::ImguiDebug, false),
         ]
     }
 }
@@ -73,32 +416,61 @@ impl Indicator {
 struct IndicatorConfig {
     indicator: String,
     enabled: bool,
+    anchor: Option<Anchor>,
+    #[serde(default)]
+    offset: [f32; 2],
+    /// Times per second.
+    refresh_rate: Option<f64>,
 }
 
 impl TryFrom<IndicatorConfig> for Indicator {
     type Error = String;
 
     fn try_from(indicator: IndicatorConfig) -> Result<Self, Self::Error> {
-        match indicator.indicator.as_str() {
-            "igt" => Ok(Indicator { indicator: IndicatorType::Igt, enabled: indicator.enabled }),
-            "position" => {
-                Ok(Indicator { indicator: IndicatorType::Position, enabled: indicator.enabled })
-            },
-            "game_version" => {
-                Ok(Indicator { indicator: IndicatorType::GameVersion, enabled: indicator.enabled })
-            },
-            "imgui_debug" => {
-                Ok(Indicator { indicator: IndicatorType::ImguiDebug, enabled: indicator.enabled })
-            },
-            "fps" => Ok(Indicator { indicator: IndicatorType::Fps, enabled: indicator.enabled }),
-            "framecount" => {
-                Ok(Indicator { indicator: IndicatorType::FrameCount, enabled: indicator.enabled })
-            },
-            "animation" => {
-                Ok(Indicator { indicator: IndicatorType::Animation, enabled: indicator.enabled })
-            },
-            value => Err(format!("Unrecognized indicator: {value}")),
-        }
+        let indicator_type = match indicator.indicator.as_str() {
+            "igt" => IndicatorType::Igt,
+            "position" => IndicatorType::Position,
+            "game_version" => IndicatorType::GameVersion,
+            "imgui_debug" => IndicatorType::ImguiDebug,
+            "fps" => IndicatorType::Fps,
+            "framecount" => IndicatorType::FrameCount,
+            "animation" => IndicatorType::Animation,
+            "input_display" => IndicatorType::InputDisplay,
+            "deaths" => IndicatorType::Deaths,
+            "iframes" => IndicatorType::IFrames,
+            "status_buildup" => IndicatorType::StatusBuildup,
+            "vitals" => IndicatorType::Vitals,
+            "compass" => IndicatorType::Compass,
+            "speed_graph" => IndicatorType::SpeedGraph,
+            "target_distance" => IndicatorType::TargetDistance,
+            "area" => IndicatorType::Area,
+            value => return Err(format!("Unrecognized indicator: {value}")),
+        };
+
+        let refresh_interval = indicator
+            .refresh_rate
+            .map(|rate| {
+                // Rates so small that the interval overflows a `Duration` are rejected too.
+                Some(rate)
+                    .filter(|rate| *rate > 0. && rate.is_finite())
+                    .and_then(|rate| Duration::try_from_secs_f64(1. / rate).ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "Invalid refresh rate for indicator {}: {rate}",
+                            indicator.indicator
+                        )
+                    })
+            })
+            .transpose()?;
+
+        Ok(Indicator {
+            indicator: indicator_type,
+            enabled: indicator.enabled,
+            placement: indicator
+                .anchor
+                .map(|anchor| Placement { anchor, offset: indicator.offset }),
+            refresh_interval,
+        })
     }
 }
 
@@ -119,12 +491,79 @@ impl<T> PlaceholderOption<T> {
     }
 }
 
+/// A command along with the fields every command accepts.
+#[derive(Debug, Deserialize)]
+struct CommandSpec {
+    #[serde(flatten)]
+    command: CfgCommand,
+    /// Shown as a tooltip when hovering the command.
+    description: Option<String>,
+}
+
+/// Tells which commands are kept in a restricted mode.
+type CommandFilter = fn(&CfgCommand) -> bool;
+
+impl CommandSpec {
+    /// Whether the command is kept by `keep`, or is a group with commands
+    /// that are.
+    fn is_kept(&self, keep: CommandFilter) -> bool {
+        match &self.command {
+            CfgCommand::Group { commands, .. } | CfgCommand::InArea { commands, .. } => {
+                commands.iter().any(|c| c.is_kept(keep))
+            },
+            command => keep(command),
+        }
+    }
+
+    /// Drops the command, or the commands of a group, that aren't kept by
+    /// `keep`.
+    fn retain(self, keep: CommandFilter) -> Option<CommandSpec> {
+        let retain = |commands: Vec<CommandSpec>| {
+            let commands: Vec<_> = commands.into_iter().filter_map(|c| c.retain(keep)).collect();
+            (!commands.is_empty()).then_some(commands)
+        };
+
+        let command = match self.command {
+            CfgCommand::Group { label, collapsible, commands } => {
+                CfgCommand::Group { label, collapsible, commands: retain(commands)? }
+            },
+            CfgCommand::InArea { areas, commands } => {
+                CfgCommand::InArea { areas, commands: retain(commands)? }
+            },
+            command if keep(&command) => command,
+            _ => return None,
+        };
+
+        Some(CommandSpec { command, description: self.description })
+    }
+
+    fn into_widget(self, settings: &Settings, chains: &PointerChains) -> Box<dyn Widget> {
+        let interlock = settings.online_interlock
+            && !matches!(self.command, CfgCommand::Group { .. } | CfgCommand::InArea { .. })
+            && !self.command.is_read_only();
+        let widget = self.command.into_widget(settings, chains);
+        let widget = if interlock { interlocked(widget) } else { widget };
+        match self.description {
+            Some(description) => described(description, widget),
+            None => widget,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 enum CfgCommand {
     SavefileManager {
         #[serde(rename = "savefile_manager")]
         hotkey_load: PlaceholderOption<Key>,
+        #[serde(default)]
+        backups: Option<usize>,
+        #[serde(default)]
+        next: Option<Key>,
+        #[serde(default)]
+        previous: Option<Key>,
+        #[serde(default)]
+        folder: Option<PathBuf>,
     },
     ItemSpawner {
         #[serde(rename = "item_spawner")]
@@ -134,6 +573,13 @@ enum CfgCommand {
         flag: FlagSpec,
         hotkey: Option<Key>,
     },
+    // Before `Label`, which would otherwise match any sequence with a label.
+    Sequence {
+        #[serde(rename = "sequence")]
+        steps: Vec<SequenceStep>,
+        label: Option<String>,
+        hotkey: Option<Key>,
+    },
     Label {
         #[serde(rename = "label")]
         label: String,
@@ -141,20 +587,115 @@ enum CfgCommand {
     Position {
         position: PlaceholderOption<Key>,
         save: Option<Key>,
+        #[serde(default)]
+        camera: bool,
+    },
+    FlagWatch {
+        #[serde(rename = "watch_flags")]
+        flags: Vec<WatchedFlags>,
+        hotkey: Option<Key>,
+    },
+    Minimap {
+        #[serde(rename = "minimap")]
+        hotkey: PlaceholderOption<Key>,
+        #[serde(default)]
+        points: Vec<NamedPoint>,
+        placement: Option<Placement>,
+    },
+    Waypoints {
+        #[serde(rename = "waypoints")]
+        points: Vec<NamedPoint>,
+        place: Option<Key>,
+        clear: Option<Key>,
+    },
+    Trail {
+        #[serde(rename = "trail")]
+        seconds: f32,
+        hotkey: Option<Key>,
+    },
+    Fov {
+        #[serde(rename = "fov")]
+        step: f32,
+        fov_up: Option<Key>,
+        fov_down: Option<Key>,
     },
     CycleSpeed {
         #[serde(rename = "cycle_speed")]
         values: Vec<f32>,
+        #[serde(default)]
+        target: SpeedTarget,
         hotkey: Option<Key>,
     },
+    StepSpeed {
+        #[serde(rename = "speed_step")]
+        step: f32,
+        #[serde(default = "default_speed_range")]
+        range: [f32; 2],
+        #[serde(default)]
+        target: SpeedTarget,
+        speed_up: Option<Key>,
+        speed_down: Option<Key>,
+    },
     CharacterStats {
         #[serde(rename = "character_stats")]
         value: PlaceholderOption<Key>,
     },
+    Attunement {
+        #[serde(rename = "attunement")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    Loadout {
+        loadout: String,
+        equipment: LoadoutSpec,
+        hotkey: Option<Key>,
+    },
+    ItemPreset {
+        preset: String,
+        items: Vec<PresetItem>,
+        hotkey: Option<Key>,
+    },
+    ParamEditor {
+        #[serde(rename = "param_editor")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    MemoryViewer {
+        #[serde(rename = "memory_viewer")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    BossPractice {
+        #[serde(rename = "boss_practice")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    Covenant {
+        #[serde(rename = "covenant")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    UnlockBonfires {
+        #[serde(rename = "unlock_bonfires")]
+        hotkey: PlaceholderOption<Key>,
+        #[serde(default)]
+        flags: Vec<u32>,
+    },
+    Estus {
+        #[serde(rename = "estus")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    Animation {
+        #[serde(rename = "animation")]
+        pause: PlaceholderOption<Key>,
+        restart: Option<Key>,
+    },
     Souls {
         #[serde(rename = "souls")]
         amount: u32,
+        #[serde(default)]
+        mode: SoulsMode,
         hotkey: Option<Key>,
+        repeat: Option<f32>,
+    },
+    SoulsInput {
+        #[serde(rename = "souls_input")]
+        hotkey: PlaceholderOption<Key>,
     },
     OpenMenu {
         #[serde(rename = "open_menu")]
@@ -164,33 +705,203 @@ enum CfgCommand {
     Quitout {
         #[serde(rename = "quitout")]
         hotkey: PlaceholderOption<Key>,
+        #[serde(default)]
+        reload: bool,
+        #[serde(default)]
+        save_position: bool,
+        restore_position: Option<Key>,
+    },
+    HitCounter {
+        #[serde(rename = "hit_counter")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    Heal {
+        #[serde(rename = "heal")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    SetHp {
+        #[serde(rename = "set_hp")]
+        value: u32,
+        hotkey: Option<Key>,
+    },
+    Refill {
+        #[serde(rename = "refill")]
+        hotkey: PlaceholderOption<Key>,
+        #[serde(default)]
+        consumables: Vec<u32>,
+    },
+    ResurrectBoss {
+        #[serde(rename = "resurrect_boss")]
+        hotkey: PlaceholderOption<Key>,
     },
     Target {
         #[serde(rename = "target")]
         hotkey: PlaceholderOption<Key>,
     },
+    TargetStats {
+        #[serde(rename = "target_stats")]
+        hotkey: PlaceholderOption<Key>,
+    },
+    HpAlerts {
+        #[serde(rename = "hp_alerts")]
+        thresholds: Vec<f32>,
+        #[serde(default)]
+        silent: bool,
+    },
+    TargetHp {
+        #[serde(rename = "target_hp")]
+        percent: f32,
+        hotkey: Option<Key>,
+    },
+    Script {
+        script: String,
+        hotkey: Option<Key>,
+    },
+    Ghost {
+        ghost: String,
+        record: Option<Key>,
+        play: Option<Key>,
+    },
+    InputMacro {
+        #[serde(rename = "macro")]
+        name: String,
+        record: Option<Key>,
+        play: Option<Key>,
+    },
+    Splits {
+        splits: String,
+        segments: Vec<SegmentConfig>,
+        hotkey: Option<Key>,
+    },
     NudgePosition {
         nudge: f32,
         nudge_up: Option<Key>,
         nudge_down: Option<Key>,
+        repeat: Option<f32>,
+        #[serde(default)]
+        rotate: f32,
+        rotate_left: Option<Key>,
+        rotate_right: Option<Key>,
+        #[serde(default = "default_snap_angle")]
+        snap_angle: f32,
+        snap: Option<Key>,
     },
     Group {
         #[serde(rename = "group")]
         label: String,
-        commands: Vec<CfgCommand>,
+        #[serde(default)]
+        collapsible: bool,
+        commands: Vec<CommandSpec>,
+    },
+    InArea {
+        #[serde(rename = "area")]
+        areas: Vec<AreaSpec>,
+        commands: Vec<CommandSpec>,
     },
 }
 
+fn default_snap_angle() -> f32 {
+    45.
+}
+
+fn default_speed_range() -> [f32; 2] {
+    [0.1, 10.]
+}
+
+/// What a command does to the game, from the least to the most intrusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Access {
+    /// Doesn't show anything the game doesn't, or only manages savefiles and
+    /// timing. Allowed in runs submitted to the leaderboards.
+    SpeedrunLegal,
+    /// Only reads the game's memory.
+    ReadOnly,
+    /// Writes to the game's memory.
+    Writes,
+}
+
 impl CfgCommand {
+    /// What the command does to the game. Savefiles are files on disk, not
+    /// memory, so the savefile manager doesn't write. Groups are containers:
+    /// their commands are checked one by one.
+    fn access(&self) -> Access {
+        match self {
+            CfgCommand::Label { .. }
+            | CfgCommand::SavefileManager { .. }
+            | CfgCommand::HitCounter { .. }
+            | CfgCommand::Splits { .. }
+            | CfgCommand::Group { .. }
+            | CfgCommand::InArea { .. } => Access::SpeedrunLegal,
+            CfgCommand::FlagWatch { .. }
+            | CfgCommand::Minimap { .. }
+            | CfgCommand::Waypoints { .. }
+            | CfgCommand::Trail { .. }
+            | CfgCommand::TargetStats { .. }
+            | CfgCommand::HpAlerts { .. }
+            | CfgCommand::Ghost { .. } => Access::ReadOnly,
+            CfgCommand::ItemSpawner { .. }
+            | CfgCommand::Flag { .. }
+            | CfgCommand::Sequence { .. }
+            | CfgCommand::Position { .. }
+            | CfgCommand::Fov { .. }
+            | CfgCommand::CycleSpeed { .. }
+            | CfgCommand::StepSpeed { .. }
+            | CfgCommand::CharacterStats { .. }
+            | CfgCommand::Attunement { .. }
+            | CfgCommand::Loadout { .. }
+            | CfgCommand::ItemPreset { .. }
+            | CfgCommand::ParamEditor { .. }
+            | CfgCommand::MemoryViewer { .. }
+            | CfgCommand::BossPractice { .. }
+            | CfgCommand::Covenant { .. }
+            | CfgCommand::UnlockBonfires { .. }
+            | CfgCommand::Estus { .. }
+            | CfgCommand::Animation { .. }
+            | CfgCommand::Souls { .. }
+            | CfgCommand::SoulsInput { .. }
+            | CfgCommand::OpenMenu { .. }
+            | CfgCommand::Quitout { .. }
+            | CfgCommand::Heal { .. }
+            | CfgCommand::SetHp { .. }
+            | CfgCommand::Refill { .. }
+            | CfgCommand::ResurrectBoss { .. }
+            | CfgCommand::Target { .. }
+            | CfgCommand::TargetHp { .. }
+            | CfgCommand::Script { .. }
+            | CfgCommand::InputMacro { .. }
+            | CfgCommand::NudgePosition { .. } => Access::Writes,
+        }
+    }
+
+    /// Whether the command only reads the game's memory.
+    fn is_read_only(&self) -> bool {
+        self.access() <= Access::ReadOnly
+    }
+
+    /// Whether the command is allowed in runs submitted to the leaderboards.
+    fn is_speedrun_legal(&self) -> bool {
+        self.access() == Access::SpeedrunLegal
+    }
+
     fn into_widget(self, settings: &Settings, chains: &PointerChains) -> Box<dyn Widget> {
         match self {
             CfgCommand::Flag { flag, hotkey: key } => {
-                flag_widget(&flag.label, (flag.getter)(chains).clone(), key)
+                flag_widget(&flag.label, (flag.getter)(chains).clone(), flag.warning, key)
             },
             CfgCommand::Label { label } => label_widget(label.as_str()),
-            CfgCommand::SavefileManager { hotkey_load: key_load } => {
-                savefile_manager(key_load.into_option(), settings.display)
-            },
+            CfgCommand::SavefileManager {
+                hotkey_load: key_load,
+                backups,
+                next,
+                previous,
+                folder,
+            } => savefile_manager(
+                key_load.into_option(),
+                settings.display,
+                backups,
+                SavefileCycle { next, previous, folder },
+                chains.hp.clone(),
+            ),
             CfgCommand::ItemSpawner { hotkey_load: key_load } => Box::new(ItemSpawner::new(
                 chains.spawn_item_func_ptr as usize,
                 chains.map_item_man as usize,
@@ -198,22 +909,137 @@ impl CfgCommand {
                 key_load.into_option(),
                 settings.display,
             )),
-            CfgCommand::Position { position, save } => {
-                save_position(chains.position.clone(), position.into_option(), save)
-            },
-            CfgCommand::NudgePosition { nudge, nudge_up, nudge_down } => {
-                nudge_position(chains.position.clone(), nudge, nudge_up, nudge_down)
-            },
+            CfgCommand::Position { position, save, camera } => save_position(
+                chains.position.clone(),
+                camera.then(|| chains.camera.clone()),
+                position.into_option(),
+                save,
+                settings.display,
+            ),
+            CfgCommand::NudgePosition {
+                nudge,
+                nudge_up,
+                nudge_down,
+                repeat,
+                rotate,
+                rotate_left,
+                rotate_right,
+                snap_angle,
+                snap,
+            } => nudge_position(
+                chains.position.clone(),
+                nudge,
+                nudge_up,
+                nudge_down,
+                repeat,
+                NudgeRotation { rotate, rotate_left, rotate_right, snap_angle, snap },
+            ),
             CfgCommand::CharacterStats { value } => character_stats_edit(
                 chains.character_stats.clone(),
                 value.into_option(),
                 settings.display,
             ),
-            CfgCommand::CycleSpeed { values, hotkey } => {
-                cycle_speed(values.as_slice(), chains.speed.clone(), hotkey)
+            CfgCommand::Attunement { hotkey } => Box::new(Attunement::new(
+                chains.attunement.clone(),
+                hotkey.into_option(),
+                settings.display,
+            )),
+            CfgCommand::Loadout { loadout: name, equipment, hotkey } => {
+                loadout(&name, equipment, chains.equipment.clone(), hotkey)
+            },
+            CfgCommand::ItemPreset { preset, items, hotkey } => item_preset(
+                &preset,
+                items,
+                chains.spawn_item_func_ptr as usize,
+                chains.map_item_man as usize,
+                chains.gravity.clone(),
+                hotkey,
+            ),
+            CfgCommand::ParamEditor { hotkey } => {
+                Box::new(ParamEditor::new(hotkey.into_option(), settings.display))
+            },
+            CfgCommand::MemoryViewer { hotkey } => {
+                Box::new(MemoryViewer::new(hotkey.into_option(), settings.display))
+            },
+            CfgCommand::BossPractice { hotkey } => {
+                boss_practice(&settings.boss_presets, hotkey.into_option(), settings.display)
             },
-            CfgCommand::Souls { amount, hotkey } => souls(amount, chains.souls.clone(), hotkey),
-            CfgCommand::Quitout { hotkey } => quitout(chains.quitout.clone(), hotkey.into_option()),
+            CfgCommand::Covenant { hotkey } => {
+                Box::new(Covenant::new(chains, hotkey.into_option(), settings.display))
+            },
+            CfgCommand::UnlockBonfires { hotkey, flags } => {
+                unlock_bonfires(chains.hp.clone(), flags, hotkey.into_option())
+            },
+            CfgCommand::Estus { hotkey } => Box::new(Estus::new(
+                chains.estus_allocation.clone(),
+                chains.inventory.clone(),
+                hotkey.into_option(),
+                settings.display,
+            )),
+            CfgCommand::FlagWatch { flags, hotkey } => flag_watch(&flags, hotkey),
+            CfgCommand::Minimap { hotkey, points, placement } => minimap(
+                chains.position.clone(),
+                chains.map_id.clone(),
+                points,
+                placement,
+                hotkey.into_option(),
+            ),
+            CfgCommand::Waypoints { points, place, clear } => {
+                waypoints(chains, points, place, clear)
+            },
+            CfgCommand::Trail { seconds, hotkey } => trail(chains, seconds, hotkey),
+            CfgCommand::Fov { step, fov_up, fov_down } => {
+                fov(chains.fov.clone(), step, fov_up, fov_down)
+            },
+            CfgCommand::CycleSpeed { values, target, hotkey } => {
+                cycle_speed(values.as_slice(), target.speed(chains), hotkey)
+            },
+            CfgCommand::StepSpeed { step, range: [min, max], target, speed_up, speed_down } => {
+                step_speed(step, (min, max), target.speed(chains), speed_up, speed_down)
+            },
+            CfgCommand::Animation { pause, restart } => Box::new(Animation::new(
+                chains.cur_anim.clone(),
+                chains.cur_anim_time.clone(),
+                chains.cur_anim_length.clone(),
+                chains.speed.clone(),
+                pause.into_option(),
+                restart,
+            )),
+            CfgCommand::Souls { amount, mode, hotkey, repeat } => {
+                souls(amount, mode, chains.souls.clone(), hotkey, repeat)
+            },
+            CfgCommand::SoulsInput { hotkey } => {
+                Box::new(SoulsInput::new(chains.souls.clone(), hotkey.into_option()))
+            },
+            CfgCommand::Quitout { hotkey, reload, save_position, restore_position } => quitout(
+                chains.quitout.clone(),
+                chains.hp.clone(),
+                reload,
+                (save_position || restore_position.is_some()).then(|| QuitoutPosition {
+                    ptr: chains.position.clone(),
+                    restore: restore_position,
+                }),
+                hotkey.into_option(),
+            ),
+            CfgCommand::HitCounter { hotkey } => {
+                Box::new(HitCounter::new(chains.hp.clone(), hotkey.into_option()))
+            },
+            CfgCommand::Heal { hotkey } => {
+                heal(chains.hp.clone(), chains.fp.clone(), hotkey.into_option())
+            },
+            CfgCommand::SetHp { value, hotkey } => set_hp(chains.hp.clone(), value, hotkey),
+            CfgCommand::Refill { hotkey, consumables } => refill(
+                chains.inventory.clone(),
+                chains.estus_allocation.clone(),
+                consumables,
+                hotkey.into_option(),
+            ),
+            CfgCommand::ResurrectBoss { hotkey } => Box::new(ResurrectBoss::new(
+                chains.hp.clone(),
+                chains.position.clone(),
+                chains.quitout.clone(),
+                hotkey.into_option(),
+            )),
             CfgCommand::OpenMenu { hotkey, kind } => {
                 open_menu(kind, chains.travel_ptr, chains.attune_ptr, hotkey)
             },
@@ -222,11 +1048,45 @@ impl CfgCommand {
                 chains.xa,
                 hotkey.into_option(),
             )),
-            CfgCommand::Group { label, commands } => group(
+            CfgCommand::TargetStats { hotkey } => {
+                Box::new(TargetStats::new(chains.xa, hotkey.into_option(), settings.display))
+            },
+            CfgCommand::HpAlerts { thresholds, silent } => {
+                Box::new(HpAlerts::new(chains.xa, thresholds, !silent))
+            },
+            CfgCommand::TargetHp { percent, hotkey } => {
+                Box::new(TargetHp::new(chains.xa, percent, hotkey))
+            },
+            CfgCommand::Sequence { steps, label, hotkey } => sequence(steps, label, hotkey),
+            CfgCommand::Script { script, hotkey } => Box::new(Script::new(script, hotkey)),
+            CfgCommand::Ghost { ghost, record, play } => Box::new(Ghost::new(
+                ghost,
+                chains.position.clone(),
+                chains.cur_anim.clone(),
+                record,
+                play,
+            )),
+            CfgCommand::InputMacro { name, record, play } => {
+                Box::new(InputMacro::new(name, record, play))
+            },
+            CfgCommand::Splits { splits, segments, hotkey } => Box::new(Splits::new(
+                splits,
+                segments,
+                chains.igt.clone(),
+                chains.position.1.clone(),
+                hotkey,
+            )),
+            CfgCommand::Group { label, collapsible, commands } => group(
                 label.as_str(),
                 commands.into_iter().map(|c| c.into_widget(settings, chains)).collect(),
+                collapsible,
                 settings.display,
             ),
+            CfgCommand::InArea { areas, commands } => in_area(
+                areas,
+                chains.map_id.clone(),
+                commands.into_iter().map(|c| c.into_widget(settings, chains)).collect(),
+            ),
         }
     }
 }
@@ -252,36 +1112,283 @@ impl TryFrom<String> for LevelFilterSerde {
     }
 }
 
+/// File next to the DLL holding the path of the configuration file, written
+/// by the launcher from `--config` or `JDSD_DSIII_CONFIG`.
+const CONFIG_PATH_FILE: &str = "jdsd_dsiii_practice_tool.config_path";
+
+/// Returns the path of the configuration file. This is the one written next
+/// to the DLL by the launcher if any, or else the default one next to the
+/// DLL. Relative paths are resolved from the DLL's directory.
+pub(crate) fn config_path() -> Option<PathBuf> {
+    let mut dir = util::get_dll_path()?;
+    dir.pop();
+
+    let custom = std::fs::read_to_string(dir.join(CONFIG_PATH_FILE))
+        .ok()
+        .map(|path| PathBuf::from(path.trim()))
+        .filter(|path| !path.as_os_str().is_empty());
+
+    Some(dir.join(custom.unwrap_or_else(|| PathBuf::from("jdsd_dsiii_practice_tool.toml"))))
+}
+
 impl Config {
+    /// Parses the configuration. Errors quote the line they point at, which
+    /// for commands written as inline tables is the offending command.
     pub(crate) fn parse(cfg: &str) -> Result<Self, String> {
-        toml::from_str::<Config>(cfg).map_err(|e| format!("TOML configuration parse error: {}", e))
+        let mut config = toml::from_str::<Config>(cfg).map_err(|e| {
+            let msg = format!("TOML configuration parse error: {}", e);
+            match e.line_col().and_then(|(line, _)| Some((line + 1, cfg.lines().nth(line)?))) {
+                Some((line, text)) => format!("{msg}\n\n{line:>4} | {}", text.trim_end()),
+                None => msg,
+            }
+        })?;
+        if let Ok(doc) = cfg.parse::<DocumentMut>() {
+            for commands in profile_commands(&doc) {
+                config.names.push(command_names(commands));
+                let mut entries = Vec::new();
+                if let Some(commands) = commands {
+                    palette_entries(commands, "", &mut entries);
+                }
+                config.palette.push(entries);
+            }
+        }
+        config.settings.retain_indicators();
+        Ok(config)
+    }
+
+    /// Names of the profiles. The first one is made of the top level
+    /// `commands`.
+    pub(crate) fn profile_names(&self) -> Vec<String> {
+        std::iter::once("Default".to_string())
+            .chain(self.profiles.iter().map(|p| p.name.clone()))
+            .collect()
+    }
+
+    /// Index of the profile at `profile` in `profile_names`, falling back to
+    /// the default one if there is no such profile.
+    fn profile_index(&self, profile: usize) -> usize {
+        if profile <= self.profiles.len() {
+            profile
+        } else {
+            0
+        }
+    }
+
+    /// Command palette entries of the profile at `profile` in
+    /// `profile_names`. In safe and speedrun mode, the ones run through the
+    /// remote control that would change the game are left out.
+    pub(crate) fn palette_entries(&self, profile: usize) -> Vec<PaletteEntry> {
+        let mut entries =
+            self.palette.get(self.profile_index(profile)).cloned().unwrap_or_default();
+        if self.settings.read_only() {
+            entries.retain(
+                |e| !matches!(&e.action, Action::Command(command) if command.writes_memory()),
+            );
+        }
+        entries
     }
 
-    pub(crate) fn make_commands(self, chains: &PointerChains) -> Vec<Box<dyn Widget>> {
-        self.commands.into_iter().map(|c| c.into_widget(&self.settings, chains)).collect()
+    /// Names of the top level commands of the profile at `profile` in
+    /// `profile_names`, as listed in the command checklist. In safe and
+    /// speedrun mode, only the commands that are kept are listed.
+    pub(crate) fn command_names(&self, profile: usize) -> Vec<String> {
+        let profile = self.profile_index(profile);
+        let commands = match profile.checked_sub(1) {
+            Some(i) => &self.profiles[i].commands,
+            None => &self.commands,
+        };
+        let names = self.names.get(profile);
+        let filter = self.settings.command_filter();
+
+        commands
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| filter.map_or(true, |keep| c.is_kept(keep)))
+            .map(|(i, _)| {
+                names
+                    .and_then(|names| names.get(i))
+                    .cloned()
+                    .unwrap_or_else(|| format!("command {}", i + 1))
+            })
+            .collect()
+    }
+
+    /// Builds the widgets of the profile at `profile` in `profile_names`.
+    /// Widgets of `previous` built from the same command and settings are
+    /// kept as they are, along with their state.
+    pub(crate) fn make_commands(
+        self,
+        chains: &PointerChains,
+        profile: usize,
+        mut previous: CommandWidgets,
+    ) -> CommandWidgets {
+        let names = self.command_names(profile);
+        let commands = match profile.checked_sub(1).and_then(|i| self.profiles.into_iter().nth(i)) {
+            Some(profile) => profile.commands,
+            None => self.commands,
+        };
+        let filter = self.settings.command_filter();
+        let interlock = self.settings.online_interlock;
+        let scenarios = if filter.is_some() { Vec::new() } else { self.scenarios };
+        let settings =
+            format!("{:?} {interlock} {:?}", self.settings.display, self.settings.boss_presets);
+
+        let mut widgets = CommandWidgets::new();
+        let commands = commands.into_iter().filter_map(|c| match filter {
+            Some(keep) => c.retain(keep),
+            None => Some(c),
+        });
+        for (c, name) in commands.zip(names) {
+            let source = format!("{name} {c:?} {settings}");
+            let widget = match take_widget(&mut previous, &source) {
+                Some(widget) => widget,
+                None => switchable(name, c.into_widget(&self.settings, chains)),
+            };
+            widgets.push((source, widget));
+        }
+        for s in scenarios {
+            let source = format!("scenario {s:?} {interlock}");
+            let widget = match take_widget(&mut previous, &source) {
+                Some(widget) => widget,
+                None if interlock => interlocked(scenario(s)),
+                None => scenario(s),
+            };
+            widgets.push((source, widget));
+        }
+
+        widgets
     }
 }
 
+/// Widgets built from the configuration, each with a description of the
+/// command and settings it was built from.
+pub(crate) type CommandWidgets = Vec<(String, Box<dyn Widget>)>;
+
+fn take_widget(widgets: &mut CommandWidgets, source: &str) -> Option<Box<dyn Widget>> {
+    let index = widgets.iter().position(|(s, _)| s == source)?;
+    Some(widgets.remove(index).1)
+}
+
 impl Default for Config {
     fn default() -> Self {
         Config {
             settings: Settings {
                 log_level: LevelFilterSerde(LevelFilter::DEBUG),
+                log_format: LogFormat::Text,
                 display: "0".parse().unwrap(),
                 hide: "rshift+0".parse().ok(),
                 show_console: false,
                 indicators: Indicator::default_set(),
+                remote_port: None,
+                command_pipe: false,
+                log_position: None,
+                log_panel: false,
+                telemetry: None,
+                overlay: None,
+                switch_profile: None,
+                command_palette: None,
+                radial_menu: None,
+                gamepad_hotkeys: Vec::new(),
+                rumble: None,
+                audio: None,
+                boss_presets: Vec::new(),
+                check_updates: false,
+                safe_mode: false,
+                speedrun_mode: false,
+                online_interlock: true,
+                render_backend: None,
             },
+            theme: Theme::default(),
+            font: FontConfig::default(),
             commands: Vec::new(),
+            profiles: Vec::new(),
+            scenarios: Vec::new(),
+            names: Vec::new(),
+            palette: Vec::new(),
         }
     }
 }
 
-#[derive(Deserialize)]
+/// The `commands` of every profile, the first one being the top level
+/// `commands`.
+fn profile_commands(doc: &DocumentMut) -> Vec<Option<&Array>> {
+    let profiles = doc.get("profiles").and_then(|p| p.as_array_of_tables());
+    std::iter::once(doc.get("commands").and_then(|c| c.as_array()))
+        .chain(profiles.into_iter().flatten().map(|p| p.get("commands").and_then(|c| c.as_array())))
+        .collect()
+}
+
+/// Names of the top level commands of a profile. A command is named after its
+/// first field, and repeated names are numbered.
+fn command_names(commands: Option<&Array>) -> Vec<String> {
+    let mut seen = HashMap::<String, usize>::new();
+    commands
+        .into_iter()
+        .flatten()
+        .map(|command| {
+            let name = command.as_inline_table().map(command_label).unwrap_or_default();
+            let name = name.trim().to_string();
+            let count = seen.entry(name.clone()).or_default();
+            *count += 1;
+            if *count > 1 {
+                format!("{name} ({count})")
+            } else {
+                name
+            }
+        })
+        .collect()
+}
+
+/// Command palette entries of a list of commands, including the ones in
+/// groups, which are prefixed with the group's label. Commands with a hotkey
+/// are run by pressing it, and flag and quitout commands without one through
+/// the remote control. Other commands aren't listed.
+fn palette_entries(commands: &Array, group: &str, entries: &mut Vec<PaletteEntry>) {
+    for table in commands.iter().filter_map(|c| c.as_inline_table()) {
+        if let Some(commands) = table.get("commands").and_then(|c| c.as_array()) {
+            let group = match table.get("group").and_then(|g| g.as_str()) {
+                Some(label) if group.is_empty() => label.to_string(),
+                Some(label) => format!("{group} / {label}"),
+                None => group.to_string(),
+            };
+            palette_entries(commands, &group, entries);
+            continue;
+        }
+
+        let name = command_label(table).trim().to_string();
+        let hotkey = table.get(&hotkey_field(table)).and_then(|v| v.as_str());
+        let action = match (hotkey, table.get("flag").and_then(|f| f.as_str())) {
+            (Some(key), _) => key
+                .parse::<Key>()
+                .ok()
+                .and_then(|k| KeyCombo::try_from(k).ok())
+                .map(|key| Action::Key { key }),
+            (None, Some(flag)) => FlagSpec::try_from(flag.to_string())
+                .ok()
+                .map(|flag| Action::Command(RemoteCommand::Flag { flag, state: None })),
+            (None, None) if table.contains_key("quitout") => {
+                Some(Action::Command(RemoteCommand::Quitout))
+            },
+            _ => None,
+        };
+
+        if let Some(action) = action {
+            entries.push(PaletteEntry {
+                label: if group.is_empty() { name } else { format!("{group} / {name}") },
+                hotkey: hotkey.map(str::to_string),
+                action,
+            });
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
 #[serde(try_from = "String")]
-struct FlagSpec {
-    label: String,
-    getter: fn(&PointerChains) -> &Bitflag<u8>,
+pub(crate) struct FlagSpec {
+    pub(crate) label: String,
+    pub(crate) getter: fn(&PointerChains) -> &Bitflag<u8>,
+    /// Logged whenever the flag gets enabled.
+    pub(crate) warning: Option<&'static str>,
 }
 
 impl std::fmt::Debug for FlagSpec {
@@ -292,10 +1399,48 @@ impl std::fmt::Debug for FlagSpec {
 
 impl FlagSpec {
     fn new(label: &str, getter: fn(&PointerChains) -> &Bitflag<u8>) -> FlagSpec {
-        FlagSpec { label: label.to_string(), getter }
+        FlagSpec { label: label.to_string(), getter, warning: None }
+    }
+
+    fn with_warning(mut self, warning: &'static str) -> FlagSpec {
+        self.warning = Some(warning);
+        self
     }
 }
 
+/// Every valid flag specifier, in the order the config editor offers them.
+pub(crate) const FLAG_NAMES: [&str; 29] = [
+    "all_no_damage",
+    "inf_stamina",
+    "inf_focus",
+    "inf_consumables",
+    "deathcam",
+    "no_death",
+    "one_shot",
+    "evt_draw",
+    "bloodstain_draw",
+    "evt_disable",
+    "ai_disable",
+    "no_aggro",
+    "ember",
+    "rend_chr",
+    "hide_player",
+    "rend_obj",
+    "rend_map",
+    "rend_mesh_hi",
+    "rend_mesh_lo",
+    "rend_mesh_hit",
+    "debug_draw",
+    "hurtbox",
+    "all_draw_hit",
+    "ik_foot_ray",
+    "debug_sphere_1",
+    "debug_sphere_2",
+    "gravity",
+    "collision",
+    "no_fog_walls",
+];
+
 impl TryFrom<String> for FlagSpec {
     type Error = String;
 
@@ -314,8 +1459,10 @@ impl TryFrom<String> for FlagSpec {
             },
             "evt_disable" => Ok(FlagSpec::new("Event disable", |c| &c.evt_disable)),
             "ai_disable" => Ok(FlagSpec::new("AI disable", |c| &c.ai_disable)),
+            "no_aggro" => Ok(FlagSpec::new("No aggro", |c| &c.no_aggro)),
             "ember" => Ok(FlagSpec::new("Ember", |c| &c.ember)),
             "rend_chr" => Ok(FlagSpec::new("Render characters", |c| &c.rend_chr)),
+            "hide_player" => Ok(FlagSpec::new("Hide player", |c| &c.hide_player)),
             "rend_obj" => Ok(FlagSpec::new("Render objects", |c| &c.rend_obj)),
             "rend_map" => Ok(FlagSpec::new("Render map", |c| &c.rend_map)),
             "rend_mesh_hi" => Ok(FlagSpec::new("Collision mesh hi", |c| &c.rend_mesh_hi)),
@@ -329,6 +1476,9 @@ impl TryFrom<String> for FlagSpec {
             "debug_sphere_2" => Ok(FlagSpec::new("Debug sphere 2", |c| &c.debug_sphere_2)),
             "gravity" => Ok(FlagSpec::new("No Gravity", |c| &c.gravity)),
             "collision" => Ok(FlagSpec::new("No Collision", |c| &c.collision)),
+            "no_fog_walls" => Ok(FlagSpec::new("No fog walls", |c| &c.no_fog_walls).with_warning(
+                "Fog walls and barriers are disabled: turn this off for timed practice",
+            )),
             e => Err(format!("\"{}\" is not a valid flag specifier", e)),
         }
     }
@@ -336,7 +1486,12 @@ impl TryFrom<String> for FlagSpec {
 
 #[cfg(test)]
 mod tests {
-    use super::Config;
+    use std::time::Duration;
+
+    use windows::Win32::UI::Input::KeyboardAndMouse::{VK_CONTROL, VK_SHIFT};
+
+    use super::{Color, Config, FlagSpec, IndicatorType, Theme, FLAG_NAMES};
+    use crate::util;
 
     #[test]
     fn test_parse_ok() {
@@ -359,4 +1514,198 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_flag_names() {
+        for name in FLAG_NAMES {
+            assert!(FlagSpec::try_from(name.to_string()).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_safe_mode() {
+        let config = Config::parse(
+            r#"commands = [
+              { flag = "gravity" },
+              { label = "Hello" },
+              { group = "Render", commands = [ { flag = "rend_map" } ] },
+              { group = "Watch", commands = [ { trail = 5.0 }, { souls = 1000 } ] },
+            ]
+            [settings]
+            log_level = "DEBUG"
+            display = "0"
+            safe_mode = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.command_names(0), ["label Hello", "group Watch"]);
+    }
+
+    #[test]
+    fn test_key_combo() {
+        let parse = |key: &str| {
+            Config::parse(&format!(
+                r#"commands = [ {{ flag = "gravity", hotkey = "{key}" }} ]
+                [settings]
+                log_level = "DEBUG"
+                display = "0"
+                gamepad_hotkeys = [ {{ chord = "back+x", key = "{key}" }} ]
+                "#
+            ))
+        };
+
+        assert!(parse("ctrl+shift+k").is_ok());
+        assert!(parse("ctrl+shift+nope").is_err());
+
+        let mut keys = util::virtual_keys(&"ctrl+shift+k".parse().unwrap()).unwrap();
+        keys.sort();
+        assert_eq!(keys, [VK_SHIFT.0, VK_CONTROL.0, b'K' as u16]);
+    }
+
+    #[test]
+    fn test_theme_colors() {
+        let color = |value: &str| Color::try_from(value.to_string()).map(|c| c.0);
+
+        assert_eq!(color("#ff0000"), Ok([1., 0., 0., 1.]));
+        assert_eq!(color("00FF0080"), Ok([0., 1., 128. / 255., 128. / 255.]));
+        assert_eq!(color("#000000ff"), Ok([0., 0., 0., 1.]));
+
+        for invalid in ["", "#", "#fff", "#ff00000", "#ff0000ff0", "#gg0000", "#ééé"] {
+            assert_eq!(color(invalid), Err(format!("Invalid color: {invalid}")));
+        }
+
+        let config = Config::parse(
+            r##"commands = []
+            [settings]
+            log_level = "DEBUG"
+            display = "0"
+            [theme]
+            accent = "#336699"
+            position_x = "#ffffff80"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(config.theme.accent.map(|c| c.0), Some([0.2, 0.4, 0.6, 1.]));
+        assert_eq!(config.theme.position_x.0, [1., 1., 1., 128. / 255.]);
+        assert_eq!(config.theme.position_y.0, Theme::default().position_y.0);
+
+        assert!(Config::parse(
+            r##"commands = []
+            [settings]
+            log_level = "DEBUG"
+            display = "0"
+            [theme]
+            accent = "blue"
+            "##
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_speedrun_mode() {
+        let config = Config::parse(
+            r#"commands = [
+              { savefile_manager = "f1" },
+              { minimap = "m" },
+              { group = "Run", commands = [ { splits = "Any%", segments = [] }, { trail = 5.0 } ] },
+            ]
+            [settings]
+            log_level = "DEBUG"
+            display = "0"
+            speedrun_mode = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.command_names(0), ["savefile_manager f1", "group Run"]);
+    }
+
+    #[test]
+    fn test_split_segment_end() {
+        let parse = |segment: &str| {
+            Config::parse(&format!(
+                r#"commands = [ {{ splits = "Any%", segments = [ {segment} ] }} ]
+                [settings]
+                log_level = "DEBUG"
+                display = "0"
+                "#
+            ))
+        };
+
+        assert!(parse(r#"{ name = "Iudex Gundyr", event_flag = 14000800 }"#).is_ok());
+        assert!(parse(r#"{ name = "Firelink", area_transition = true }"#).is_ok());
+        assert!(parse(r#"{ name = "Forever" }"#).is_err());
+    }
+
+    #[test]
+    fn test_speedrun_mode_locked() {
+        let parse = |mode: &str| {
+            Config::parse(&format!(
+                r#"commands = []
+                [settings]
+                log_level = "DEBUG"
+                display = "0"
+                {mode}
+                indicators = [
+                  {{ indicator = "igt", enabled = true }},
+                  {{ indicator = "position", enabled = true }},
+                  {{ indicator = "vitals", enabled = true }},
+                ]
+                "#
+            ))
+            .unwrap()
+        };
+
+        let speedrun = parse("speedrun_mode = true");
+        assert_eq!(speedrun.settings.indicators.len(), 1);
+        assert!(matches!(speedrun.settings.indicators[0].indicator, IndicatorType::Igt));
+
+        // Reloading a configuration without speedrun mode keeps it on.
+        let mut reloaded = parse("");
+        assert_eq!(reloaded.settings.indicators.len(), 3);
+        reloaded.settings.keep_restrictions(&speedrun.settings);
+        assert!(reloaded.settings.speedrun_mode);
+        assert_eq!(reloaded.settings.indicators.len(), 1);
+    }
+
+    #[test]
+    fn test_indicator_refresh_rate() {
+        let config = Config::parse(
+            r#"commands = []
+            [settings]
+            log_level = "DEBUG"
+            display = "0"
+            indicators = [
+              { indicator = "igt", enabled = true, refresh_rate = 10 },
+              { indicator = "position", enabled = true },
+            ]
+            "#,
+        )
+        .unwrap();
+
+        let intervals: Vec<_> =
+            config.settings.indicators.iter().map(|i| i.refresh_interval).collect();
+        assert_eq!(intervals, [Some(Duration::from_millis(100)), None]);
+
+        assert!(Config::parse(
+            r#"commands = []
+            [settings]
+            log_level = "DEBUG"
+            display = "0"
+            indicators = [ { indicator = "igt", enabled = true, refresh_rate = 0 } ]
+            "#,
+        )
+        .is_err());
+
+        assert!(Config::parse(
+            r#"commands = []
+            [settings]
+            log_level = "DEBUG"
+            display = "0"
+            indicators = [ { indicator = "igt", enabled = true, refresh_rate = 1e-300 } ]
+            "#,
+        )
+        .is_err());
+    }
 }
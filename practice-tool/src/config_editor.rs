@@ -0,0 +1,303 @@
+//! Command list editor.
+//!
+//! Adds, removes and reorders the top level commands, changes the flag of
+//! flag commands and rebinds their main hotkey, then writes the configuration
+//! file back preserving its formatting and comments. Groups are moved as a
+//! whole, and profiles are left untouched.
+
+use std::fs;
+use std::path::PathBuf;
+
+use practice_tool_core::widgets::{scaling_factor, BUTTON_HEIGHT, BUTTON_WIDTH};
+use toml_edit::{Array, DocumentMut, Value};
+use windows::Win32::UI::Input::KeyboardAndMouse::VK_ESCAPE;
+
+use crate::config::{self, FLAG_NAMES};
+use crate::hotkey_editor::{capture_key, command_label, hotkey_field, is_down, replace_value};
+
+const CONFIG_EDITOR_TAG: &str = "##config-editor";
+
+/// Commands that can be added, as written in the configuration file.
+const TEMPLATES: [(&str, &str); 11] = [
+    ("Flag", r#"{ flag = "gravity" }"#),
+    ("Position", "{ position = true }"),
+    ("Nudge position", "{ nudge = 1.0 }"),
+    ("Cycle speed", "{ cycle_speed = [0.5, 1.0, 2.0] }"),
+    ("Souls", "{ souls = 10000 }"),
+    ("Quitout", "{ quitout = true }"),
+    ("Savefile manager", "{ savefile_manager = true }"),
+    ("Item spawner", "{ item_spawner = true }"),
+    ("Character stats", "{ character_stats = true }"),
+    ("Target", "{ target = true }"),
+    ("Label", r#"{ label = "" }"#),
+];
+
+/// Prefix of every command in the `commands` array.
+const INDENT: &str = "\n  ";
+
+enum Edit {
+    Up(usize),
+    Down(usize),
+    Remove(usize),
+    Add(usize),
+    Flag(usize, usize),
+    Hotkey(usize, String),
+    /// Start capturing the new hotkey of a command.
+    Capture(usize),
+}
+
+fn commands(doc: &mut DocumentMut) -> Option<&mut Array> {
+    doc.get_mut("commands")?.as_array_mut()
+}
+
+pub(crate) struct ConfigEditor {
+    path: Option<PathBuf>,
+    doc: Option<DocumentMut>,
+    capturing: Option<usize>,
+    template: usize,
+    dirty: bool,
+    error: Option<String>,
+}
+
+impl ConfigEditor {
+    pub(crate) fn new() -> Self {
+        ConfigEditor {
+            path: config::config_path(),
+            doc: None,
+            capturing: None,
+            template: 0,
+            dirty: false,
+            error: None,
+        }
+    }
+
+    fn load(&mut self) {
+        self.capturing = None;
+        self.dirty = false;
+        self.error = None;
+
+        let doc = self
+            .path
+            .as_ref()
+            .ok_or_else(|| "Couldn't find config file".to_string())
+            .and_then(|path| {
+                fs::read_to_string(path).map_err(|e| format!("Couldn't read config file: {e}"))
+            })
+            .and_then(|content| {
+                content.parse::<DocumentMut>().map_err(|e| format!("Couldn't parse config: {e}"))
+            });
+
+        match doc {
+            Ok(mut doc) if commands(&mut doc).is_some() => self.doc = Some(doc),
+            Ok(_) => {
+                self.error = Some("The config file has no commands list".to_string());
+                self.doc = None;
+            },
+            Err(e) => {
+                self.error = Some(e);
+                self.doc = None;
+            },
+        }
+    }
+
+    fn apply(&mut self, edit: Edit) {
+        let Some(commands) = self.doc.as_mut().and_then(commands) else {
+            return;
+        };
+
+        match edit {
+            Edit::Up(i) if i > 0 => {
+                let command = commands.remove(i);
+                commands.insert_formatted(i - 1, command);
+            },
+            Edit::Down(i) if i + 1 < commands.len() => {
+                let command = commands.remove(i);
+                commands.insert_formatted(i + 1, command);
+            },
+            Edit::Remove(i) => {
+                commands.remove(i);
+            },
+            Edit::Add(template) => {
+                let Ok(mut command) = TEMPLATES[template].1.parse::<Value>() else {
+                    return;
+                };
+                command.decor_mut().set_prefix(INDENT);
+                commands.push_formatted(command);
+            },
+            Edit::Flag(i, flag) => {
+                if let Some(value) = commands
+                    .get_mut(i)
+                    .and_then(Value::as_inline_table_mut)
+                    .and_then(|t| t.get_mut("flag"))
+                {
+                    replace_value(value, FLAG_NAMES[flag]);
+                }
+            },
+            Edit::Hotkey(i, key) => {
+                let Some(table) = commands.get_mut(i).and_then(Value::as_inline_table_mut) else {
+                    return;
+                };
+                let field = hotkey_field(table);
+                match table.get_mut(&field) {
+                    Some(value) => replace_value(value, &key),
+                    None => {
+                        table.insert(field.as_str(), Value::from(key));
+                    },
+                }
+            },
+            _ => return,
+        }
+
+        self.dirty = true;
+    }
+
+    /// Writes the edited document to the configuration file and returns its
+    /// contents.
+    fn save(&mut self) -> Option<String> {
+        let content = self.doc.as_ref()?.to_string();
+
+        if let Err(e) = config::Config::parse(&content) {
+            self.error = Some(e);
+            return None;
+        }
+
+        if let Err(e) = self.path.as_ref().map(|path| fs::write(path, &content)).transpose() {
+            self.error = Some(format!("Couldn't write config file: {e}"));
+            return None;
+        }
+
+        self.dirty = false;
+        self.error = None;
+        Some(content)
+    }
+
+    pub(crate) fn open(&mut self, ui: &imgui::Ui) {
+        self.load();
+        ui.open_popup(CONFIG_EDITOR_TAG);
+    }
+
+    fn render_commands(&self, ui: &imgui::Ui) -> Option<Edit> {
+        let commands = self.doc.as_ref()?.get("commands")?.as_array()?;
+        let mut edit = None;
+
+        for (i, command) in commands.iter().enumerate() {
+            let _id = ui.push_id_usize(i);
+
+            if ui.small_button("^") {
+                edit = Some(Edit::Up(i));
+            }
+            ui.same_line();
+            if ui.small_button("v") {
+                edit = Some(Edit::Down(i));
+            }
+            ui.same_line();
+            if ui.small_button("x") {
+                edit = Some(Edit::Remove(i));
+            }
+            ui.same_line();
+
+            let Some(table) = command.as_inline_table() else {
+                ui.text(command.to_string().trim());
+                continue;
+            };
+
+            if let Some(group) = table.get("group").and_then(Value::as_str) {
+                ui.text(format!("group {group}"));
+                continue;
+            }
+
+            if let Some(flag) = table.get("flag").and_then(Value::as_str) {
+                let mut index = FLAG_NAMES.iter().position(|f| *f == flag).unwrap_or(0);
+                ui.set_next_item_width(BUTTON_WIDTH * scaling_factor(ui) * 0.6);
+                if ui.combo_simple_string("##flag", &mut index, &FLAG_NAMES) {
+                    edit = Some(Edit::Flag(i, index));
+                }
+            } else {
+                ui.text(command_label(table));
+            }
+
+            ui.same_line();
+            let field = hotkey_field(table);
+            let key = table.get(&field).and_then(Value::as_str).unwrap_or("none");
+            let key_label = if self.capturing == Some(i) {
+                "...".to_string()
+            } else {
+                format!("{field}: {key}")
+            };
+            if ui.small_button(format!("{key_label}##hotkey")) {
+                edit = Some(Edit::Capture(i));
+            }
+        }
+
+        edit
+    }
+
+    /// Renders the editor. Returns the new configuration file contents after
+    /// the changes have been saved.
+    pub(crate) fn render(&mut self, ui: &imgui::Ui) -> Option<String> {
+        let mut new_config = None;
+        let button_size = [BUTTON_WIDTH * scaling_factor(ui) * 0.5, BUTTON_HEIGHT];
+
+        if let Some(_token) = ui
+            .modal_popup_config(CONFIG_EDITOR_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            if let Some(index) = self.capturing {
+                if is_down(VK_ESCAPE.0) {
+                    self.capturing = None;
+                } else if let Some(key) = capture_key() {
+                    self.capturing = None;
+                    self.apply(Edit::Hotkey(index, key));
+                }
+            }
+
+            if let Some(error) = &self.error {
+                ui.text_colored([1., 0.3, 0.3, 1.], error);
+            }
+
+            ui.text("Click a hotkey, then press the new key combination (Esc to cancel).");
+            ui.separator();
+
+            match self.render_commands(ui) {
+                Some(Edit::Capture(i)) => self.capturing = Some(i),
+                Some(edit) => {
+                    self.capturing = None;
+                    self.apply(edit);
+                },
+                None => {},
+            }
+
+            ui.separator();
+            let labels: Vec<&str> = TEMPLATES.iter().map(|(label, _)| *label).collect();
+            ui.set_next_item_width(BUTTON_WIDTH * scaling_factor(ui) * 0.6);
+            ui.combo_simple_string("##template", &mut self.template, &labels);
+            ui.same_line();
+            if ui.button("Add") {
+                self.apply(Edit::Add(self.template));
+            }
+
+            ui.separator();
+            if self.dirty {
+                ui.text("Unsaved changes");
+            }
+            if ui.button_with_size("Save", button_size) {
+                new_config = self.save();
+            }
+            ui.same_line();
+            if ui.button_with_size("Discard", button_size) {
+                self.load();
+            }
+            ui.same_line();
+            if ui.button_with_size("Close", button_size) {
+                self.capturing = None;
+                ui.close_current_popup();
+            }
+        }
+
+        new_config
+    }
+}
@@ -0,0 +1,225 @@
+//! Persistent death and attempt counters.
+//!
+//! Deaths are detected when the player's HP drops to zero. Every death also
+//! counts as an attempt; when a boss gets defeated, the number of attempts it
+//! took is recorded and the attempt counter starts over. The counters are
+//! stored in `jdsd_dsiii_practice_tool.counters.json` next to the DLL.
+//!
+//! Alongside them, statistics are kept both for the current session and for
+//! all sessions: attempts, deaths, in-game time spent, hits taken and the
+//! average length of an attempt, which ends on a death or a boss kill.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use hudhook::tracing::error;
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use serde::{Deserialize, Serialize};
+
+use crate::util;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CounterData {
+    deaths: u32,
+    attempts: u32,
+    #[serde(default)]
+    boss_attempts: BTreeMap<String, u32>,
+    #[serde(default)]
+    totals: Stats,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct Stats {
+    attempts: u32,
+    deaths: u32,
+    igt_ms: u64,
+    hits: u32,
+    /// In-game time spent in attempts that ended, for the average.
+    attempts_igt_ms: u64,
+}
+
+impl Stats {
+    fn render(&self, ui: &imgui::Ui) {
+        let format_ms = |ms: u64| {
+            let s = ms / 1000;
+            format!("{:02}:{:02}:{:02}", s / 3600, s / 60 % 60, s % 60)
+        };
+        let average = match self.attempts {
+            0 => "-".to_string(),
+            n => format_ms(self.attempts_igt_ms / n as u64),
+        };
+
+        ui.text(format!("Attempts     {}", self.attempts));
+        ui.text(format!("Deaths       {}", self.deaths));
+        ui.text(format!("Hits taken   {}", self.hits));
+        ui.text(format!("IGT spent    {}", format_ms(self.igt_ms)));
+        ui.text(format!("Avg. attempt {average}"));
+    }
+}
+
+/// Longest IGT increase between two frames that counts as time spent; any
+/// longer jump comes from loading a different save.
+const MAX_IGT_STEP: u32 = 1000;
+/// Interval at which the in-game time spent gets saved.
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+/// Interval at which the boss flags are checked while in game.
+const BOSS_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+pub(crate) struct Counters {
+    path: Option<PathBuf>,
+    data: CounterData,
+    prev_hp: Option<u32>,
+    prev_igt: Option<u32>,
+    prev_bosses: Vec<Option<bool>>,
+    last_boss_check: Instant,
+    session: Stats,
+    attempt_igt_ms: u64,
+    last_save: Instant,
+}
+
+impl Counters {
+    pub(crate) fn new() -> Self {
+        let path = util::get_dll_path().map(|mut path| {
+            path.pop();
+            path.push("jdsd_dsiii_practice_tool.counters.json");
+            path
+        });
+
+        let data = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        Counters {
+            path,
+            data,
+            prev_hp: None,
+            prev_igt: None,
+            prev_bosses: vec![None; BOSS_FLAGS.len()],
+            last_boss_check: Instant::now(),
+            session: Stats::default(),
+            attempt_igt_ms: 0,
+            last_save: Instant::now(),
+        }
+    }
+
+    pub(crate) fn deaths(&self) -> u32 {
+        self.data.deaths
+    }
+
+    pub(crate) fn attempts(&self) -> u32 {
+        self.data.attempts
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.data = CounterData::default();
+        self.session = Stats::default();
+        self.save();
+    }
+
+    /// Draws the session and all-time statistics.
+    pub(crate) fn render_stats(&self, ui: &imgui::Ui) {
+        ui.text("This session");
+        self.session.render(ui);
+        ui.separator();
+        ui.text("All sessions");
+        self.data.totals.render(ui);
+    }
+
+    /// Ends the current attempt, counting its length.
+    fn end_attempt(&mut self) {
+        for stats in [&mut self.session, &mut self.data.totals] {
+            stats.attempts += 1;
+            stats.attempts_igt_ms += self.attempt_igt_ms;
+        }
+        self.attempt_igt_ms = 0;
+    }
+
+    fn save(&mut self) {
+        self.last_save = Instant::now();
+
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let result = serde_json::to_string_pretty(&self.data)
+            .map_err(|e| e.to_string())
+            .and_then(|content| fs::write(path, content).map_err(|e| e.to_string()));
+
+        if let Err(e) = result {
+            error!("Couldn't save counters: {e}");
+        }
+    }
+
+    /// Records the bosses defeated since the last check. Returns whether
+    /// there was any.
+    fn check_bosses(&mut self, in_game: bool, log_tx: &Sender<String>) -> bool {
+        let mut boss_defeated = false;
+        for ((flag, name), prev) in BOSS_FLAGS.iter().zip(self.prev_bosses.iter_mut()) {
+            // Flags can change arbitrarily while a save is being loaded.
+            let state = if in_game { EventFlag(*flag).get() } else { None };
+            if let (Some(false), Some(true)) = (*prev, state) {
+                let attempts = self.data.attempts + 1;
+                log_tx.send(format!("{name} defeated after {attempts} attempts")).ok();
+                self.data.boss_attempts.insert(name.to_string(), attempts);
+                self.data.attempts = 0;
+                boss_defeated = true;
+            }
+            *prev = state;
+        }
+        if boss_defeated {
+            self.end_attempt();
+        }
+        boss_defeated
+    }
+
+    /// Checks for deaths and boss kills. Must be called every frame.
+    pub(crate) fn update(&mut self, pointers: &PointerChains, log_tx: &Sender<String>) {
+        let mut changed = false;
+
+        let hp = pointers.hp.read().map(|[hp, _, _]| hp);
+        if let (Some(prev), Some(0)) = (self.prev_hp, hp) {
+            if prev > 0 {
+                self.data.deaths += 1;
+                self.data.attempts += 1;
+                self.session.deaths += 1;
+                self.data.totals.deaths += 1;
+                self.end_attempt();
+                changed = true;
+            }
+        }
+        if let (Some(prev), Some(hp)) = (self.prev_hp, hp) {
+            if hp < prev {
+                self.session.hits += 1;
+                self.data.totals.hits += 1;
+            }
+        }
+        self.prev_hp = hp;
+
+        let igt = hp.and(pointers.igt.read());
+        if let (Some(prev), Some(igt)) = (self.prev_igt, igt) {
+            let step = igt.wrapping_sub(prev);
+            if step <= MAX_IGT_STEP {
+                self.session.igt_ms += step as u64;
+                self.data.totals.igt_ms += step as u64;
+                self.attempt_igt_ms += step as u64;
+            }
+        }
+        self.prev_igt = igt;
+
+        // Out of the game nothing is read, and the states must be forgotten
+        // right away.
+        if hp.is_none() || self.last_boss_check.elapsed() > BOSS_CHECK_INTERVAL {
+            self.last_boss_check = Instant::now();
+            changed |= self.check_bosses(hp.is_some(), log_tx);
+        }
+
+        if changed || self.last_save.elapsed() > SAVE_INTERVAL {
+            self.save();
+        }
+    }
+}
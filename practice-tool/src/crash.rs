@@ -0,0 +1,159 @@
+//! Crash reports.
+//!
+//! An unhandled exception filter writes a minidump of the game, along with
+//! the last lines logged by the tool, when an exception that nothing handled
+//! is about to end the process while the tool is loaded. Both are written next
+//! to the DLL as `jdsd_dsiii_practice_tool.crash-<timestamp>.dmp` and `.log`.
+//!
+//! The filter only runs for exceptions the game didn't handle, so the ones it
+//! raises and catches on purpose aren't reported. The report is written from
+//! the crashing thread, as starting a thread there can deadlock on the loader
+//! lock, and the previous filter is called afterwards.
+
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io;
+use std::os::windows::io::AsRawHandle;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hudhook::tracing::{error, info};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tracing_subscriber::fmt::MakeWriter;
+use windows::Win32::Foundation::HANDLE;
+use windows::Win32::System::Diagnostics::Debug::{
+    MiniDumpWithIndirectlyReferencedMemory, MiniDumpWriteDump, SetUnhandledExceptionFilter,
+    EXCEPTION_POINTERS, LPTOP_LEVEL_EXCEPTION_FILTER, MINIDUMP_EXCEPTION_INFORMATION,
+};
+use windows::Win32::System::Threading::{
+    GetCurrentProcess, GetCurrentProcessId, GetCurrentThreadId,
+};
+
+use crate::util;
+
+/// Number of log lines kept for crash reports.
+const RECENT_LINES: usize = 200;
+
+const EXCEPTION_CONTINUE_SEARCH: i32 = 0;
+
+/// Time to wait for a lock while writing a report, as it may be held by the
+/// crashing thread.
+const LOCK_TIMEOUT: Duration = Duration::from_millis(100);
+
+static RECENT_LOG: Lazy<Mutex<VecDeque<String>>> = Lazy::new(Default::default);
+
+/// Filter that was installed before ours, while ours is installed.
+static PREVIOUS_FILTER: Lazy<Mutex<Option<LPTOP_LEVEL_EXCEPTION_FILTER>>> =
+    Lazy::new(Default::default);
+
+static REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Keeps the last lines logged for crash reports. Every event is formatted
+/// and written in one go, so each write is a line.
+pub(crate) struct RecentLog;
+
+impl io::Write for RecentLog {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // The crash filter logs from the crashing thread, which may be the
+        // one holding the lock.
+        let Some(mut lines) = RECENT_LOG.try_lock_for(LOCK_TIMEOUT) else {
+            return Ok(buf.len());
+        };
+        if lines.len() == RECENT_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(String::from_utf8_lossy(buf).trim_end().to_string());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for RecentLog {
+    type Writer = RecentLog;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RecentLog
+    }
+}
+
+fn report_path(extension: &str, timestamp: u64) -> Option<PathBuf> {
+    util::get_dll_path().map(|mut path| {
+        path.pop();
+        path.push(format!("jdsd_dsiii_practice_tool.crash-{timestamp}.{extension}"));
+        path
+    })
+}
+
+fn write_report(pointers: *const EXCEPTION_POINTERS) -> Result<PathBuf, String> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (Some(dump_path), Some(log_path)) =
+        (report_path("dmp", timestamp), report_path("log", timestamp))
+    else {
+        return Err("Couldn't construct crash report path".to_string());
+    };
+
+    let log = match RECENT_LOG.try_lock_for(LOCK_TIMEOUT) {
+        Some(lines) => lines.iter().map(|line| format!("{line}\n")).collect::<String>(),
+        None => "Log unavailable\n".to_string(),
+    };
+    fs::write(&log_path, log).map_err(|e| format!("Couldn't write {log_path:?}: {e}"))?;
+
+    let file =
+        File::create(&dump_path).map_err(|e| format!("Couldn't create {dump_path:?}: {e}"))?;
+    let exception = MINIDUMP_EXCEPTION_INFORMATION {
+        ThreadId: unsafe { GetCurrentThreadId() },
+        ExceptionPointers: pointers as *mut _,
+        ClientPointers: false.into(),
+    };
+
+    unsafe {
+        MiniDumpWriteDump(
+            GetCurrentProcess(),
+            GetCurrentProcessId(),
+            HANDLE(file.as_raw_handle() as isize),
+            MiniDumpWithIndirectlyReferencedMemory,
+            Some(&exception),
+            None,
+            None,
+        )
+    }
+    .map_err(|e| format!("Couldn't write {dump_path:?}: {e}"))?;
+
+    Ok(dump_path)
+}
+
+unsafe extern "system" fn filter(pointers: *const EXCEPTION_POINTERS) -> i32 {
+    if !REPORTED.swap(true, Ordering::SeqCst) {
+        let code = (*(*pointers).ExceptionRecord).ExceptionCode;
+        match write_report(pointers) {
+            Ok(path) => error!("Exception {:#x}, crash report written to {path:?}", code.0),
+            Err(e) => error!("{e}"),
+        }
+    }
+
+    match PREVIOUS_FILTER.try_lock_for(LOCK_TIMEOUT).and_then(|previous| *previous) {
+        Some(Some(previous)) => previous(pointers),
+        _ => EXCEPTION_CONTINUE_SEARCH,
+    }
+}
+
+pub(crate) fn install() {
+    let mut previous = PREVIOUS_FILTER.lock();
+    if previous.is_none() {
+        *previous = Some(unsafe { SetUnhandledExceptionFilter(Some(filter)) });
+        info!("Crash handler installed");
+    }
+}
+
+/// Restores the previous filter. Must be called before ejecting, as the
+/// filter lives in the DLL.
+pub(crate) fn uninstall() {
+    if let Some(previous) = PREVIOUS_FILTER.lock().take() {
+        unsafe { SetUnhandledExceptionFilter(previous) };
+    }
+}
@@ -0,0 +1,21 @@
+//! Acknowledgement of executed commands.
+//!
+//! Commands report that they ran with [`executed`] once they have actually
+//! done something, not when their hotkey is pressed, so that a command that is
+//! refused or fails, or a hotkey that only opens a window, isn't acknowledged.
+//! The practice tool collects the reports once per frame with
+//! [`take_executed`], to pulse the controller and play the command cue.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static EXECUTED: AtomicBool = AtomicBool::new(false);
+
+/// Reports that a command ran.
+pub(crate) fn executed() {
+    EXECUTED.store(true, Ordering::Relaxed);
+}
+
+/// Whether a command ran since the last call.
+pub(crate) fn take_executed() -> bool {
+    EXECUTED.swap(false, Ordering::Relaxed)
+}
@@ -0,0 +1,230 @@
+//! Gamepad state as seen by the game through XInput, hotkeys bound to
+//! button chords, and vibration feedback.
+
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use practice_tool_core::crossbeam_channel::Sender;
+use serde::Deserialize;
+use windows::Win32::UI::Input::XboxController::*;
+
+use crate::action::Action;
+use crate::remote::RemoteCommand;
+use crate::XINPUT_GAMEPADS;
+
+/// Trigger values above this count as pressed.
+const TRIGGER_THRESHOLD: u8 = 30;
+
+/// Bits for the analog triggers, past the ones XInput uses for buttons.
+const LEFT_TRIGGER: u32 = 1 << 16;
+const RIGHT_TRIGGER: u32 = 1 << 17;
+
+/// A gamepad button, written as in `"l3"` or `"dpad_up"`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(try_from = "String")]
+pub(crate) struct GamepadButton(u32);
+
+impl GamepadButton {
+    pub(crate) const DPAD_LEFT: GamepadButton = GamepadButton(XINPUT_GAMEPAD_DPAD_LEFT.0 as u32);
+    pub(crate) const DPAD_RIGHT: GamepadButton = GamepadButton(XINPUT_GAMEPAD_DPAD_RIGHT.0 as u32);
+}
+
+impl TryFrom<String> for GamepadButton {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let flags = match value.to_lowercase().as_str() {
+            "a" => XINPUT_GAMEPAD_A,
+            "b" => XINPUT_GAMEPAD_B,
+            "x" => XINPUT_GAMEPAD_X,
+            "y" => XINPUT_GAMEPAD_Y,
+            "lb" => XINPUT_GAMEPAD_LEFT_SHOULDER,
+            "rb" => XINPUT_GAMEPAD_RIGHT_SHOULDER,
+            "l3" => XINPUT_GAMEPAD_LEFT_THUMB,
+            "r3" => XINPUT_GAMEPAD_RIGHT_THUMB,
+            "start" => XINPUT_GAMEPAD_START,
+            "back" | "select" => XINPUT_GAMEPAD_BACK,
+            "dpad_up" => XINPUT_GAMEPAD_DPAD_UP,
+            "dpad_down" => XINPUT_GAMEPAD_DPAD_DOWN,
+            "dpad_left" => XINPUT_GAMEPAD_DPAD_LEFT,
+            "dpad_right" => XINPUT_GAMEPAD_DPAD_RIGHT,
+            "lt" => return Ok(GamepadButton(LEFT_TRIGGER)),
+            "rt" => return Ok(GamepadButton(RIGHT_TRIGGER)),
+            _ => return Err(format!("Invalid gamepad button: {value}")),
+        };
+        Ok(GamepadButton(flags.0 as u32))
+    }
+}
+
+/// Pressed buttons, with the triggers as extra bits.
+fn buttons(pad: &XINPUT_GAMEPAD) -> u32 {
+    let mut buttons = pad.wButtons.0 as u32;
+    if pad.bLeftTrigger > TRIGGER_THRESHOLD {
+        buttons |= LEFT_TRIGGER;
+    }
+    if pad.bRightTrigger > TRIGGER_THRESHOLD {
+        buttons |= RIGHT_TRIGGER;
+    }
+    buttons
+}
+
+/// Snapshot of the first connected gamepad.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct GamepadState {
+    buttons: u32,
+    /// Right stick position, each axis in -1..=1 with up being positive.
+    pub(crate) right_stick: [f32; 2],
+}
+
+impl GamepadState {
+    /// Reads the state of the first connected gamepad, if any.
+    pub(crate) fn read() -> Option<Self> {
+        let pad = XINPUT_GAMEPADS.lock().iter().find_map(|pad| *pad)?;
+
+        let buttons = buttons(&pad);
+        let axis = |v: i16| v as f32 / i16::MAX as f32;
+        Some(GamepadState { buttons, right_stick: [axis(pad.sThumbRX), axis(pad.sThumbRY)] })
+    }
+
+    pub(crate) fn is_down(&self, button: GamepadButton) -> bool {
+        self.buttons & button.0 != 0
+    }
+}
+
+/// Combination of buttons held together, written as in `"back+a"`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(try_from = "String")]
+pub(crate) struct GamepadChord(u32);
+
+impl TryFrom<String> for GamepadChord {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value
+            .split('+')
+            .map(|button| GamepadButton::try_from(button.trim().to_string()))
+            .try_fold(0, |chord, button| button.map(|b| chord | b.0))
+            .map(GamepadChord)
+    }
+}
+
+impl GamepadState {
+    fn is_chord_down(&self, chord: GamepadChord) -> bool {
+        self.buttons & chord.0 == chord.0
+    }
+}
+
+/// Chords hidden from the game while they are held.
+static BLOCKED_CHORDS: Lazy<Mutex<Vec<GamepadChord>>> = Lazy::new(Default::default);
+
+/// Clears the buttons of every fully held chord from a gamepad state before
+/// the game gets to see it. Called from the XInput hook.
+pub(crate) fn block_chords(pad: &mut XINPUT_GAMEPAD) {
+    for &GamepadChord(chord) in BLOCKED_CHORDS.lock().iter() {
+        if buttons(pad) & chord != chord {
+            continue;
+        }
+
+        pad.wButtons.0 &= !(chord as u16);
+        if chord & LEFT_TRIGGER != 0 {
+            pad.bLeftTrigger = 0;
+        }
+        if chord & RIGHT_TRIGGER != 0 {
+            pad.bRightTrigger = 0;
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct GamepadHotkey {
+    chord: GamepadChord,
+    #[serde(flatten)]
+    action: Action,
+}
+
+/// Runs the actions bound to gamepad chords when they get pressed.
+pub(crate) struct GamepadHotkeys {
+    hotkeys: Vec<GamepadHotkey>,
+    commands: Sender<RemoteCommand>,
+    prev: GamepadState,
+}
+
+impl GamepadHotkeys {
+    pub(crate) fn new(hotkeys: Vec<GamepadHotkey>, commands: Sender<RemoteCommand>) -> Self {
+        *BLOCKED_CHORDS.lock() = hotkeys.iter().map(|h| h.chord).collect();
+        GamepadHotkeys { hotkeys, commands, prev: GamepadState::default() }
+    }
+
+    pub(crate) fn update(&mut self) {
+        let state = GamepadState::read().unwrap_or_default();
+
+        for hotkey in &self.hotkeys {
+            if state.is_chord_down(hotkey.chord) && !self.prev.is_chord_down(hotkey.chord) {
+                hotkey.action.run(&self.commands);
+            }
+        }
+
+        self.prev = state;
+    }
+}
+
+/// Vibration pulse confirming that a command ran while the tool is hidden.
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct RumbleConfig {
+    /// Motor speed, from 0 to 1.
+    #[serde(default = "RumbleConfig::default_strength")]
+    strength: f32,
+    /// Length of the pulse in milliseconds.
+    #[serde(default = "RumbleConfig::default_duration")]
+    duration: u64,
+}
+
+impl RumbleConfig {
+    fn default_strength() -> f32 {
+        0.5
+    }
+
+    fn default_duration() -> u64 {
+        150
+    }
+}
+
+pub(crate) struct Rumble {
+    config: RumbleConfig,
+    /// Controller that is vibrating and when to stop it.
+    active: Option<(u32, Instant)>,
+}
+
+impl Rumble {
+    pub(crate) fn new(config: RumbleConfig) -> Self {
+        Rumble { config, active: None }
+    }
+
+    fn set(user_index: u32, speed: u16) {
+        let vibration = XINPUT_VIBRATION { wLeftMotorSpeed: speed, wRightMotorSpeed: speed };
+        unsafe { XInputSetState(user_index, &vibration) };
+    }
+
+    /// Starts a pulse on the first connected controller.
+    pub(crate) fn pulse(&mut self) {
+        let Some(user_index) = XINPUT_GAMEPADS.lock().iter().position(Option::is_some) else {
+            return;
+        };
+
+        let speed = (self.config.strength.clamp(0., 1.) * u16::MAX as f32) as u16;
+        Self::set(user_index as u32, speed);
+        let until = Instant::now() + Duration::from_millis(self.config.duration);
+        self.active = Some((user_index as u32, until));
+    }
+
+    /// Stops the pulse once it has lasted long enough.
+    pub(crate) fn update(&mut self) {
+        if let Some((user_index, until)) = self.active {
+            if Instant::now() >= until {
+                Self::set(user_index, 0);
+                self.active = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Hotkey rebinding panel.
+//!
+//! Lists every hotkey found in the configuration file, captures a new key
+//! combination when one is clicked, and writes the result back to the file
+//! preserving its formatting and comments.
+
+use std::fs;
+use std::path::PathBuf;
+
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, BUTTON_HEIGHT, BUTTON_WIDTH};
+use toml_edit::{DocumentMut, InlineTable, Value};
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    GetAsyncKeyState, VK_CONTROL, VK_ESCAPE, VK_LCONTROL, VK_LMENU, VK_LSHIFT, VK_MENU,
+    VK_RCONTROL, VK_RMENU, VK_RSHIFT, VK_SHIFT,
+};
+
+use crate::codegen::hotkey_fields::{HOTKEY_FIELDS, SETTINGS_FIELDS};
+use crate::{config, util};
+
+const HOTKEY_EDITOR_TAG: &str = "##hotkey-editor";
+
+#[derive(Debug, Clone)]
+enum Location {
+    Settings(&'static str),
+    Command(Vec<usize>, String),
+}
+
+#[derive(Debug)]
+struct Binding {
+    label: String,
+    location: Location,
+    key: String,
+}
+
+pub(crate) fn is_down(vk: u16) -> bool {
+    unsafe { GetAsyncKeyState(vk as i32) as u16 & 0x8000 != 0 }
+}
+
+/// Returns the key combination currently held down, if any.
+pub(crate) fn capture_key() -> Option<String> {
+    let modifiers = [
+        VK_SHIFT,
+        VK_LSHIFT,
+        VK_RSHIFT,
+        VK_CONTROL,
+        VK_LCONTROL,
+        VK_RCONTROL,
+        VK_MENU,
+        VK_LMENU,
+        VK_RMENU,
+    ];
+
+    let key = (0x08u16..0xff)
+        .filter(|vk| !modifiers.iter().any(|m| m.0 == *vk))
+        .filter(|vk| is_down(*vk))
+        .find_map(util::key_name)?;
+
+    let mut combo = String::new();
+    if is_down(VK_CONTROL.0) {
+        combo.push_str("ctrl+");
+    }
+    if is_down(VK_MENU.0) {
+        combo.push_str("alt+");
+    }
+    if is_down(VK_RSHIFT.0) {
+        combo.push_str("rshift+");
+    } else if is_down(VK_SHIFT.0) {
+        combo.push_str("shift+");
+    }
+    combo.push_str(&key);
+
+    combo.parse::<Key>().ok().map(|_| combo)
+}
+
+pub(crate) fn command_label(table: &InlineTable) -> String {
+    table
+        .iter()
+        .find(|(k, _)| *k != "description")
+        .map(|(k, v)| match v.as_str() {
+            Some(s) => format!("{k} {s}"),
+            None => k.to_string(),
+        })
+        .unwrap_or_default()
+}
+
+/// The field holding the main hotkey of a command: the command's own key for
+/// commands like `{ quitout = "ctrl+q" }`, `hotkey` otherwise.
+pub(crate) fn hotkey_field(table: &InlineTable) -> String {
+    match table.iter().find(|(k, _)| *k != "description") {
+        Some((k, _)) if HOTKEY_FIELDS.contains(&k) => k.to_string(),
+        _ => "hotkey".to_string(),
+    }
+}
+
+fn collect_commands(
+    commands: &toml_edit::Array,
+    path: &mut Vec<usize>,
+    group: &str,
+    bindings: &mut Vec<Binding>,
+) {
+    for (i, command) in commands.iter().enumerate() {
+        let Some(table) = command.as_inline_table() else {
+            continue;
+        };
+
+        path.push(i);
+
+        if let (Some(label), Some(commands)) =
+            (table.get("group").and_then(Value::as_str), table.get("commands"))
+        {
+            if let Some(commands) = commands.as_array() {
+                collect_commands(commands, path, label, bindings);
+            }
+        } else {
+            let label = command_label(table);
+            for field in HOTKEY_FIELDS {
+                // Some commands have a field of another type with the same
+                // name as a hotkey field, like `target` of `cycle_speed`.
+                let key = table.get(field).and_then(Value::as_str);
+                if let Some(key) = key.filter(|key| key.parse::<Key>().is_ok()) {
+                    let label = match (group, field == label.split(' ').next().unwrap_or("")) {
+                        ("", true) => label.clone(),
+                        ("", false) => format!("{label} ({field})"),
+                        (group, true) => format!("{group} / {label}"),
+                        (group, false) => format!("{group} / {label} ({field})"),
+                    };
+                    bindings.push(Binding {
+                        label,
+                        location: Location::Command(path.clone(), field.to_string()),
+                        key: key.to_string(),
+                    });
+                }
+            }
+        }
+
+        path.pop();
+    }
+}
+
+fn command_table<'a>(doc: &'a mut DocumentMut, path: &[usize]) -> Option<&'a mut InlineTable> {
+    let (first, rest) = path.split_first()?;
+    let mut table =
+        doc.get_mut("commands")?.as_array_mut()?.get_mut(*first)?.as_inline_table_mut()?;
+
+    for i in rest {
+        table = table.get_mut("commands")?.as_array_mut()?.get_mut(*i)?.as_inline_table_mut()?;
+    }
+
+    Some(table)
+}
+
+pub(crate) fn replace_value(value: &mut Value, key: &str) {
+    let decor = value.decor().clone();
+    *value = Value::from(key);
+    *value.decor_mut() = decor;
+}
+
+pub(crate) struct HotkeyEditor {
+    path: Option<PathBuf>,
+    doc: Option<DocumentMut>,
+    bindings: Vec<Binding>,
+    capturing: Option<usize>,
+    error: Option<String>,
+}
+
+impl HotkeyEditor {
+    pub(crate) fn new() -> Self {
+        HotkeyEditor {
+            path: config::config_path(),
+            doc: None,
+            bindings: Vec::new(),
+            capturing: None,
+            error: None,
+        }
+    }
+
+    fn load(&mut self) {
+        self.bindings.clear();
+        self.capturing = None;
+        self.error = None;
+
+        let doc = self
+            .path
+            .as_ref()
+            .ok_or_else(|| "Couldn't find config file".to_string())
+            .and_then(|path| {
+                fs::read_to_string(path).map_err(|e| format!("Couldn't read config file: {e}"))
+            })
+            .and_then(|content| {
+                content.parse::<DocumentMut>().map_err(|e| format!("Couldn't parse config: {e}"))
+            });
+
+        let doc = match doc {
+            Ok(doc) => doc,
+            Err(e) => {
+                self.error = Some(e);
+                self.doc = None;
+                return;
+            },
+        };
+
+        for field in SETTINGS_FIELDS {
+            if let Some(key) =
+                doc.get("settings").and_then(|s| s.get(field)).and_then(|v| v.as_str())
+            {
+                self.bindings.push(Binding {
+                    label: format!("Settings / {field}"),
+                    location: Location::Settings(field),
+                    key: key.to_string(),
+                });
+            }
+        }
+
+        if let Some(commands) = doc.get("commands").and_then(|c| c.as_array()) {
+            collect_commands(commands, &mut Vec::new(), "", &mut self.bindings);
+        }
+
+        self.doc = Some(doc);
+    }
+
+    /// Writes the new key to the configuration file and returns its updated
+    /// contents.
+    fn rebind(&mut self, index: usize, key: String) -> Option<String> {
+        let doc = self.doc.as_mut()?;
+        let binding = &mut self.bindings[index];
+
+        let value = match &binding.location {
+            Location::Settings(field) => doc
+                .get_mut("settings")
+                .and_then(|s| s.get_mut(field))
+                .and_then(|v| v.as_value_mut()),
+            Location::Command(path, field) => {
+                command_table(doc, path).and_then(|table| table.get_mut(field))
+            },
+        }?;
+
+        replace_value(value, &key);
+        binding.key = key;
+
+        let content = doc.to_string();
+        if let Err(e) = self.path.as_ref().map(|path| fs::write(path, &content)).transpose() {
+            self.error = Some(format!("Couldn't write config file: {e}"));
+            return None;
+        }
+
+        Some(content)
+    }
+
+    pub(crate) fn open(&mut self, ui: &imgui::Ui) {
+        self.load();
+        ui.open_popup(HOTKEY_EDITOR_TAG);
+    }
+
+    /// Renders the panel. Returns the new configuration file contents after a
+    /// hotkey has been rebound.
+    pub(crate) fn render(&mut self, ui: &imgui::Ui) -> Option<String> {
+        let mut new_config = None;
+        let scale = scaling_factor(ui);
+
+        if let Some(_token) = ui
+            .modal_popup_config(HOTKEY_EDITOR_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            if let Some(index) = self.capturing {
+                if is_down(VK_ESCAPE.0) {
+                    self.capturing = None;
+                } else if let Some(key) = capture_key() {
+                    self.capturing = None;
+                    new_config = self.rebind(index, key);
+                }
+            }
+
+            if let Some(error) = &self.error {
+                ui.text_colored([1., 0.3, 0.3, 1.], error);
+            }
+
+            ui.text("Click a hotkey, then press the new key combination (Esc to cancel).");
+            ui.separator();
+
+            for (i, binding) in self.bindings.iter().enumerate() {
+                let key_label = if self.capturing == Some(i) {
+                    format!("...##hotkey-{i}")
+                } else {
+                    format!("{}##hotkey-{i}", binding.key)
+                };
+
+                if ui.button_with_size(&key_label, [BUTTON_WIDTH * scale * 0.5, BUTTON_HEIGHT]) {
+                    self.capturing = Some(i);
+                }
+                ui.same_line();
+                ui.text(&binding.label);
+            }
+
+            ui.separator();
+            if ui.button_with_size("Close", [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+                self.capturing = None;
+                ui.close_current_popup();
+            }
+        }
+
+        new_config
+    }
+}
@@ -0,0 +1,89 @@
+//! Invulnerability windows of the player's animations.
+//!
+//! The game marks invulnerability with an event in each animation's TimeAct
+//! (TAE) data. The events of the current animation are read from memory, so
+//! every dodge is covered, including the ones changed by rings such as the
+//! Dark Wood Grain Ring, along with any other animation with invulnerability.
+
+use libds3::memedit::PointerChain;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+/// The game's animation frames run at 30 fps.
+const ANIM_FPS: f32 = 30.;
+
+/// Type of the TimeAct event during which the character can't be hit.
+const INVINCIBLE_EVENT: u32 = 795;
+
+/// Upper bound on the events of an animation, in case the data is garbage.
+const MAX_EVENTS: u32 = 1024;
+
+fn read<T>(addr: usize) -> Option<T> {
+    PointerChain::<T>::new(&[addr]).read()
+}
+
+/// Start and end time, in seconds, of the invulnerability in the animation
+/// whose TimeAct data is at `tae`. Several windows are merged into one.
+fn window(tae: usize) -> Option<(f32, f32)> {
+    // The game resolves the offsets of the TimeAct data to pointers. The
+    // animation starts with the pointers to its event headers, event groups,
+    // times and animation file, followed by the event count.
+    let [event_headers, _, _, _, counts] = read::<[usize; 5]>(tae)?;
+    let event_count = (counts as u32).min(MAX_EVENTS) as usize;
+
+    (0..event_count)
+        .filter_map(|i| {
+            // Pointers to the start time, the end time and the event data,
+            // which starts with the event type.
+            let [start, end, data] = read::<[usize; 3]>(event_headers + i * 24)?;
+            if read::<u32>(data)? != INVINCIBLE_EVENT {
+                return None;
+            }
+            Some((read::<f32>(start)?, read::<f32>(end)?))
+        })
+        .reduce(|(start, end), (s, e)| (start.min(s), end.max(e)))
+}
+
+/// The window of the last animation checked, so that its events are only
+/// read once per animation.
+static LAST_WINDOW: Lazy<Mutex<Option<(usize, Option<(f32, f32)>)>>> = Lazy::new(Default::default);
+
+/// State of the invulnerability window of the current animation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum IFrames {
+    /// The animation has no invulnerability.
+    None,
+    /// Invulnerable: current frame of the window and its length.
+    Active { frame: u32, total: u32 },
+    /// The window is over or hasn't started yet.
+    Outside { total: u32 },
+}
+
+impl IFrames {
+    /// `tae` is the address of the current animation's TimeAct data and
+    /// `time` the time elapsed in it.
+    pub(crate) fn of(tae: usize, time: f32) -> IFrames {
+        let window = {
+            let mut last = LAST_WINDOW.lock();
+            match *last {
+                Some((addr, window)) if addr == tae => window,
+                _ => {
+                    let window = window(tae);
+                    *last = Some((tae, window));
+                    window
+                },
+            }
+        };
+
+        let Some((start, end)) = window else {
+            return IFrames::None;
+        };
+
+        let total = ((end - start) * ANIM_FPS).round().max(1.) as u32;
+        if (start..end).contains(&time) {
+            IFrames::Active { frame: (((time - start) * ANIM_FPS) as u32 + 1).min(total), total }
+        } else {
+            IFrames::Outside { total }
+        }
+    }
+}
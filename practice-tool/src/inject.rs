@@ -0,0 +1,158 @@
+//! Finding the game process and loading the tool's DLL into it.
+//!
+//! Processes are looked up by PID or executable name rather than by window
+//! title, since the title depends on the game's language.
+
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::os::windows::ffi::OsStrExt;
+use std::path::Path;
+
+use windows::core::{s, w};
+use windows::Win32::Foundation::{CloseHandle, BOOL, HANDLE, HWND, LPARAM};
+use windows::Win32::System::Diagnostics::Debug::WriteProcessMemory;
+use windows::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W, TH32CS_SNAPPROCESS,
+};
+use windows::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress};
+use windows::Win32::System::Memory::{
+    VirtualAllocEx, VirtualFreeEx, MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_READWRITE,
+};
+use windows::Win32::System::Threading::{
+    CreateRemoteThread, GetExitCodeThread, OpenProcess, WaitForSingleObject, INFINITE,
+    PROCESS_ALL_ACCESS,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    EnumWindows, GetWindowThreadProcessId, IsWindowVisible,
+};
+
+/// Process to inject into.
+#[derive(Debug, Clone)]
+pub(crate) enum Target {
+    Pid(u32),
+    Name(String),
+}
+
+impl Target {
+    /// Resolves the target to a PID, if the process is running.
+    pub(crate) fn pid(&self) -> Option<u32> {
+        match self {
+            Target::Pid(pid) => processes().into_iter().find(|(p, _)| p == pid).map(|(p, _)| p),
+            Target::Name(name) => processes()
+                .into_iter()
+                .find(|(_, exe)| exe.eq_ignore_ascii_case(name))
+                .map(|(pid, _)| pid),
+        }
+    }
+}
+
+impl std::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Target::Pid(pid) => write!(f, "PID {pid}"),
+            Target::Name(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Lists the PIDs and executable names of the running processes.
+fn processes() -> Vec<(u32, String)> {
+    let mut processes = Vec::new();
+
+    let Ok(snapshot) = (unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) }) else {
+        return processes;
+    };
+
+    let mut entry =
+        PROCESSENTRY32W { dwSize: size_of::<PROCESSENTRY32W>() as u32, ..Default::default() };
+
+    let mut next = unsafe { Process32FirstW(snapshot, &mut entry) };
+    while next.is_ok() {
+        let len = entry.szExeFile.iter().position(|&c| c == 0).unwrap_or(entry.szExeFile.len());
+        processes.push((entry.th32ProcessID, String::from_utf16_lossy(&entry.szExeFile[..len])));
+        next = unsafe { Process32NextW(snapshot, &mut entry) };
+    }
+
+    unsafe { CloseHandle(snapshot).ok() };
+    processes
+}
+
+/// Whether the process has created a visible top level window yet.
+pub(crate) fn has_window(pid: u32) -> bool {
+    unsafe extern "system" fn callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let search = &mut *(lparam.0 as *mut (u32, bool));
+
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == search.0 && IsWindowVisible(hwnd).as_bool() {
+            search.1 = true;
+            return BOOL(0);
+        }
+
+        BOOL(1)
+    }
+
+    let mut search = (pid, false);
+    // Stopping the enumeration early makes `EnumWindows` report an error.
+    unsafe { EnumWindows(Some(callback), LPARAM(&mut search as *mut _ as isize)).ok() };
+    search.1
+}
+
+/// Loads the DLL in the process by running `LoadLibraryW` in a remote thread.
+pub(crate) fn inject(pid: u32, dll_path: &Path) -> Result<(), String> {
+    let path = dll_path.as_os_str().encode_wide().chain(Some(0)).collect::<Vec<u16>>();
+    let path_size = path.len() * size_of::<u16>();
+
+    let process = unsafe { OpenProcess(PROCESS_ALL_ACCESS, false, pid) }
+        .map_err(|e| format!("Could not open process {pid}: {e}"))?;
+
+    let result = unsafe { load_library(process, &path, path_size) };
+    unsafe { CloseHandle(process).ok() };
+    result
+}
+
+unsafe fn load_library(process: HANDLE, path: &[u16], path_size: usize) -> Result<(), String> {
+    let load_library = GetModuleHandleW(w!("kernel32.dll"))
+        .ok()
+        .and_then(|kernel32| GetProcAddress(kernel32, s!("LoadLibraryW")))
+        .ok_or_else(|| "Could not find LoadLibraryW".to_string())?;
+
+    let remote_path =
+        VirtualAllocEx(process, None, path_size, MEM_COMMIT | MEM_RESERVE, PAGE_READWRITE);
+    if remote_path.is_null() {
+        return Err("Could not allocate memory in the game process".to_string());
+    }
+
+    let result =
+        WriteProcessMemory(process, remote_path, path.as_ptr() as *const c_void, path_size, None)
+            .map_err(|e| format!("Could not write DLL path: {e}"))
+            .and_then(|_| {
+                CreateRemoteThread(
+                    process,
+                    None,
+                    0,
+                    Some(std::mem::transmute(load_library)),
+                    Some(remote_path as *const c_void),
+                    0,
+                    None,
+                )
+                .map_err(|e| format!("Could not start loader thread: {e}"))
+            })
+            .and_then(|thread| {
+                WaitForSingleObject(thread, INFINITE);
+
+                // The exit code is the low half of the module handle, zero on failure.
+                let mut exit_code = 0u32;
+                GetExitCodeThread(thread, &mut exit_code).ok();
+                CloseHandle(thread).ok();
+
+                if exit_code == 0 {
+                    Err("LoadLibraryW failed in the game process".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+
+    VirtualFreeEx(process, remote_path, 0, MEM_RELEASE).ok();
+    result
+}
@@ -0,0 +1,259 @@
+//! Game state export for external tools such as LiveSplit components.
+//!
+//! Connected clients of the `\\.\pipe\jdsd_dsiii_practice_tool` named pipe
+//! receive one JSON object per line, roughly once per frame:
+//!
+//! ```json
+//! {"igt":123456,"loading":false,"bosses_defeated":[14000800,13000800]}
+//! ```
+//!
+//! The same state is also published in the `Local\jdsd_dsiii_practice_tool`
+//! shared memory block, laid out as [`SharedState`], for load removers that
+//! poll memory instead of reading a stream.
+
+use std::mem::size_of;
+use std::sync::atomic::{fence, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hudhook::tracing::{debug, error, info};
+use libds3::prelude::*;
+use serde::Serialize;
+use windows::core::w;
+use windows::Win32::Foundation::{CloseHandle, ERROR_PIPE_CONNECTED, HANDLE, INVALID_HANDLE_VALUE};
+use windows::Win32::Storage::FileSystem::{WriteFile, PIPE_ACCESS_OUTBOUND};
+use windows::Win32::System::Memory::{
+    CreateFileMappingW, MapViewOfFile, FILE_MAP_ALL_ACCESS, PAGE_READWRITE,
+};
+use windows::Win32::System::Pipes::{
+    ConnectNamedPipe, CreateNamedPipeW, DisconnectNamedPipe, PIPE_TYPE_BYTE,
+    PIPE_UNLIMITED_INSTANCES, PIPE_WAIT,
+};
+
+const UPDATE_INTERVAL: Duration = Duration::from_millis(16);
+/// Time between two reads of the boss flags, which only change when a boss
+/// is defeated.
+const BOSS_FLAGS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The pointer chains the exported state is read from, shared with the
+/// background threads.
+struct Pointers {
+    igt: PointerChain<u32>,
+    position: (PointerChain<f32>, PointerChain<[f32; 3]>),
+}
+
+#[derive(Serialize)]
+struct State {
+    igt: Option<u32>,
+    loading: bool,
+    bosses_defeated: Vec<u32>,
+}
+
+/// Defeated boss flags, read again at most every [`BOSS_FLAGS_INTERVAL`].
+#[derive(Default)]
+struct DefeatedBosses {
+    flags: Vec<u32>,
+    last_read: Option<Instant>,
+}
+
+impl DefeatedBosses {
+    fn read(&mut self) -> Vec<u32> {
+        if !self.last_read.is_some_and(|t| t.elapsed() < BOSS_FLAGS_INTERVAL) {
+            self.flags = BOSS_FLAGS
+                .iter()
+                .filter(|(flag, _)| EventFlag(*flag).get().unwrap_or(false))
+                .map(|(flag, _)| *flag)
+                .collect();
+            self.last_read = Some(Instant::now());
+        }
+        self.flags.clone()
+    }
+}
+
+impl State {
+    fn read(pointers: &Pointers, bosses: &mut DefeatedBosses) -> Self {
+        State {
+            igt: pointers.igt.read(),
+            // The player character is unloaded during loading screens and in the
+            // main menu.
+            loading: pointers.position.1.read().is_none(),
+            bosses_defeated: bosses.read(),
+        }
+    }
+}
+
+/// Layout of the shared memory block. Fields are only ever appended, and
+/// `version` is bumped when that happens.
+///
+/// `sequence` is odd while the block is being written: readers should read
+/// it, copy the block, and retry if it was odd or changed in the meantime.
+#[repr(C)]
+struct SharedState {
+    /// Always `b"DS3P"`.
+    magic: [u8; 4],
+    version: u32,
+    sequence: u32,
+    /// In game time in milliseconds, 0 while not available.
+    igt: u32,
+    /// 1 during loading screens and in the main menu.
+    loading: u32,
+    /// X, Y, Z and angle of the player, zeroed while loading.
+    position: [f32; 4],
+    /// Bit `i` is set if the boss `i` of `bosses` is defeated.
+    bosses_defeated: u32,
+    boss_count: u32,
+    /// Defeated event flags of the tracked bosses, in route order.
+    bosses: [u32; 32],
+}
+
+const SHARED_STATE_VERSION: u32 = 1;
+
+/// Writes the game state to the shared memory block forever.
+fn publish_shared_state(pointers: Arc<Pointers>) {
+    let mapping = match unsafe {
+        CreateFileMappingW(
+            INVALID_HANDLE_VALUE,
+            None,
+            PAGE_READWRITE,
+            0,
+            size_of::<SharedState>() as u32,
+            w!("Local\\jdsd_dsiii_practice_tool"),
+        )
+    } {
+        Ok(mapping) => mapping,
+        Err(e) => {
+            error!("Couldn't create interop shared memory: {e}");
+            return;
+        },
+    };
+
+    let view = unsafe { MapViewOfFile(mapping, FILE_MAP_ALL_ACCESS, 0, 0, 0) };
+    if view.Value.is_null() {
+        error!("Couldn't map interop shared memory");
+        unsafe { CloseHandle(mapping).ok() };
+        return;
+    }
+
+    let shared = view.Value as *mut SharedState;
+    let mut bosses = [0u32; 32];
+    for (slot, (flag, _)) in bosses.iter_mut().zip(BOSS_FLAGS.iter()) {
+        *slot = *flag;
+    }
+
+    let mut defeated = DefeatedBosses::default();
+    let mut sequence = 0u32;
+    loop {
+        let state = State::read(&pointers, &mut defeated);
+        let position = match (pointers.position.1.read(), pointers.position.0.read()) {
+            (Some([x, y, z]), Some(angle)) => [x, y, z, angle],
+            _ => [0.; 4],
+        };
+        let bosses_defeated = BOSS_FLAGS
+            .iter()
+            .enumerate()
+            .filter(|(_, (flag, _))| state.bosses_defeated.contains(flag))
+            .fold(0, |mask, (i, _)| mask | 1 << i);
+
+        sequence = sequence.wrapping_add(1);
+        unsafe {
+            std::ptr::addr_of_mut!((*shared).sequence).write_volatile(sequence);
+            fence(Ordering::Release);
+            shared.write_volatile(SharedState {
+                magic: *b"DS3P",
+                version: SHARED_STATE_VERSION,
+                sequence,
+                igt: state.igt.unwrap_or(0),
+                loading: state.loading as u32,
+                position,
+                bosses_defeated,
+                boss_count: BOSS_FLAGS.len() as u32,
+                bosses,
+            });
+            fence(Ordering::Release);
+            sequence = sequence.wrapping_add(1);
+            std::ptr::addr_of_mut!((*shared).sequence).write_volatile(sequence);
+        }
+
+        thread::sleep(UPDATE_INTERVAL);
+    }
+}
+
+/// Streams the game state to a connected client until it disconnects.
+fn serve_client(pipe: HANDLE, pointers: Arc<Pointers>) {
+    let mut defeated = DefeatedBosses::default();
+    loop {
+        let mut line = match serde_json::to_string(&State::read(&pointers, &mut defeated)) {
+            Ok(line) => line,
+            Err(e) => {
+                error!("Couldn't serialize interop state: {e}");
+                break;
+            },
+        };
+        line.push('\n');
+
+        if let Err(e) = unsafe { WriteFile(pipe, Some(line.as_bytes()), None, None) } {
+            debug!("Interop client disconnected: {e}");
+            break;
+        }
+
+        thread::sleep(UPDATE_INTERVAL);
+    }
+
+    unsafe {
+        DisconnectNamedPipe(pipe).ok();
+        CloseHandle(pipe).ok();
+    }
+}
+
+/// Starts the named pipe server and the shared memory writer in background
+/// threads.
+pub(crate) fn start(pointers: &PointerChains) {
+    let pointers =
+        Arc::new(Pointers { igt: pointers.igt.clone(), position: pointers.position.clone() });
+
+    let shared_pointers = Arc::clone(&pointers);
+    if let Err(e) = thread::Builder::new()
+        .name("interop-shm".to_string())
+        .spawn(move || publish_shared_state(shared_pointers))
+    {
+        error!("Couldn't start interop shared memory thread: {e}");
+    }
+
+    let spawned = thread::Builder::new().name("interop".to_string()).spawn(move || loop {
+        let pipe = unsafe {
+            CreateNamedPipeW(
+                w!("\\\\.\\pipe\\jdsd_dsiii_practice_tool"),
+                PIPE_ACCESS_OUTBOUND,
+                PIPE_TYPE_BYTE | PIPE_WAIT,
+                PIPE_UNLIMITED_INSTANCES,
+                4096,
+                0,
+                0,
+                None,
+            )
+        };
+
+        if pipe.is_invalid() {
+            error!("Couldn't create interop named pipe");
+            return;
+        }
+
+        match unsafe { ConnectNamedPipe(pipe, None) } {
+            Err(e) if e.code() != ERROR_PIPE_CONNECTED.to_hresult() => {
+                error!("Couldn't connect interop client: {e}");
+                unsafe { CloseHandle(pipe).ok() };
+                continue;
+            },
+            _ => {},
+        }
+
+        info!("Interop client connected");
+        let pointers = Arc::clone(&pointers);
+        thread::spawn(move || serve_client(pipe, pointers));
+    });
+
+    if let Err(e) = spawned {
+        error!("Couldn't start interop thread: {e}");
+    }
+}
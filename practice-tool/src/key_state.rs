@@ -0,0 +1,88 @@
+//! Hotkeys polled from the keyboard state.
+//!
+//! Hotkeys are normally handled through imgui, which only sees input while
+//! the game window has the focus and once per frame. Code that polls keys on
+//! its own, such as key repeat, goes through [`KeyState`] instead, so that a
+//! combination like `ctrl+shift+k` is checked the same way everywhere: the
+//! modifiers it names must be held, and the ones it doesn't name must not, so
+//! that `k` doesn't also fire on `ctrl+k`.
+
+use practice_tool_core::key::Key;
+use windows::Win32::UI::Input::KeyboardAndMouse::*;
+
+use crate::util;
+
+/// Modifier keys, each with the left and right keys it stands for.
+const MODIFIERS: [(VIRTUAL_KEY, [VIRTUAL_KEY; 2]); 3] = [
+    (VK_CONTROL, [VK_LCONTROL, VK_RCONTROL]),
+    (VK_SHIFT, [VK_LSHIFT, VK_RSHIFT]),
+    (VK_MENU, [VK_LMENU, VK_RMENU]),
+];
+
+fn is_down(vk: u16) -> bool {
+    // The most significant bit is set while the key is down.
+    (unsafe { GetAsyncKeyState(vk as i32) }) < 0
+}
+
+fn modifier_of(vk: u16) -> Option<usize> {
+    MODIFIERS.iter().position(|(any, sides)| any.0 == vk || sides.iter().any(|s| s.0 == vk))
+}
+
+/// State of a hotkey, polled once per frame with [`KeyState::update`] to tell
+/// presses and releases.
+#[derive(Debug)]
+pub(crate) struct KeyState {
+    keys: Vec<u16>,
+    /// For each of [`MODIFIERS`], the key the hotkey needs held, if any.
+    modifiers: [Option<u16>; 3],
+    down: bool,
+    was_down: bool,
+}
+
+impl KeyState {
+    /// Returns `None` if one of the hotkey's keys has no virtual key code.
+    pub(crate) fn new(key: &Key) -> Option<Self> {
+        let mut keys = Vec::new();
+        let mut modifiers = [None; 3];
+        for vk in util::virtual_keys(key)? {
+            match modifier_of(vk) {
+                Some(i) => modifiers[i] = Some(vk),
+                None => keys.push(vk),
+            }
+        }
+
+        Some(KeyState { keys, modifiers, down: false, was_down: false })
+    }
+
+    /// Whether the whole combination is held, with no other modifier.
+    fn poll(&self) -> bool {
+        let modifiers =
+            MODIFIERS.iter().zip(self.modifiers).all(|((any, _), needed)| match needed {
+                Some(vk) => is_down(vk),
+                None => !is_down(any.0),
+            });
+
+        modifiers && self.keys.iter().all(|&vk| is_down(vk))
+    }
+
+    /// Reads the keyboard state again.
+    pub(crate) fn update(&mut self) {
+        self.was_down = self.down;
+        self.down = self.poll();
+    }
+
+    /// Whether the combination is held.
+    pub(crate) fn is_down(&self) -> bool {
+        self.down
+    }
+
+    /// Whether the combination went down on the last update.
+    pub(crate) fn is_pressed(&self) -> bool {
+        self.down && !self.was_down
+    }
+
+    /// Whether the combination was released on the last update.
+    pub(crate) fn keyup(&self) -> bool {
+        !self.down && self.was_down
+    }
+}
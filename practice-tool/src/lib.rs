@@ -14,8 +14,33 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+mod action;
+mod audio;
+mod codegen;
+mod command_palette;
 mod config;
+mod config_editor;
+mod counters;
+mod crash;
+mod feedback;
+mod gamepad;
+mod hotkey_editor;
+mod iframes;
+mod interop;
+mod key_state;
+mod online;
+mod overlay;
+mod plugins;
 mod practice_tool;
+mod projection;
+mod radial_menu;
+mod remote;
+mod restore;
+mod sl2;
+mod speed_graph;
+mod telemetry;
+mod ui_state;
+mod update;
 mod util;
 mod widgets;
 
@@ -23,20 +48,24 @@ use std::ffi::c_void;
 use std::time::{Duration, Instant};
 use std::{mem, ptr, thread};
 
+use config::RenderBackend;
 use hudhook::hooks::dx11::ImguiDx11Hooks;
+use hudhook::hooks::dx12::ImguiDx12Hooks;
 use hudhook::mh::{MH_ApplyQueued, MH_Initialize, MhHook, MH_STATUS};
-use hudhook::tracing::{error, trace};
+use hudhook::tracing::{error, info, trace};
 use hudhook::{eject, Hudhook};
 use libds3::pointers::PointerChains;
 use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 use practice_tool::PracticeTool;
+pub use update::{current_version, latest_release, Release};
 use windows::core::{s, w, GUID, HRESULT, PCWSTR};
 use windows::Win32::Foundation::{ERROR_SUCCESS, HINSTANCE, MAX_PATH};
-use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryW};
+use windows::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress, LoadLibraryW};
 use windows::Win32::System::SystemInformation::GetSystemDirectoryW;
 use windows::Win32::System::SystemServices::DLL_PROCESS_ATTACH;
 use windows::Win32::UI::Input::KeyboardAndMouse::{GetAsyncKeyState, VK_RSHIFT};
-use windows::Win32::UI::Input::XboxController::XINPUT_STATE;
+use windows::Win32::UI::Input::XboxController::{XINPUT_GAMEPAD, XINPUT_STATE};
 
 type FDirectInput8Create = unsafe extern "stdcall" fn(
     hinst: HINSTANCE,
@@ -54,11 +83,7 @@ static DIRECTINPUT8CREATE: Lazy<FDirectInput8Create> = Lazy::new(|| unsafe {
     std::ptr::copy_nonoverlapping(w!("\\dinput8.dll").0, dinput8_path[count..].as_mut_ptr(), 12);
 
     let dinput8 = LoadLibraryW(PCWSTR(dinput8_path.as_ptr())).unwrap();
-    let directinput8create = std::mem::transmute(GetProcAddress(dinput8, s!("DirectInput8Create")));
-
-    apply_no_logo();
-
-    directinput8create
+    std::mem::transmute(GetProcAddress(dinput8, s!("DirectInput8Create")))
 });
 
 #[no_mangle]
@@ -103,13 +128,43 @@ static XINPUTGETSTATE: Lazy<FXInputGetState> = Lazy::new(|| unsafe {
     mem::transmute(hook.trampoline())
 });
 
+/// Parses a configuration file the way the injected tool does, for the
+/// launcher's `--check-config`.
+pub fn check_config(content: &str) -> Result<(), String> {
+    config::Config::parse(content).map(|_| ())
+}
+
+/// Last gamepad state seen by the XInput hook for each user index, or `None`
+/// if the controller was not connected.
+pub(crate) static XINPUT_GAMEPADS: Lazy<Mutex<[Option<XINPUT_GAMEPAD>; 4]>> =
+    Lazy::new(Default::default);
+
+/// Gamepad state fed to the game instead of the first controller's, e.g. by a
+/// playing input macro. The game sees a connected controller while it is set.
+pub(crate) static XINPUT_OVERRIDE: Lazy<Mutex<Option<XINPUT_GAMEPAD>>> =
+    Lazy::new(Default::default);
+
 unsafe extern "stdcall" fn xinput_get_state_impl(
     dw_user_index: u32,
     xinput_state: *mut XINPUT_STATE,
 ) -> u32 {
     let r = (XINPUTGETSTATE)(dw_user_index, xinput_state);
 
+    if dw_user_index == 0 {
+        if let (Some(gamepad), Some(state)) = (*XINPUT_OVERRIDE.lock(), xinput_state.as_mut()) {
+            if r != ERROR_SUCCESS.0 {
+                *state = XINPUT_STATE::default();
+            }
+            state.dwPacketNumber = state.dwPacketNumber.wrapping_add(1);
+            state.Gamepad = gamepad;
+            return ERROR_SUCCESS.0;
+        }
+    }
+
     if r != ERROR_SUCCESS.0 {
+        if let Some(gamepad) = XINPUT_GAMEPADS.lock().get_mut(dw_user_index as usize) {
+            *gamepad = None;
+        }
         return r;
     }
 
@@ -127,12 +182,29 @@ unsafe extern "stdcall" fn xinput_get_state_impl(
         if (-10..=10).contains(&state.Gamepad.sThumbRY) {
             state.Gamepad.sThumbRY = 0;
         }
+
+        if let Some(gamepad) = XINPUT_GAMEPADS.lock().get_mut(dw_user_index as usize) {
+            *gamepad = Some(state.Gamepad);
+        }
+
+        gamepad::block_chords(&mut state.Gamepad);
     }
 
     r
 }
 
+/// Skips the intro logos, unless the configuration is read only. Reads the
+/// configuration file, so it runs on the startup thread rather than in
+/// `DllMain`, where the loader lock is held.
 fn apply_no_logo() {
+    let read_only = config::config_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| config::Config::parse(&content).ok())
+        .is_some_and(|config| config.settings.read_only());
+    if read_only {
+        return;
+    }
+
     // This is evaluated twice: here and in [`PracticeTool::new()`]. No big deal,
     // but might want to refactor that eventually.
     let pointer_chains = PointerChains::new();
@@ -142,16 +214,40 @@ fn apply_no_logo() {
     ]);
 }
 
+/// Detects the graphics API from the loaded modules. The game itself is DX11,
+/// so DX11 wins whenever `d3d11.dll` is loaded, as overlays and capture tools
+/// often load `d3d12.dll` too. `render_backend` in the settings forces DX12
+/// for wrappers that need it. Waits for either module to be loaded, as the
+/// tool can be started before the renderer.
+fn detect_render_backend() -> RenderBackend {
+    let loaded = |name: PCWSTR| unsafe { GetModuleHandleW(name) }.is_ok();
+    let start_time = Instant::now();
+
+    loop {
+        if loaded(w!("d3d11.dll")) || start_time.elapsed() > Duration::from_secs(10) {
+            return RenderBackend::Dx11;
+        }
+        if loaded(w!("d3d12.dll")) {
+            return RenderBackend::Dx12;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
 fn start_practice_tool(hmodule: HINSTANCE) {
     let practice_tool = PracticeTool::new();
 
-    if let Err(e) = Hudhook::builder()
-        .with::<ImguiDx11Hooks>(practice_tool)
-        .with_hmodule(hmodule)
-        .build()
-        .apply()
-    {
+    let backend = practice_tool.render_backend().unwrap_or_else(detect_render_backend);
+    info!("Render backend: {backend:?}");
+
+    let builder = match backend {
+        RenderBackend::Dx11 => Hudhook::builder().with::<ImguiDx11Hooks>(practice_tool),
+        RenderBackend::Dx12 => Hudhook::builder().with::<ImguiDx12Hooks>(practice_tool),
+    };
+
+    if let Err(e) = builder.with_hmodule(hmodule).build().apply() {
         error!("Couldn't apply hooks: {e:?}");
+        crash::uninstall();
         eject();
     }
 }
@@ -197,6 +293,8 @@ pub unsafe extern "stdcall" fn DllMain(hmodule: HINSTANCE, reason: u32, _: *mut
         Lazy::force(&XINPUTGETSTATE);
 
         thread::spawn(move || {
+            apply_no_logo();
+
             if util::get_dll_path()
                 .and_then(|path| {
                     path.file_name().map(|s| s.to_string_lossy().to_lowercase() == "dinput8.dll")
@@ -1,7 +1,13 @@
-use hudhook::inject::Process;
+mod inject;
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
 use hudhook::tracing::trace;
-use pkg_version::*;
-use semver::Version;
+use inject::Target;
+use libjdsd_dsiii_practice_tool::{current_version, latest_release, Release};
 use tracing_subscriber::filter::LevelFilter;
 use windows::core::PCSTR;
 use windows::Win32::Foundation::HWND;
@@ -9,41 +15,84 @@ use windows::Win32::UI::WindowsAndMessaging::{
     MessageBoxA, IDYES, MB_ICONERROR, MB_ICONINFORMATION, MB_OK, MB_YESNO,
 };
 
+const GAME_EXE: &str = "DarkSoulsIII.exe";
+const STEAM_APP_ID: u32 = 374320;
+const LAUNCH_TIMEOUT: Duration = Duration::from_secs(120);
+const LAUNCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const LAUNCH_SETTLE_TIME: Duration = Duration::from_secs(5);
+/// Holds the path of the configuration file given with `--config`. Read by
+/// the DLL, see `config::config_path`.
+const CONFIG_PATH_FILE: &str = "jdsd_dsiii_practice_tool.config_path";
+
+/// Environment variable giving the configuration file when `--config` isn't
+/// passed. It is read here and written to [`CONFIG_PATH_FILE`], since the game
+/// doesn't necessarily inherit the launcher's environment.
+const CONFIG_PATH_ENV: &str = "JDSD_DSIII_CONFIG";
+
 fn err_to_string<T: std::fmt::Display>(e: T) -> String {
     format!("Error: {}", e)
 }
 
-fn get_current_version() -> Version {
-    Version {
-        major: pkg_version_major!(),
-        minor: pkg_version_minor!(),
-        patch: pkg_version_patch!(),
-        pre: vec![],
-        build: vec![],
-    }
-}
+/// Finds the target process and waits for its main window, since injecting
+/// before the window is created fails. If the game isn't running and no
+/// explicit target was given, it is started through Steam first.
+fn wait_for_game(target: Option<Target>) -> Result<u32, String> {
+    let launch = target.is_none();
+    let target = target.unwrap_or_else(|| Target::Name(GAME_EXE.to_string()));
 
-fn get_latest_version() -> Result<(Version, String, String), String> {
-    #[derive(serde::Deserialize)]
-    struct GithubRelease {
-        tag_name: String,
-        html_url: String,
-        body: String,
+    match target.pid() {
+        Some(pid) if inject::has_window(pid) => return Ok(pid),
+        Some(_) => {},
+        None if launch => {
+            trace!("Game not running, starting it via Steam");
+            open::that(format!("steam://rungameid/{STEAM_APP_ID}"))
+                .map_err(|e| format!("Could not start the game via Steam: {e}"))?;
+        },
+        None => return Err(format!("Could not find process {target}")),
     }
 
-    let release =
-        ureq::get("https://api.github.com/repos/veeenu/darksoulsiii-practice-tool/releases/latest")
-            .call()
-            .map_err(|e| format!("Couldn't check version: {:?}", e))?
-            .into_json::<GithubRelease>()
-            .map_err(|e| format!("Couldn't check version: {:?}", e))?;
+    let started = Instant::now();
+    loop {
+        if let Some(pid) = target.pid().filter(|&pid| inject::has_window(pid)) {
+            // Give the game time to set up its renderer before hooking it.
+            thread::sleep(LAUNCH_SETTLE_TIME);
+            return Ok(pid);
+        }
+
+        if started.elapsed() > LAUNCH_TIMEOUT {
+            return Err(format!(
+                "The game didn't start within {} seconds. Start it manually and run the tool \
+                 again.",
+                LAUNCH_TIMEOUT.as_secs()
+            ));
+        }
+        thread::sleep(LAUNCH_POLL_INTERVAL);
+    }
+}
 
-    let version = Version::parse(&release.tag_name).map_err(err_to_string)?;
+/// Points the tool to the configuration file given with `--config` or
+/// `JDSD_DSIII_CONFIG`, or back to the default one next to the DLL. Relative
+/// paths in the environment variable are resolved from the DLL's folder.
+fn set_config_path(dll_path: &Path, config: Option<&str>) -> Result<(), String> {
+    let pointer_path = dll_path.with_file_name(CONFIG_PATH_FILE);
+    let env_config = std::env::var(CONFIG_PATH_ENV)
+        .ok()
+        .filter(|config| !config.is_empty())
+        .map(|config| dll_path.with_file_name(config));
 
-    Ok((version, release.html_url, release.body))
+    match config.map(PathBuf::from).or(env_config) {
+        Some(config) => {
+            let config = config
+                .canonicalize()
+                .map_err(|e| format!("Could not find config file {}: {e}", config.display()))?;
+            std::fs::write(pointer_path, config.to_string_lossy().as_bytes()).map_err(err_to_string)
+        },
+        None if pointer_path.exists() => std::fs::remove_file(pointer_path).map_err(err_to_string),
+        None => Ok(()),
+    }
 }
 
-fn perform_injection() -> Result<(), String> {
+fn perform_injection(target: Option<Target>, config: Option<String>) -> Result<(), String> {
     let mut dll_path = std::env::current_exe().unwrap();
     dll_path.pop();
     dll_path.push("jdsd_dsiii_practice_tool.dll");
@@ -57,15 +106,104 @@ fn perform_injection() -> Result<(), String> {
     let dll_path = dll_path.canonicalize().map_err(err_to_string)?;
     trace!("Injecting {:?}", dll_path);
 
-    Process::by_name("DarkSoulsIII.exe")
-        .map_err(|e| format!("Could not find process: {e:?}"))?
-        .inject(dll_path)
-        .map_err(|e| format!("Could not inject DLL: {e:?}"))?;
+    set_config_path(&dll_path, config.as_deref())?;
+
+    let pid = wait_for_game(target)?;
+    inject::inject(pid, &dll_path).map_err(|e| format!("Could not inject DLL: {e}"))?;
 
     Ok(())
 }
 
+/// Sends a command to an already injected instance over its command pipe and
+/// returns the reply.
+fn exec_command(command: &str) -> Result<String, String> {
+    let mut pipe = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(r"\\.\pipe\jdsd_dsiii_practice_tool_commands")
+        .map_err(|e| format!("Couldn't connect to the practice tool, is command_pipe on? {e}"))?;
+
+    writeln!(pipe, "{command}").map_err(err_to_string)?;
+
+    let mut reply = String::new();
+    pipe.read_to_string(&mut reply).map_err(err_to_string)?;
+
+    let reply = reply.trim();
+    match reply.strip_prefix("error: ") {
+        Some(e) => Err(e.to_string()),
+        None => Ok(reply.strip_prefix("ok: ").unwrap_or(reply).to_string()),
+    }
+}
+
+/// Returns the value following `flag` in the arguments, if the flag is given.
+fn arg_value(args: &[String], flag: &str) -> Option<Result<String, String>> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .map(|i| args.get(i + 1).cloned().ok_or_else(|| format!("Missing value for {flag}")))
+}
+
+/// Reads the process to inject into from the `--pid` or `--process`
+/// arguments, if given.
+fn parse_target(args: &[String]) -> Result<Option<Target>, String> {
+    if let Some(pid) = arg_value(args, "--pid") {
+        let pid = pid?;
+        return pid
+            .parse()
+            .map(|pid| Some(Target::Pid(pid)))
+            .map_err(|e| format!("Invalid PID {pid:?}: {e}"));
+    }
+
+    arg_value(args, "--process").transpose().map(|name| name.map(Target::Name))
+}
+
+/// Validates the configuration file given with `--config`, or the default one
+/// next to the launcher, without injecting.
+fn check_config(args: &[String]) -> Result<PathBuf, String> {
+    let path = match arg_value(args, "--config").transpose()? {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let mut path = std::env::current_exe().map_err(err_to_string)?;
+            path.set_file_name("jdsd_dsiii_practice_tool.toml");
+            path
+        },
+    };
+
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Could not read config file {}: {e}", path.display()))?;
+    libjdsd_dsiii_practice_tool::check_config(&content)?;
+
+    Ok(path)
+}
+
 fn main() {
+    let args = std::env::args().collect::<Vec<_>>();
+    if let Some(i) = args.iter().position(|arg| arg == "--exec") {
+        let Some(command) = args.get(i + 1) else {
+            eprintln!("Usage: jdsd_dsiii_practice_tool.exe --exec <command>");
+            std::process::exit(2);
+        };
+
+        match exec_command(command) {
+            Ok(reply) => println!("{reply}"),
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            },
+        }
+        return;
+    }
+
+    if args.iter().any(|arg| arg == "--check-config") {
+        match check_config(&args) {
+            Ok(path) => println!("{} is valid", path.display()),
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            },
+        }
+        return;
+    }
+
     tracing_subscriber::fmt()
         .with_max_level(LevelFilter::TRACE)
         .with_thread_ids(true)
@@ -74,10 +212,10 @@ fn main() {
         .with_thread_names(true)
         .init();
 
-    let current_version = get_current_version();
+    let current_version = current_version();
 
-    match get_latest_version() {
-        Ok((latest_version, download_url, release_notes)) => {
+    match latest_release() {
+        Ok(Release { version: latest_version, url: download_url, notes: release_notes }) => {
             if latest_version > current_version {
                 let release_notes = match release_notes.find("## What's Changed") {
                     Some(i) => release_notes[..i].trim(),
@@ -117,7 +255,12 @@ fn main() {
         },
     }
 
-    if let Err(e) = perform_injection() {
+    let injection = parse_target(&args).and_then(|target| {
+        let config = arg_value(&args, "--config").transpose()?;
+        perform_injection(target, config)
+    });
+
+    if let Err(e) = injection {
         let error_msg = format!("{}\0", e);
         unsafe {
             MessageBoxA(
@@ -0,0 +1,130 @@
+//! Online play detection.
+//!
+//! Using the tool's commands while connected to the matchmaking servers can
+//! get the account soft banned. The game keeps a TCP connection to the
+//! servers open for as long as it is online, so it is considered online while
+//! it has an established connection to a remote address. The tool's own
+//! connections, to GitHub over HTTPS and to local remote control clients,
+//! don't count.
+
+use std::ffi::c_void;
+use std::net::Ipv4Addr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use windows::Win32::Foundation::NO_ERROR;
+use windows::Win32::NetworkManagement::IpHelper::{
+    GetExtendedTcpTable, MIB_TCPROW_OWNER_PID, MIB_TCPTABLE_OWNER_PID, MIB_TCP_STATE_ESTAB,
+    TCP_TABLE_OWNER_PID_CONNECTIONS,
+};
+use windows::Win32::Networking::WinSock::AF_INET;
+use windows::Win32::System::Threading::GetCurrentProcessId;
+
+/// How often the game's connections are listed.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Port of the update check's connection.
+const HTTPS_PORT: u16 = 443;
+
+static ONLINE: AtomicBool = AtomicBool::new(false);
+
+/// Whether the game was online when last checked. Always `false` while no
+/// [`OnlineWatch`] is running.
+pub(crate) fn is_online() -> bool {
+    ONLINE.load(Ordering::Relaxed)
+}
+
+/// Lists the TCP connections of the game's process.
+fn connections() -> Result<Vec<MIB_TCPROW_OWNER_PID>, String> {
+    let mut buf: Vec<u8> = Vec::new();
+    let mut size = 0u32;
+
+    // The table can grow between the call asking for its size and the one
+    // filling it.
+    for _ in 0..3 {
+        buf.resize(size as usize, 0);
+        let ptr = (!buf.is_empty()).then(|| buf.as_mut_ptr() as *mut c_void);
+        let result = unsafe {
+            GetExtendedTcpTable(
+                ptr,
+                &mut size,
+                false,
+                AF_INET.0 as u32,
+                TCP_TABLE_OWNER_PID_CONNECTIONS,
+                0,
+            )
+        };
+
+        if result == NO_ERROR.0 && ptr.is_some() {
+            let table = buf.as_ptr() as *const MIB_TCPTABLE_OWNER_PID;
+            let rows = unsafe {
+                std::slice::from_raw_parts(
+                    std::ptr::addr_of!((*table).table) as *const MIB_TCPROW_OWNER_PID,
+                    (*table).dwNumEntries as usize,
+                )
+            };
+            return Ok(rows.to_vec());
+        }
+    }
+
+    Err("Couldn't list the game's connections".to_string())
+}
+
+fn has_server_connection() -> Result<bool, String> {
+    let pid = unsafe { GetCurrentProcessId() };
+
+    Ok(connections()?.iter().any(|row| {
+        let address = Ipv4Addr::from(u32::from_be(row.dwRemoteAddr));
+        let port = u16::from_be(row.dwRemotePort as u16);
+
+        row.dwOwningPid == pid
+            && row.dwState == MIB_TCP_STATE_ESTAB.0 as u32
+            && !address.is_loopback()
+            && port != HTTPS_PORT
+    }))
+}
+
+/// Checks the game's connections every few seconds, from the render thread.
+pub(crate) struct OnlineWatch {
+    last_poll: Option<Instant>,
+}
+
+impl OnlineWatch {
+    pub(crate) fn new() -> Self {
+        OnlineWatch { last_poll: None }
+    }
+
+    /// Checks the connections if it is time to. Returns a message when the
+    /// game went online or offline.
+    pub(crate) fn update(&mut self) -> Option<String> {
+        if self.last_poll.is_some_and(|t| t.elapsed() < POLL_INTERVAL) {
+            return None;
+        }
+        self.last_poll = Some(Instant::now());
+
+        // If the connections can't be listed, the game is assumed to be
+        // online: refusing commands is the safe side.
+        let (online, error) = match has_server_connection() {
+            Ok(online) => (online, None),
+            Err(e) => (true, Some(e)),
+        };
+
+        if ONLINE.swap(online, Ordering::Relaxed) == online {
+            return None;
+        }
+
+        Some(match (online, error) {
+            (true, Some(e)) => format!("{e}: commands that change the game are disabled"),
+            (true, None) => {
+                "Online play detected: commands that change the game are disabled".to_string()
+            },
+            (false, _) => "Offline: commands are enabled again".to_string(),
+        })
+    }
+}
+
+impl Drop for OnlineWatch {
+    fn drop(&mut self) {
+        ONLINE.store(false, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,197 @@
+//! HTTP overlay for streaming software.
+//!
+//! Serves a page on `http://127.0.0.1:<port>/` that shows the selected
+//! indicators as plain text, meant to be added as a browser source in OBS.
+//! The page polls `/state`, which returns the current lines as JSON; the
+//! lines are produced on the render thread.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hudhook::tracing::{error, info};
+use libds3::prelude::*;
+use parking_lot::Mutex;
+use serde::Deserialize;
+
+use crate::counters::Counters;
+use crate::util;
+
+const UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+/// Time after which a client that stopped sending or receiving is dropped,
+/// so that it can't hold up the others.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(2);
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<style>
+  body { margin: 0; background: transparent; color: #fff; font: bold 28px monospace;
+         text-shadow: 0 0 4px #000, 0 0 4px #000; }
+  div { white-space: pre; }
+</style>
+</head>
+<body>
+<div id="lines"></div>
+<script>
+  async function poll() {
+    try {
+      const state = await (await fetch("/state")).json();
+      document.getElementById("lines").textContent = state.lines.join("\n");
+    } catch (e) {}
+    setTimeout(poll, 250);
+  }
+  poll();
+</script>
+</body>
+</html>
+"#;
+
+/// Indicator that can be shown on the overlay.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum OverlayItem {
+    Igt,
+    Deaths,
+    Position,
+    Animation,
+    Fps,
+    Vitals,
+}
+
+impl OverlayItem {
+    /// Whether the item is allowed in speedrun mode, as the matching
+    /// indicator is.
+    pub(crate) fn is_speedrun_legal(&self) -> bool {
+        match self {
+            OverlayItem::Igt | OverlayItem::Deaths | OverlayItem::Animation | OverlayItem::Fps => {
+                true
+            },
+            OverlayItem::Position | OverlayItem::Vitals => false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct OverlayConfig {
+    pub(crate) port: u16,
+    #[serde(default = "OverlayConfig::default_items")]
+    pub(crate) indicators: Vec<OverlayItem>,
+}
+
+impl OverlayConfig {
+    fn default_items() -> Vec<OverlayItem> {
+        vec![OverlayItem::Igt, OverlayItem::Deaths]
+    }
+}
+
+pub(crate) struct Overlay {
+    items: Vec<OverlayItem>,
+    lines: Arc<Mutex<Vec<String>>>,
+    last_update: Instant,
+}
+
+fn respond(mut stream: TcpStream, lines: &Mutex<Vec<String>>) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+    let (status, content_type, body) = match path {
+        "/" => ("200 OK", "text/html; charset=utf-8", PAGE.to_string()),
+        "/state" => (
+            "200 OK",
+            "application/json",
+            serde_json::json!({ "lines": *lines.lock() }).to_string(),
+        ),
+        _ => ("404 Not Found", "text/plain", "Not found".to_string()),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: \
+         {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+impl Overlay {
+    /// Starts the HTTP server on `127.0.0.1:port` in a background thread.
+    pub(crate) fn start(config: &OverlayConfig) -> Self {
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let port = config.port;
+
+        let server_lines = Arc::clone(&lines);
+        let spawned = thread::Builder::new().name("overlay".to_string()).spawn(move || {
+            let listener = match TcpListener::bind(("127.0.0.1", port)) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    error!("Couldn't start overlay server on port {port}: {e}");
+                    return;
+                },
+            };
+
+            info!("Overlay available at http://127.0.0.1:{port}/");
+
+            for stream in listener.incoming().filter_map(Result::ok) {
+                if let Err(e) = respond(stream, &server_lines) {
+                    error!("Overlay request failed: {e}");
+                }
+            }
+        });
+
+        if let Err(e) = spawned {
+            error!("Couldn't start overlay thread: {e}");
+        }
+
+        Overlay { items: config.indicators.clone(), lines, last_update: Instant::now() }
+    }
+
+    /// Refreshes the lines served to the overlay page.
+    pub(crate) fn update(&mut self, pointers: &PointerChains, counters: &Counters) {
+        if self.last_update.elapsed() < UPDATE_INTERVAL {
+            return;
+        }
+        self.last_update = Instant::now();
+
+        let lines = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                OverlayItem::Igt => pointers.igt.read().map(|igt| {
+                    let mut line = String::new();
+                    util::write_igt(&mut line, igt).ok();
+                    line
+                }),
+                OverlayItem::Deaths => {
+                    Some(format!("Deaths {} Attempts {}", counters.deaths(), counters.attempts()))
+                },
+                OverlayItem::Position => {
+                    match (pointers.position.1.read(), pointers.position.0.read()) {
+                        (Some([x, y, z]), Some(a)) => Some(format!("{x:.2} {y:.2} {z:.2} {a:.2}")),
+                        _ => None,
+                    }
+                },
+                OverlayItem::Animation => {
+                    pointers.cur_anim.read().map(|anim| format!("Animation {anim}"))
+                },
+                OverlayItem::Fps => pointers.fps.read().map(|fps| format!("FPS {fps}")),
+                OverlayItem::Vitals => {
+                    match (pointers.hp.read(), pointers.fp.read(), pointers.sp.read()) {
+                        (Some([hp, _, max_hp]), Some([fp, _, max_fp]), Some([sp, _, max_sp])) => {
+                            Some(format!("HP {hp}/{max_hp} FP {fp}/{max_fp} SP {sp}/{max_sp}"))
+                        },
+                        _ => None,
+                    }
+                },
+            })
+            .collect();
+
+        *self.lines.lock() = lines;
+    }
+}
@@ -0,0 +1,222 @@
+//! Third-party widget plugins.
+//!
+//! Every DLL in the `plugins` folder next to the tool's DLL is loaded at
+//! startup. A plugin must export the following function:
+//!
+//! ```c
+//! bool jdsd_dsiii_plugin_init(const PluginHost *host);
+//! ```
+//!
+//! and call `host->register_widget` once per widget it provides. It may also
+//! export:
+//!
+//! ```c
+//! void jdsd_dsiii_plugin_destroy(void);
+//! ```
+//!
+//! which is called once the tool is done with the plugin's widgets: when the
+//! tool is ejected, and when the configuration is reloaded, right before
+//! `jdsd_dsiii_plugin_init` is called again. None of the widget callbacks are
+//! called after it, so the plugin should free the widgets' data there. The
+//! host
+//! structure and the widget descriptor are defined as follows:
+//!
+//! ```c
+//! typedef struct {
+//!     void *data;
+//!     void (*render)(void *data, ImGuiContext *ctx);
+//!     void (*render_closed)(void *data, ImGuiContext *ctx);
+//!     void (*interact)(void *data, ImGuiContext *ctx);
+//! } PluginWidget;
+//!
+//! typedef struct {
+//!     uint32_t version;
+//!     void *host;
+//!     void (*register_widget)(void *host, PluginWidget widget);
+//!     void (*log)(const char *message);
+//! } PluginHost;
+//! ```
+//!
+//! `host` and the structure it points to are only valid during the call to
+//! `jdsd_dsiii_plugin_init`: `register_widget` must be called from there, and
+//! neither may be kept. The `log` function itself can be kept and called from
+//! any thread until `jdsd_dsiii_plugin_destroy` returns.
+//!
+//! All callbacks are optional and run on the render thread. Plugins render
+//! through Dear ImGui after setting the context they are handed with
+//! `igSetCurrentContext`; they must be built against the same Dear ImGui
+//! version as the tool. Plugins stay loaded until the game exits.
+
+use std::ffi::{c_char, c_void, CStr};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use hudhook::tracing::{error, info};
+use imgui::sys::{igGetCurrentContext, ImGuiContext};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::widgets::Widget;
+use widestring::U16CString;
+use windows::core::{s, PCWSTR};
+use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryW};
+
+use crate::util;
+
+/// Version of the plugin ABI. Bumped on every incompatible change.
+pub(crate) const PLUGIN_API_VERSION: u32 = 1;
+
+type PluginCallback = Option<unsafe extern "C" fn(data: *mut c_void, ctx: *mut ImGuiContext)>;
+type PluginInit = unsafe extern "C" fn(host: *const PluginHost) -> bool;
+type PluginDestroy = unsafe extern "C" fn();
+
+static PLUGIN_LOGS: Lazy<Mutex<Vec<String>>> = Lazy::new(Default::default);
+
+#[repr(C)]
+pub(crate) struct PluginWidget {
+    data: *mut c_void,
+    render: PluginCallback,
+    render_closed: PluginCallback,
+    interact: PluginCallback,
+}
+
+// SAFETY
+// Plugin widgets are only ever called from the render thread.
+unsafe impl Send for PluginWidget {}
+unsafe impl Sync for PluginWidget {}
+
+impl PluginWidget {
+    fn call(&self, callback: PluginCallback) {
+        if let Some(callback) = callback {
+            unsafe { callback(self.data, igGetCurrentContext()) };
+        }
+    }
+}
+
+/// Calls the plugin's destroy hook, if it has one, once the last of its
+/// widgets is dropped.
+struct Plugin {
+    destroy: Option<PluginDestroy>,
+}
+
+impl Drop for Plugin {
+    fn drop(&mut self) {
+        if let Some(destroy) = self.destroy {
+            unsafe { destroy() };
+        }
+    }
+}
+
+/// A widget registered by a plugin, which keeps the plugin alive.
+struct LoadedWidget {
+    widget: PluginWidget,
+    _plugin: Arc<Plugin>,
+}
+
+impl Widget for LoadedWidget {
+    fn render(&mut self, _ui: &imgui::Ui) {
+        self.widget.call(self.widget.render);
+    }
+
+    fn render_closed(&mut self, _ui: &imgui::Ui) {
+        self.widget.call(self.widget.render_closed);
+    }
+
+    fn interact(&mut self, _ui: &imgui::Ui) {
+        self.widget.call(self.widget.interact);
+    }
+}
+
+#[repr(C)]
+pub(crate) struct PluginHost {
+    version: u32,
+    host: *mut c_void,
+    register_widget: unsafe extern "C" fn(host: *mut c_void, widget: PluginWidget),
+    log: unsafe extern "C" fn(message: *const c_char),
+}
+
+unsafe extern "C" fn register_widget(host: *mut c_void, widget: PluginWidget) {
+    let widgets = &mut *(host as *mut Vec<PluginWidget>);
+    widgets.push(widget);
+}
+
+unsafe extern "C" fn log(message: *const c_char) {
+    if message.is_null() {
+        return;
+    }
+
+    PLUGIN_LOGS.lock().push(CStr::from_ptr(message).to_string_lossy().into_owned());
+}
+
+fn load_plugin(path: &Path, widgets: &mut Vec<Box<dyn Widget>>) -> Result<(), String> {
+    let wpath = U16CString::from_os_str(path.as_os_str())
+        .map_err(|e| format!("Invalid plugin path: {e}"))?;
+
+    let module = unsafe { LoadLibraryW(PCWSTR(wpath.as_ptr())) }
+        .map_err(|e| format!("Couldn't load plugin: {e}"))?;
+
+    let init = unsafe { GetProcAddress(module, s!("jdsd_dsiii_plugin_init")) }
+        .ok_or_else(|| "Missing jdsd_dsiii_plugin_init export".to_string())?;
+    let init: PluginInit = unsafe { std::mem::transmute(init) };
+    let destroy = unsafe { GetProcAddress(module, s!("jdsd_dsiii_plugin_destroy")) }
+        .map(|destroy| unsafe { std::mem::transmute::<_, PluginDestroy>(destroy) });
+
+    let mut registered = Vec::<PluginWidget>::new();
+    let host = PluginHost {
+        version: PLUGIN_API_VERSION,
+        host: &mut registered as *mut Vec<PluginWidget> as *mut c_void,
+        register_widget,
+        log,
+    };
+
+    // From here on, the plugin is destroyed once its widgets are dropped, or
+    // right away if initialization fails.
+    let plugin = Arc::new(Plugin { destroy });
+    if !unsafe { init(&host) } {
+        return Err("Plugin initialization failed".to_string());
+    }
+
+    widgets.extend(registered.into_iter().map(|widget| {
+        Box::new(LoadedWidget { widget, _plugin: Arc::clone(&plugin) }) as Box<dyn Widget>
+    }));
+    Ok(())
+}
+
+/// Loads every plugin in the `plugins` folder and returns the widgets they
+/// registered.
+pub(crate) fn load() -> Vec<Box<dyn Widget>> {
+    let mut widgets = Vec::new();
+
+    let Some(plugins_path) = util::get_dll_path().map(|mut path| {
+        path.pop();
+        path.push("plugins");
+        path
+    }) else {
+        return widgets;
+    };
+
+    let Ok(entries) = fs::read_dir(&plugins_path) else {
+        return widgets;
+    };
+
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        if !path.extension().map(|ext| ext.eq_ignore_ascii_case("dll")).unwrap_or(false) {
+            continue;
+        }
+
+        match load_plugin(&path, &mut widgets) {
+            Ok(()) => info!("Loaded plugin {path:?}"),
+            Err(e) => error!("Plugin {path:?}: {e}"),
+        }
+    }
+
+    widgets
+}
+
+/// Forwards the messages logged by plugins to the tool's log.
+pub(crate) fn flush_logs(tx: &Sender<String>) {
+    for log in PLUGIN_LOGS.lock().drain(..) {
+        tx.send(log).ok();
+    }
+}
@@ -1,25 +1,102 @@
+use std::collections::VecDeque;
 use std::fmt::Write;
 use std::sync::Mutex;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use std::{mem, thread};
 
 use const_format::formatcp;
 use hudhook::tracing::metadata::LevelFilter;
-use hudhook::tracing::{debug, error, info};
+use hudhook::tracing::{error, info, warn};
 use hudhook::{ImguiRenderLoop, RenderContext};
 use imgui::*;
 use libds3::prelude::*;
+use once_cell::sync::Lazy;
 use pkg_version::*;
 use practice_tool_core::crossbeam_channel::{self, Receiver, Sender};
 use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
 use tracing_subscriber::prelude::*;
-
-use crate::config::{Config, IndicatorType, Settings};
-use crate::util;
+use tracing_subscriber::{reload, Registry};
+
+use crate::audio::{self, Cue};
+use crate::command_palette::CommandPalette;
+use crate::config::{
+    self, Config, FontConfig, Indicator, IndicatorType, LogFormat, RenderBackend, Settings,
+    TelemetryConfig, Theme,
+};
+use crate::config_editor::ConfigEditor;
+use crate::counters::Counters;
+use crate::crash::{self, RecentLog};
+use crate::gamepad::{GamepadHotkeys, Rumble};
+use crate::hotkey_editor::HotkeyEditor;
+use crate::iframes::IFrames;
+use crate::online::{self, OnlineWatch};
+use crate::overlay::Overlay;
+use crate::radial_menu::RadialMenu;
+use crate::remote::RemoteControl;
+use crate::speed_graph::SpeedGraph;
+use crate::telemetry::Telemetry;
+use crate::update::{current_version, UpdateCheck};
+use crate::widgets::interlock::interlocked;
+use crate::widgets::item_spawn::load_item_ids;
+use crate::widgets::target::{target_position, target_status_buildup};
+use crate::{feedback, interop, plugins, restore, ui_state, util, XINPUT_GAMEPADS};
 
 const MAJOR: usize = pkg_version_major!();
 const MINOR: usize = pkg_version_minor!();
 const PATCH: usize = pkg_version_patch!();
 
+const EMBEDDED_FONT: &[u8] = include_bytes!("../../lib/data/ComicMono.ttf");
+
+/// Characters every font must have glyphs for.
+const REQUIRED_GLYPHS: std::ops::RangeInclusive<char> = ' '..='~';
+
+/// Loads the first configured font that can be read and covers the required
+/// glyphs, falling back to the embedded font.
+fn load_font(font: &FontConfig, log_tx: &Sender<String>) -> &'static [u8] {
+    let check_font = |path: &std::path::Path| -> Result<Vec<u8>, String> {
+        let data = std::fs::read(path).map_err(|e| format!("Couldn't read {path:?}: {e}"))?;
+        let face = ttf_parser::Face::parse(&data, 0)
+            .map_err(|e| format!("Couldn't parse {path:?}: {e}"))?;
+        let missing: String = REQUIRED_GLYPHS.filter(|&c| face.glyph_index(c).is_none()).collect();
+        if !missing.is_empty() {
+            return Err(format!("Font {path:?} lacks glyphs for {missing:?}"));
+        }
+        Ok(data)
+    };
+
+    for path in &font.paths {
+        let path = match util::get_dll_path() {
+            Some(mut dll_path) if path.is_relative() => {
+                dll_path.pop();
+                dll_path.join(path)
+            },
+            _ => path.clone(),
+        };
+
+        match check_font(&path) {
+            Ok(data) => {
+                info!("Using font {path:?}");
+                return Box::leak(data.into_boxed_slice());
+            },
+            Err(e) => {
+                error!("{e}");
+                log_tx.send(e).ok();
+            },
+        }
+    }
+
+    EMBEDDED_FONT
+}
+
+fn load_config() -> Result<Config, String> {
+    let config_path =
+        config::config_path().ok_or_else(|| "Couldn't find config file".to_string())?;
+    let config_content = std::fs::read_to_string(&config_path)
+        .map_err(|e| format!("Couldn't read config file {}: {:?}", config_path.display(), e))?;
+    println!("{}", config_content);
+    Config::parse(&config_content).map_err(String::from)
+}
+
 struct FontIDs {
     small: FontId,
     normal: FontId,
@@ -29,24 +106,117 @@ struct FontIDs {
 unsafe impl Send for FontIDs {}
 unsafe impl Sync for FontIDs {}
 
+/// Levels that can be picked at runtime, from the least to the most verbose.
+const LOG_LEVELS: [LevelFilter; 6] = [
+    LevelFilter::OFF,
+    LevelFilter::ERROR,
+    LevelFilter::WARN,
+    LevelFilter::INFO,
+    LevelFilter::DEBUG,
+    LevelFilter::TRACE,
+];
+
+/// Number of lines kept by the log panel.
+const LOG_HISTORY: usize = 5000;
+
+const ONLINE_WARNING_COLOR: [f32; 4] = [1., 0.3, 0.3, 1.];
+
+const PARAMS_PATCH_RETRIES: usize = 60;
+const PARAMS_PATCH_INTERVAL: Duration = Duration::from_secs(1);
+
 enum UiState {
     MenuOpen,
     Closed,
     Hidden,
 }
 
+/// Loads the params in a background thread, so that injecting never waits
+/// on the game, and patches them unless `patch` is false.
+///
+/// The params may still be filling in after they are found, so patching is
+/// retried for a while.
+fn load_params(patch: bool) {
+    let spawned = thread::Builder::new().name("params".to_string()).spawn(move || {
+        let params = Lazy::force(&PARAMS);
+        if !patch {
+            return;
+        }
+
+        for _ in 0..PARAMS_PATCH_RETRIES {
+            let mut params = params.write();
+            let darksign = unsafe {
+                if let Err(e) = params.refresh() {
+                    error!("{}", e);
+                }
+                params.get_equip_param_goods()
+            }
+            .and_then(|mut goods| goods.find(|i| i.id == 117))
+            .and_then(|p| p.param);
+
+            if let Some(darksign) = darksign {
+                darksign.icon_id = 116;
+                info!("Params patched");
+                return;
+            }
+
+            drop(params);
+            thread::sleep(PARAMS_PATCH_INTERVAL);
+        }
+
+        warn!("Couldn't patch params: Darksign not found");
+    });
+
+    if let Err(e) = spawned {
+        error!("Couldn't start params thread: {e}");
+    }
+}
+
 pub(crate) struct PracticeTool {
     settings: Settings,
+    theme: Theme,
+    font: FontConfig,
     pointers: PointerChains,
     version_label: String,
     widgets: Vec<Box<dyn Widget>>,
+    config_widgets: usize,
+    /// What each of the first `config_widgets` widgets was built from, to keep
+    /// the unchanged ones when the configuration is reloaded.
+    widget_sources: Vec<String>,
+    /// Names of the configured commands, for the command checklist.
+    command_names: Vec<String>,
+    profiles: Vec<String>,
+    profile: usize,
+    hotkey_editor: HotkeyEditor,
+    config_editor: ConfigEditor,
+    remote: RemoteControl,
+    overlay: Option<Overlay>,
+    counters: Counters,
+    telemetry: Option<Telemetry>,
+    radial_menu: Option<RadialMenu>,
+    command_palette: CommandPalette,
+    update_check: Option<UpdateCheck>,
+    online_watch: Option<OnlineWatch>,
+    gamepad_hotkeys: GamepadHotkeys,
+    rumble: Option<Rumble>,
+    /// Whether an invulnerability window was active last frame.
+    iframes_active: bool,
 
     log: Vec<(Instant, String)>,
+    /// Every log line of the session, for the log panel.
+    log_history: VecDeque<(Instant, String)>,
+    log_panel: bool,
+    started: Instant,
+    /// Changes the level of the log file and console without restarting.
+    log_level: reload::Handle<LevelFilter, Registry>,
+    /// Why the configuration file couldn't be loaded, until dismissed.
+    config_error: Option<String>,
     log_rx: Receiver<String>,
     log_tx: Sender<String>,
     ui_state: UiState,
     fonts: Option<FontIDs>,
 
+    /// When each configured indicator last formatted its text.
+    indicator_refreshes: Vec<Option<Instant>>,
     position_bufs: [String; 4],
     igt_buf: String,
     fps_buf: String,
@@ -55,27 +225,26 @@ pub(crate) struct PracticeTool {
     framecount_buf: String,
 
     cur_anim_buf: String,
+    deaths_buf: String,
+    iframes_buf: String,
+    iframes_highlight: bool,
+    status_buildup_buf: String,
+    vitals_buf: String,
+    target_distance_buf: String,
+    area_buf: String,
+    speed_graph: SpeedGraph,
 }
 
 impl PracticeTool {
+    /// Graphics API set in the configuration, if any.
+    pub(crate) fn render_backend(&self) -> Option<RenderBackend> {
+        self.settings.render_backend
+    }
+
     pub(crate) fn new() -> Self {
         hudhook::alloc_console().ok();
         log_panics::init();
 
-        fn load_config() -> Result<Config, String> {
-            let config_path = util::get_dll_path()
-                .map(|mut path| {
-                    path.pop();
-                    path.push("jdsd_dsiii_practice_tool.toml");
-                    path
-                })
-                .ok_or_else(|| "Couldn't find config file".to_string())?;
-            let config_content = std::fs::read_to_string(config_path)
-                .map_err(|e| format!("Couldn't read config file: {:?}", e))?;
-            println!("{}", config_content);
-            Config::parse(&config_content).map_err(String::from)
-        }
-
         let (config, config_err) = match load_config() {
             Ok(config) => (config, None),
             Err(e) => (Config::default(), Some(e)),
@@ -89,6 +258,16 @@ impl PracticeTool {
             })
             .map(std::fs::File::create);
 
+        let (level_filter, log_level) = reload::Layer::new(config.settings.log_level.inner());
+        let stdout_layer = tracing_subscriber::fmt::layer()
+            .with_thread_ids(true)
+            .with_file(true)
+            .with_line_number(true)
+            .with_thread_names(true)
+            .with_ansi(true)
+            .boxed();
+        let crash_layer = tracing_subscriber::fmt::layer().with_writer(RecentLog).with_ansi(false);
+
         match log_file {
             Some(Ok(log_file)) => {
                 let file_layer = tracing_subscriber::fmt::layer()
@@ -97,30 +276,24 @@ impl PracticeTool {
                     .with_line_number(true)
                     .with_thread_names(true)
                     .with_writer(Mutex::new(log_file))
-                    .with_ansi(false)
-                    .boxed();
-                let stdout_layer = tracing_subscriber::fmt::layer()
-                    .with_thread_ids(true)
-                    .with_file(true)
-                    .with_line_number(true)
-                    .with_thread_names(true)
-                    .with_ansi(true)
-                    .boxed();
+                    .with_ansi(false);
+                let file_layer = match config.settings.log_format {
+                    LogFormat::Text => file_layer.boxed(),
+                    LogFormat::Json => file_layer.json().boxed(),
+                };
 
                 tracing_subscriber::registry()
-                    .with(config.settings.log_level.inner())
+                    .with(level_filter)
                     .with(file_layer)
                     .with(stdout_layer)
+                    .with(crash_layer)
                     .init();
             },
             e => {
-                tracing_subscriber::fmt()
-                    .with_max_level(config.settings.log_level.inner())
-                    .with_thread_ids(true)
-                    .with_file(true)
-                    .with_line_number(true)
-                    .with_thread_names(true)
-                    .with_ansi(true)
+                tracing_subscriber::registry()
+                    .with(level_filter)
+                    .with(stdout_layer)
+                    .with(crash_layer)
                     .init();
 
                 match e {
@@ -131,8 +304,10 @@ impl PracticeTool {
             },
         }
 
-        if let Some(err) = config_err {
-            debug!("{:?}", err);
+        crash::install();
+
+        if let Some(err) = &config_err {
+            error!("{}", err);
         }
 
         if config.settings.log_level.inner() < LevelFilter::DEBUG || !config.settings.show_console {
@@ -141,55 +316,239 @@ impl PracticeTool {
             hudhook::enable_console_colors();
         }
 
-        {
-            let mut params = PARAMS.write();
-            if let Some(darksign) = wait_option(|| unsafe {
-                if let Err(e) = params.refresh() {
-                    error!("{}", e);
-                }
-                params.get_equip_param_goods()
-            })
-            .find(|i| i.id == 117)
-            .and_then(|p| p.param)
-            {
-                darksign.icon_id = 116;
-            }
-        }
+        load_params(!config.settings.read_only());
+        load_item_ids();
 
         let pointers = PointerChains::new();
         let version_label = {
-            let (maj, min, patch) = (*VERSION).into();
-            format!("Game Ver {}.{:02}.{}", maj, min, patch)
+            let (maj, min, patch) = *FILE_VERSION;
+            if *VERSION_KNOWN {
+                format!("Game Ver {}.{:02}.{}", maj, min, patch)
+            } else {
+                format!("Game Ver {}.{:02}.{} (unsupported)", maj, min, patch)
+            }
         };
+
+        if let Some(results) = scan_results() {
+            let missing: Vec<_> =
+                results.iter().filter(|r| r.address.is_none()).map(|r| r.name).collect();
+            if !missing.is_empty() {
+                error!("Base addresses not found: {}", missing.join(", "));
+            }
+        }
         let settings = config.settings.clone();
-        let widgets = config.make_commands(&pointers);
+        let theme = config.theme.clone();
+        let font = config.font.clone();
+        let profiles = config.profile_names();
+        let command_names = config.command_names(0);
+        let palette_entries = config.palette_entries(0);
+        let (widget_sources, mut widgets): (Vec<_>, Vec<_>) =
+            config.make_commands(&pointers, 0, Vec::new()).into_iter().unzip();
+        let config_widgets = widgets.len();
+        widgets.extend(load_plugins(&settings));
+
+        interop::start(&pointers);
+        let remote = RemoteControl::new(&pointers);
+        if settings.command_pipe {
+            remote.listen_pipe();
+        }
+        if let Some(port) = settings.remote_port {
+            remote.listen(port);
+        }
+        let overlay = settings.overlay.as_ref().map(Overlay::start);
 
         let (log_tx, log_rx) = crossbeam_channel::unbounded();
+        let telemetry = settings.telemetry.as_ref().and_then(|cfg| start_telemetry(cfg, &log_tx));
+        let radial_menu =
+            settings.radial_menu.clone().map(|cfg| RadialMenu::new(cfg, remote.sender()));
+        let gamepad_hotkeys =
+            GamepadHotkeys::new(settings.gamepad_hotkeys.clone(), remote.sender());
+        let command_palette = CommandPalette::new(palette_entries, remote.sender());
+        let update_check = settings.check_updates.then(UpdateCheck::start);
+        let online_watch = settings.online_interlock.then(OnlineWatch::new);
+        let rumble = settings.rumble.clone().map(Rumble::new);
+        for e in audio::configure(settings.audio.as_ref()) {
+            log_tx.send(e).ok();
+        }
+        let log_panel = settings.log_panel;
         info!("Initialized");
 
         PracticeTool {
             settings,
+            theme,
+            font,
             pointers,
             version_label,
             widgets,
+            config_widgets,
+            widget_sources,
+            command_names,
+            profiles,
+            profile: 0,
+            hotkey_editor: HotkeyEditor::new(),
+            config_editor: ConfigEditor::new(),
+            remote,
+            overlay,
+            counters: Counters::new(),
+            telemetry,
+            radial_menu,
+            gamepad_hotkeys,
+            command_palette,
+            update_check,
+            online_watch,
+            rumble,
+            iframes_active: false,
             log: Vec::new(),
+            log_history: VecDeque::new(),
+            log_panel,
+            started: Instant::now(),
+            log_level,
+            config_error: config_err
+                .map(|e| format!("{e}\n\nThe default configuration is used until this is fixed.")),
             log_rx,
             log_tx,
             fonts: None,
             ui_state: UiState::Closed,
+            indicator_refreshes: vec![None; config.settings.indicators.len()],
             position_bufs: Default::default(),
             igt_buf: Default::default(),
             fps_buf: Default::default(),
             framecount: 0,
             framecount_buf: Default::default(),
             cur_anim_buf: Default::default(),
+            deaths_buf: Default::default(),
+            iframes_buf: Default::default(),
+            iframes_highlight: false,
+            status_buildup_buf: Default::default(),
+            vitals_buf: Default::default(),
+            target_distance_buf: Default::default(),
+            area_buf: Default::default(),
+            speed_graph: Default::default(),
+        }
+    }
+
+    /// Rebuilds the settings and the configured widgets after the
+    /// configuration file has been edited from within the tool.
+    fn reload_config(&mut self, content: &str) {
+        match Config::parse(content) {
+            Ok(config) => {
+                self.apply_config(config);
+                self.log_tx.send("Configuration reloaded".to_string()).ok();
+            },
+            Err(e) => {
+                error!("{e}");
+                self.config_error =
+                    Some(format!("{e}\n\nThe previous configuration is still in use."));
+                self.log_tx.send(e).ok();
+            },
+        }
+    }
+
+    /// Switches to another profile, rebuilding the widgets from the
+    /// configuration file.
+    fn switch_profile(&mut self, profile: usize) {
+        match load_config() {
+            Ok(config) => {
+                self.profile = profile;
+                self.apply_config(config);
+                self.log_tx.send(format!("Profile: {}", self.profiles[self.profile])).ok();
+            },
+            Err(e) => {
+                error!("{e}");
+                self.config_error =
+                    Some(format!("{e}\n\nThe previous configuration is still in use."));
+                self.log_tx.send(e).ok();
+            },
+        }
+    }
+
+    fn apply_config(&mut self, mut config: Config) {
+        config.settings.keep_restrictions(&self.settings);
+        self.settings = config.settings.clone();
+        self.indicator_refreshes = vec![None; self.settings.indicators.len()];
+        self.set_log_level(self.settings.log_level.inner());
+        self.theme = config.theme.clone();
+        match (&self.settings.telemetry, self.telemetry.as_mut()) {
+            (None, _) => self.telemetry = None,
+            (Some(cfg), Some(telemetry)) => telemetry.set_every(cfg.every),
+            (Some(cfg), None) => self.telemetry = start_telemetry(cfg, &self.log_tx),
+        }
+        self.radial_menu =
+            self.settings.radial_menu.clone().map(|cfg| RadialMenu::new(cfg, self.remote.sender()));
+        self.gamepad_hotkeys =
+            GamepadHotkeys::new(self.settings.gamepad_hotkeys.clone(), self.remote.sender());
+        self.rumble = self.settings.rumble.clone().map(Rumble::new);
+        match (self.settings.check_updates, &self.update_check) {
+            (false, _) => self.update_check = None,
+            (true, None) => self.update_check = Some(UpdateCheck::start()),
+            (true, Some(_)) => {},
+        }
+        match (self.settings.online_interlock, &self.online_watch) {
+            (false, _) => self.online_watch = None,
+            (true, None) => self.online_watch = Some(OnlineWatch::new()),
+            (true, Some(_)) => {},
+        }
+        for e in audio::configure(self.settings.audio.as_ref()) {
+            self.log_tx.send(e).ok();
+        }
+
+        // Stay on the same profile if it still exists.
+        let profiles = config.profile_names();
+        self.profile = self
+            .profiles
+            .get(self.profile)
+            .and_then(|name| profiles.iter().position(|p| p == name))
+            .unwrap_or(0);
+        self.profiles = profiles;
+
+        // Plugins are destroyed before being initialized again.
+        self.widgets.truncate(self.config_widgets);
+        let plugin_widgets = load_plugins(&self.settings);
+        self.command_names = config.command_names(self.profile);
+        self.command_palette.set_entries(config.palette_entries(self.profile));
+        let previous = mem::take(&mut self.widget_sources)
+            .into_iter()
+            .zip(mem::take(&mut self.widgets))
+            .collect();
+        let (sources, widgets): (Vec<_>, Vec<_>) =
+            config.make_commands(&self.pointers, self.profile, previous).into_iter().unzip();
+        self.widget_sources = sources;
+        self.widgets = widgets;
+        self.config_widgets = self.widgets.len();
+        self.widgets.extend(plugin_widgets);
+    }
+
+    fn render_diagnostics(&self, ui: &imgui::Ui) {
+        const OK: [f32; 4] = [0.3, 0.9, 0.3, 1.];
+        const FAIL: [f32; 4] = [0.9, 0.3, 0.3, 1.];
+
+        match scan_results() {
+            None => ui.text("Base addresses: hardcoded"),
+            Some(results) => {
+                ui.text("Base addresses: signature scan");
+                for r in results {
+                    match r.address {
+                        Some(addr) => ui.text_colored(OK, format!("  {} {addr:#x}", r.name)),
+                        None => ui.text_colored(FAIL, format!("  {} not found", r.name)),
+                    }
+                }
+            },
+        }
+
+        ui.text("Pointer chains:");
+        for (name, resolved) in self.pointers.diagnostics() {
+            if resolved {
+                ui.text_colored(OK, format!("  {name} ok"));
+            } else {
+                ui.text_colored(FAIL, format!("  {name} unresolved"));
+            }
         }
     }
 
     fn render_visible(&mut self, ui: &imgui::Ui) {
         ui.window("##tool_window")
             .position([16., 16.], Condition::Always)
-            .bg_alpha(0.8)
+            .bg_alpha(self.theme.window_alpha)
             .flags({
                 WindowFlags::NO_TITLE_BAR
                     | WindowFlags::NO_RESIZE
@@ -204,10 +563,62 @@ impl PracticeTool {
                     }
                 }
 
+                if self.settings.speedrun_mode {
+                    ui.text_disabled("Speedrun mode: only leaderboard-legal features");
+                } else if self.settings.safe_mode {
+                    ui.text_disabled("Safe mode: the game can't be changed");
+                } else if online::is_online() {
+                    ui.text_colored(ONLINE_WARNING_COLOR, "Online: commands are disabled");
+                }
+
+                if self.profiles.len() > 1 {
+                    let mut profile = self.profile;
+                    ui.set_next_item_width(BUTTON_WIDTH * scaling_factor(ui));
+                    if ui.combo_simple_string("##profile", &mut profile, &self.profiles)
+                        && profile != self.profile
+                    {
+                        self.switch_profile(profile);
+                    }
+                }
+
                 for w in self.widgets.iter_mut() {
                     w.render(ui);
                 }
 
+                if ui.collapsing_header("Enabled commands", TreeNodeFlags::empty()) {
+                    for name in &self.command_names {
+                        let mut enabled = ui_state::command_enabled(name);
+                        if ui.checkbox(name, &mut enabled) {
+                            ui_state::set_command_enabled(name, enabled);
+                        }
+                    }
+                }
+
+                if ui.collapsing_header("Session stats", TreeNodeFlags::empty()) {
+                    self.counters.render_stats(ui);
+                }
+
+                if ui
+                    .button_with_size("Hotkeys", [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT])
+                {
+                    self.hotkey_editor.open(ui);
+                }
+
+                if let Some(content) = self.hotkey_editor.render(ui) {
+                    self.reload_config(&content);
+                }
+
+                if ui.button_with_size("Commands", [
+                    BUTTON_WIDTH * scaling_factor(ui),
+                    BUTTON_HEIGHT,
+                ]) {
+                    self.config_editor.open(ui);
+                }
+
+                if let Some(content) = self.config_editor.render(ui) {
+                    self.reload_config(&content);
+                }
+
                 if ui.button_with_size("Close", [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT])
                 {
                     self.ui_state = UiState::Closed;
@@ -222,6 +633,9 @@ impl PracticeTool {
                 {
                     self.ui_state = UiState::Closed;
                     self.pointers.cursor_show.set(false);
+                    restore::restore_all();
+                    crash::uninstall();
+                    self.widgets.truncate(self.config_widgets);
                     hudhook::eject();
                 }
             });
@@ -246,6 +660,27 @@ impl PracticeTool {
             .build(|| {
                 ui.text("johndisandonato's Dark Souls III Practice Tool");
 
+                if online::is_online() && !self.settings.read_only() {
+                    ui.text_colored(ONLINE_WARNING_COLOR, "Online: commands are disabled");
+                }
+
+                if let Some(update_check) = self.update_check.as_mut() {
+                    if let Some(release) = update_check.available() {
+                        ui.text_colored(
+                            [1., 0.8, 0.3, 1.],
+                            format!("Version {} is available", release.version),
+                        );
+                        ui.same_line();
+                        if ui.small_button("Release page") {
+                            open::that(&release.url).ok();
+                        }
+                        ui.same_line();
+                        if ui.small_button("Dismiss") {
+                            update_check.dismiss();
+                        }
+                    }
+                }
+
                 // ui.same_line();
 
                 if ui.small_button("Open") {
@@ -268,9 +703,9 @@ impl PracticeTool {
                         self.pointers.cursor_show.set(true);
 
                         ui.text(
-                            "You can toggle indicators here, as\nwell as reset the frame \
-                             counter.\n\nKeep in mind that the available\nindicators and order of \
-                             them depend\non your config file.",
+                            "You can toggle indicators here, as\nwell as reset the frame and \
+                             death\ncounters.\n\nKeep in mind that the available\nindicators and \
+                             order of them depend\non your config file.",
                         );
                         ui.separator();
 
@@ -283,6 +718,15 @@ impl PracticeTool {
                                 IndicatorType::FrameCount => "Frame Counter",
                                 IndicatorType::ImguiDebug => "ImGui Debug Info",
                                 IndicatorType::Animation => "Animation",
+                                IndicatorType::InputDisplay => "Input Display",
+                                IndicatorType::Deaths => "Deaths/Attempts",
+                                IndicatorType::IFrames => "I-Frames",
+                                IndicatorType::StatusBuildup => "Target Status Buildup",
+                                IndicatorType::Vitals => "HP/FP/Stamina",
+                                IndicatorType::Compass => "Compass",
+                                IndicatorType::SpeedGraph => "Speed Graph",
+                                IndicatorType::TargetDistance => "Target Distance",
+                                IndicatorType::Area => "Current Area",
                             };
 
                             let mut state = indicator.enabled;
@@ -307,6 +751,36 @@ impl PracticeTool {
                                     self.framecount = 0;
                                 }
                             }
+
+                            if let IndicatorType::Deaths = indicator.indicator {
+                                ui.same_line();
+
+                                let btn_reset_label = "Reset##deaths";
+                                let btn_reset_width =
+                                    ui.calc_text_size("Reset")[0] + style.frame_padding[0] * 2.0;
+
+                                ui.set_cursor_pos([
+                                    ui.content_region_max()[0] - btn_reset_width,
+                                    ui.cursor_pos()[1],
+                                ]);
+
+                                if ui.button(btn_reset_label) {
+                                    self.counters.reset();
+                                }
+                            }
+                        }
+
+                        ui.separator();
+
+                        ui.checkbox("Log Panel", &mut self.log_panel);
+
+                        let current = self.log_level.clone_current().unwrap_or(LevelFilter::OFF);
+                        let mut index =
+                            LOG_LEVELS.iter().position(|l| *l == current).unwrap_or_default();
+                        if ui.combo("Log Level", &mut index, &LOG_LEVELS, |l| {
+                            l.to_string().to_uppercase().into()
+                        }) {
+                            self.set_log_level(LOG_LEVELS[index]);
                         }
 
                         ui.separator();
@@ -348,6 +822,9 @@ impl PracticeTool {
                              file!\n\nThank you for using my tool! <3\n",
                             self.settings.display
                         ));
+                        if ui.collapsing_header("Diagnostics", TreeNodeFlags::empty()) {
+                            self.render_diagnostics(ui);
+                        }
                         ui.separator();
                         ui.text("-- johndisandonato");
                         ui.text("   https://twitch.tv/johndisandonato");
@@ -374,89 +851,10 @@ impl PracticeTool {
 
                 ui.new_line();
 
-                for indicator in &self.settings.indicators {
-                    if !indicator.enabled {
-                        continue;
-                    }
-
-                    match indicator.indicator {
-                        IndicatorType::GameVersion => {
-                            ui.text(&self.version_label);
-                        },
-                        IndicatorType::Position => {
-                            if let (Some([x, y, z]), Some(a)) =
-                                (self.pointers.position.1.read(), self.pointers.position.0.read())
-                            {
-                                self.position_bufs.iter_mut().for_each(String::clear);
-                                write!(self.position_bufs[0], "{x:.2}").ok();
-                                write!(self.position_bufs[1], "{y:.2}").ok();
-                                write!(self.position_bufs[2], "{z:.2}").ok();
-                                write!(self.position_bufs[3], "{a:.2}").ok();
-
-                                ui.text_colored(
-                                    [0.7048, 0.1228, 0.1734, 1.],
-                                    &self.position_bufs[0],
-                                );
-                                ui.same_line();
-                                ui.text_colored(
-                                    [0.1161, 0.5327, 0.3512, 1.],
-                                    &self.position_bufs[1],
-                                );
-                                ui.same_line();
-                                ui.text_colored(
-                                    [0.1445, 0.2852, 0.5703, 1.],
-                                    &self.position_bufs[2],
-                                );
-                                ui.same_line();
-                                ui.text(&self.position_bufs[3]);
-                            }
-                        },
-                        IndicatorType::Igt => {
-                            if let Some(igt) = self.pointers.igt.read() {
-                                let millis = (igt % 1000) / 10;
-                                let total_seconds = igt / 1000;
-                                let seconds = total_seconds % 60;
-                                let minutes = total_seconds / 60 % 60;
-                                let hours = total_seconds / 3600;
-                                self.igt_buf.clear();
-                                write!(
-                                    self.igt_buf,
-                                    "IGT {hours:02}:{minutes:02}:{seconds:02}.{millis:02}",
-                                )
-                                .ok();
-                                ui.text(&self.igt_buf);
-                            }
-                        },
-                        IndicatorType::Fps => {
-                            if let Some(fps) = self.pointers.fps.read() {
-                                self.fps_buf.clear();
-                                write!(self.fps_buf, "FPS {fps}",).ok();
-                                ui.text(&self.fps_buf);
-                            }
-                        },
-                        IndicatorType::Animation => {
-                            if let (Some(cur_anim), Some(cur_anim_time), Some(cur_anim_length)) = (
-                                self.pointers.cur_anim.read(),
-                                self.pointers.cur_anim_time.read(),
-                                self.pointers.cur_anim_length.read(),
-                            ) {
-                                self.cur_anim_buf.clear();
-                                write!(
-                                    self.cur_anim_buf,
-                                    "Animation {cur_anim} ({cur_anim_time}s /  {cur_anim_length}s)",
-                                )
-                                .ok();
-                                ui.text(&self.cur_anim_buf);
-                            }
-                        },
-                        IndicatorType::FrameCount => {
-                            self.framecount_buf.clear();
-                            write!(self.framecount_buf, "Frame count {0}", self.framecount,).ok();
-                            ui.text(&self.framecount_buf);
-                        },
-                        IndicatorType::ImguiDebug => {
-                            imgui_debug(ui);
-                        },
+                for i in 0..self.settings.indicators.len() {
+                    let Indicator { enabled, placement, .. } = self.settings.indicators[i];
+                    if enabled && placement.is_none() {
+                        self.render_indicator(ui, i);
                     }
                 }
 
@@ -464,16 +862,265 @@ impl PracticeTool {
                     w.render_closed(ui);
                 }
 
-                for w in self.widgets.iter_mut() {
-                    w.interact(ui);
+                // Typing in the command palette shouldn't trigger hotkeys.
+                if !self.command_palette.is_open() {
+                    for w in self.widgets.iter_mut() {
+                        w.interact(ui);
+                    }
                 }
             });
 
+        let display_size = ui.io().display_size;
+        for i in 0..self.settings.indicators.len() {
+            let Indicator { enabled, placement, .. } = self.settings.indicators[i];
+            let (true, Some(placement)) = (enabled, placement) else {
+                continue;
+            };
+
+            let (position, pivot) = placement.position(display_size);
+            ui.window(format!("##indicator_{i}"))
+                .position(position, Condition::Always)
+                .position_pivot(pivot)
+                .bg_alpha(0.0)
+                .flags({
+                    WindowFlags::NO_TITLE_BAR
+                        | WindowFlags::NO_RESIZE
+                        | WindowFlags::NO_MOVE
+                        | WindowFlags::NO_SCROLLBAR
+                        | WindowFlags::ALWAYS_AUTO_RESIZE
+                        | WindowFlags::NO_INPUTS
+                })
+                .build(|| self.render_indicator(ui, i));
+        }
+
         for st in stack_tokens.into_iter().rev() {
             st.pop();
         }
     }
 
+    /// Whether the indicator at `i` should format its text again, as its
+    /// refresh interval elapsed.
+    fn refresh_indicator(&mut self, i: usize) -> bool {
+        let Some(interval) = self.settings.indicators[i].refresh_interval else {
+            return true;
+        };
+
+        let last = &mut self.indicator_refreshes[i];
+        if last.is_some_and(|t| t.elapsed() < interval) {
+            return false;
+        }
+        *last = Some(Instant::now());
+        true
+    }
+
+    /// Renders the indicator at `i`. Text indicators keep showing their
+    /// buffer until it is time to refresh them.
+    fn render_indicator(&mut self, ui: &imgui::Ui, i: usize) {
+        let refresh = self.refresh_indicator(i);
+        let indicator = self.settings.indicators[i].indicator;
+
+        match indicator {
+            IndicatorType::GameVersion => {
+                ui.text(&self.version_label);
+            },
+            IndicatorType::Position => {
+                if refresh {
+                    self.position_bufs.iter_mut().for_each(String::clear);
+                    if let (Some([x, y, z]), Some(a)) =
+                        (self.pointers.position.1.read(), self.pointers.position.0.read())
+                    {
+                        write!(self.position_bufs[0], "{x:.2}").ok();
+                        write!(self.position_bufs[1], "{y:.2}").ok();
+                        write!(self.position_bufs[2], "{z:.2}").ok();
+                        write!(self.position_bufs[3], "{a:.2}").ok();
+                    }
+                }
+
+                if !self.position_bufs[0].is_empty() {
+                    ui.text_colored(self.theme.position_x.0, &self.position_bufs[0]);
+                    ui.same_line();
+                    ui.text_colored(self.theme.position_y.0, &self.position_bufs[1]);
+                    ui.same_line();
+                    ui.text_colored(self.theme.position_z.0, &self.position_bufs[2]);
+                    ui.same_line();
+                    ui.text(&self.position_bufs[3]);
+                }
+            },
+            IndicatorType::Igt => {
+                if refresh {
+                    self.igt_buf.clear();
+                    if let Some(igt) = self.pointers.igt.read() {
+                        util::write_igt(&mut self.igt_buf, igt).ok();
+                    }
+                }
+                text_if_any(ui, &self.igt_buf);
+            },
+            IndicatorType::Fps => {
+                if refresh {
+                    self.fps_buf.clear();
+                    if let Some(fps) = self.pointers.fps.read() {
+                        write!(self.fps_buf, "FPS {fps}",).ok();
+                    }
+                }
+                text_if_any(ui, &self.fps_buf);
+            },
+            IndicatorType::Animation => {
+                if refresh {
+                    self.cur_anim_buf.clear();
+                    if let (Some(cur_anim), Some(cur_anim_time), Some(cur_anim_length)) = (
+                        self.pointers.cur_anim.read(),
+                        self.pointers.cur_anim_time.read(),
+                        self.pointers.cur_anim_length.read(),
+                    ) {
+                        write!(
+                            self.cur_anim_buf,
+                            "Animation {cur_anim} ({cur_anim_time}s /  {cur_anim_length}s)",
+                        )
+                        .ok();
+                    }
+                }
+                text_if_any(ui, &self.cur_anim_buf);
+            },
+            IndicatorType::FrameCount => {
+                if refresh {
+                    self.framecount_buf.clear();
+                    write!(self.framecount_buf, "Frame count {0}", self.framecount,).ok();
+                }
+                ui.text(&self.framecount_buf);
+            },
+            IndicatorType::InputDisplay => {
+                input_display(ui);
+            },
+            IndicatorType::Deaths => {
+                if refresh {
+                    self.deaths_buf.clear();
+                    write!(
+                        self.deaths_buf,
+                        "Deaths {} Attempts {}",
+                        self.counters.deaths(),
+                        self.counters.attempts()
+                    )
+                    .ok();
+                }
+                ui.text(&self.deaths_buf);
+            },
+            IndicatorType::IFrames => {
+                if refresh {
+                    self.iframes_buf.clear();
+                    self.iframes_highlight = false;
+                    if let (Some(tae), Some(time)) =
+                        (self.pointers.cur_anim_tae.read(), self.pointers.cur_anim_time.read())
+                    {
+                        match IFrames::of(tae, time) {
+                            IFrames::Active { frame, total } => {
+                                self.iframes_highlight = true;
+                                write!(self.iframes_buf, "I-frames {frame}/{total}").ok();
+                            },
+                            IFrames::Outside { total } => {
+                                write!(self.iframes_buf, "I-frames -/{total}").ok();
+                            },
+                            IFrames::None => self.iframes_buf.push_str("I-frames -"),
+                        }
+                    }
+                }
+
+                if self.iframes_highlight {
+                    ui.text_colored([0.2, 1.0, 0.2, 1.0], &self.iframes_buf);
+                } else {
+                    text_if_any(ui, &self.iframes_buf);
+                }
+            },
+            IndicatorType::StatusBuildup => {
+                if refresh {
+                    self.status_buildup_buf.clear();
+                    let rows = [
+                        ("Player", self.pointers.status_buildup.read()),
+                        ("Target", target_status_buildup(self.pointers.xa)),
+                    ];
+                    for (label, buildup) in rows {
+                        let Some(b) = buildup else {
+                            continue;
+                        };
+                        if !self.status_buildup_buf.is_empty() {
+                            self.status_buildup_buf.push('\n');
+                        }
+                        write!(
+                            self.status_buildup_buf,
+                            "{label:6} Bleed {}/{} Poison {}/{} Frost {}/{} Curse {}/{}",
+                            b.bleed,
+                            b.bleed_max,
+                            b.poison,
+                            b.poison_max,
+                            b.frost,
+                            b.frost_max,
+                            b.curse,
+                            b.curse_max
+                        )
+                        .ok();
+                    }
+                }
+                text_if_any(ui, &self.status_buildup_buf);
+            },
+            IndicatorType::Vitals => {
+                if refresh {
+                    self.vitals_buf.clear();
+                    if let (Some([hp, _, max_hp]), Some([fp, _, max_fp]), Some([sp, _, max_sp])) =
+                        (self.pointers.hp.read(), self.pointers.fp.read(), self.pointers.sp.read())
+                    {
+                        write!(
+                            self.vitals_buf,
+                            "HP {hp}/{max_hp} FP {fp}/{max_fp} SP {sp}/{max_sp}"
+                        )
+                        .ok();
+                    }
+                }
+                text_if_any(ui, &self.vitals_buf);
+            },
+            IndicatorType::Compass => {
+                if let Some(angle) = self.pointers.position.0.read() {
+                    compass(ui, angle);
+                }
+            },
+            IndicatorType::SpeedGraph => {
+                self.speed_graph.sample(self.pointers.position.1.read());
+                self.speed_graph.render(ui);
+            },
+            IndicatorType::TargetDistance => {
+                if refresh {
+                    self.target_distance_buf.clear();
+                    match (self.pointers.position.1.read(), target_position(self.pointers.xa)) {
+                        (Some([px, py, pz]), Some([tx, ty, tz])) => {
+                            let (dx, dy, dz) = (tx - px, ty - py, tz - pz);
+                            let distance = (dx * dx + dy * dy + dz * dz).sqrt();
+                            write!(
+                                self.target_distance_buf,
+                                "Target distance {distance:.2} height {dy:+.2}"
+                            )
+                            .ok();
+                        },
+                        _ => self.target_distance_buf.push_str("Target distance -"),
+                    }
+                }
+                ui.text(&self.target_distance_buf);
+            },
+            IndicatorType::Area => {
+                if refresh {
+                    self.area_buf.clear();
+                    if let Some(map_id) = self.pointers.map_id.read() {
+                        write!(self.area_buf, "{map_id}").ok();
+                        if let Some(name) = map_id.name() {
+                            write!(self.area_buf, " {name}").ok();
+                        }
+                    }
+                }
+                text_if_any(ui, &self.area_buf);
+            },
+            IndicatorType::ImguiDebug => {
+                imgui_debug(ui);
+            },
+        }
+    }
+
     fn render_hidden(&mut self, ui: &imgui::Ui) {
         for w in self.widgets.iter_mut() {
             w.interact(ui);
@@ -485,6 +1132,10 @@ impl PracticeTool {
 
         let [dw, dh] = io.display_size;
         let [ww, wh] = [dw * 0.3, 14.0 * 6.];
+        let (position, pivot) = match self.settings.log_position {
+            Some(placement) => placement.position([dw, dh]),
+            None => ([dw * 0.95, dh * 0.8], [1., 1.]),
+        };
 
         let stack_tokens = vec![
             ui.push_style_var(StyleVar::WindowRounding(0.)),
@@ -493,8 +1144,8 @@ impl PracticeTool {
         ];
 
         ui.window("##logs")
-            .position_pivot([1., 1.])
-            .position([dw * 0.95, dh * 0.8], Condition::Always)
+            .position_pivot(pivot)
+            .position(position, Condition::Always)
             .flags({
                 WindowFlags::NO_TITLE_BAR
                     | WindowFlags::NO_RESIZE
@@ -520,6 +1171,117 @@ impl PracticeTool {
         }
     }
 
+    /// Marks the screen while in speedrun mode, whatever the state of the
+    /// tool's window, so that recordings show which features were available.
+    fn render_watermark(&self, ui: &imgui::Ui) {
+        const SHADOW: [f32; 4] = [0., 0., 0., 0.8];
+        const TEXT: [f32; 4] = [1., 1., 1., 0.8];
+
+        if !self.settings.speedrun_mode {
+            return;
+        }
+
+        let text = format!("Speedrun mode - Practice Tool v{}", current_version());
+        let [_, h] = ui.io().display_size;
+        let [_, th] = ui.calc_text_size(&text);
+        let [x, y] = [16., h - th - 16.];
+
+        let draw_list = ui.get_foreground_draw_list();
+        draw_list.add_text([x + 1., y + 1.], SHADOW, &text);
+        draw_list.add_text([x, y], TEXT, &text);
+    }
+
+    fn render_config_error(&mut self, ui: &imgui::Ui) {
+        const TAG: &str = "Configuration error##config_error";
+
+        let Some(error) = &self.config_error else {
+            return;
+        };
+
+        if !ui.is_popup_open(TAG) {
+            ui.open_popup(TAG);
+        }
+
+        let mut dismissed = false;
+        if let Some(_token) = ui
+            .modal_popup_config(TAG)
+            .resizable(false)
+            .movable(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            self.pointers.cursor_show.set(true);
+            ui.text(error);
+            ui.separator();
+            if ui.button_with_size("Close", [ui.content_region_avail()[0], BUTTON_HEIGHT]) {
+                ui.close_current_popup();
+                dismissed = true;
+            }
+        }
+
+        if dismissed {
+            self.config_error = None;
+            if !matches!(self.ui_state, UiState::MenuOpen) {
+                self.pointers.cursor_show.set(false);
+            }
+        }
+    }
+
+    fn set_log_level(&self, level: LevelFilter) {
+        match self.log_level.modify(|filter| *filter = level) {
+            Ok(()) => info!("Log level set to {level}"),
+            Err(e) => error!("Couldn't change the log level: {e}"),
+        }
+    }
+
+    /// Line of the log panel, prefixed with the time since the tool started.
+    fn log_line(&self, (time, log): &(Instant, String)) -> String {
+        let elapsed = time.duration_since(self.started).as_secs();
+        format!("[{:02}:{:02}:{:02}] {log}", elapsed / 3600, elapsed / 60 % 60, elapsed % 60)
+    }
+
+    fn render_log_panel(&mut self, ui: &imgui::Ui) {
+        let [dw, dh] = ui.io().display_size;
+        let mut opened = true;
+
+        ui.window("Log##log_panel")
+            .position([dw * 0.6, dh * 0.1], Condition::FirstUseEver)
+            .size([dw * 0.35, dh * 0.4], Condition::FirstUseEver)
+            .opened(&mut opened)
+            .build(|| {
+                if ui.small_button("Copy") {
+                    let text = self
+                        .log_history
+                        .iter()
+                        .map(|l| self.log_line(l))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    ui.set_clipboard_text(text);
+                }
+                ui.same_line();
+                if ui.small_button("Clear") {
+                    self.log_history.clear();
+                }
+                ui.separator();
+
+                ui.child_window("##log_lines").build(|| {
+                    let mut clipper = ListClipper::new(self.log_history.len() as i32).begin(ui);
+                    while clipper.step() {
+                        for i in clipper.display_start()..clipper.display_end() {
+                            ui.text(self.log_line(&self.log_history[i as usize]));
+                        }
+                    }
+
+                    // Follows new lines unless scrolled up.
+                    if ui.scroll_y() >= ui.scroll_max_y() {
+                        ui.set_scroll_here_y_with_ratio(1.0);
+                    }
+                });
+            });
+
+        self.log_panel &= opened;
+    }
+
     fn set_font<'a>(&mut self, ui: &'a imgui::Ui) -> imgui::FontStackToken<'a> {
         let width = ui.io().display_size[0];
         let font_id = self
@@ -549,6 +1311,20 @@ impl ImguiRenderLoop for PracticeTool {
 
         self.framecount += 1;
 
+        if self.profiles.len() > 1
+            && !ui.io().want_capture_keyboard
+            && self.settings.switch_profile.map(|k| k.is_pressed(ui)).unwrap_or(false)
+        {
+            self.switch_profile((self.profile + 1) % self.profiles.len());
+        }
+
+        if !ui.io().want_capture_keyboard
+            && !matches!(self.ui_state, UiState::Hidden)
+            && self.settings.command_palette.map(|k| k.is_pressed(ui)).unwrap_or(false)
+        {
+            self.command_palette.toggle();
+        }
+
         if !ui.io().want_capture_keyboard && (display || hide) {
             self.ui_state = match (&self.ui_state, hide) {
                 (UiState::Hidden, _) => UiState::Closed,
@@ -577,40 +1353,147 @@ impl ImguiRenderLoop for PracticeTool {
             },
         }
 
+        self.render_watermark(ui);
+        self.render_config_error(ui);
+        self.gamepad_hotkeys.update();
+
+        if !matches!(self.ui_state, UiState::Hidden) {
+            self.command_palette.render(ui);
+        }
+
+        if let Some(message) = self.online_watch.as_mut().and_then(OnlineWatch::update) {
+            info!("{message}");
+            self.log_tx.send(message).ok();
+        }
+
+        if let Some(radial_menu) = self.radial_menu.as_mut() {
+            if !matches!(self.ui_state, UiState::Hidden) {
+                radial_menu.render(ui);
+            }
+            for log in radial_menu.drain_logs() {
+                self.log_tx.send(log).ok();
+            }
+        }
+
         for w in &mut self.widgets {
             w.log(self.log_tx.clone());
         }
+        let refuse_writes = if self.settings.speedrun_mode {
+            Some("speedrun mode is on")
+        } else if self.settings.safe_mode {
+            Some("safe mode is on")
+        } else if online::is_online() {
+            Some("the game is online")
+        } else {
+            None
+        };
+        let remote_executed = self.remote.process(&self.pointers, &self.log_tx, refuse_writes);
+        let executed = feedback::take_executed() || remote_executed;
+
+        if let Some(rumble) = self.rumble.as_mut() {
+            if executed && matches!(self.ui_state, UiState::Hidden) {
+                rumble.pulse();
+            }
+            rumble.update();
+        }
+
+        if executed {
+            audio::play(Cue::Command);
+        }
+
+        let iframes_active =
+            match (self.pointers.cur_anim_tae.read(), self.pointers.cur_anim_time.read()) {
+                (Some(tae), Some(time)) => {
+                    matches!(IFrames::of(tae, time), IFrames::Active { .. })
+                },
+                _ => false,
+            };
+        if iframes_active && !self.iframes_active {
+            audio::play(Cue::IFrames);
+        }
+        self.iframes_active = iframes_active;
+
+        plugins::flush_logs(&self.log_tx);
+        self.counters.update(&self.pointers, &self.log_tx);
+
+        if let Some(Err(e)) = self.telemetry.as_mut().map(|t| t.update(&self.pointers)) {
+            error!("{e}");
+            self.log_tx.send(e).ok();
+            self.telemetry = None;
+        }
+
+        if let Some(overlay) = self.overlay.as_mut() {
+            overlay.update(&self.pointers, &self.counters);
+        }
 
         let now = Instant::now();
-        self.log.extend(self.log_rx.try_iter().inspect(|log| info!("{}", log)).map(|l| (now, l)));
+        for log in self.log_rx.try_iter() {
+            info!("{}", log);
+            if self.log_history.len() == LOG_HISTORY {
+                self.log_history.pop_front();
+            }
+            self.log_history.push_back((now, log.clone()));
+            self.log.push((now, log));
+        }
         self.log.retain(|(tm, _)| tm.elapsed() < std::time::Duration::from_secs(5));
 
         self.render_logs(ui);
+        if self.log_panel && !matches!(self.ui_state, UiState::Hidden) {
+            self.render_log_panel(ui);
+        }
         drop(font_token);
     }
 
     fn initialize(&mut self, ctx: &mut Context, _: &mut dyn RenderContext) {
+        let style = ctx.style_mut();
+        let accents = [
+            (self.theme.accent, [StyleColor::Button, StyleColor::Header, StyleColor::FrameBg]),
+            (self.theme.accent_hovered, [
+                StyleColor::ButtonHovered,
+                StyleColor::HeaderHovered,
+                StyleColor::FrameBgHovered,
+            ]),
+            (self.theme.accent_active, [
+                StyleColor::ButtonActive,
+                StyleColor::HeaderActive,
+                StyleColor::FrameBgActive,
+            ]),
+        ];
+        for (color, style_colors) in accents {
+            if let Some(color) = color {
+                for style_color in style_colors {
+                    style[style_color] = color.0;
+                }
+            }
+        }
+        if let Some(color) = self.theme.accent_active {
+            style[StyleColor::CheckMark] = color.0;
+            style[StyleColor::SliderGrabActive] = color.0;
+        }
+        if let Some(color) = self.theme.accent {
+            style[StyleColor::SliderGrab] = color.0;
+        }
+
+        let data = load_font(&self.font, &self.log_tx);
         let fonts = ctx.fonts();
+        let mut add_font = |size_pixels| {
+            fonts.add_font(&[FontSource::TtfData { data, size_pixels, config: None }])
+        };
         self.fonts = Some(FontIDs {
-            small: fonts.add_font(&[FontSource::TtfData {
-                data: include_bytes!("../../lib/data/ComicMono.ttf"),
-                size_pixels: 11.,
-                config: None,
-            }]),
-            normal: fonts.add_font(&[FontSource::TtfData {
-                data: include_bytes!("../../lib/data/ComicMono.ttf"),
-                size_pixels: 18.,
-                config: None,
-            }]),
-            big: fonts.add_font(&[FontSource::TtfData {
-                data: include_bytes!("../../lib/data/ComicMono.ttf"),
-                size_pixels: 24.,
-                config: None,
-            }]),
+            small: add_font(self.font.small),
+            normal: add_font(self.font.normal),
+            big: add_font(self.font.big),
         });
     }
 }
 
+// Indicators whose value can't be read show nothing rather than an empty line.
+fn text_if_any(ui: &Ui, text: &str) {
+    if !text.is_empty() {
+        ui.text(text);
+    }
+}
+
 // Display some imgui debug information. Very expensive.
 fn imgui_debug(ui: &Ui) {
     let io = ui.io();
@@ -625,3 +1508,146 @@ fn imgui_debug(ui: &Ui) {
     ui.text(format!("Any item focused   {:?}", ui.is_any_item_focused()));
     ui.text(format!("Any mouse down     {:?}", ui.is_any_mouse_down()));
 }
+
+// Draw the state of the first connected gamepad: triggers and bumpers on top,
+// then left stick, d-pad, face buttons and right stick.
+fn input_display(ui: &Ui) {
+    use windows::Win32::UI::Input::XboxController::*;
+
+    const COLOR_ON: [f32; 4] = [1.0, 1.0, 1.0, 0.9];
+    const COLOR_OFF: [f32; 4] = [1.0, 1.0, 1.0, 0.25];
+
+    let Some(pad) = XINPUT_GAMEPADS.lock().iter().find_map(|pad| *pad) else {
+        ui.text("No controller");
+        return;
+    };
+
+    let pressed = |button: XINPUT_GAMEPAD_BUTTON_FLAGS| pad.wButtons.0 & button.0 != 0;
+    let color = |on: bool| if on { COLOR_ON } else { COLOR_OFF };
+
+    let r = ui.current_font_size() * 0.5;
+    let [x, y] = ui.cursor_screen_pos();
+    let draw_list = ui.get_window_draw_list();
+
+    // Triggers and bumpers.
+    for (x0, trigger, bumper) in [
+        (x, pad.bLeftTrigger, XINPUT_GAMEPAD_LEFT_SHOULDER),
+        (x + 14. * r, pad.bRightTrigger, XINPUT_GAMEPAD_RIGHT_SHOULDER),
+    ] {
+        let fill = 4. * r * trigger as f32 / 255.;
+        draw_list.add_rect([x0, y], [x0 + 4. * r, y + r], COLOR_OFF).build();
+        draw_list.add_rect([x0, y], [x0 + fill, y + r], COLOR_ON).filled(true).build();
+        draw_list
+            .add_rect([x0, y + 1.5 * r], [x0 + 4. * r, y + 2.5 * r], color(pressed(bumper)))
+            .filled(true)
+            .build();
+    }
+
+    // Back and start.
+    draw_list
+        .add_circle([x + 7.5 * r, y + 2. * r], 0.4 * r, color(pressed(XINPUT_GAMEPAD_BACK)))
+        .filled(true)
+        .build();
+    draw_list
+        .add_circle([x + 10.5 * r, y + 2. * r], 0.4 * r, color(pressed(XINPUT_GAMEPAD_START)))
+        .filled(true)
+        .build();
+
+    // Sticks.
+    for (cx, sx, sy, thumb) in [
+        (x + 2. * r, pad.sThumbLX, pad.sThumbLY, XINPUT_GAMEPAD_LEFT_THUMB),
+        (x + 16. * r, pad.sThumbRX, pad.sThumbRY, XINPUT_GAMEPAD_RIGHT_THUMB),
+    ] {
+        let cy = y + 5. * r;
+        let dx = sx as f32 / i16::MAX as f32 * 1.5 * r;
+        let dy = -(sy as f32) / i16::MAX as f32 * 1.5 * r;
+        draw_list.add_circle([cx, cy], 2. * r, color(pressed(thumb))).thickness(2.).build();
+        draw_list.add_circle([cx + dx, cy + dy], 0.5 * r, COLOR_ON).filled(true).build();
+    }
+
+    // D-pad and face buttons.
+    let (cx, cy) = (x + 6.5 * r, y + 5. * r);
+    for (dx, dy, button) in [
+        (0., -1., XINPUT_GAMEPAD_DPAD_UP),
+        (0., 1., XINPUT_GAMEPAD_DPAD_DOWN),
+        (-1., 0., XINPUT_GAMEPAD_DPAD_LEFT),
+        (1., 0., XINPUT_GAMEPAD_DPAD_RIGHT),
+    ] {
+        let (bx, by) = (cx + dx * r, cy + dy * r);
+        draw_list
+            .add_rect(
+                [bx - 0.4 * r, by - 0.4 * r],
+                [bx + 0.4 * r, by + 0.4 * r],
+                color(pressed(button)),
+            )
+            .filled(true)
+            .build();
+    }
+
+    let (cx, cy) = (x + 11.5 * r, y + 5. * r);
+    for (dx, dy, button) in [
+        (0., -1., XINPUT_GAMEPAD_Y),
+        (0., 1., XINPUT_GAMEPAD_A),
+        (-1., 0., XINPUT_GAMEPAD_X),
+        (1., 0., XINPUT_GAMEPAD_B),
+    ] {
+        draw_list
+            .add_circle([cx + dx * r, cy + dy * r], 0.45 * r, color(pressed(button)))
+            .filled(true)
+            .build();
+    }
+
+    ui.dummy([18. * r, 7.5 * r]);
+}
+
+/// Draws a compass needle for the player's facing angle, followed by the
+/// angle in degrees and the closest cardinal direction. North is where the
+/// game's angle 0 points to.
+fn compass(ui: &Ui, angle: f32) {
+    const COLOR_RING: [f32; 4] = [1.0, 1.0, 1.0, 0.4];
+    const COLOR_NEEDLE: [f32; 4] = [1.0, 0.3, 0.3, 1.0];
+    const CARDINALS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+
+    let degrees = angle.to_degrees().rem_euclid(360.);
+    let cardinal = CARDINALS[((degrees + 22.5) / 45.) as usize % 8];
+
+    let r = ui.current_font_size() * 0.5;
+    let [x, y] = ui.cursor_screen_pos();
+    let (cx, cy) = (x + r, y + r);
+    let draw_list = ui.get_window_draw_list();
+
+    draw_list.add_circle([cx, cy], r, COLOR_RING).thickness(1.5).build();
+    draw_list
+        .add_line([cx, cy], [cx + r * angle.sin(), cy - r * angle.cos()], COLOR_NEEDLE)
+        .thickness(2.)
+        .build();
+
+    ui.dummy([2. * r, 2. * r]);
+    ui.same_line();
+    ui.text(format!("{degrees:5.1}° {cardinal}"));
+}
+
+fn start_telemetry(cfg: &TelemetryConfig, log_tx: &Sender<String>) -> Option<Telemetry> {
+    match Telemetry::new(cfg.every) {
+        Ok(telemetry) => Some(telemetry),
+        Err(e) => {
+            error!("{e}");
+            log_tx.send(e).ok();
+            None
+        },
+    }
+}
+
+fn load_plugins(settings: &Settings) -> Vec<Box<dyn Widget>> {
+    // Plugins can write anywhere, so they aren't loaded in safe mode.
+    if settings.read_only() {
+        return Vec::new();
+    }
+
+    let plugins = plugins::load();
+    if settings.online_interlock {
+        plugins.into_iter().map(interlocked).collect()
+    } else {
+        plugins
+    }
+}
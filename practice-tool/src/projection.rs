@@ -0,0 +1,72 @@
+//! Projection of world coordinates to the screen, from the gameplay camera.
+
+use libds3::prelude::*;
+
+/// Closest distance in front of the camera that is still drawn.
+const NEAR: f32 = 0.1;
+
+/// Snapshot of the gameplay camera for one frame.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Projection {
+    right: [f32; 3],
+    up: [f32; 3],
+    forward: [f32; 3],
+    eye: [f32; 3],
+    /// Focal length for a vertical field of view spanning `-1..1`.
+    focal: f32,
+    display_size: [f32; 2],
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+impl Projection {
+    /// Reads the camera. The matrix rows are its right, up and forward axes
+    /// followed by its position, each padded to four floats.
+    pub(crate) fn read(
+        camera: &PointerChain<[f32; 16]>,
+        fov: &PointerChain<f32>,
+        display_size: [f32; 2],
+    ) -> Option<Self> {
+        let m = camera.read()?;
+        let fov = fov.read()?;
+        if !(0.01..3.).contains(&fov) || display_size[1] <= 0. {
+            return None;
+        }
+
+        Some(Projection {
+            right: [m[0], m[1], m[2]],
+            up: [m[4], m[5], m[6]],
+            forward: [m[8], m[9], m[10]],
+            eye: [m[12], m[13], m[14]],
+            focal: 1. / (fov / 2.).tan(),
+            display_size,
+        })
+    }
+
+    /// Distance from the camera to the point.
+    pub(crate) fn distance(&self, [x, y, z]: [f32; 3]) -> f32 {
+        let [ex, ey, ez] = self.eye;
+        ((x - ex).powi(2) + (y - ey).powi(2) + (z - ez).powi(2)).sqrt()
+    }
+
+    /// Screen position of a world point, or `None` if it is behind the camera.
+    /// Points beside the screen are returned too, so lines to them can be
+    /// clipped by the draw list.
+    pub(crate) fn to_screen(&self, [x, y, z]: [f32; 3]) -> Option<[f32; 2]> {
+        let [ex, ey, ez] = self.eye;
+        let d = [x - ex, y - ey, z - ez];
+
+        let depth = dot(d, self.forward);
+        if depth < NEAR {
+            return None;
+        }
+
+        let [w, h] = self.display_size;
+        let ndc_x = dot(d, self.right) * self.focal / (w / h) / depth;
+        let ndc_y = dot(d, self.up) * self.focal / depth;
+
+        Some([(ndc_x + 1.) / 2. * w, (1. - ndc_y) / 2. * h])
+    }
+}
@@ -0,0 +1,160 @@
+//! Gamepad radial menu.
+//!
+//! While the configured button is held, up to eight entries are shown in a
+//! circle around the center of the screen. The right stick highlights one,
+//! and releasing the button activates it. Entries either run one of the
+//! remote control commands directly, or press a key combination as if it was
+//! pressed on the keyboard. The D-pad's left and right cycle between pages of
+//! entries.
+
+use std::f32::consts::PI;
+
+use imgui::Ui;
+use practice_tool_core::crossbeam_channel::Sender;
+use serde::Deserialize;
+
+use crate::action::Action;
+use crate::gamepad::{GamepadButton, GamepadState};
+use crate::remote::RemoteCommand;
+
+/// Number of entries in a page.
+const SLOTS: usize = 8;
+
+/// How far the right stick must be pushed to highlight an entry.
+const STICK_THRESHOLD: f32 = 0.5;
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct RadialMenuConfig {
+    pub(crate) open: GamepadButton,
+    pub(crate) pages: Vec<RadialPage>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct RadialPage {
+    name: String,
+    entries: Vec<RadialEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct RadialEntry {
+    label: String,
+    #[serde(flatten)]
+    action: Action,
+}
+
+pub(crate) struct RadialMenu {
+    config: RadialMenuConfig,
+    commands: Sender<RemoteCommand>,
+    page: usize,
+    selected: Option<usize>,
+    prev: GamepadState,
+    logs: Vec<String>,
+}
+
+impl RadialMenu {
+    pub(crate) fn new(mut config: RadialMenuConfig, commands: Sender<RemoteCommand>) -> Self {
+        let mut logs = Vec::new();
+        for page in config.pages.iter_mut().filter(|page| page.entries.len() > SLOTS) {
+            logs.push(format!("Radial menu page \"{}\" has more than {SLOTS} entries", page.name));
+            page.entries.truncate(SLOTS);
+        }
+
+        RadialMenu {
+            config,
+            commands,
+            page: 0,
+            selected: None,
+            prev: GamepadState::default(),
+            logs,
+        }
+    }
+
+    /// Slot the right stick points to, clockwise from the top.
+    fn slot([x, y]: [f32; 2]) -> Option<usize> {
+        if x.hypot(y) < STICK_THRESHOLD {
+            return None;
+        }
+        let step = 2. * PI / SLOTS as f32;
+        Some((x.atan2(y).rem_euclid(2. * PI) / step).round() as usize % SLOTS)
+    }
+
+    fn activate(&mut self, slot: usize) {
+        let Some(entry) = self.config.pages.get(self.page).and_then(|p| p.entries.get(slot)) else {
+            return;
+        };
+        entry.action.run(&self.commands);
+        self.logs.push(format!("Radial menu: {}", entry.label));
+    }
+
+    /// Handles the gamepad input and draws the menu while it is open.
+    pub(crate) fn render(&mut self, ui: &Ui) {
+        let state = GamepadState::read().unwrap_or_default();
+        let open = self.config.open;
+        let pressed = |button| state.is_down(button) && !self.prev.is_down(button);
+
+        if !state.is_down(open) {
+            if self.prev.is_down(open) {
+                if let Some(slot) = self.selected.take() {
+                    self.activate(slot);
+                }
+            }
+            self.prev = state;
+            return;
+        }
+
+        let pages = self.config.pages.len().max(1);
+        if pressed(GamepadButton::DPAD_RIGHT) {
+            self.page = (self.page + 1) % pages;
+        }
+        if pressed(GamepadButton::DPAD_LEFT) {
+            self.page = (self.page + pages - 1) % pages;
+        }
+        self.selected = Self::slot(state.right_stick);
+        self.prev = state;
+
+        self.draw(ui);
+    }
+
+    fn draw(&self, ui: &Ui) {
+        const BACKGROUND: [f32; 4] = [0., 0., 0., 0.6];
+        const HIGHLIGHT: [f32; 4] = [1., 1., 1., 0.25];
+        const TEXT: [f32; 4] = [1., 1., 1., 1.];
+
+        let Some(page) = self.config.pages.get(self.page) else {
+            return;
+        };
+
+        let [w, h] = ui.io().display_size;
+        let center = [w / 2., h / 2.];
+        let radius = w.min(h) * 0.25;
+        let draw_list = ui.get_foreground_draw_list();
+
+        draw_list.add_circle(center, radius * 1.3, BACKGROUND).filled(true).build();
+
+        let centered_text = |[x, y]: [f32; 2], text: &str| {
+            let [tw, th] = ui.calc_text_size(text);
+            draw_list.add_text([x - tw / 2., y - th / 2.], TEXT, text);
+        };
+
+        for (i, entry) in page.entries.iter().enumerate() {
+            let angle = i as f32 * 2. * PI / SLOTS as f32;
+            let pos = [center[0] + radius * angle.sin(), center[1] - radius * angle.cos()];
+
+            if self.selected == Some(i) {
+                draw_list.add_circle(pos, radius * 0.3, HIGHLIGHT).filled(true).build();
+            }
+            centered_text(pos, &entry.label);
+        }
+
+        let line = ui.text_line_height_with_spacing();
+        centered_text([center[0], center[1] - line / 2.], &page.name);
+        if self.config.pages.len() > 1 {
+            let pages = format!("< {}/{} >", self.page + 1, self.config.pages.len());
+            centered_text([center[0], center[1] + line / 2.], &pages);
+        }
+    }
+
+    pub(crate) fn drain_logs(&mut self) -> impl Iterator<Item = String> + '_ {
+        self.logs.drain(..)
+    }
+}
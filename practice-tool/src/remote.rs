@@ -0,0 +1,677 @@
+//! WebSocket remote control server.
+//!
+//! Clients send JSON commands such as
+//!
+//! ```json
+//! {"command": "flag", "flag": "gravity", "state": true}
+//! {"command": "spawn_item", "item_id": 1073744743, "qty": 5}
+//! {"command": "quitout"}
+//! {"command": "nudge", "y": 1.5}
+//! {"command": "save_position"}
+//! {"command": "load_position"}
+//! {"command": "load_savefile", "file": "practice/gundyr.sl2"}
+//! ```
+//!
+//! and receive the current indicator values as a JSON object a few times per
+//! second. Commands are forwarded to the render thread and executed there.
+//! Handshakes with an `Origin` header are refused, so that web pages open in
+//! a browser can't drive the tool.
+//!
+//! When `command_pipe` is enabled, the same commands are also accepted as
+//! plain text lines, such as `quitout` or `spawn 0x40000B67 5`, on the
+//! `\\.\pipe\jdsd_dsiii_practice_tool_commands` named pipe. This is what
+//! `jdsd_dsiii_practice_tool.exe --exec` uses. Only the current user can open
+//! the pipe. Each line is answered once the command has run, with `ok:
+//! <result>` or `error: <reason>`, except for `load_savefile`, which is
+//! answered once the copy has started on the savefile manager's thread.
+
+use std::io::ErrorKind;
+use std::net::{TcpListener, TcpStream};
+use std::path::{Component, Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hudhook::tracing::{debug, error, info};
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::{self, Receiver, Sender};
+use practice_tool_core::widgets::position::PositionStorage;
+use serde::{Deserialize, Serialize};
+use tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tungstenite::http::StatusCode;
+use tungstenite::{Message, WebSocket};
+use windows::core::{w, HSTRING, PWSTR};
+use windows::Win32::Foundation::{CloseHandle, LocalFree, ERROR_PIPE_CONNECTED, HANDLE, HLOCAL};
+use windows::Win32::Security::Authorization::{
+    ConvertSidToStringSidW, ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1,
+};
+use windows::Win32::Security::{
+    GetTokenInformation, TokenUser, PSECURITY_DESCRIPTOR, SECURITY_ATTRIBUTES, TOKEN_QUERY,
+    TOKEN_USER,
+};
+use windows::Win32::Storage::FileSystem::{
+    FlushFileBuffers, ReadFile, WriteFile, PIPE_ACCESS_DUPLEX,
+};
+use windows::Win32::System::Pipes::{
+    ConnectNamedPipe, CreateNamedPipeW, DisconnectNamedPipe, PIPE_READMODE_BYTE, PIPE_TYPE_BYTE,
+    PIPE_WAIT,
+};
+use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+use crate::config::FlagSpec;
+use crate::restore;
+use crate::widgets::item_spawn::{ItemSpawnInstance, MAX_QTY};
+use crate::widgets::position::SavePosition;
+use crate::widgets::savefile_manager::load_savefile;
+
+const READ_TIMEOUT: Duration = Duration::from_millis(50);
+const STATE_INTERVAL: Duration = Duration::from_millis(200);
+/// Time to wait before creating the command pipe again after a failure.
+const PIPE_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+/// Time a pipe client waits for its command to run on the render thread.
+const PIPE_REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A command received on the pipe, with the channel its result is sent to.
+type PipeRequest = (RemoteCommand, Sender<Result<String, String>>);
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub(crate) enum RemoteCommand {
+    Flag {
+        flag: FlagSpec,
+        state: Option<bool>,
+    },
+    SpawnItem {
+        item_id: u32,
+        #[serde(default = "default_qty")]
+        qty: u32,
+    },
+    Quitout,
+    /// Moves the player by the given offsets.
+    Nudge {
+        #[serde(default)]
+        x: f32,
+        #[serde(default)]
+        y: f32,
+        #[serde(default)]
+        z: f32,
+    },
+    SavePosition,
+    LoadPosition,
+    /// Copies a savefile, relative to the savefile's directory, over the
+    /// current one.
+    LoadSavefile {
+        file: PathBuf,
+    },
+}
+
+fn default_qty() -> u32 {
+    1
+}
+
+impl RemoteCommand {
+    /// Whether running the command changes the game's memory. Loading a
+    /// savefile only copies files.
+    pub(crate) fn writes_memory(&self) -> bool {
+        !matches!(self, RemoteCommand::SavePosition | RemoteCommand::LoadSavefile { .. })
+    }
+
+    /// Whether the command only starts when executed and finishes in the
+    /// background, reporting by itself that it ran.
+    fn runs_in_background(&self) -> bool {
+        matches!(self, RemoteCommand::LoadSavefile { .. })
+    }
+
+    /// Parses the plain text form of a command, as sent over the named pipe.
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let command = words.next().ok_or_else(|| "Empty command".to_string())?;
+
+        let parse_u32 = |value: &str| {
+            match value.strip_prefix("0x") {
+                Some(hex) => u32::from_str_radix(hex, 16),
+                None => value.parse(),
+            }
+            .map_err(|e| format!("Invalid number {value:?}: {e}"))
+        };
+
+        let command = match command {
+            "flag" => {
+                let flag = words.next().ok_or_else(|| "Missing flag name".to_string())?;
+                let flag = FlagSpec::try_from(flag.to_string())?;
+                let state = match words.next() {
+                    Some("on") => Some(true),
+                    Some("off") => Some(false),
+                    Some(other) => return Err(format!("Invalid flag state {other:?}")),
+                    None => None,
+                };
+                RemoteCommand::Flag { flag, state }
+            },
+            "spawn" => {
+                let item_id = words.next().ok_or_else(|| "Missing item id".to_string())?;
+                let item_id = parse_u32(item_id)?;
+                let qty = words.next().map(parse_u32).transpose()?.unwrap_or_else(default_qty);
+                RemoteCommand::SpawnItem { item_id, qty }
+            },
+            "quitout" => RemoteCommand::Quitout,
+            "nudge" => {
+                let offsets = words
+                    .by_ref()
+                    .map(|w| w.parse::<f32>().map_err(|e| format!("Invalid offset {w:?}: {e}")))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match offsets[..] {
+                    [y] => RemoteCommand::Nudge { x: 0., y, z: 0. },
+                    [x, y, z] => RemoteCommand::Nudge { x, y, z },
+                    _ => return Err("Expected a height or x, y and z offsets".to_string()),
+                }
+            },
+            "save_position" => RemoteCommand::SavePosition,
+            "load_position" => RemoteCommand::LoadPosition,
+            "load_savefile" => {
+                let file = words.next().ok_or_else(|| "Missing savefile".to_string())?;
+                RemoteCommand::LoadSavefile { file: PathBuf::from(file) }
+            },
+            other => return Err(format!("Unknown command {other:?}")),
+        };
+
+        match words.next() {
+            Some(extra) => Err(format!("Unexpected argument {extra:?}")),
+            None => Ok(command),
+        }
+    }
+
+    /// Executes the command, with `position` as the saved position slot, and
+    /// returns a description of what happened, or why nothing did.
+    pub(crate) fn execute(
+        self,
+        pointers: &PointerChains,
+        position: &mut SavePosition,
+    ) -> Result<String, String> {
+        match self {
+            RemoteCommand::Flag { flag, state } => {
+                let bitflag = (flag.getter)(pointers);
+                restore::remember_flag(bitflag);
+                match state {
+                    Some(state) => bitflag.set(state),
+                    None => bitflag.toggle(),
+                }
+                match bitflag.get() {
+                    Some(true) => Ok(format!("{} enabled", flag.label)),
+                    Some(false) => Ok(format!("{} disabled", flag.label)),
+                    None => Err(format!("{} unavailable", flag.label)),
+                }
+            },
+            RemoteCommand::SpawnItem { item_id, qty } => {
+                if pointers.gravity.get().is_none() {
+                    Err("Not spawning item when not in game".to_string())
+                } else {
+                    let instance = ItemSpawnInstance {
+                        spawn_item_func_ptr: pointers.spawn_item_func_ptr,
+                        map_item_man: pointers.map_item_man,
+                        qty: qty.clamp(1, MAX_QTY),
+                        durability: 100,
+                        item_id,
+                        infusion: 0,
+                        upgrade: 0,
+                    };
+                    unsafe { instance.spawn() };
+                    Ok(format!("Spawning {instance}"))
+                }
+            },
+            RemoteCommand::Quitout => {
+                pointers.quitout.write(1);
+                Ok("Quitout".to_string())
+            },
+            RemoteCommand::Nudge { x, y, z } => match pointers.position.1.read() {
+                Some([px, py, pz]) => {
+                    pointers.position.1.write([px + x, py + y, pz + z]);
+                    Ok(format!("Nudged by {x:.2} {y:.2} {z:.2}"))
+                },
+                None => Err("Can't nudge when not in game".to_string()),
+            },
+            RemoteCommand::SavePosition => {
+                position.save();
+                Ok("Position saved".to_string())
+            },
+            RemoteCommand::LoadPosition => {
+                position.load();
+                Ok("Position loaded".to_string())
+            },
+            RemoteCommand::LoadSavefile { file } => {
+                check_savefile(&file)?;
+                let result = format!("Loading savefile {}", file.display());
+                load_savefile(file).map(|()| result)
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct IndicatorState {
+    igt: Option<u32>,
+    fps: Option<f32>,
+    position: Option<[f32; 4]>,
+    animation: Option<u32>,
+}
+
+impl IndicatorState {
+    fn read(pointers: &PointerChains) -> Self {
+        IndicatorState {
+            igt: pointers.igt.read(),
+            fps: pointers.fps.read(),
+            position: match (pointers.position.1.read(), pointers.position.0.read()) {
+                (Some([x, y, z]), Some(angle)) => Some([x, y, z, angle]),
+                _ => None,
+            },
+            animation: pointers.cur_anim.read(),
+        }
+    }
+}
+
+/// Refuses the handshakes sent by browsers, which always carry the `Origin`
+/// of the page that opened the connection.
+fn check_origin(request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+    match request.headers().get("Origin") {
+        Some(origin) => {
+            debug!("Refused remote client from origin {origin:?}");
+            let mut error = ErrorResponse::new(Some("Browser clients are not allowed".to_string()));
+            *error.status_mut() = StatusCode::FORBIDDEN;
+            Err(error)
+        },
+        None => Ok(response),
+    }
+}
+
+fn serve_client(mut ws: WebSocket<TcpStream>, tx: Sender<RemoteCommand>) {
+    let pointers = PointerChains::new();
+    let mut last_state: Option<Instant> = None;
+
+    if let Err(e) = ws.get_mut().set_read_timeout(Some(READ_TIMEOUT)) {
+        error!("Couldn't set remote client read timeout: {e}");
+        return;
+    }
+
+    loop {
+        match ws.read() {
+            Ok(Message::Text(text)) => match serde_json::from_str::<RemoteCommand>(&text) {
+                Ok(command) => {
+                    tx.send(command).ok();
+                },
+                Err(e) => {
+                    let error = serde_json::json!({ "error": e.to_string() }).to_string();
+                    ws.send(Message::Text(error)).ok();
+                },
+            },
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {},
+            Err(tungstenite::Error::Io(e))
+                if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {},
+            Err(e) => {
+                debug!("Remote client disconnected: {e}");
+                break;
+            },
+        }
+
+        if last_state.map(|t| t.elapsed() >= STATE_INTERVAL).unwrap_or(true) {
+            last_state = Some(Instant::now());
+
+            let state = match serde_json::to_string(&IndicatorState::read(&pointers)) {
+                Ok(state) => state,
+                Err(e) => {
+                    error!("Couldn't serialize indicator state: {e}");
+                    break;
+                },
+            };
+
+            if let Err(e) = ws.send(Message::Text(state)) {
+                debug!("Remote client disconnected: {e}");
+                break;
+            }
+        }
+    }
+}
+
+/// Checks that a savefile to load stays within the savefile's directory.
+fn check_savefile(file: &Path) -> Result<(), String> {
+    if file.as_os_str().is_empty()
+        || file.is_absolute()
+        || !file.components().all(|c| matches!(c, Component::Normal(_)))
+    {
+        return Err(format!(
+            "Invalid savefile {}: expected a path relative to the savefile directory",
+            file.display()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reads one command line from a connected pipe client.
+fn read_line(pipe: HANDLE) -> Result<String, String> {
+    let mut line = Vec::new();
+    let mut buf = [0u8; 256];
+
+    while !line.contains(&b'\n') {
+        let mut read = 0u32;
+        match unsafe { ReadFile(pipe, Some(&mut buf), Some(&mut read), None) } {
+            Ok(()) if read == 0 => break,
+            Ok(()) => line.extend_from_slice(&buf[..read as usize]),
+            Err(e) if line.is_empty() => return Err(format!("{e}")),
+            Err(_) => break,
+        }
+    }
+
+    String::from_utf8(line).map_err(|e| format!("{e}"))
+}
+
+/// Security descriptor that grants access to the current user only.
+fn current_user_only() -> Result<PSECURITY_DESCRIPTOR, String> {
+    unsafe {
+        let mut token = HANDLE::default();
+        OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token)
+            .map_err(|e| format!("Couldn't open process token: {e}"))?;
+
+        // Large enough for a TOKEN_USER and the SID that follows it.
+        let mut buf = [0usize; 64];
+        let mut len = 0u32;
+        let info = GetTokenInformation(
+            token,
+            TokenUser,
+            Some(buf.as_mut_ptr().cast()),
+            std::mem::size_of_val(&buf) as u32,
+            &mut len,
+        );
+        CloseHandle(token).ok();
+        info.map_err(|e| format!("Couldn't read current user: {e}"))?;
+
+        let user = &*(buf.as_ptr() as *const TOKEN_USER);
+        let mut sid = PWSTR::null();
+        ConvertSidToStringSidW(user.User.Sid, &mut sid)
+            .map_err(|e| format!("Couldn't convert current user SID: {e}"))?;
+        let sddl = HSTRING::from(format!("D:P(A;;GA;;;{})", sid.to_string().unwrap_or_default()));
+        let _ = LocalFree(HLOCAL(sid.0 as _));
+
+        let mut descriptor = PSECURITY_DESCRIPTOR::default();
+        ConvertStringSecurityDescriptorToSecurityDescriptorW(
+            &sddl,
+            SDDL_REVISION_1,
+            &mut descriptor,
+            None,
+        )
+        .map_err(|e| format!("Couldn't build pipe security descriptor: {e}"))?;
+
+        Ok(descriptor)
+    }
+}
+
+/// Creates the command pipe, accessible to the current user only.
+fn create_pipe() -> Result<HANDLE, String> {
+    let descriptor = current_user_only()?;
+    let attributes = SECURITY_ATTRIBUTES {
+        nLength: std::mem::size_of::<SECURITY_ATTRIBUTES>() as u32,
+        lpSecurityDescriptor: descriptor.0,
+        bInheritHandle: false.into(),
+    };
+
+    let pipe = unsafe {
+        CreateNamedPipeW(
+            w!("\\\\.\\pipe\\jdsd_dsiii_practice_tool_commands"),
+            PIPE_ACCESS_DUPLEX,
+            PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
+            1,
+            4096,
+            4096,
+            0,
+            Some(&attributes as *const _),
+        )
+    };
+    unsafe {
+        let _ = LocalFree(HLOCAL(descriptor.0 as _));
+    }
+
+    if pipe.is_invalid() {
+        Err(format!("Couldn't create command named pipe: {}", windows::core::Error::from_win32()))
+    } else {
+        Ok(pipe)
+    }
+}
+
+/// Runs the command sent by a pipe client on the render thread and returns
+/// the reply line.
+fn handle_pipe_client(pipe: HANDLE, tx: &Sender<PipeRequest>) -> String {
+    let command = match read_line(pipe).and_then(|line| RemoteCommand::parse(line.trim())) {
+        Ok(command) => command,
+        Err(e) => return format!("error: {e}\n"),
+    };
+
+    let (reply_tx, reply_rx) = crossbeam_channel::bounded(1);
+    if tx.send((command, reply_tx)).is_err() {
+        return "error: The practice tool is shutting down\n".to_string();
+    }
+
+    match reply_rx.recv_timeout(PIPE_REPLY_TIMEOUT) {
+        Ok(Ok(result)) => format!("ok: {result}\n"),
+        Ok(Err(e)) => format!("error: {e}\n"),
+        Err(_) => "error: Timed out waiting for the command to run\n".to_string(),
+    }
+}
+
+/// Serves the command pipe, one client at a time. The pipe is created again
+/// whenever it fails.
+fn serve_pipe(tx: Sender<PipeRequest>) {
+    loop {
+        let pipe = match create_pipe() {
+            Ok(pipe) => pipe,
+            Err(e) => {
+                error!("{e}");
+                thread::sleep(PIPE_RETRY_INTERVAL);
+                continue;
+            },
+        };
+
+        loop {
+            match unsafe { ConnectNamedPipe(pipe, None) } {
+                Err(e) if e.code() != ERROR_PIPE_CONNECTED.to_hresult() => {
+                    error!("Couldn't connect command pipe client: {e}");
+                    break;
+                },
+                _ => {},
+            }
+
+            let reply = handle_pipe_client(pipe, &tx);
+
+            unsafe {
+                WriteFile(pipe, Some(reply.as_bytes()), None, None).ok();
+                FlushFileBuffers(pipe).ok();
+                DisconnectNamedPipe(pipe).ok();
+            }
+        }
+
+        unsafe { CloseHandle(pipe).ok() };
+        thread::sleep(PIPE_RETRY_INTERVAL);
+    }
+}
+
+/// Receives the commands sent by remote clients and applies them on the
+/// render thread.
+pub(crate) struct RemoteControl {
+    tx: Sender<RemoteCommand>,
+    rx: Receiver<RemoteCommand>,
+    pipe_tx: Sender<PipeRequest>,
+    pipe_rx: Receiver<PipeRequest>,
+    position: SavePosition,
+}
+
+impl RemoteControl {
+    pub(crate) fn new(pointers: &PointerChains) -> Self {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let (pipe_tx, pipe_rx) = crossbeam_channel::unbounded();
+        RemoteControl {
+            tx,
+            rx,
+            pipe_tx,
+            pipe_rx,
+            position: SavePosition::new(pointers.position.clone(), 0.0).shared(),
+        }
+    }
+
+    /// Returns a sender to queue commands from elsewhere in the tool.
+    pub(crate) fn sender(&self) -> Sender<RemoteCommand> {
+        self.tx.clone()
+    }
+
+    /// Starts the command named pipe server in a background thread.
+    pub(crate) fn listen_pipe(&self) {
+        let tx = self.pipe_tx.clone();
+        if let Err(e) =
+            thread::Builder::new().name("commands".to_string()).spawn(move || serve_pipe(tx))
+        {
+            error!("Couldn't start command pipe thread: {e}");
+        }
+    }
+
+    /// Starts the WebSocket server on `127.0.0.1:port` in a background thread.
+    pub(crate) fn listen(&self, port: u16) {
+        let tx = self.tx.clone();
+
+        let spawned = thread::Builder::new().name("remote".to_string()).spawn(move || {
+            let listener = match TcpListener::bind(("127.0.0.1", port)) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    error!("Couldn't start remote control server on port {port}: {e}");
+                    return;
+                },
+            };
+
+            info!("Remote control server listening on port {port}");
+
+            for stream in listener.incoming().filter_map(Result::ok) {
+                let tx = tx.clone();
+                thread::spawn(move || match tungstenite::accept_hdr(stream, check_origin) {
+                    Ok(ws) => serve_client(ws, tx),
+                    Err(e) => error!("Remote control handshake failed: {e}"),
+                });
+            }
+        });
+
+        if let Err(e) = spawned {
+            error!("Couldn't start remote control thread: {e}");
+        }
+    }
+
+    /// Executes the pending remote commands. While `refuse_writes` is set,
+    /// the commands that would change the game are dropped with that reason.
+    /// Returns whether any command ran, as opposed to being refused or
+    /// failing. Commands running in the background report it themselves.
+    pub(crate) fn process(
+        &mut self,
+        pointers: &PointerChains,
+        log_tx: &Sender<String>,
+        refuse_writes: Option<&str>,
+    ) -> bool {
+        let mut executed = false;
+
+        for command in self.rx.try_iter() {
+            let background = command.runs_in_background();
+            let result = self.run(command, pointers, refuse_writes);
+            executed |= result.is_ok() && !background;
+            log_tx.send(format!("[remote] {}", result.unwrap_or_else(|e| e))).ok();
+        }
+
+        for (command, reply_tx) in self.pipe_rx.try_iter() {
+            let background = command.runs_in_background();
+            let result = self.run(command, pointers, refuse_writes);
+            executed |= result.is_ok() && !background;
+            log_tx.send(format!("[remote] {}", result.as_ref().unwrap_or_else(|e| e))).ok();
+            reply_tx.send(result).ok();
+        }
+
+        executed
+    }
+
+    fn run(
+        &mut self,
+        command: RemoteCommand,
+        pointers: &PointerChains,
+        refuse_writes: Option<&str>,
+    ) -> Result<String, String> {
+        match refuse_writes {
+            Some(reason) if command.writes_memory() => Err(format!("Command refused: {reason}")),
+            _ => command.execute(pointers, &mut self.position),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{check_savefile, RemoteCommand};
+
+    #[test]
+    fn test_parse_commands() {
+        assert!(matches!(RemoteCommand::parse("quitout"), Ok(RemoteCommand::Quitout)));
+        assert!(matches!(RemoteCommand::parse("save_position"), Ok(RemoteCommand::SavePosition)));
+        assert!(matches!(RemoteCommand::parse("load_position"), Ok(RemoteCommand::LoadPosition)));
+        assert!(matches!(
+            RemoteCommand::parse("flag gravity off"),
+            Ok(RemoteCommand::Flag { state: Some(false), .. })
+        ));
+        assert!(matches!(
+            RemoteCommand::parse("flag gravity"),
+            Ok(RemoteCommand::Flag { state: None, .. })
+        ));
+        assert!(matches!(
+            RemoteCommand::parse("load_savefile practice/gundyr.sl2"),
+            Ok(RemoteCommand::LoadSavefile { file }) if file == PathBuf::from("practice/gundyr.sl2")
+        ));
+    }
+
+    #[test]
+    fn test_parse_spawn() {
+        assert!(matches!(
+            RemoteCommand::parse("spawn 0x40000B67 5"),
+            Ok(RemoteCommand::SpawnItem { item_id: 0x40000B67, qty: 5 })
+        ));
+        assert!(matches!(
+            RemoteCommand::parse("spawn 1073744743"),
+            Ok(RemoteCommand::SpawnItem { item_id: 0x40000B67, qty: 1 })
+        ));
+        assert!(RemoteCommand::parse("spawn 0xZZ").is_err());
+        assert!(RemoteCommand::parse("spawn").is_err());
+    }
+
+    #[test]
+    fn test_parse_nudge() {
+        assert!(matches!(
+            RemoteCommand::parse("nudge 1.5"),
+            Ok(RemoteCommand::Nudge { x, y, z }) if x == 0. && y == 1.5 && z == 0.
+        ));
+        assert!(matches!(
+            RemoteCommand::parse("nudge 1 2 3"),
+            Ok(RemoteCommand::Nudge { x, y, z }) if x == 1. && y == 2. && z == 3.
+        ));
+        assert!(RemoteCommand::parse("nudge 1 2").is_err());
+        assert!(RemoteCommand::parse("nudge up").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(RemoteCommand::parse("").is_err());
+        assert!(RemoteCommand::parse("teleport").is_err());
+        assert!(RemoteCommand::parse("flag gravity maybe").is_err());
+        assert!(RemoteCommand::parse("flag not_a_flag").is_err());
+        assert!(RemoteCommand::parse("quitout now").is_err());
+    }
+
+    #[test]
+    fn test_check_savefile() {
+        assert!(check_savefile(Path::new("gundyr.sl2")).is_ok());
+        assert!(check_savefile(Path::new("practice/gundyr.sl2")).is_ok());
+        assert!(check_savefile(Path::new("")).is_err());
+        assert!(check_savefile(Path::new("../gundyr.sl2")).is_err());
+        assert!(check_savefile(Path::new("practice/../../gundyr.sl2")).is_err());
+        assert!(check_savefile(Path::new("./gundyr.sl2")).is_err());
+        assert!(check_savefile(Path::new("/gundyr.sl2")).is_err());
+        assert!(check_savefile(Path::new("C:\\gundyr.sl2")).is_err());
+    }
+}
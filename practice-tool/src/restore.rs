@@ -0,0 +1,78 @@
+//! Original values of the game state changed by the tool.
+//!
+//! Before a flag, a speed, the FOV or a code patch is changed for the first
+//! time, its value is remembered here. Ejecting the tool puts every remembered
+//! value back, so that the game isn't left with gravity off or running at a
+//! quarter of its speed, or with a patch jumping into the unloaded DLL.
+//!
+//! Values the player is expected to keep, such as the position, souls, items
+//! or event flags, aren't remembered.
+
+use hudhook::tracing::{info, warn};
+use libds3::memedit::{Bitflag, PointerChain};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+/// What a remembered value was read from. Each is only remembered once, the
+/// first time it is changed.
+#[derive(PartialEq)]
+enum Target {
+    Value(PointerChain<u8>),
+    Flag(Bitflag<u8>),
+}
+
+/// Writes a remembered value back. Returns `None` if it couldn't.
+type Restore = Box<dyn Fn() -> Option<()> + Send>;
+
+struct Saved {
+    target: Target,
+    restore: Restore,
+}
+
+static SAVED: Lazy<Mutex<Vec<Saved>>> = Lazy::new(Default::default);
+
+fn save(target: Target, restore: impl FnOnce() -> Option<Restore>) {
+    let mut saved = SAVED.lock();
+    if saved.iter().any(|s| s.target == target) {
+        return;
+    }
+    if let Some(restore) = restore() {
+        saved.push(Saved { target, restore });
+    }
+}
+
+/// Remembers the value a pointer chain points to, unless it already was.
+/// Nothing is remembered if it can't be read.
+pub(crate) fn remember<T: Copy + Send + 'static>(ptr: &PointerChain<T>) {
+    save(Target::Value(ptr.cast()), || {
+        let value = ptr.read()?;
+        let ptr = ptr.clone();
+        Some(Box::new(move || ptr.write(value)))
+    });
+}
+
+/// Remembers the state of a flag, unless it already was.
+pub(crate) fn remember_flag(flag: &Bitflag<u8>) {
+    save(Target::Flag(flag.clone()), || {
+        let state = flag.get()?;
+        let flag = flag.clone();
+        Some(Box::new(move || {
+            flag.set(state);
+            flag.get().filter(|&s| s == state).map(|_| ())
+        }))
+    });
+}
+
+/// Writes back every remembered value, the last changed first, and forgets
+/// them. Must be called before ejecting.
+pub(crate) fn restore_all() {
+    let saved = std::mem::take(&mut *SAVED.lock());
+    let count = saved.len();
+    let failed = saved.iter().rev().filter(|s| (s.restore)().is_none()).count();
+
+    if failed > 0 {
+        warn!("Couldn't restore {failed} of {count} changed values");
+    } else {
+        info!("Restored {count} changed values");
+    }
+}
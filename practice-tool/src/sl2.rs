@@ -0,0 +1,205 @@
+//! Reading character summaries from `.sl2` savefiles.
+//!
+//! A savefile is a BND4 archive of AES-128-CBC encrypted entries. The eleventh
+//! entry, `USER_DATA010`, holds the summary the game shows on the load menu
+//! for each of the ten character slots; that's all that is needed to tell
+//! savefiles apart.
+
+use std::path::Path;
+
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{BlockDecryptMut, KeyIvInit};
+
+type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
+
+const KEY: [u8; 16] = [
+    0xfd, 0x46, 0x4d, 0x69, 0x5e, 0x69, 0xa3, 0x9a, 0x10, 0xe3, 0x19, 0xa7, 0xac, 0xe8, 0xb7, 0xfa,
+];
+
+const BND4_ENTRIES: usize = 0x40;
+const BND4_ENTRY_SIZE: usize = 0x20;
+const SUMMARY_ENTRY: usize = 10;
+
+// Offsets into the decrypted `USER_DATA010` entry.
+const SLOTS: usize = 10;
+const ACTIVE_SLOTS: usize = 0x1004;
+const SUMMARIES: usize = 0x1010;
+const SUMMARY_SIZE: usize = 0x1d0;
+const SUMMARY_NAME: usize = 0x00;
+const SUMMARY_NAME_LEN: usize = 16;
+const SUMMARY_LEVEL: usize = 0x22;
+const SUMMARY_PLAY_TIME: usize = 0x26;
+const SUMMARY_LOCATION: usize = 0x2e;
+
+/// What the load menu shows for a character slot.
+#[derive(Debug, Clone)]
+pub(crate) struct CharacterSummary {
+    pub(crate) slot: usize,
+    pub(crate) name: String,
+    pub(crate) level: u32,
+    /// Play time in seconds.
+    pub(crate) play_time: u32,
+    /// Entity ID of the last rested bonfire.
+    pub(crate) location: u32,
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+}
+
+fn u64_at(data: &[u8], offset: usize) -> Option<u64> {
+    data.get(offset..offset + 8).map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+}
+
+/// Returns the decrypted contents of the `index`-th BND4 entry.
+fn decrypt_entry(data: &[u8], index: usize) -> Result<Vec<u8>, String> {
+    if data.get(0..4) != Some(b"BND4") {
+        return Err("Not a BND4 archive".to_string());
+    }
+
+    let header = BND4_ENTRIES + index * BND4_ENTRY_SIZE;
+    let size = u64_at(data, header + 0x08).ok_or("Truncated entry header")? as usize;
+    let offset = u32_at(data, header + 0x10).ok_or("Truncated entry header")? as usize;
+    let entry =
+        offset.checked_add(size).and_then(|end| data.get(offset..end)).ok_or("Truncated entry")?;
+
+    // MD5 checksum, IV, then the encrypted payload.
+    let iv = entry.get(0x10..0x20).ok_or("Truncated entry")?;
+    let mut payload = entry[0x20..].to_vec();
+    payload.truncate(payload.len() / 16 * 16);
+
+    let mut decryptor = Aes128CbcDec::new(&KEY.into(), GenericArray::from_slice(iv));
+    for block in payload.chunks_exact_mut(16) {
+        decryptor.decrypt_block_mut(GenericArray::from_mut_slice(block));
+    }
+
+    Ok(payload)
+}
+
+/// Reads the summaries of the active character slots of a savefile.
+pub(crate) fn read_summaries(path: &Path) -> Result<Vec<CharacterSummary>, String> {
+    let data = std::fs::read(path).map_err(|e| format!("Couldn't read {path:?}: {e}"))?;
+    parse_summaries(&data)
+}
+
+fn parse_summaries(data: &[u8]) -> Result<Vec<CharacterSummary>, String> {
+    let entry = decrypt_entry(data, SUMMARY_ENTRY)?;
+
+    let active = entry.get(ACTIVE_SLOTS..ACTIVE_SLOTS + SLOTS).ok_or("Truncated summary")?;
+
+    (0..SLOTS)
+        .filter(|&slot| active[slot] != 0)
+        .map(|slot| -> Result<CharacterSummary, String> {
+            let base = SUMMARIES + slot * SUMMARY_SIZE;
+            let name = entry
+                .get(base + SUMMARY_NAME..base + SUMMARY_NAME + SUMMARY_NAME_LEN * 2)
+                .ok_or("Truncated summary")?
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .take_while(|&c| c != 0)
+                .collect::<Vec<_>>();
+
+            Ok(CharacterSummary {
+                slot,
+                name: String::from_utf16_lossy(&name),
+                level: u32_at(&entry, base + SUMMARY_LEVEL).ok_or("Truncated summary")?,
+                play_time: u32_at(&entry, base + SUMMARY_PLAY_TIME).ok_or("Truncated summary")?,
+                location: u32_at(&entry, base + SUMMARY_LOCATION).ok_or("Truncated summary")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aes::cipher::generic_array::GenericArray;
+    use aes::cipher::{BlockEncryptMut, KeyIvInit};
+
+    use super::*;
+
+    type Aes128CbcEnc = cbc::Encryptor<aes::Aes128>;
+
+    const ENTRY_OFFSET: usize = 0x200;
+    const IV: [u8; 16] = [7; 16];
+
+    /// Builds a savefile whose summary entry holds `slots`.
+    fn savefile(slots: &[(usize, &str, u32, u32, u32)]) -> Vec<u8> {
+        let mut summary = vec![0u8; SUMMARIES + SLOTS * SUMMARY_SIZE];
+        for &(slot, name, level, play_time, location) in slots {
+            summary[ACTIVE_SLOTS + slot] = 1;
+            let base = SUMMARIES + slot * SUMMARY_SIZE;
+            for (i, c) in name.encode_utf16().enumerate() {
+                summary[base + SUMMARY_NAME + i * 2..][..2].copy_from_slice(&c.to_le_bytes());
+            }
+            summary[base + SUMMARY_LEVEL..][..4].copy_from_slice(&level.to_le_bytes());
+            summary[base + SUMMARY_PLAY_TIME..][..4].copy_from_slice(&play_time.to_le_bytes());
+            summary[base + SUMMARY_LOCATION..][..4].copy_from_slice(&location.to_le_bytes());
+        }
+
+        let mut encryptor = Aes128CbcEnc::new(&KEY.into(), &IV.into());
+        for block in summary.chunks_exact_mut(16) {
+            encryptor.encrypt_block_mut(GenericArray::from_mut_slice(block));
+        }
+
+        let mut entry = vec![0u8; 0x10];
+        entry.extend_from_slice(&IV);
+        entry.extend_from_slice(&summary);
+
+        let mut data = vec![0u8; ENTRY_OFFSET];
+        data[0..4].copy_from_slice(b"BND4");
+        let header = BND4_ENTRIES + SUMMARY_ENTRY * BND4_ENTRY_SIZE;
+        data[header + 0x08..][..8].copy_from_slice(&(entry.len() as u64).to_le_bytes());
+        data[header + 0x10..][..4].copy_from_slice(&(ENTRY_OFFSET as u32).to_le_bytes());
+        data.extend_from_slice(&entry);
+        data
+    }
+
+    #[test]
+    fn test_summaries() {
+        let data = savefile(&[(0, "Ashen One", 42, 3600, 4001950), (3, "Unkindled", 1, 0, 0)]);
+
+        let summaries = parse_summaries(&data).unwrap();
+        assert_eq!(summaries.len(), 2);
+
+        let ashen = &summaries[0];
+        assert_eq!(ashen.slot, 0);
+        assert_eq!(ashen.name, "Ashen One");
+        assert_eq!(ashen.level, 42);
+        assert_eq!(ashen.play_time, 3600);
+        assert_eq!(ashen.location, 4001950);
+
+        assert_eq!(summaries[1].slot, 3);
+        assert_eq!(summaries[1].name, "Unkindled");
+
+        assert!(parse_summaries(&savefile(&[])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_full_length_name() {
+        let name = "Sixteen chars!!!";
+        let summaries = parse_summaries(&savefile(&[(9, name, 802, 1, 2)])).unwrap();
+        assert_eq!(summaries[0].slot, 9);
+        assert_eq!(summaries[0].name, name);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let data = savefile(&[(0, "Ashen One", 42, 3600, 4001950)]);
+
+        assert_eq!(parse_summaries(b"").unwrap_err(), "Not a BND4 archive");
+        assert_eq!(parse_summaries(b"BND3").unwrap_err(), "Not a BND4 archive");
+        assert_eq!(parse_summaries(b"BND4").unwrap_err(), "Truncated entry header");
+        assert_eq!(parse_summaries(&data[..data.len() - 1]).unwrap_err(), "Truncated entry");
+
+        // An entry size that would overflow the end offset.
+        let mut huge = data.clone();
+        let header = BND4_ENTRIES + SUMMARY_ENTRY * BND4_ENTRY_SIZE;
+        huge[header + 0x08..][..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(parse_summaries(&huge).unwrap_err(), "Truncated entry");
+
+        // A summary entry too short to hold the slots.
+        let mut short = data.clone();
+        short[header + 0x08..][..8].copy_from_slice(&0x40u64.to_le_bytes());
+        assert_eq!(parse_summaries(&short).unwrap_err(), "Truncated summary");
+    }
+}
@@ -0,0 +1,56 @@
+//! Plot of the player's movement speed over the last few seconds.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+const HISTORY: Duration = Duration::from_secs(4);
+
+#[derive(Default)]
+pub(crate) struct SpeedGraph {
+    prev: Option<(Instant, [f32; 3])>,
+    samples: VecDeque<(Instant, f32)>,
+    values: Vec<f32>,
+}
+
+impl SpeedGraph {
+    /// Records the player's position for this frame. Passing `None` while not
+    /// in game clears the history, so loading screens don't show as spikes.
+    pub(crate) fn sample(&mut self, position: Option<[f32; 3]>) {
+        let now = Instant::now();
+
+        let Some(position) = position else {
+            self.prev = None;
+            self.samples.clear();
+            return;
+        };
+
+        if let Some((prev_time, [px, py, pz])) = self.prev {
+            let dt = now.duration_since(prev_time).as_secs_f32();
+            if dt > 0. {
+                let [x, y, z] = position;
+                let distance = ((x - px).powi(2) + (y - py).powi(2) + (z - pz).powi(2)).sqrt();
+                self.samples.push_back((now, distance / dt));
+            }
+        }
+        self.prev = Some((now, position));
+
+        while self.samples.front().is_some_and(|&(t, _)| now.duration_since(t) > HISTORY) {
+            self.samples.pop_front();
+        }
+    }
+
+    pub(crate) fn render(&mut self, ui: &imgui::Ui) {
+        self.values.clear();
+        self.values.extend(self.samples.iter().map(|&(_, speed)| speed));
+
+        let current = self.values.last().copied().unwrap_or(0.);
+        let max = self.values.iter().copied().fold(0f32, f32::max);
+
+        ui.plot_lines("##speed-graph", &self.values)
+            .overlay_text(format!("Speed {current:.2} (max {max:.2})"))
+            .scale_min(0.)
+            .scale_max(max.max(1.))
+            .graph_size([ui.current_font_size() * 16., ui.current_font_size() * 3.])
+            .build();
+    }
+}
@@ -0,0 +1,94 @@
+//! Per-frame telemetry export.
+//!
+//! When enabled, a CSV file named after the time the tool was loaded is
+//! written next to the log file, with one row every `every` frames while the
+//! player is in game.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use libds3::prelude::*;
+use windows::Win32::System::SystemInformation::GetLocalTime;
+
+use crate::util;
+
+const HEADER: &str = "frame,time_ms,igt_ms,x,y,z,angle,speed,hp,animation";
+
+pub(crate) struct Telemetry {
+    writer: BufWriter<File>,
+    every: u32,
+    frame: u32,
+    start: Instant,
+    prev: Option<(Instant, [f32; 3])>,
+}
+
+impl Telemetry {
+    pub(crate) fn new(every: u32) -> Result<Self, String> {
+        let t = unsafe { GetLocalTime() };
+        let mut path: PathBuf = util::get_dll_path().ok_or("Couldn't find the DLL path")?;
+        path.pop();
+        path.push(format!(
+            "jdsd_dsiii_practice_tool.telemetry_{:04}{:02}{:02}_{:02}{:02}{:02}.csv",
+            t.wYear, t.wMonth, t.wDay, t.wHour, t.wMinute, t.wSecond
+        ));
+
+        let file = File::create(&path).map_err(|e| format!("Couldn't create {path:?}: {e}"))?;
+        let mut writer = BufWriter::new(file);
+        writeln!(writer, "{HEADER}").map_err(|e| format!("Couldn't write telemetry: {e}"))?;
+
+        Ok(Telemetry { writer, every: every.max(1), frame: 0, start: Instant::now(), prev: None })
+    }
+
+    pub(crate) fn set_every(&mut self, every: u32) {
+        self.every = every.max(1);
+    }
+
+    /// Records the current frame. Must be called every frame.
+    pub(crate) fn update(&mut self, pointers: &PointerChains) -> Result<(), String> {
+        self.frame = self.frame.wrapping_add(1);
+
+        let (Some([x, y, z]), Some(angle), Some([hp, _, _])) =
+            (pointers.position.1.read(), pointers.position.0.read(), pointers.hp.read())
+        else {
+            self.prev = None;
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        let speed = match self.prev {
+            Some((t, [px, py, pz])) => {
+                let dt = now.duration_since(t).as_secs_f32();
+                let distance = ((x - px).powi(2) + (y - py).powi(2) + (z - pz).powi(2)).sqrt();
+                if dt > 0. {
+                    distance / dt
+                } else {
+                    0.
+                }
+            },
+            None => 0.,
+        };
+        self.prev = Some((now, [x, y, z]));
+
+        if self.frame % self.every != 0 {
+            return Ok(());
+        }
+
+        let igt = pointers.igt.read().unwrap_or(0);
+        let anim = pointers.cur_anim.read().unwrap_or(0);
+        writeln!(
+            self.writer,
+            "{},{},{igt},{x:.3},{y:.3},{z:.3},{angle:.4},{speed:.3},{hp},{anim}",
+            self.frame,
+            now.duration_since(self.start).as_millis(),
+        )
+        .map_err(|e| format!("Couldn't write telemetry: {e}"))
+    }
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        self.writer.flush().ok();
+    }
+}
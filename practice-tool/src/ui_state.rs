@@ -0,0 +1,80 @@
+//! UI state remembered between sessions, such as which groups are expanded
+//! and which commands are turned off.
+//!
+//! Stored in `jdsd_dsiii_practice_tool.ui_state.json` next to the DLL.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+use hudhook::tracing::error;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::util;
+
+#[derive(Default, Serialize, Deserialize)]
+struct UiState {
+    /// Expanded state of the collapsible groups, by label.
+    #[serde(default)]
+    groups: BTreeMap<String, bool>,
+    /// Commands turned off in the command checklist, by name.
+    #[serde(default)]
+    disabled_commands: BTreeSet<String>,
+}
+
+static UI_STATE: Lazy<Mutex<UiState>> = Lazy::new(|| {
+    let state = path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    Mutex::new(state)
+});
+
+fn path() -> Option<PathBuf> {
+    util::get_dll_path().map(|mut path| {
+        path.pop();
+        path.push("jdsd_dsiii_practice_tool.ui_state.json");
+        path
+    })
+}
+
+fn save(state: &UiState) {
+    let Some(path) = path() else {
+        return;
+    };
+
+    match serde_json::to_string_pretty(state) {
+        Ok(content) => {
+            if let Err(e) = fs::write(&path, content) {
+                error!("Couldn't write {path:?}: {e}");
+            }
+        },
+        Err(e) => error!("Couldn't serialize UI state: {e}"),
+    }
+}
+
+pub(crate) fn group_open(label: &str) -> bool {
+    UI_STATE.lock().groups.get(label).copied().unwrap_or(false)
+}
+
+pub(crate) fn set_group_open(label: &str, open: bool) {
+    let mut state = UI_STATE.lock();
+    state.groups.insert(label.to_string(), open);
+    save(&state);
+}
+
+pub(crate) fn command_enabled(name: &str) -> bool {
+    !UI_STATE.lock().disabled_commands.contains(name)
+}
+
+pub(crate) fn set_command_enabled(name: &str, enabled: bool) {
+    let mut state = UI_STATE.lock();
+    if enabled {
+        state.disabled_commands.remove(name);
+    } else {
+        state.disabled_commands.insert(name.to_string());
+    }
+    save(&state);
+}
@@ -0,0 +1,86 @@
+//! Checks GitHub for a newer release of the tool.
+//!
+//! The launcher always checks before injecting. The tool itself only checks
+//! when `check_updates` is enabled, which is mostly useful when it is
+//! installed and started along with the game.
+
+use std::sync::Arc;
+use std::thread;
+
+use hudhook::tracing::{info, warn};
+use once_cell::sync::OnceCell;
+use pkg_version::*;
+use semver::Version;
+
+const LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/veeenu/darksoulsiii-practice-tool/releases/latest";
+
+/// A published release of the tool.
+pub struct Release {
+    pub version: Version,
+    /// Address of the release page.
+    pub url: String,
+    pub notes: String,
+}
+
+pub fn current_version() -> Version {
+    Version {
+        major: pkg_version_major!(),
+        minor: pkg_version_minor!(),
+        patch: pkg_version_patch!(),
+        pre: vec![],
+        build: vec![],
+    }
+}
+
+pub fn latest_release() -> Result<Release, String> {
+    #[derive(serde::Deserialize)]
+    struct GithubRelease {
+        tag_name: String,
+        html_url: String,
+        body: String,
+    }
+
+    let release = ureq::get(LATEST_RELEASE_URL)
+        .call()
+        .map_err(|e| format!("Couldn't check version: {:?}", e))?
+        .into_json::<GithubRelease>()
+        .map_err(|e| format!("Couldn't check version: {:?}", e))?;
+
+    let version = Version::parse(&release.tag_name).map_err(|e| format!("Error: {e}"))?;
+
+    Ok(Release { version, url: release.html_url, notes: release.body })
+}
+
+/// Check running in the background, started along with the tool.
+pub(crate) struct UpdateCheck {
+    newer: Arc<OnceCell<Release>>,
+    dismissed: bool,
+}
+
+impl UpdateCheck {
+    pub(crate) fn start() -> Self {
+        let newer = Arc::new(OnceCell::new());
+        let result = Arc::clone(&newer);
+
+        thread::spawn(move || match latest_release() {
+            Ok(release) if release.version > current_version() => {
+                info!("Version {} is available", release.version);
+                result.set(release).ok();
+            },
+            Ok(_) => info!("The tool is up to date"),
+            Err(e) => warn!("{e}"),
+        });
+
+        UpdateCheck { newer, dismissed: false }
+    }
+
+    /// The newer release, if one was found and the banner wasn't dismissed.
+    pub(crate) fn available(&self) -> Option<&Release> {
+        self.newer.get().filter(|_| !self.dismissed)
+    }
+
+    pub(crate) fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
+}
@@ -1,14 +1,19 @@
 use std::ffi::OsString;
+use std::fmt;
 use std::os::windows::prelude::OsStringExt;
 use std::path::PathBuf;
 
 use hudhook::tracing::error;
+use practice_tool_core::key::Key;
 use windows::core::PCSTR;
 use windows::Win32::Foundation::{HMODULE, MAX_PATH};
 use windows::Win32::System::LibraryLoader::{
     GetModuleFileNameW, GetModuleHandleExA, GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS,
     GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
 };
+use windows::Win32::System::Threading::GetCurrentProcessId;
+use windows::Win32::UI::Input::KeyboardAndMouse::*;
+use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
 
 /// Returns the path of the implementor's DLL.
 pub fn get_dll_path() -> Option<PathBuf> {
@@ -34,3 +39,182 @@ pub fn get_dll_path() -> Option<PathBuf> {
 
     Some(OsString::from_wide(&sz_filename[..len]).into())
 }
+
+/// Synthesizes a press and release of the key with the given scan code. The
+/// game reads raw keyboard input, so virtual key codes aren't enough.
+pub fn press_key(scan: u16) {
+    let input = |flags| INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 { ki: KEYBDINPUT { wScan: scan, dwFlags: flags, ..Default::default() } },
+    };
+
+    let inputs = [input(KEYEVENTF_SCANCODE), input(KEYEVENTF_SCANCODE | KEYEVENTF_KEYUP)];
+    unsafe { SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) };
+}
+
+/// Names of the keys that aren't a letter, a digit or a function key, as
+/// written in hotkeys, and their virtual key codes.
+const KEY_NAMES: [(&str, VIRTUAL_KEY); 46] = [
+    ("ctrl", VK_CONTROL),
+    ("lctrl", VK_LCONTROL),
+    ("rctrl", VK_RCONTROL),
+    ("shift", VK_SHIFT),
+    ("lshift", VK_LSHIFT),
+    ("rshift", VK_RSHIFT),
+    ("alt", VK_MENU),
+    ("lalt", VK_LMENU),
+    ("ralt", VK_RMENU),
+    ("space", VK_SPACE),
+    ("enter", VK_RETURN),
+    ("tab", VK_TAB),
+    ("esc", VK_ESCAPE),
+    ("backspace", VK_BACK),
+    ("insert", VK_INSERT),
+    ("delete", VK_DELETE),
+    ("home", VK_HOME),
+    ("end", VK_END),
+    ("pageup", VK_PRIOR),
+    ("pagedown", VK_NEXT),
+    ("left", VK_LEFT),
+    ("right", VK_RIGHT),
+    ("up", VK_UP),
+    ("down", VK_DOWN),
+    ("[", VK_OEM_4),
+    ("]", VK_OEM_6),
+    (",", VK_OEM_COMMA),
+    (".", VK_OEM_PERIOD),
+    ("/", VK_OEM_2),
+    (";", VK_OEM_1),
+    ("-", VK_OEM_MINUS),
+    ("=", VK_OEM_PLUS),
+    ("numpad0", VK_NUMPAD0),
+    ("numpad1", VK_NUMPAD1),
+    ("numpad2", VK_NUMPAD2),
+    ("numpad3", VK_NUMPAD3),
+    ("numpad4", VK_NUMPAD4),
+    ("numpad5", VK_NUMPAD5),
+    ("numpad6", VK_NUMPAD6),
+    ("numpad7", VK_NUMPAD7),
+    ("numpad8", VK_NUMPAD8),
+    ("numpad9", VK_NUMPAD9),
+    ("multiply", VK_MULTIPLY),
+    ("add", VK_ADD),
+    ("subtract", VK_SUBTRACT),
+    ("divide", VK_DIVIDE),
+];
+
+fn virtual_key(name: &str) -> Option<u16> {
+    if let Some((_, vk)) = KEY_NAMES.iter().find(|(n, _)| *n == name) {
+        return Some(vk.0);
+    }
+
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c @ ('a'..='z' | '0'..='9')), None) => Some(c.to_ascii_uppercase() as u16),
+        (Some('f'), Some(_)) => match name[1..].parse::<u16>() {
+            Ok(n @ 1..=24) => Some(VK_F1.0 + n - 1),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Virtual key codes of a hotkey, in the order it names them, for pressing or
+/// polling it outside of imgui. Returns `None` if one of its keys has no
+/// virtual key code here.
+pub fn virtual_keys(key: &Key) -> Option<Vec<u16>> {
+    key.to_string().split('+').map(|key| virtual_key(&key.trim().to_lowercase())).collect()
+}
+
+/// Name of a key in hotkeys, the inverse of [`virtual_keys`] for a single
+/// key. Returns `None` for keys that [`Key`] can't parse.
+pub fn key_name(vk: u16) -> Option<String> {
+    let name = match vk {
+        0x30..=0x39 | 0x41..=0x5a => (vk as u8 as char).to_ascii_lowercase().to_string(),
+        vk if (VK_F1.0..=VK_F24.0).contains(&vk) => format!("f{}", vk - VK_F1.0 + 1),
+        vk => KEY_NAMES.iter().find(|(_, k)| k.0 == vk)?.0.to_string(),
+    };
+
+    name.parse::<Key>().is_ok().then_some(name)
+}
+
+/// Virtual keys whose scan codes have the `0xE0` prefix. The scan code alone
+/// is the same as the one of another key, such as the numpad arrows for the
+/// arrow keys, so these are sent with `KEYEVENTF_EXTENDEDKEY`.
+const EXTENDED_KEYS: [VIRTUAL_KEY; 17] = [
+    VK_INSERT,
+    VK_DELETE,
+    VK_HOME,
+    VK_END,
+    VK_PRIOR,
+    VK_NEXT,
+    VK_LEFT,
+    VK_RIGHT,
+    VK_UP,
+    VK_DOWN,
+    VK_RCONTROL,
+    VK_RMENU,
+    VK_LWIN,
+    VK_RWIN,
+    VK_APPS,
+    VK_DIVIDE,
+    VK_NUMLOCK,
+];
+
+/// Builds the input event pressing or releasing a key, given its virtual key
+/// code.
+fn key_input(vk: u16, down: bool) -> INPUT {
+    let mut flags = KEYEVENTF_SCANCODE;
+    if !down {
+        flags |= KEYEVENTF_KEYUP;
+    }
+    if EXTENDED_KEYS.contains(&VIRTUAL_KEY(vk)) {
+        flags |= KEYEVENTF_EXTENDEDKEY;
+    }
+
+    INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wScan: unsafe { MapVirtualKeyW(vk as u32, MAPVK_VK_TO_VSC) } as u16,
+                dwFlags: flags,
+                ..Default::default()
+            },
+        },
+    }
+}
+
+/// Synthesizes pressing or releasing a single key, given its virtual key code.
+pub fn send_key(vk: u16, down: bool) {
+    unsafe { SendInput(&[key_input(vk, down)], std::mem::size_of::<INPUT>() as i32) };
+}
+
+/// Synthesizes pressing a key combination, as returned by [`virtual_keys`],
+/// and releasing it in reverse order.
+pub fn press_key_combo(vks: &[u16]) {
+    let inputs = vks
+        .iter()
+        .map(|&vk| key_input(vk, true))
+        .chain(vks.iter().rev().map(|&vk| key_input(vk, false)))
+        .collect::<Vec<_>>();
+    unsafe { SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) };
+}
+
+/// Whether the game's window is in the foreground, so that synthesized input
+/// goes to the game rather than to another window.
+pub fn game_is_foreground() -> bool {
+    let mut pid = 0;
+    unsafe { GetWindowThreadProcessId(GetForegroundWindow(), Some(&mut pid)) };
+    pid == unsafe { GetCurrentProcessId() }
+}
+
+/// Writes the in-game time, given in milliseconds, as shown by the IGT
+/// indicator.
+pub fn write_igt(w: &mut impl fmt::Write, igt: u32) -> fmt::Result {
+    let millis = (igt % 1000) / 10;
+    let total_seconds = igt / 1000;
+    let seconds = total_seconds % 60;
+    let minutes = total_seconds / 60 % 60;
+    let hours = total_seconds / 3600;
+    write!(w, "IGT {hours:02}:{minutes:02}:{seconds:02}.{millis:02}")
+}
@@ -0,0 +1,154 @@
+use libds3::memedit::PointerChain;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::{feedback, restore};
+
+/// Shows the player's current animation and lets it be slowed down, paused,
+/// scrubbed through or restarted.
+pub(crate) struct Animation {
+    anim: PointerChain<u32>,
+    time: PointerChain<f32>,
+    length: PointerChain<f32>,
+    speed: PointerChain<f32>,
+    /// Speed to restore when resuming, if paused.
+    paused: Option<f32>,
+    slider_speed: f32,
+    key_pause: Option<Key>,
+    key_restart: Option<Key>,
+    label_pause: String,
+    label_resume: String,
+    label_restart: String,
+    logs: Vec<String>,
+}
+
+impl Animation {
+    pub(crate) fn new(
+        anim: PointerChain<u32>,
+        time: PointerChain<f32>,
+        length: PointerChain<f32>,
+        speed: PointerChain<f32>,
+        key_pause: Option<Key>,
+        key_restart: Option<Key>,
+    ) -> Self {
+        let with_key = |label: &str, key: Option<Key>| match key {
+            Some(k) => format!("{label} ({k})"),
+            None => label.to_string(),
+        };
+
+        Animation {
+            label_pause: with_key("Pause", key_pause),
+            label_resume: with_key("Resume", key_pause),
+            label_restart: with_key("Restart", key_restart),
+            anim,
+            time,
+            length,
+            speed,
+            paused: None,
+            slider_speed: 1.0,
+            key_pause,
+            key_restart,
+            logs: Vec::new(),
+        }
+    }
+
+    fn toggle_pause(&mut self) {
+        match self.paused.take() {
+            Some(speed) => {
+                self.speed.write(speed);
+                self.logs.push("Animation resumed".to_string());
+                feedback::executed();
+            },
+            None => {
+                let Some(speed) = self.speed.read() else {
+                    return;
+                };
+                self.paused = Some(speed);
+                restore::remember(&self.speed);
+                self.speed.write(0.);
+                self.logs.push("Animation paused".to_string());
+                feedback::executed();
+            },
+        }
+    }
+
+    fn restart(&mut self) {
+        if self.time.write(0.).is_some() {
+            feedback::executed();
+        }
+    }
+}
+
+impl Widget for Animation {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        let button_width = BUTTON_WIDTH * scale;
+        let style = ui.clone_style();
+        let half_width = (button_width - style.item_spacing[0]) / 2.;
+
+        let (Some(anim), Some(mut time), Some(length)) =
+            (self.anim.read(), self.time.read(), self.length.read())
+        else {
+            ui.text("Animation unavailable");
+            return;
+        };
+
+        ui.text(format!("Animation {anim}"));
+
+        ui.set_next_item_width(button_width);
+        if ui
+            .slider_config("##animation-time", 0., length.max(0.))
+            .display_format("%.3fs")
+            .build(&mut time)
+        {
+            self.time.write(time);
+        }
+
+        ui.set_next_item_width(button_width);
+        if ui
+            .slider_config("##animation-speed", 0.05, 2.)
+            .display_format("%.2fx")
+            .build(&mut self.slider_speed)
+        {
+            match self.paused.as_mut() {
+                Some(speed) => *speed = self.slider_speed,
+                None => {
+                    restore::remember(&self.speed);
+                    self.speed.write(self.slider_speed);
+                },
+            }
+        }
+
+        let label_pause =
+            if self.paused.is_some() { &self.label_resume } else { &self.label_pause };
+        if ui.button_with_size(label_pause, [half_width, BUTTON_HEIGHT]) {
+            self.toggle_pause();
+        }
+        ui.same_line();
+        if ui.button_with_size(&self.label_restart, [half_width, BUTTON_HEIGHT]) {
+            self.restart();
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        if self.paused.is_some() {
+            ui.text("Animation paused");
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.key_pause.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.toggle_pause();
+        }
+        if self.key_restart.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.restart();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
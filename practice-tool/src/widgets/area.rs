@@ -0,0 +1,110 @@
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::widgets::Widget;
+use serde::Deserialize;
+
+/// An area, written as `"m30"`, or one of its blocks, written as `"m30_01"`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(try_from = "String")]
+pub(crate) struct AreaSpec {
+    area: u8,
+    block: Option<u8>,
+}
+
+impl AreaSpec {
+    /// The block of the given map.
+    pub(crate) fn of(map_id: MapId) -> Self {
+        AreaSpec { area: map_id.area(), block: Some(map_id.block()) }
+    }
+
+    pub(crate) fn matches(&self, map_id: MapId) -> bool {
+        map_id.area() == self.area && self.block.map(|b| b == map_id.block()).unwrap_or(true)
+    }
+}
+
+impl TryFrom<String> for AreaSpec {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("Invalid area {value:?}, expected e.g. \"m30\" or \"m30_01\"");
+        let mut parts = value.strip_prefix('m').ok_or_else(invalid)?.split('_');
+        let mut number = || parts.next().map(|p| p.parse::<u8>().map_err(|_| invalid()));
+
+        let area = number().ok_or_else(invalid)??;
+        let block = number().transpose()?;
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(AreaSpec { area, block })
+    }
+}
+
+/// A named point in the world, e.g. the start of a skip.
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct NamedPoint {
+    pub(crate) name: String,
+    pub(crate) position: [f32; 3],
+    /// Only shows the point in the given area.
+    pub(crate) area: Option<AreaSpec>,
+}
+
+impl NamedPoint {
+    pub(crate) fn is_in(&self, map_id: MapId) -> bool {
+        self.area.map(|a| a.matches(map_id)).unwrap_or(true)
+    }
+}
+
+/// Commands that only react to their hotkeys while the player is in one of
+/// the given areas. Several of them can share a hotkey, so that it does
+/// something different depending on where the player is.
+struct InArea {
+    areas: Vec<AreaSpec>,
+    map_id: PointerChain<MapId>,
+    commands: Vec<Box<dyn Widget>>,
+}
+
+impl InArea {
+    fn is_active(&self) -> bool {
+        self.map_id.read().map(|id| self.areas.iter().any(|a| a.matches(id))).unwrap_or(false)
+    }
+}
+
+impl Widget for InArea {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let _tok = ui.begin_disabled(!self.is_active());
+        for c in self.commands.iter_mut() {
+            c.render(ui);
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        for c in self.commands.iter_mut() {
+            c.render_closed(ui);
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if !self.is_active() {
+            return;
+        }
+
+        for c in self.commands.iter_mut() {
+            c.interact(ui);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for c in self.commands.iter_mut() {
+            c.log(tx.clone());
+        }
+    }
+}
+
+pub(crate) fn in_area(
+    areas: Vec<AreaSpec>,
+    map_id: PointerChain<MapId>,
+    commands: Vec<Box<dyn Widget>>,
+) -> Box<dyn Widget> {
+    Box::new(InArea { areas, map_id, commands })
+}
@@ -0,0 +1,199 @@
+use imgui::InputText;
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use super::item_spawn::{loaded_item_ids, ItemIDNode, ItemIDTree};
+use crate::feedback;
+
+const ATT_TAG: &str = "##attunement";
+
+/// Spell goods IDs are the `Magic` param IDs with the goods category bit set.
+const GOODS_MASK: u32 = 0x0fff_ffff;
+
+/// The spell categories of the item tree (sorceries, pyromancies, miracles).
+fn spells(tree: &'static [ItemIDNode]) -> &'static [ItemIDNode] {
+    fn children<'a>(nodes: &'a [ItemIDNode], name: &str) -> &'a [ItemIDNode] {
+        nodes
+            .iter()
+            .find_map(|n| match n {
+                ItemIDNode::Node { node, children } if node == name => Some(children.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    children(children(tree, "Items"), "Spells")
+}
+
+fn spell_name(magic_id: i32) -> Option<&'static str> {
+    fn find(node: &'static ItemIDNode, magic_id: u32) -> Option<&'static str> {
+        match node {
+            ItemIDNode::Leaf { id, desc } => {
+                (id.0 & GOODS_MASK == magic_id).then_some(desc.as_str())
+            },
+            ItemIDNode::Node { children, .. } => children.iter().find_map(|c| find(c, magic_id)),
+        }
+    }
+
+    if magic_id < 0 {
+        return None;
+    }
+
+    spells(loaded_item_ids()?).iter().find_map(|node| find(node, magic_id as u32))
+}
+
+pub(crate) struct Attunement<'a> {
+    ptr: PointerChain<[AttunementSlot; ATTUNEMENT_SLOTS]>,
+    hotkey_open: Option<Key>,
+    hotkey_close: Key,
+    label_open: String,
+    label_close: String,
+    slots: [AttunementSlot; ATTUNEMENT_SLOTS],
+    slot_labels: Vec<String>,
+    selected_slot: usize,
+    spell_id: u32,
+    filter_string: String,
+    spell_tree: ItemIDTree<'a>,
+    logs: Vec<String>,
+}
+
+impl Attunement<'_> {
+    pub(crate) fn new(
+        ptr: PointerChain<[AttunementSlot; ATTUNEMENT_SLOTS]>,
+        hotkey_open: Option<Key>,
+        hotkey_close: Key,
+    ) -> Self {
+        let label_open = match hotkey_open {
+            Some(k) => format!("Attuned spells ({k})"),
+            None => "Attuned spells".to_string(),
+        };
+
+        Attunement {
+            ptr,
+            hotkey_open,
+            hotkey_close,
+            label_open,
+            label_close: format!("Close ({hotkey_close})"),
+            slots: Default::default(),
+            slot_labels: Vec::new(),
+            selected_slot: 0,
+            spell_id: 0,
+            filter_string: String::new(),
+            spell_tree: ItemIDTree::new(spells),
+            logs: Vec::new(),
+        }
+    }
+
+    fn read(&mut self) -> bool {
+        let Some(slots) = self.ptr.read() else {
+            return false;
+        };
+
+        self.slots = slots;
+        self.slot_labels = slots
+            .iter()
+            .enumerate()
+            .map(|(i, slot)| match (slot.magic_id, spell_name(slot.magic_id)) {
+                (-1, _) => format!("{:>2}. -", i + 1),
+                (_, Some(name)) => format!("{:>2}. {name}", i + 1),
+                (id, None) => format!("{:>2}. #{id}", i + 1),
+            })
+            .collect();
+
+        true
+    }
+
+    fn write_slot(&mut self, magic_id: i32) {
+        let mut slots = self.slots;
+        slots[self.selected_slot].magic_id = magic_id;
+
+        if self.ptr.write(slots).is_none() {
+            self.logs.push("Couldn't write attunement slots".to_string());
+            return;
+        }
+
+        self.logs.push(match spell_name(magic_id) {
+            Some(name) => format!("Attuned {name} to slot {}", self.selected_slot + 1),
+            None => format!("Cleared slot {}", self.selected_slot + 1),
+        });
+        feedback::executed();
+        self.read();
+    }
+}
+
+impl Widget for Attunement<'_> {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        let button_width = BUTTON_WIDTH * scale;
+
+        if ui.button_with_size(&self.label_open, [button_width, BUTTON_HEIGHT]) {
+            if self.read() {
+                ui.open_popup(ATT_TAG);
+            } else {
+                self.logs.push("Attunement slots unavailable when not in game".to_string());
+            }
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(ATT_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .scroll_bar(false)
+            .begin_popup()
+        {
+            let button_height = BUTTON_HEIGHT * scale;
+
+            ui.child_window("##attunement-slots").size([400., 150.]).build(|| {
+                for (i, label) in self.slot_labels.iter().enumerate() {
+                    if ui.selectable_config(label).selected(i == self.selected_slot).build() {
+                        self.selected_slot = i;
+                    }
+                }
+            });
+
+            {
+                let _tok = ui.push_item_width(-1.);
+                if InputText::new(ui, "##attunement-filter", &mut self.filter_string)
+                    .hint("Filter...")
+                    .build()
+                {
+                    self.spell_tree.set_filter(&self.filter_string);
+                }
+            }
+
+            ui.child_window("##attunement-spells").size([400., 200.]).build(|| {
+                self.spell_tree.render(ui, &mut self.spell_id);
+            });
+
+            if ui.button_with_size("Attune to slot", [400., button_height]) && self.spell_id != 0 {
+                self.write_slot((self.spell_id & GOODS_MASK) as i32);
+            }
+
+            if ui.button_with_size("Clear slot", [400., button_height]) {
+                self.write_slot(-1);
+            }
+
+            if ui.button_with_size(&self.label_close, [400., button_height])
+                || (self.hotkey_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey_open.map(|k| k.is_pressed(ui)).unwrap_or(false) && self.read() {
+            ui.open_popup(ATT_TAG);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use imgui::InputText;
+use libds3::prelude::boss_positions::BOSS_POSITIONS;
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use serde::Deserialize;
+
+use super::refill::refill_estus;
+use super::scenario::ScenarioStats;
+use crate::feedback;
+
+const POPUP_TAG: &str = "##boss-practice";
+
+/// Setup for a boss, from `[[settings.boss_presets]]`. Presets named after a
+/// boss of the built-in list override its entry; others need a defeat flag.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BossPreset {
+    name: String,
+    flag: Option<u32>,
+    /// Where to stand, such as in front of the fog gate.
+    position: Option<[f32; 4]>,
+    #[serde(default)]
+    stats: ScenarioStats,
+}
+
+struct Boss {
+    name: String,
+    flag: u32,
+    position: Option<[f32; 4]>,
+    stats: ScenarioStats,
+}
+
+impl Boss {
+    /// Area and block of the boss's map. Defeated flags are numbered
+    /// `1AAB____` after it, like `14000800` for Iudex Gundyr in `m40_00`.
+    fn map(&self) -> Option<(u8, u8)> {
+        (10_000_000..20_000_000)
+            .contains(&self.flag)
+            .then(|| (((self.flag / 100_000) % 100) as u8, ((self.flag / 10_000) % 10) as u8))
+    }
+}
+
+/// Searchable list of bosses. Applying a setup warps the player if the boss
+/// has a position, clears the boss's defeated flag, refills estus, sets the
+/// configured attributes and quits out, so that the boss is back once the save
+/// is loaded.
+struct BossPractice {
+    bosses: Vec<Boss>,
+    pointers: PointerChains,
+    filter: String,
+    selected: Option<usize>,
+    hotkey: Option<Key>,
+    key_close: Key,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl BossPractice {
+    fn apply(&mut self, idx: usize) {
+        let boss = &self.bosses[idx];
+        let pointers = &self.pointers;
+
+        if pointers.hp.read().is_none() {
+            self.logs.push(format!("Can't set up {} when not in game", boss.name));
+            return;
+        }
+
+        // Positions are local to the map, so warping from another one would
+        // leave the player somewhere random.
+        let map = pointers.map_id.read().map(|map| (map.area(), map.block()));
+        match (boss.position, boss.map()) {
+            (Some(_), Some(boss_map)) if map != Some(boss_map) => {
+                let area = MapId(u32::from_be_bytes([boss_map.0, boss_map.1, 0, 0])).name();
+                self.logs.push(format!(
+                    "Not warping to {}: go to {} first",
+                    boss.name,
+                    area.unwrap_or("the boss's area")
+                ));
+            },
+            (Some([x, y, z, angle]), _) => {
+                pointers.position.1.write([x, y, z]);
+                pointers.position.0.write(angle);
+            },
+            (None, _) => {},
+        }
+
+        if EventFlag(boss.flag).set(false).is_none() {
+            self.logs.push(format!("Couldn't resurrect {}", boss.name));
+            return;
+        }
+
+        if !boss.stats.is_empty() {
+            if let Some(mut stats) = pointers.character_stats.read() {
+                boss.stats.apply(&mut stats);
+                pointers.character_stats.write(stats);
+            }
+        }
+
+        refill_estus(&pointers.inventory, &pointers.estus_allocation);
+        pointers.quitout.write(1);
+        self.logs.push(format!("{} set up, reload the save to fight", boss.name));
+        feedback::executed();
+    }
+
+    fn matches(&self, boss: &Boss) -> bool {
+        boss.name.to_lowercase().contains(&self.filter.to_lowercase())
+    }
+}
+
+impl Widget for BossPractice {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            ui.open_popup(POPUP_TAG);
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(POPUP_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .begin_popup()
+        {
+            {
+                let _tok = ui.push_item_width(-1.);
+                InputText::new(ui, "##boss-practice-filter", &mut self.filter)
+                    .hint("Filter...")
+                    .build();
+            }
+
+            let mut apply = None;
+            ui.child_window("##boss-practice-list").size([400., 200.]).build(|| {
+                for (idx, boss) in self.bosses.iter().enumerate() {
+                    if !self.matches(boss) {
+                        continue;
+                    }
+                    if ui.selectable_config(&boss.name).selected(self.selected == Some(idx)).build()
+                    {
+                        apply = Some(idx);
+                    }
+                }
+            });
+
+            if let Some(idx) = apply {
+                self.selected = Some(idx);
+                self.apply(idx);
+                ui.close_current_popup();
+            }
+
+            if ui.button_with_size(format!("Close ({})", self.key_close), [400., BUTTON_HEIGHT])
+                || (self.key_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if !self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            return;
+        }
+
+        match self.selected {
+            Some(idx) => self.apply(idx),
+            None => self.logs.push("No boss selected yet".to_string()),
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+/// Builds the list from the built-in bosses, overridden and extended by the
+/// configured presets.
+fn bosses(presets: &[BossPreset], logs: &mut Vec<String>) -> Vec<Boss> {
+    let mut bosses: Vec<Boss> = BOSS_FLAGS
+        .iter()
+        .map(|&(flag, name)| Boss {
+            name: name.to_string(),
+            flag,
+            position: BOSS_POSITIONS.iter().find(|(f, _)| *f == flag).map(|(_, p)| *p),
+            stats: ScenarioStats::default(),
+        })
+        .collect();
+
+    for preset in presets {
+        let preset = preset.clone();
+        match bosses.iter_mut().find(|b| b.name.eq_ignore_ascii_case(&preset.name)) {
+            Some(boss) => {
+                boss.flag = preset.flag.unwrap_or(boss.flag);
+                boss.position = preset.position.or(boss.position);
+                boss.stats = preset.stats;
+            },
+            None => match preset.flag {
+                Some(flag) => bosses.push(Boss {
+                    name: preset.name,
+                    flag,
+                    position: preset.position,
+                    stats: preset.stats,
+                }),
+                None => logs.push(format!("Boss preset \"{}\" needs a flag", preset.name)),
+            },
+        }
+    }
+
+    bosses
+}
+
+pub(crate) fn boss_practice(
+    presets: &[BossPreset],
+    hotkey: Option<Key>,
+    key_close: Key,
+) -> Box<dyn Widget> {
+    let mut logs = Vec::new();
+    let bosses = bosses(presets, &mut logs);
+    let label = match hotkey {
+        Some(k) => format!("Boss practice ({k})"),
+        None => "Boss practice".to_string(),
+    };
+
+    Box::new(BossPractice {
+        bosses,
+        pointers: PointerChains::new(),
+        filter: String::new(),
+        selected: None,
+        hotkey,
+        key_close,
+        label,
+        logs,
+    })
+}
@@ -3,6 +3,8 @@ use practice_tool_core::key::Key;
 use practice_tool_core::widgets::stats_editor::{Datum, Stats, StatsEditor};
 use practice_tool_core::widgets::Widget;
 
+use crate::feedback;
+
 #[derive(Debug)]
 struct CharacterStatsEdit {
     ptr: PointerChain<CharacterStats>,
@@ -35,7 +37,9 @@ impl Stats for CharacterStatsEdit {
 
     fn write(&mut self) {
         if let Some(stats) = self.stats.clone() {
-            self.ptr.write(stats);
+            if self.ptr.write(stats).is_some() {
+                feedback::executed();
+            }
         }
     }
 
@@ -0,0 +1,194 @@
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use super::item_spawn::ItemSpawnInstance;
+use crate::feedback;
+
+const COVENANT_TAG: &str = "##covenant";
+
+const GOODS: u32 = 0x40000000;
+
+const COVENANTS: [&str; 9] = [
+    "None",
+    "Blade of the Darkmoon",
+    "Warrior of Sunlight",
+    "Mound-makers",
+    "Way of Blue",
+    "Blue Sentinels",
+    "Rosaria's Fingers",
+    "Watchdogs of Farron",
+    "Aldrich Faithful",
+];
+
+/// Items offered to covenants, as goods IDs without the category.
+const COVENANT_ITEMS: [(u32, &str); 8] = [
+    (375, "Sunlight Medal"),
+    (374, "Vertebra Shackle"),
+    (372, "Proof of a Concord Kept"),
+    (369, "Proof of a Concord Well Kept"),
+    (373, "Pale Tongue"),
+    (368, "Forked Pale Tongue"),
+    (366, "Wolf's Blood Swordgrass"),
+    (367, "Human Dregs"),
+];
+
+/// Shows and changes the current covenant and how many of each covenant item
+/// the player holds. Items that aren't in the inventory are spawned.
+pub(crate) struct Covenant {
+    covenant: PointerChain<u8>,
+    inventory: PointerChain<[InventoryItem; INVENTORY_SLOTS]>,
+    func_ptr: usize,
+    map_item_man: usize,
+    counts: [i32; COVENANT_ITEMS.len()],
+    hotkey_open: Option<Key>,
+    hotkey_close: Key,
+    label_open: String,
+    label_close: String,
+    logs: Vec<String>,
+}
+
+impl Covenant {
+    pub(crate) fn new(chains: &PointerChains, hotkey_open: Option<Key>, hotkey_close: Key) -> Self {
+        let label_open = match hotkey_open {
+            Some(k) => format!("Covenant ({k})"),
+            None => "Covenant".to_string(),
+        };
+
+        Covenant {
+            covenant: chains.covenant.clone(),
+            inventory: chains.inventory.clone(),
+            func_ptr: chains.spawn_item_func_ptr as usize,
+            map_item_man: chains.map_item_man as usize,
+            counts: [0; COVENANT_ITEMS.len()],
+            hotkey_open,
+            hotkey_close,
+            label_open,
+            label_close: format!("Close ({hotkey_close})"),
+            logs: Vec::new(),
+        }
+    }
+
+    fn read_counts(&mut self) {
+        let Some(inventory) = self.inventory.read() else {
+            return;
+        };
+
+        for ((id, _), count) in COVENANT_ITEMS.iter().zip(self.counts.iter_mut()) {
+            *count = inventory
+                .iter()
+                .find(|item| item.id == GOODS | id)
+                .map(|item| item.quantity as i32)
+                .unwrap_or(0);
+        }
+    }
+
+    fn set_count(&mut self, idx: usize) {
+        let (id, name) = COVENANT_ITEMS[idx];
+        let count = self.counts[idx].clamp(0, 99) as u32;
+
+        let Some(mut inventory) = self.inventory.read() else {
+            self.logs.push("Covenant items unavailable when not in game".to_string());
+            return;
+        };
+
+        match inventory.iter_mut().find(|item| item.id == GOODS | id) {
+            Some(item) => {
+                item.quantity = count;
+                self.inventory.write(inventory);
+            },
+            None if count > 0 => unsafe {
+                ItemSpawnInstance {
+                    spawn_item_func_ptr: self.func_ptr as _,
+                    map_item_man: self.map_item_man as _,
+                    qty: count,
+                    durability: 100,
+                    item_id: GOODS | id,
+                    infusion: 0,
+                    upgrade: 0,
+                }
+                .spawn();
+            },
+            None => {},
+        }
+
+        self.logs.push(format!("{name} set to {count}"));
+        feedback::executed();
+    }
+
+    fn render_popup(&mut self, ui: &imgui::Ui) {
+        let Some(current) = self.covenant.read() else {
+            ui.text("Covenant unavailable when not in game");
+            return;
+        };
+
+        let mut selected = current as usize;
+        ui.set_next_item_width(300.);
+        if ui.combo_simple_string("Covenant##covenant-select", &mut selected, &COVENANTS)
+            && selected != current as usize
+        {
+            self.covenant.write(selected as u8);
+            self.logs.push(format!("Covenant set to {}", COVENANTS[selected]));
+            feedback::executed();
+        }
+
+        let mut set = None;
+        for (idx, (_, name)) in COVENANT_ITEMS.iter().enumerate() {
+            ui.set_next_item_width(150.);
+            ui.input_int(format!("##covenant-item-{idx}"), &mut self.counts[idx]).build();
+            ui.same_line();
+            if ui.button(format!("Set##covenant-item-{idx}")) {
+                set = Some(idx);
+            }
+            ui.same_line();
+            ui.text(name);
+        }
+
+        if let Some(idx) = set {
+            self.set_count(idx);
+        }
+    }
+}
+
+impl Widget for Covenant {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+
+        if ui.button_with_size(&self.label_open, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            self.read_counts();
+            ui.open_popup(COVENANT_TAG);
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(COVENANT_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            self.render_popup(ui);
+
+            if ui.button_with_size(&self.label_close, [400., BUTTON_HEIGHT])
+                || (self.hotkey_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey_open.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.read_counts();
+            ui.open_popup(COVENANT_TAG);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
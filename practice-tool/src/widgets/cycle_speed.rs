@@ -2,35 +2,102 @@ use std::cmp::Ordering;
 use std::fmt::Write;
 
 use libds3::memedit::PointerChain;
+use libds3::pointers::PointerChains;
+use practice_tool_core::crossbeam_channel::Sender;
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::store_value::{ReadWrite, StoreValue};
-use practice_tool_core::widgets::Widget;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use serde::Deserialize;
+
+use crate::widgets::target::target_speed;
+use crate::{feedback, restore};
+
+/// Whose speed a speed cycler changes.
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum SpeedTarget {
+    /// The player character's animation speed.
+    #[default]
+    Player,
+    /// The locked on enemy's animation speed. Needs the target entity info
+    /// widget to be enabled to know which enemy that is.
+    Enemy,
+}
+
+impl SpeedTarget {
+    pub(crate) fn speed(self, chains: &PointerChains) -> Speed {
+        match self {
+            SpeedTarget::Player => Speed::Player(chains.speed.clone()),
+            SpeedTarget::Enemy => Speed::Enemy(chains.xa),
+        }
+    }
+}
+
+/// Where a speed is read from and written to.
+#[derive(Debug)]
+pub(crate) enum Speed {
+    Player(PointerChain<f32>),
+    /// Resolved on every access, as the locked on enemy changes.
+    Enemy(u32),
+}
+
+impl Speed {
+    fn name(&self) -> &'static str {
+        match self {
+            Speed::Player(_) => "Speed",
+            Speed::Enemy(_) => "Enemy speed",
+        }
+    }
+
+    fn read(&self) -> Option<f32> {
+        match self {
+            Speed::Player(ptr) => ptr.read(),
+            Speed::Enemy(xa) => target_speed(*xa)?.read(),
+        }
+    }
+
+    fn write(&self, value: f32) {
+        let written = match self {
+            Speed::Player(ptr) => {
+                restore::remember(ptr);
+                ptr.write(value)
+            },
+            // Enemies come back at their normal speed once reloaded, so
+            // there's nothing to restore.
+            Speed::Enemy(xa) => target_speed(*xa).and_then(|ptr| ptr.write(value)),
+        };
+
+        if written.is_some() {
+            feedback::executed();
+        }
+    }
+}
 
 #[derive(Debug)]
 struct CycleSpeed {
-    ptr: PointerChain<f32>,
+    speed: Speed,
     values: Vec<f32>,
     current: Option<f32>,
     label: String,
 }
 
 impl CycleSpeed {
-    fn new(values: &[f32], ptr: PointerChain<f32>) -> Self {
+    fn new(values: &[f32], speed: Speed) -> Self {
         let mut values = values.to_vec();
         values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
-        CycleSpeed { ptr, values, current: None, label: String::new() }
+        CycleSpeed { speed, values, current: None, label: String::new() }
     }
 }
 
 impl ReadWrite for CycleSpeed {
     fn read(&mut self) -> bool {
-        self.current = self.ptr.read();
+        self.current = self.speed.read();
 
         self.label.clear();
 
         match self.current {
-            Some(c) => write!(self.label, "Speed [{:.1}x]", c).ok(),
-            None => write!(self.label, "Speed").ok(),
+            Some(c) => write!(self.label, "{} [{:.1}x]", self.speed.name(), c).ok(),
+            None => write!(self.label, "{}", self.speed.name()).ok(),
         };
 
         self.current.is_some()
@@ -42,7 +109,7 @@ impl ReadWrite for CycleSpeed {
             .and_then(|current| self.values.iter().find(|&&x| x > current))
             .unwrap_or_else(|| self.values.first().unwrap_or(&1.0));
 
-        self.ptr.write(next);
+        self.speed.write(next);
     }
 
     fn label(&self) -> &str {
@@ -50,10 +117,94 @@ impl ReadWrite for CycleSpeed {
     }
 }
 
-pub(crate) fn cycle_speed(
-    values: &[f32],
-    ptr: PointerChain<f32>,
-    key: Option<Key>,
+pub(crate) fn cycle_speed(values: &[f32], speed: Speed, key: Option<Key>) -> Box<dyn Widget> {
+    Box::new(StoreValue::new(CycleSpeed::new(values, speed), key))
+}
+
+/// Raises and lowers the speed by a fixed step, within `[min, max]`, so that
+/// it can be dialed in without listing every value.
+struct StepSpeed {
+    speed: Speed,
+    step: f32,
+    min: f32,
+    max: f32,
+    key_up: Option<Key>,
+    key_down: Option<Key>,
+    label_up: String,
+    label_down: String,
+    logs: Vec<String>,
+}
+
+impl StepSpeed {
+    fn change(&mut self, delta: f32) {
+        let Some(current) = self.speed.read() else {
+            return;
+        };
+
+        // Rounded to the step, so that repeated steps don't accumulate errors.
+        let next = (((current + delta) / self.step).round() * self.step).clamp(self.min, self.max);
+        self.speed.write(next);
+        self.logs.push(format!("{} set to {next:.2}x", self.speed.name()));
+    }
+}
+
+impl Widget for StepSpeed {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        let button_width = BUTTON_WIDTH * scale;
+        let style = ui.clone_style();
+        let third_width = (button_width - style.item_spacing[0] * 2.) / 3.;
+
+        if ui.button_with_size(&self.label_down, [third_width, BUTTON_HEIGHT]) {
+            self.change(-self.step);
+        }
+        ui.same_line();
+        if ui.button_with_size(&self.label_up, [third_width, BUTTON_HEIGHT]) {
+            self.change(self.step);
+        }
+        ui.same_line();
+        match self.speed.read() {
+            Some(current) => ui.text(format!("{} [{current:.2}x]", self.speed.name())),
+            None => ui.text(self.speed.name()),
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.key_up.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.change(self.step);
+        } else if self.key_down.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.change(-self.step);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn step_speed(
+    step: f32,
+    (min, max): (f32, f32),
+    speed: Speed,
+    key_up: Option<Key>,
+    key_down: Option<Key>,
 ) -> Box<dyn Widget> {
-    Box::new(StoreValue::new(CycleSpeed::new(values, ptr), key))
+    let label = |sign: &str, key: Option<Key>| match key {
+        Some(k) => format!("{sign} ({k})"),
+        None => sign.to_string(),
+    };
+
+    Box::new(StepSpeed {
+        speed,
+        step: step.abs().max(0.01),
+        min: min.min(max),
+        max,
+        label_up: label("+", key_up),
+        label_down: label("-", key_down),
+        key_up,
+        key_down,
+        logs: Vec::new(),
+    })
 }
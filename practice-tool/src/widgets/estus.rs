@@ -0,0 +1,150 @@
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use super::refill::{ASHEN_ESTUS_FLASK, ESTUS_FLASK};
+use crate::feedback;
+
+const ESTUS_TAG: &str = "##estus";
+
+/// Most flasks a character can carry.
+const MAX_FLASKS: i32 = 15;
+const MAX_UPGRADE: i32 = 10;
+
+/// Sets how many estus flasks the character has, how they are split between
+/// HP and ashen estus, and their upgrade level. The flasks are refilled to
+/// the new allocation.
+pub(crate) struct Estus {
+    estus_allocation: PointerChain<[u8; 2]>,
+    inventory: PointerChain<[InventoryItem; INVENTORY_SLOTS]>,
+    total: i32,
+    ashen: i32,
+    upgrade: i32,
+    hotkey_open: Option<Key>,
+    hotkey_close: Key,
+    label_open: String,
+    label_close: String,
+    logs: Vec<String>,
+}
+
+impl Estus {
+    pub(crate) fn new(
+        estus_allocation: PointerChain<[u8; 2]>,
+        inventory: PointerChain<[InventoryItem; INVENTORY_SLOTS]>,
+        hotkey_open: Option<Key>,
+        hotkey_close: Key,
+    ) -> Self {
+        let label_open = match hotkey_open {
+            Some(k) => format!("Estus ({k})"),
+            None => "Estus".to_string(),
+        };
+
+        Estus {
+            estus_allocation,
+            inventory,
+            total: 0,
+            ashen: 0,
+            upgrade: 0,
+            hotkey_open,
+            hotkey_close,
+            label_open,
+            label_close: format!("Close ({hotkey_close})"),
+            logs: Vec::new(),
+        }
+    }
+
+    fn read(&mut self) {
+        if let Some([estus, ashen]) = self.estus_allocation.read() {
+            self.total = estus as i32 + ashen as i32;
+            self.ashen = ashen as i32;
+        }
+
+        if let Some(inventory) = self.inventory.read() {
+            if let Some(item) = inventory.iter().find(|item| ESTUS_FLASK.contains(&item.id)) {
+                self.upgrade = ((item.id - ESTUS_FLASK.start()) / 2) as i32;
+            }
+        }
+    }
+
+    fn apply(&mut self) {
+        let (Some(_), Some(mut inventory)) = (self.estus_allocation.eval(), self.inventory.read())
+        else {
+            self.logs.push("Estus unavailable when not in game".to_string());
+            return;
+        };
+
+        let ashen = self.ashen.clamp(0, self.total) as u8;
+        let estus = self.total as u8 - ashen;
+        let level = self.upgrade as u32 * 2;
+
+        for item in inventory.iter_mut() {
+            // The flasks are refilled, so they are never empty.
+            if ESTUS_FLASK.contains(&item.id) {
+                item.id = ESTUS_FLASK.start() + level;
+                item.quantity = estus as u32;
+            } else if ASHEN_ESTUS_FLASK.contains(&item.id) {
+                item.id = ASHEN_ESTUS_FLASK.start() + level;
+                item.quantity = ashen as u32;
+            }
+        }
+
+        self.estus_allocation.write([estus, ashen]);
+        self.inventory.write(inventory);
+        self.logs.push(format!("Estus set to {estus} + {ashen} ashen, +{}", self.upgrade));
+        feedback::executed();
+    }
+}
+
+impl Widget for Estus {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+
+        if ui.button_with_size(&self.label_open, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            self.read();
+            ui.open_popup(ESTUS_TAG);
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(ESTUS_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            ui.set_next_item_width(300.);
+            ui.slider("Flasks", 0, MAX_FLASKS, &mut self.total);
+            ui.set_next_item_width(300.);
+            ui.slider("Ashen", 0, self.total, &mut self.ashen);
+            self.ashen = self.ashen.min(self.total);
+            ui.set_next_item_width(300.);
+            ui.slider("Upgrade", 0, MAX_UPGRADE, &mut self.upgrade);
+            ui.text(format!("{} estus, {} ashen estus", self.total - self.ashen, self.ashen));
+
+            if ui.button_with_size("Apply", [400., BUTTON_HEIGHT]) {
+                self.apply();
+            }
+
+            if ui.button_with_size(&self.label_close, [400., BUTTON_HEIGHT])
+                || (self.hotkey_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey_open.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.read();
+            ui.open_popup(ESTUS_TAG);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use libds3::memedit::Bitflag as BitflagInner;
+use practice_tool_core::crossbeam_channel::Sender;
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::flag::{Flag, FlagWidget};
 use practice_tool_core::widgets::Widget;
 
+use crate::{feedback, restore};
+
 struct Bitflag(BitflagInner<u8>);
 
 impl Flag for Bitflag {
     fn set(&mut self, value: bool) {
+        restore::remember_flag(&self.0);
         self.0.set(value);
+        feedback::executed();
     }
 
     fn get(&self) -> Option<bool> {
@@ -15,10 +20,48 @@ impl Flag for Bitflag {
     }
 }
 
+/// Logs a warning every time the flag goes from disabled to enabled, however
+/// that happened.
+struct WarningFlag {
+    widget: FlagWidget<Bitflag>,
+    bitflag: BitflagInner<u8>,
+    warning: &'static str,
+    prev: Option<bool>,
+}
+
+impl Widget for WarningFlag {
+    fn render(&mut self, ui: &imgui::Ui) {
+        self.widget.render(ui);
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.widget.render_closed(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.widget.interact(ui);
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        let state = self.bitflag.get();
+        if let (Some(false), Some(true)) = (self.prev, state) {
+            tx.send(self.warning.to_string()).ok();
+        }
+        self.prev = state;
+
+        self.widget.log(tx);
+    }
+}
+
 pub(crate) fn flag_widget(
     label: &str,
     bitflag: BitflagInner<u8>,
+    warning: Option<&'static str>,
     key: Option<Key>,
 ) -> Box<dyn Widget> {
-    Box::new(FlagWidget::new(label, Bitflag(bitflag), key))
+    let widget = FlagWidget::new(label, Bitflag(bitflag.clone()), key);
+    match warning {
+        Some(warning) => Box::new(WarningFlag { widget, bitflag, warning, prev: Some(false) }),
+        None => Box::new(widget),
+    }
 }
@@ -0,0 +1,137 @@
+use std::time::{Duration, Instant};
+
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::Widget;
+use serde::Deserialize;
+
+use crate::feedback;
+
+/// How often a pass over the flags starts.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Largest number of flags read in a single frame. Passes over more flags
+/// are spread over several frames.
+const FLAGS_PER_FRAME: usize = 500;
+
+/// Largest number of flags a watcher reads, ranges included.
+const MAX_FLAGS: usize = 10_000;
+
+/// A single event flag, or an inclusive range of them.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(untagged)]
+pub(crate) enum WatchedFlags {
+    Flag(u32),
+    Range([u32; 2]),
+}
+
+/// Logs every change of the watched event flags, e.g. to find out which flags
+/// a quest step sets.
+struct FlagWatch {
+    flags: Vec<u32>,
+    states: Vec<Option<bool>>,
+    enabled: bool,
+    last_poll: Instant,
+    /// Next flag to read in the current pass, if one is ongoing.
+    cursor: Option<usize>,
+    hotkey: Option<Key>,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl FlagWatch {
+    fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+        // Flags are read again from scratch, so nothing is logged right away.
+        self.states.iter_mut().for_each(|state| *state = None);
+        self.cursor = None;
+        let state = if self.enabled { "started" } else { "stopped" };
+        self.logs.push(format!("Watching {} event flags {state}", self.flags.len()));
+        feedback::executed();
+    }
+
+    fn poll(&mut self) {
+        if !self.enabled {
+            return;
+        }
+
+        let start = match self.cursor {
+            Some(cursor) => cursor,
+            None if self.last_poll.elapsed() >= POLL_INTERVAL => {
+                self.last_poll = Instant::now();
+                0
+            },
+            None => return,
+        };
+        let end = (start + FLAGS_PER_FRAME).min(self.flags.len());
+        self.cursor = (end < self.flags.len()).then_some(end);
+
+        for (flag, state) in self.flags[start..end].iter().zip(self.states[start..end].iter_mut()) {
+            let current = EventFlag(*flag).get();
+            if let (Some(previous), Some(value)) = (*state, current) {
+                if previous != value {
+                    self.logs.push(format!("Event flag {flag} = {}", value as u8));
+                }
+            }
+            *state = current;
+        }
+    }
+}
+
+impl Widget for FlagWatch {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let mut enabled = self.enabled;
+        if ui.checkbox(&self.label, &mut enabled) {
+            self.toggle();
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.toggle();
+        }
+
+        self.poll();
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn flag_watch(watched: &[WatchedFlags], hotkey: Option<Key>) -> Box<dyn Widget> {
+    let mut logs = Vec::new();
+    let ranges = watched.iter().map(|w| match *w {
+        WatchedFlags::Flag(flag) => flag..=flag,
+        WatchedFlags::Range([start, end]) => start..=end,
+    });
+
+    let total: u64 =
+        ranges.clone().map(|r| (*r.end() as u64 + 1).saturating_sub(*r.start() as u64)).sum();
+    if total > MAX_FLAGS as u64 {
+        logs.push(format!("Watching only the first {MAX_FLAGS} of {total} event flags"));
+    }
+
+    let mut flags: Vec<u32> = ranges.flatten().take(MAX_FLAGS).collect();
+    flags.sort_unstable();
+    flags.dedup();
+
+    let label = match hotkey {
+        Some(k) => format!("Watch event flags ({k})"),
+        None => "Watch event flags".to_string(),
+    };
+
+    Box::new(FlagWatch {
+        states: vec![None; flags.len()],
+        flags,
+        enabled: true,
+        last_poll: Instant::now(),
+        cursor: None,
+        hotkey,
+        label,
+        logs,
+    })
+}
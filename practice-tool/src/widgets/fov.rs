@@ -0,0 +1,119 @@
+use libds3::memedit::PointerChain;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::{feedback, restore};
+
+const MIN_FOV: f32 = 20.;
+const MAX_FOV: f32 = 140.;
+
+/// Overrides the vertical field of view of the gameplay camera, in degrees.
+/// The game resets it on some camera transitions, so the override is written
+/// again whenever it drifts, until it is reset.
+struct Fov {
+    ptr: PointerChain<f32>,
+    step: f32,
+    fov: f32,
+    /// FOV the game used before the first override.
+    original: Option<f32>,
+    enabled: bool,
+    key_up: Option<Key>,
+    key_down: Option<Key>,
+    logs: Vec<String>,
+}
+
+impl Fov {
+    fn set(&mut self, fov: f32) -> bool {
+        let Some(current) = self.ptr.read() else {
+            self.logs.push("FOV unavailable when not in game".to_string());
+            return false;
+        };
+
+        self.original.get_or_insert(current.to_degrees());
+        restore::remember(&self.ptr);
+        self.fov = fov.clamp(MIN_FOV, MAX_FOV);
+        self.enabled = true;
+        self.ptr.write(self.fov.to_radians()).is_some()
+    }
+
+    fn reset(&mut self) {
+        if let Some(original) = self.original {
+            self.ptr.write(original.to_radians());
+            self.fov = original;
+            feedback::executed();
+        }
+        self.enabled = false;
+    }
+}
+
+impl Widget for Fov {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        let button_width = BUTTON_WIDTH * scale;
+
+        let mut fov = self.fov;
+        ui.set_next_item_width(button_width);
+        if ui.slider_config("##fov", MIN_FOV, MAX_FOV).display_format("FOV %.0f").build(&mut fov) {
+            self.set(fov);
+        }
+
+        let label = if self.enabled { "Reset FOV" } else { "FOV (game default)" };
+        let _tok = ui.begin_disabled(!self.enabled);
+        if ui.button_with_size(label, [button_width, BUTTON_HEIGHT]) {
+            self.reset();
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if !self.enabled {
+            if let Some(current) = self.ptr.read() {
+                self.fov = current.to_degrees();
+            }
+        }
+
+        let step = if self.key_up.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.step
+        } else if self.key_down.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            -self.step
+        } else {
+            0.
+        };
+
+        if step != 0. && self.set(self.fov + step) {
+            self.logs.push(format!("FOV set to {:.0}", self.fov));
+            feedback::executed();
+        }
+
+        if self.enabled {
+            let fov = self.fov.to_radians();
+            if self.ptr.read().is_some_and(|current| (current - fov).abs() > f32::EPSILON) {
+                self.ptr.write(fov);
+            }
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn fov(
+    ptr: PointerChain<f32>,
+    step: f32,
+    key_up: Option<Key>,
+    key_down: Option<Key>,
+) -> Box<dyn Widget> {
+    Box::new(Fov {
+        ptr,
+        step,
+        fov: 0.,
+        original: None,
+        enabled: false,
+        key_up,
+        key_down,
+        logs: Vec::new(),
+    })
+}
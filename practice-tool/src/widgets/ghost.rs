@@ -0,0 +1,381 @@
+//! Ghost recording and playback.
+//!
+//! A ghost is the player's position, rotation and animation sampled every
+//! frame while the menu is closed. Ghosts are stored in the `ghosts` folder
+//! next to the DLL as `<name>.ghost` files with the following little-endian
+//! layout:
+//!
+//! ```text
+//! magic    b"DS3G"
+//! version  u32
+//! count    u32
+//! frames   count * { x: f32, y: f32, z: f32, angle: f32, animation: u32 }
+//! ```
+//!
+//! During playback the ghost is drawn on a radar centered on the player,
+//! along with the ghost's path.
+
+use std::fs;
+use std::path::PathBuf;
+
+use libds3::memedit::PointerChain;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::{feedback, util};
+
+const GHOST_MAGIC: &[u8; 4] = b"DS3G";
+const GHOST_VERSION: u32 = 1;
+const FRAME_SIZE: usize = 20;
+
+/// Radius of the radar, in meters.
+const RADAR_RANGE: f32 = 20.;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct GhostFrame {
+    position: [f32; 3],
+    angle: f32,
+    animation: u32,
+}
+
+fn encode(frames: &[GhostFrame]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(12 + frames.len() * FRAME_SIZE);
+    buf.extend_from_slice(GHOST_MAGIC);
+    buf.extend_from_slice(&GHOST_VERSION.to_le_bytes());
+    buf.extend_from_slice(&(frames.len() as u32).to_le_bytes());
+
+    for frame in frames {
+        for v in frame.position {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        buf.extend_from_slice(&frame.angle.to_le_bytes());
+        buf.extend_from_slice(&frame.animation.to_le_bytes());
+    }
+
+    buf
+}
+
+fn decode(buf: &[u8]) -> Result<Vec<GhostFrame>, String> {
+    let u32_at = |offset: usize| -> u32 {
+        u32::from_le_bytes([buf[offset], buf[offset + 1], buf[offset + 2], buf[offset + 3]])
+    };
+    let f32_at = |offset: usize| f32::from_bits(u32_at(offset));
+
+    if buf.len() < 12 || &buf[0..4] != GHOST_MAGIC {
+        return Err("Not a ghost file".to_string());
+    }
+
+    if u32_at(4) != GHOST_VERSION {
+        return Err(format!("Unsupported ghost version {}", u32_at(4)));
+    }
+
+    let count = u32_at(8) as usize;
+    if buf.len() < 12 + count * FRAME_SIZE {
+        return Err("Truncated ghost file".to_string());
+    }
+
+    Ok((0..count)
+        .map(|i| {
+            let offset = 12 + i * FRAME_SIZE;
+            GhostFrame {
+                position: [f32_at(offset), f32_at(offset + 4), f32_at(offset + 8)],
+                angle: f32_at(offset + 12),
+                animation: u32_at(offset + 16),
+            }
+        })
+        .collect())
+}
+
+enum GhostState {
+    Idle,
+    Recording(Vec<GhostFrame>),
+    Playing(Vec<GhostFrame>, usize),
+}
+
+pub(crate) struct Ghost {
+    name: String,
+    path: Option<PathBuf>,
+    ptr_angle: PointerChain<f32>,
+    ptr_pos: PointerChain<[f32; 3]>,
+    ptr_anim: PointerChain<u32>,
+    key_record: Option<Key>,
+    key_play: Option<Key>,
+    label_record: String,
+    label_stop_record: String,
+    label_play: String,
+    label_stop_play: String,
+    state: GhostState,
+    logs: Vec<String>,
+}
+
+impl Ghost {
+    pub(crate) fn new(
+        name: String,
+        position: (PointerChain<f32>, PointerChain<[f32; 3]>),
+        ptr_anim: PointerChain<u32>,
+        key_record: Option<Key>,
+        key_play: Option<Key>,
+    ) -> Self {
+        let with_key = |label: String, key: Option<Key>| match key {
+            Some(k) => format!("{label} ({k})"),
+            None => label,
+        };
+
+        let path = util::get_dll_path().map(|mut path| {
+            path.pop();
+            path.push("ghosts");
+            path.push(format!("{name}.ghost"));
+            path
+        });
+
+        Ghost {
+            label_record: with_key(format!("Record ghost {name}"), key_record),
+            label_stop_record: with_key(format!("Stop recording {name}"), key_record),
+            label_play: with_key(format!("Play ghost {name}"), key_play),
+            label_stop_play: with_key(format!("Stop ghost {name}"), key_play),
+            name,
+            path,
+            ptr_angle: position.0,
+            ptr_pos: position.1,
+            ptr_anim,
+            key_record,
+            key_play,
+            state: GhostState::Idle,
+            logs: Vec::new(),
+        }
+    }
+
+    fn read_frame(&self) -> Option<GhostFrame> {
+        Some(GhostFrame {
+            position: self.ptr_pos.read()?,
+            angle: self.ptr_angle.read()?,
+            animation: self.ptr_anim.read().unwrap_or(0),
+        })
+    }
+
+    fn save(&mut self, frames: &[GhostFrame]) {
+        let Some(path) = &self.path else {
+            self.logs.push("Couldn't find ghosts folder".to_string());
+            return;
+        };
+
+        let result = path
+            .parent()
+            .map(fs::create_dir_all)
+            .transpose()
+            .and_then(|_| fs::write(path, encode(frames)));
+
+        match result {
+            Ok(()) => {
+                self.logs.push(format!("Ghost {} saved ({} frames)", self.name, frames.len()));
+                feedback::executed();
+            },
+            Err(e) => self.logs.push(format!("Couldn't save ghost {}: {e}", self.name)),
+        }
+    }
+
+    fn load(&self) -> Result<Vec<GhostFrame>, String> {
+        let path = self.path.as_ref().ok_or_else(|| "Couldn't find ghosts folder".to_string())?;
+        let buf = fs::read(path).map_err(|e| format!("Couldn't read ghost {}: {e}", self.name))?;
+        decode(&buf)
+    }
+
+    fn toggle_record(&mut self) {
+        match std::mem::replace(&mut self.state, GhostState::Idle) {
+            GhostState::Recording(frames) => self.save(&frames),
+            _ => {
+                self.logs.push(format!("Recording ghost {}", self.name));
+                self.state = GhostState::Recording(Vec::new());
+                feedback::executed();
+            },
+        }
+    }
+
+    fn toggle_play(&mut self) {
+        match std::mem::replace(&mut self.state, GhostState::Idle) {
+            GhostState::Playing(..) => feedback::executed(),
+            GhostState::Recording(frames) => {
+                self.save(&frames);
+                self.start_playing();
+            },
+            GhostState::Idle => self.start_playing(),
+        }
+    }
+
+    fn start_playing(&mut self) {
+        match self.load() {
+            Ok(frames) if frames.is_empty() => {
+                self.logs.push(format!("Ghost {} is empty", self.name));
+            },
+            Ok(frames) => {
+                self.state = GhostState::Playing(frames, 0);
+                feedback::executed();
+            },
+            Err(e) => self.logs.push(e),
+        }
+    }
+
+    fn render_radar(&self, ui: &imgui::Ui, frames: &[GhostFrame], index: usize) {
+        let Some(player) = self.read_frame() else {
+            return;
+        };
+
+        let ghost = frames[index.min(frames.len() - 1)];
+        let [px, py, pz] = player.position;
+        let [gx, gy, gz] = ghost.position;
+        let distance = ((gx - px).powi(2) + (gy - py).powi(2) + (gz - pz).powi(2)).sqrt();
+
+        ui.text(format!(
+            "Ghost {} {:>5}/{} {:6.1}m {:+6.1}m height",
+            self.name,
+            index + 1,
+            frames.len(),
+            distance,
+            gy - py
+        ));
+
+        let radius = 60. * scaling_factor(ui);
+        let [x, y] = ui.cursor_screen_pos();
+        let center = [x + radius, y + radius];
+        let scale = radius / RADAR_RANGE;
+        let (sin, cos) = player.angle.sin_cos();
+
+        // Rotates world coordinates so that the player always faces up.
+        let project = |[wx, _, wz]: [f32; 3]| {
+            let (dx, dz) = (wx - px, wz - pz);
+            let right = dx * cos - dz * sin;
+            let forward = dx * sin + dz * cos;
+            [center[0] + right * scale, center[1] - forward * scale]
+        };
+        let in_range = |p: [f32; 2]| {
+            (p[0] - center[0]).powi(2) + (p[1] - center[1]).powi(2) <= radius * radius
+        };
+
+        let draw_list = ui.get_window_draw_list();
+        draw_list.add_circle(center, radius, [0., 0., 0., 0.4]).filled(true).build();
+        draw_list.add_circle(center, radius, [1., 1., 1., 0.3]).build();
+
+        let trail = frames.iter().step_by(4).map(|f| project(f.position)).collect::<Vec<_>>();
+        for w in trail.windows(2) {
+            if in_range(w[0]) && in_range(w[1]) {
+                draw_list.add_line(w[0], w[1], [0.5, 0.8, 1., 0.35]).thickness(2.).build();
+            }
+        }
+
+        draw_list.add_circle(center, 4., [1., 1., 1., 0.9]).filled(true).build();
+        draw_list.add_line(center, [center[0], center[1] - 10.], [1., 1., 1., 0.9]).build();
+
+        let ghost_pos = project(ghost.position);
+        if in_range(ghost_pos) {
+            draw_list.add_circle(ghost_pos, 5., [0.5, 0.8, 1., 0.6]).filled(true).build();
+        }
+
+        ui.dummy([2. * radius, 2. * radius]);
+    }
+}
+
+impl Widget for Ghost {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let button_size = [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT];
+
+        let label_record = match self.state {
+            GhostState::Recording(_) => &self.label_stop_record,
+            _ => &self.label_record,
+        };
+        if ui.button_with_size(label_record, button_size) {
+            self.toggle_record();
+        }
+
+        let label_play = match self.state {
+            GhostState::Playing(..) => &self.label_stop_play,
+            _ => &self.label_play,
+        };
+        if ui.button_with_size(label_play, button_size) {
+            self.toggle_play();
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        match &self.state {
+            GhostState::Recording(frames) => {
+                ui.text(format!("Recording ghost {} ({} frames)", self.name, frames.len()));
+            },
+            GhostState::Playing(frames, index) => self.render_radar(ui, frames, *index),
+            GhostState::Idle => {},
+        }
+
+        // Frames are only sampled and played back while the menu is closed.
+        let frame = self.read_frame();
+        match &mut self.state {
+            GhostState::Recording(frames) => frames.extend(frame),
+            GhostState::Playing(frames, index) => {
+                if *index + 1 < frames.len() {
+                    *index += 1;
+                }
+            },
+            GhostState::Idle => {},
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.key_record.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.toggle_record();
+        }
+
+        if self.key_play.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.toggle_play();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, GhostFrame};
+
+    #[test]
+    fn test_round_trip() {
+        let frames = vec![GhostFrame::default(), GhostFrame {
+            position: [-265.1, -53.2, f32::MAX],
+            angle: -1.57,
+            animation: 0xffff_ffff,
+        }];
+
+        let buf = encode(&frames);
+        assert_eq!(buf.len(), 12 + 2 * 20);
+        assert_eq!(&buf[0..4], b"DS3G");
+
+        let decoded = decode(&buf).unwrap();
+        assert_eq!(decoded.len(), frames.len());
+        for (frame, decoded) in frames.iter().zip(&decoded) {
+            assert_eq!(frame.position, decoded.position);
+            assert_eq!(frame.angle, decoded.angle);
+            assert_eq!(frame.animation, decoded.animation);
+        }
+
+        assert!(decode(&encode(&[])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let buf = encode(&[GhostFrame::default()]);
+
+        assert_eq!(decode(&buf[..11]).unwrap_err(), "Not a ghost file");
+        assert_eq!(decode(b"DS3MXXXXXXXX").unwrap_err(), "Not a ghost file");
+
+        let mut version = buf.clone();
+        version[4] = 2;
+        assert_eq!(decode(&version).unwrap_err(), "Unsupported ghost version 2");
+
+        assert_eq!(decode(&buf[..buf.len() - 1]).unwrap_err(), "Truncated ghost file");
+        let mut count = buf.clone();
+        count[8] = 2;
+        assert_eq!(decode(&count).unwrap_err(), "Truncated ghost file");
+    }
+}
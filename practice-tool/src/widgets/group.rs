@@ -1,11 +1,70 @@
+use practice_tool_core::crossbeam_channel::Sender;
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::group::Group;
 use practice_tool_core::widgets::Widget;
 
+use crate::ui_state;
+
+/// Group shown inline as a collapsible section instead of a popup. Whether
+/// it is expanded is remembered between sessions.
+struct CollapsibleGroup {
+    label: String,
+    open: bool,
+    commands: Vec<Box<dyn Widget>>,
+}
+
+impl Widget for CollapsibleGroup {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let node = ui
+            .tree_node_config(&self.label)
+            .framed(true)
+            .opened(self.open, imgui::Condition::Once)
+            .push();
+
+        if node.is_some() != self.open {
+            self.open = node.is_some();
+            ui_state::set_group_open(&self.label, self.open);
+        }
+
+        if node.is_some() {
+            for c in self.commands.iter_mut() {
+                c.render(ui);
+            }
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        for c in self.commands.iter_mut() {
+            c.render_closed(ui);
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        for c in self.commands.iter_mut() {
+            c.interact(ui);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for c in self.commands.iter_mut() {
+            c.log(tx.clone());
+        }
+    }
+}
+
 pub(crate) fn group(
     label: &str,
     commands: Vec<Box<dyn Widget>>,
+    collapsible: bool,
     key_close: Key,
 ) -> Box<dyn Widget> {
-    Box::new(Group::new(label, key_close, commands))
+    if collapsible {
+        Box::new(CollapsibleGroup {
+            label: label.to_string(),
+            open: ui_state::group_open(label),
+            commands,
+        })
+    } else {
+        Box::new(Group::new(label, key_close, commands))
+    }
 }
@@ -0,0 +1,68 @@
+use libds3::memedit::PointerChain;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::store_value::{ReadWrite, StoreValue};
+use practice_tool_core::widgets::Widget;
+
+use crate::feedback;
+
+/// Restores HP and FP to their maximum.
+struct Heal {
+    hp: PointerChain<[u32; 3]>,
+    fp: PointerChain<[u32; 3]>,
+}
+
+impl ReadWrite for Heal {
+    fn read(&mut self) -> bool {
+        self.hp.read().is_some()
+    }
+
+    fn write(&mut self) {
+        for ptr in [&self.hp, &self.fp] {
+            if let Some([_, base_max, max]) = ptr.read() {
+                ptr.write([max, base_max, max]);
+            }
+        }
+        feedback::executed();
+    }
+
+    fn label(&self) -> &str {
+        "Full heal"
+    }
+}
+
+/// Sets HP to an exact value, capped to the maximum.
+struct SetHp {
+    hp: PointerChain<[u32; 3]>,
+    value: u32,
+    label: String,
+}
+
+impl ReadWrite for SetHp {
+    fn read(&mut self) -> bool {
+        self.hp.read().is_some()
+    }
+
+    fn write(&mut self) {
+        if let Some([_, base_max, max]) = self.hp.read() {
+            if self.hp.write([self.value.min(max), base_max, max]).is_some() {
+                feedback::executed();
+            }
+        }
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+pub(crate) fn heal(
+    hp: PointerChain<[u32; 3]>,
+    fp: PointerChain<[u32; 3]>,
+    key: Option<Key>,
+) -> Box<dyn Widget> {
+    Box::new(StoreValue::new(Heal { hp, fp }, key))
+}
+
+pub(crate) fn set_hp(hp: PointerChain<[u32; 3]>, value: u32, key: Option<Key>) -> Box<dyn Widget> {
+    Box::new(StoreValue::new(SetHp { hp, value, label: format!("Set HP to {value}") }, key))
+}
@@ -0,0 +1,71 @@
+use libds3::memedit::PointerChain;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::feedback;
+
+/// Counts the hits taken by the player, i.e. every time HP goes down, for
+/// no-hit practice. The count is shown while the tool is closed.
+pub(crate) struct HitCounter {
+    hp: PointerChain<[u32; 3]>,
+    prev_hp: Option<u32>,
+    hits: u32,
+    hotkey: Option<Key>,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl HitCounter {
+    pub(crate) fn new(hp: PointerChain<[u32; 3]>, hotkey: Option<Key>) -> Self {
+        let label = match hotkey {
+            Some(k) => format!("Reset hits ({k})"),
+            None => "Reset hits".to_string(),
+        };
+        HitCounter { hp, prev_hp: None, hits: 0, hotkey, label, logs: Vec::new() }
+    }
+
+    fn reset(&mut self) {
+        self.hits = 0;
+        self.logs.push("Hit counter reset".to_string());
+        feedback::executed();
+    }
+
+    fn update(&mut self) {
+        let hp = self.hp.read().map(|[hp, _, _]| hp);
+        if let (Some(prev), Some(hp)) = (self.prev_hp, hp) {
+            if hp < prev {
+                self.hits += 1;
+            }
+        }
+        self.prev_hp = hp;
+    }
+}
+
+impl Widget for HitCounter {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        ui.text(format!("Hits {}", self.hits));
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            self.reset();
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        ui.text(format!("Hits {}", self.hits));
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.update();
+
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.reset();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
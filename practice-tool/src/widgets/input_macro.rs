@@ -0,0 +1,440 @@
+//! Input macro recording and playback.
+//!
+//! A macro is the state of the keyboard and of the first controller, sampled
+//! every frame while the game's window is in the foreground. Only the frames
+//! where the state changes are kept. Macros are stored in the `macros` folder
+//! next to the DLL as `<name>.macro` files with the following little-endian
+//! layout:
+//!
+//! ```text
+//! magic    b"DS3M"
+//! version  u32
+//! count    u32
+//! samples  count * {
+//!     frame: u32, buttons: u16, left_trigger: u8, right_trigger: u8,
+//!     thumb_lx: i16, thumb_ly: i16, thumb_rx: i16, thumb_ry: i16,
+//!     keys: [u8; 32],
+//! }
+//! ```
+//!
+//! `keys` is a bitset of the held virtual keys. During playback the keys are
+//! pressed and released as scan codes, and the controller state replaces the
+//! one the game reads from the first controller. Playback pauses, releasing
+//! the keys, while another window is in the foreground.
+
+use std::fs;
+use std::path::PathBuf;
+
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use windows::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState;
+use windows::Win32::UI::Input::XboxController::{XINPUT_GAMEPAD, XINPUT_GAMEPAD_BUTTON_FLAGS};
+
+use crate::{feedback, util, XINPUT_GAMEPADS, XINPUT_OVERRIDE};
+
+const MACRO_MAGIC: &[u8; 4] = b"DS3M";
+const MACRO_VERSION: u32 = 1;
+const SAMPLE_SIZE: usize = 48;
+
+/// Virtual keys that are recorded: everything past the mouse buttons.
+const FIRST_KEY: u16 = 0x08;
+
+#[derive(Clone, Copy, Default)]
+struct Sample {
+    frame: u32,
+    gamepad: XINPUT_GAMEPAD,
+    keys: [u8; 32],
+}
+
+impl Sample {
+    fn is_held(&self, vk: u16) -> bool {
+        self.keys[vk as usize / 8] & (1 << (vk % 8)) != 0
+    }
+
+    fn same_input(&self, other: &Sample) -> bool {
+        self.gamepad == other.gamepad && self.keys == other.keys
+    }
+}
+
+fn encode(samples: &[Sample]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(12 + samples.len() * SAMPLE_SIZE);
+    buf.extend_from_slice(MACRO_MAGIC);
+    buf.extend_from_slice(&MACRO_VERSION.to_le_bytes());
+    buf.extend_from_slice(&(samples.len() as u32).to_le_bytes());
+
+    for Sample { frame, gamepad, keys } in samples {
+        buf.extend_from_slice(&frame.to_le_bytes());
+        buf.extend_from_slice(&gamepad.wButtons.0.to_le_bytes());
+        buf.push(gamepad.bLeftTrigger);
+        buf.push(gamepad.bRightTrigger);
+        for v in [gamepad.sThumbLX, gamepad.sThumbLY, gamepad.sThumbRX, gamepad.sThumbRY] {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        buf.extend_from_slice(keys);
+    }
+
+    buf
+}
+
+fn decode(buf: &[u8]) -> Result<Vec<Sample>, String> {
+    let u32_at = |offset: usize| -> u32 {
+        u32::from_le_bytes([buf[offset], buf[offset + 1], buf[offset + 2], buf[offset + 3]])
+    };
+    let u16_at = |offset: usize| u16::from_le_bytes([buf[offset], buf[offset + 1]]);
+    let i16_at = |offset: usize| u16_at(offset) as i16;
+
+    if buf.len() < 12 || &buf[0..4] != MACRO_MAGIC {
+        return Err("Not a macro file".to_string());
+    }
+
+    if u32_at(4) != MACRO_VERSION {
+        return Err(format!("Unsupported macro version {}", u32_at(4)));
+    }
+
+    let count = u32_at(8) as usize;
+    if buf.len() < 12 + count * SAMPLE_SIZE {
+        return Err("Truncated macro file".to_string());
+    }
+
+    Ok((0..count)
+        .map(|i| {
+            let offset = 12 + i * SAMPLE_SIZE;
+            Sample {
+                frame: u32_at(offset),
+                gamepad: XINPUT_GAMEPAD {
+                    wButtons: XINPUT_GAMEPAD_BUTTON_FLAGS(u16_at(offset + 4)),
+                    bLeftTrigger: buf[offset + 6],
+                    bRightTrigger: buf[offset + 7],
+                    sThumbLX: i16_at(offset + 8),
+                    sThumbLY: i16_at(offset + 10),
+                    sThumbRX: i16_at(offset + 12),
+                    sThumbRY: i16_at(offset + 14),
+                },
+                keys: buf[offset + 16..offset + SAMPLE_SIZE].try_into().unwrap(),
+            }
+        })
+        .collect())
+}
+
+enum MacroState {
+    Idle,
+    Recording { samples: Vec<Sample>, frame: u32 },
+    Playing { samples: Vec<Sample>, frame: u32, index: usize, held: Sample },
+}
+
+pub(crate) struct InputMacro {
+    name: String,
+    path: Option<PathBuf>,
+    /// Keys of the macro's own hotkeys, which are never recorded.
+    hotkey_keys: Vec<u16>,
+    key_record: Option<Key>,
+    key_play: Option<Key>,
+    label_record: String,
+    label_stop_record: String,
+    label_play: String,
+    label_stop_play: String,
+    state: MacroState,
+    logs: Vec<String>,
+}
+
+impl InputMacro {
+    pub(crate) fn new(name: String, key_record: Option<Key>, key_play: Option<Key>) -> Self {
+        let with_key = |label: String, key: Option<Key>| match key {
+            Some(k) => format!("{label} ({k})"),
+            None => label,
+        };
+
+        let path = util::get_dll_path().map(|mut path| {
+            path.pop();
+            path.push("macros");
+            path.push(format!("{name}.macro"));
+            path
+        });
+
+        let hotkey_keys = [key_record, key_play]
+            .into_iter()
+            .flatten()
+            .filter_map(|key| util::virtual_keys(&key))
+            .flatten()
+            .collect();
+
+        InputMacro {
+            label_record: with_key(format!("Record macro {name}"), key_record),
+            label_stop_record: with_key(format!("Stop recording {name}"), key_record),
+            label_play: with_key(format!("Play macro {name}"), key_play),
+            label_stop_play: with_key(format!("Stop macro {name}"), key_play),
+            name,
+            path,
+            hotkey_keys,
+            key_record,
+            key_play,
+            state: MacroState::Idle,
+            logs: Vec::new(),
+        }
+    }
+
+    fn read_sample(&self, frame: u32) -> Sample {
+        let gamepad = XINPUT_GAMEPADS.lock().iter().flatten().next().copied().unwrap_or_default();
+        let mut keys = [0u8; 32];
+        for vk in FIRST_KEY..=0xfe {
+            // The most significant bit is set while the key is down.
+            if !self.hotkey_keys.contains(&vk) && unsafe { GetAsyncKeyState(vk as i32) } < 0 {
+                keys[vk as usize / 8] |= 1 << (vk % 8);
+            }
+        }
+        Sample { frame, gamepad, keys }
+    }
+
+    fn save(&mut self, samples: &[Sample]) {
+        let Some(path) = &self.path else {
+            self.logs.push("Couldn't find macros folder".to_string());
+            return;
+        };
+
+        let result = path
+            .parent()
+            .map(fs::create_dir_all)
+            .transpose()
+            .and_then(|_| fs::write(path, encode(samples)));
+
+        match result {
+            Ok(()) => {
+                let frames = samples.last().map(|s| s.frame + 1).unwrap_or(0);
+                self.logs.push(format!("Macro {} saved ({frames} frames)", self.name));
+                feedback::executed();
+            },
+            Err(e) => self.logs.push(format!("Couldn't save macro {}: {e}", self.name)),
+        }
+    }
+
+    fn load(&self) -> Result<Vec<Sample>, String> {
+        let path = self.path.as_ref().ok_or_else(|| "Couldn't find macros folder".to_string())?;
+        let buf = fs::read(path).map_err(|e| format!("Couldn't read macro {}: {e}", self.name))?;
+        decode(&buf)
+    }
+
+    /// Releases whatever the macro is holding and gives the controller back.
+    fn release(held: &Sample) {
+        for vk in FIRST_KEY..=0xfe {
+            if held.is_held(vk) {
+                util::send_key(vk, false);
+            }
+        }
+        *XINPUT_OVERRIDE.lock() = None;
+    }
+
+    fn stop(&mut self) {
+        match std::mem::replace(&mut self.state, MacroState::Idle) {
+            MacroState::Recording { samples, frame } => {
+                // Keeps the last frame, so that playback lasts as long as the recording.
+                let mut samples = samples;
+                if let Some(&last) = samples.last() {
+                    if last.frame + 1 < frame {
+                        samples.push(Sample { frame: frame - 1, ..last });
+                    }
+                }
+                self.save(&samples);
+            },
+            MacroState::Playing { held, .. } => Self::release(&held),
+            MacroState::Idle => {},
+        }
+    }
+
+    fn toggle_record(&mut self) {
+        match self.state {
+            MacroState::Recording { .. } => self.stop(),
+            _ => {
+                self.stop();
+                self.logs.push(format!("Recording macro {}", self.name));
+                self.state = MacroState::Recording { samples: Vec::new(), frame: 0 };
+                feedback::executed();
+            },
+        }
+    }
+
+    fn toggle_play(&mut self) {
+        if let MacroState::Playing { .. } = self.state {
+            self.stop();
+            feedback::executed();
+            return;
+        }
+
+        self.stop();
+        match self.load() {
+            Ok(samples) if samples.is_empty() => {
+                self.logs.push(format!("Macro {} is empty", self.name));
+            },
+            Ok(samples) => {
+                self.logs.push(format!("Playing macro {}", self.name));
+                self.state =
+                    MacroState::Playing { samples, frame: 0, index: 0, held: Sample::default() };
+                feedback::executed();
+            },
+            Err(e) => self.logs.push(e),
+        }
+    }
+
+    /// Records or plays back one frame.
+    fn step(&mut self) {
+        if !util::game_is_foreground() {
+            // Input isn't recorded from, or sent to, other windows. The keys
+            // held by the playback are pressed again when the game is back.
+            if let MacroState::Playing { held, .. } = &mut self.state {
+                Self::release(held);
+                *held = Sample::default();
+            }
+            return;
+        }
+
+        let sample = match self.state {
+            MacroState::Recording { frame, .. } => Some(self.read_sample(frame)),
+            _ => None,
+        };
+
+        let done = match &mut self.state {
+            MacroState::Recording { samples, frame } => {
+                let sample = sample.unwrap();
+                if samples.last().map(|last| !last.same_input(&sample)).unwrap_or(true) {
+                    samples.push(sample);
+                }
+                *frame += 1;
+                false
+            },
+            MacroState::Playing { samples, frame, index, held } => {
+                while samples.get(*index).map(|s| s.frame <= *frame).unwrap_or(false) {
+                    *index += 1;
+                }
+                if let Some(sample) = index.checked_sub(1).and_then(|i| samples.get(i)) {
+                    for vk in FIRST_KEY..=0xfe {
+                        if sample.is_held(vk) != held.is_held(vk) {
+                            util::send_key(vk, sample.is_held(vk));
+                        }
+                    }
+                    *XINPUT_OVERRIDE.lock() = Some(sample.gamepad);
+                    *held = *sample;
+                }
+                *frame += 1;
+                *index >= samples.len()
+            },
+            MacroState::Idle => false,
+        };
+
+        if done {
+            self.stop();
+            self.logs.push(format!("Macro {} done", self.name));
+        }
+    }
+}
+
+impl Widget for InputMacro {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let button_size = [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT];
+
+        let label_record = match self.state {
+            MacroState::Recording { .. } => &self.label_stop_record,
+            _ => &self.label_record,
+        };
+        if ui.button_with_size(label_record, button_size) {
+            self.toggle_record();
+        }
+
+        let label_play = match self.state {
+            MacroState::Playing { .. } => &self.label_stop_play,
+            _ => &self.label_play,
+        };
+        if ui.button_with_size(label_play, button_size) {
+            self.toggle_play();
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        match &self.state {
+            MacroState::Recording { frame, .. } => {
+                ui.text(format!("Recording macro {} ({frame} frames)", self.name));
+            },
+            MacroState::Playing { samples, frame, .. } => {
+                let total = samples.last().map(|s| s.frame + 1).unwrap_or(0);
+                ui.text(format!("Playing macro {} {frame:>5}/{total}", self.name));
+            },
+            MacroState::Idle => {},
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.key_record.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.toggle_record();
+        }
+
+        if self.key_play.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.toggle_play();
+        }
+
+        // Runs whether the menu is open, closed or hidden.
+        self.step();
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+impl Drop for InputMacro {
+    fn drop(&mut self) {
+        // Releases the held keys and the controller override when the tool is
+        // ejected or the configuration reloaded mid-playback.
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use windows::Win32::UI::Input::XboxController::{XINPUT_GAMEPAD, XINPUT_GAMEPAD_BUTTON_FLAGS};
+
+    use super::{decode, encode, Sample};
+
+    #[test]
+    fn test_round_trip() {
+        let mut keys = [0u8; 32];
+        keys[0x41 / 8] |= 1 << (0x41 % 8);
+        keys[0xfe / 8] |= 1 << (0xfe % 8);
+
+        let samples = vec![Sample::default(), Sample {
+            frame: 12,
+            gamepad: XINPUT_GAMEPAD {
+                wButtons: XINPUT_GAMEPAD_BUTTON_FLAGS(0x1010),
+                bLeftTrigger: 200,
+                bRightTrigger: 3,
+                sThumbLX: i16::MIN,
+                sThumbLY: i16::MAX,
+                sThumbRX: -1,
+                sThumbRY: 1234,
+            },
+            keys,
+        }];
+
+        let decoded = decode(&encode(&samples)).unwrap();
+        assert_eq!(decoded.len(), samples.len());
+        for (sample, decoded) in samples.iter().zip(&decoded) {
+            assert_eq!(sample.frame, decoded.frame);
+            assert!(sample.same_input(decoded));
+        }
+        assert!(decoded[1].is_held(0x41));
+        assert!(decoded[1].is_held(0xfe));
+        assert!(!decoded[1].is_held(0x42));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let buf = encode(&[Sample::default()]);
+
+        assert!(decode(&buf[..buf.len() - 1]).is_err());
+        assert!(decode(b"DS3X\x01\x00\x00\x00\x00\x00\x00\x00").is_err());
+
+        let mut wrong_version = buf.clone();
+        wrong_version[4] = 2;
+        assert!(decode(&wrong_version).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::widgets::Widget;
+
+use crate::online;
+
+/// Command that changes the game. While the game is online, it is shown
+/// disabled and doesn't react to its hotkeys.
+struct Interlocked {
+    command: Box<dyn Widget>,
+}
+
+impl Widget for Interlocked {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let _token = ui.begin_disabled(online::is_online());
+        self.command.render(ui);
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.command.render_closed(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if !online::is_online() {
+            self.command.interact(ui);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        self.command.log(tx);
+    }
+}
+
+pub(crate) fn interlocked(command: Box<dyn Widget>) -> Box<dyn Widget> {
+    Box::new(Interlocked { command })
+}
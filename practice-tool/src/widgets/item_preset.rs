@@ -0,0 +1,118 @@
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use serde::Deserialize;
+
+use super::item_spawn::{ItemSpawnInstance, SpawnQueue};
+use super::loadout::Infusion;
+use crate::feedback;
+
+fn default_qty() -> u32 {
+    1
+}
+
+fn default_durability() -> u32 {
+    100
+}
+
+/// An item of a preset, with its base ID and optional quantity, upgrade level
+/// and infusion.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct PresetItem {
+    id: u32,
+    #[serde(default = "default_qty")]
+    qty: u32,
+    #[serde(default)]
+    upgrade: u32,
+    #[serde(default)]
+    infusion: Infusion,
+    #[serde(default = "default_durability")]
+    durability: u32,
+}
+
+impl PresetItem {
+    pub(crate) fn instance(&self, func_ptr: usize, map_item_man: usize) -> ItemSpawnInstance {
+        ItemSpawnInstance {
+            spawn_item_func_ptr: func_ptr as _,
+            map_item_man: map_item_man as _,
+            qty: self.qty,
+            durability: self.durability,
+            item_id: self.id,
+            infusion: self.infusion.0,
+            upgrade: self.upgrade.min(10),
+        }
+    }
+}
+
+struct ItemPreset {
+    items: Vec<PresetItem>,
+    func_ptr: usize,
+    map_item_man: usize,
+    sentinel: Bitflag<u8>,
+    hotkey: Option<Key>,
+    label: String,
+    queue: SpawnQueue,
+    logs: Vec<String>,
+}
+
+impl ItemPreset {
+    fn spawn(&mut self) {
+        if self.sentinel.get().is_none() {
+            self.logs.push("Not spawning items when not in game".to_string());
+            return;
+        }
+
+        let (func_ptr, map_item_man) = (self.func_ptr, self.map_item_man);
+        self.queue.extend(self.items.iter().map(|item| item.instance(func_ptr, map_item_man)));
+        feedback::executed();
+    }
+}
+
+impl Widget for ItemPreset {
+    fn render(&mut self, ui: &imgui::Ui) {
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT]) {
+            self.spawn();
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.queue.tick();
+
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.spawn();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn item_preset(
+    name: &str,
+    items: Vec<PresetItem>,
+    func_ptr: usize,
+    map_item_man: usize,
+    sentinel: Bitflag<u8>,
+    hotkey: Option<Key>,
+) -> Box<dyn Widget> {
+    let label = match hotkey {
+        Some(k) => format!("Spawn {name} ({k})"),
+        None => format!("Spawn {name}"),
+    };
+
+    Box::new(ItemPreset {
+        items,
+        func_ptr,
+        map_item_man,
+        sentinel,
+        hotkey,
+        label,
+        queue: SpawnQueue::default(),
+        logs: Vec::new(),
+    })
+}
@@ -1,7 +1,11 @@
 use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::ffi::c_void;
 use std::fmt::Display;
+use std::thread;
+use std::time::{Duration, Instant};
 
+use hudhook::tracing::{error, info};
 use imgui::sys::{
     igGetCursorPosX, igGetCursorPosY, igGetTreeNodeToLabelSpacing, igGetWindowPos, igIndent,
     igSetNextWindowPos, igUnindent, ImVec2,
@@ -15,9 +19,18 @@ use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer};
 
+use crate::{feedback, util};
+
 const DEFAULT_ITEM: u32 = 0x007A1200;
 
-static INFUSION_TYPES: [(u32, &str); 16] = [
+/// Largest quantity spawned at once.
+pub(crate) const MAX_QTY: u32 = 99;
+
+/// Time between two spawns of a batch. Spawning several items on the same
+/// frame drops all but one of the pickup notifications.
+const SPAWN_INTERVAL: Duration = Duration::from_millis(150);
+
+pub(crate) static INFUSION_TYPES: [(u32, &str); 16] = [
     (0, "Normal"),
     (100, "Heavy"),
     (200, "Sharp"),
@@ -52,21 +65,49 @@ static UPGRADES: [(u32, &str); 11] = [
 
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
-enum ItemIDNode {
+pub(crate) enum ItemIDNode {
     Leaf { id: HexU32, desc: String },
     Node { node: String, children: Vec<ItemIDNode> },
 }
 
+/// A node of an [`ItemIDTree`]. Leaves keep their lowercase name, so that
+/// filtering doesn't convert it again on every keystroke.
 #[derive(Debug)]
-enum ItemIDNodeRef<'a> {
-    Leaf { node: &'a str, value: u32 },
-    Node { node: &'a str, children: Vec<ItemIDNodeRef<'a>> },
+pub(crate) enum ItemIDNodeRef<'a> {
+    Leaf { node: &'a str, value: u32, key: String, visible: bool },
+    Node { node: &'a str, children: Vec<ItemIDNodeRef<'a>>, visible: bool },
 }
 
 impl<'a> ItemIDNodeRef<'a> {
+    /// Shows the leaves matching the lowercase `filter`, and the nodes with
+    /// any of them. When `narrowing`, the filter extends the previous one, so
+    /// hidden nodes can't match and aren't checked again.
+    fn filter(&mut self, filter: &str, narrowing: bool) -> bool {
+        match self {
+            ItemIDNodeRef::Leaf { key, visible, .. } => {
+                if *visible || !narrowing {
+                    *visible = string_match(filter, key);
+                }
+                *visible
+            },
+            ItemIDNodeRef::Node { children, visible, .. } => {
+                if *visible || !narrowing {
+                    // Every child is filtered, so none is left visible by
+                    // mistake. Without a filter, empty nodes are shown too.
+                    *visible = children
+                        .iter_mut()
+                        .fold(filter.is_empty(), |any, c| c.filter(filter, narrowing) || any);
+                }
+                *visible
+            },
+        }
+    }
+
     fn render(&self, ui: &imgui::Ui, current: &mut u32, filtered: bool) {
         match self {
-            ItemIDNodeRef::Leaf { node, value } => {
+            ItemIDNodeRef::Leaf { visible: false, .. }
+            | ItemIDNodeRef::Node { visible: false, .. } => {},
+            ItemIDNodeRef::Leaf { node, value, .. } => {
                 unsafe { igUnindent(igGetTreeNodeToLabelSpacing()) };
                 ui.tree_node_config(*node)
                     .label::<&str, &str>(node)
@@ -86,7 +127,7 @@ impl<'a> ItemIDNodeRef<'a> {
                     *current = *value;
                 }
             },
-            ItemIDNodeRef::Node { node, children } => {
+            ItemIDNodeRef::Node { node, children, .. } => {
                 let n = ui.tree_node_config(*node).label::<&str, &str>(node);
 
                 let n = if filtered { n.opened(filtered, Condition::Always) } else { n };
@@ -104,62 +145,130 @@ impl<'a> ItemIDNodeRef<'a> {
 impl<'a> From<&'a ItemIDNode> for ItemIDNodeRef<'a> {
     fn from(v: &'a ItemIDNode) -> Self {
         match v {
-            ItemIDNode::Leaf { id, desc } => ItemIDNodeRef::Leaf { node: desc, value: id.0 },
+            ItemIDNode::Leaf { id, desc } => ItemIDNodeRef::Leaf {
+                node: desc,
+                value: id.0,
+                key: desc.to_lowercase(),
+                visible: true,
+            },
             ItemIDNode::Node { node, children } => ItemIDNodeRef::Node {
                 node,
                 children: children.iter().map(ItemIDNodeRef::from).collect(),
+                visible: true,
             },
         }
     }
 }
 
-impl ItemIDNode {
-    fn filter(&self, filter: &str) -> Option<ItemIDNodeRef> {
-        if filter.is_empty() {
-            Some(ItemIDNodeRef::from(self))
-        } else {
-            match self {
-                ItemIDNode::Leaf { id, desc } => {
-                    if string_match(filter, desc) {
-                        Some(ItemIDNodeRef::Leaf { node: desc, value: id.0 })
-                    } else {
-                        None
-                    }
-                },
-                ItemIDNode::Node { node, children } => {
-                    let children: Vec<_> = children
-                        .iter()
-                        .filter_map(|c| c.filter(filter).map(ItemIDNodeRef::from))
-                        .collect();
-                    if children.is_empty() {
-                        None
-                    } else {
-                        Some(ItemIDNodeRef::Node { node, children })
-                    }
-                },
+/// Picks the part of the item tree to show, e.g. only the spells.
+pub(crate) type ItemIDRoots = fn(&'static [ItemIDNode]) -> &'static [ItemIDNode];
+
+/// An item tree, built once the item IDs are loaded and then filtered in
+/// place by hiding the nodes that don't match.
+#[derive(Debug)]
+pub(crate) struct ItemIDTree<'a> {
+    roots: ItemIDRoots,
+    /// `None` until the item IDs are loaded.
+    nodes: Option<Vec<ItemIDNodeRef<'a>>>,
+    /// Lowercase filter the nodes are currently filtered with.
+    filter: String,
+}
+
+impl<'a> ItemIDTree<'a> {
+    pub(crate) fn new(roots: ItemIDRoots) -> Self {
+        ItemIDTree { roots, nodes: None, filter: String::new() }
+    }
+
+    fn nodes(&mut self) -> Option<&[ItemIDNodeRef<'a>]> {
+        if self.nodes.is_none() {
+            let mut nodes: Vec<_> =
+                (self.roots)(loaded_item_ids()?).iter().map(ItemIDNodeRef::from).collect();
+            for node in &mut nodes {
+                node.filter(&self.filter, false);
             }
+            self.nodes = Some(nodes);
+        }
+
+        self.nodes.as_deref()
+    }
+
+    pub(crate) fn set_filter(&mut self, filter: &str) {
+        let filter = filter.to_lowercase();
+        if filter == self.filter {
+            return;
+        }
+
+        let narrowing = filter.starts_with(&self.filter);
+        for node in self.nodes.iter_mut().flatten() {
+            node.filter(&filter, narrowing);
+        }
+        self.filter = filter;
+    }
+
+    pub(crate) fn render(&mut self, ui: &imgui::Ui, current: &mut u32) {
+        let filtered = !self.filter.is_empty();
+        match self.nodes() {
+            Some(nodes) => {
+                for node in nodes {
+                    node.render(ui, current, filtered);
+                }
+            },
+            None => ui.text_disabled("Loading items..."),
         }
     }
 }
 
+/// Whether the characters of `needle` appear in order in `haystack`. Both are
+/// expected to be lowercase already.
 fn string_match(needle: &str, haystack: &str) -> bool {
-    let needle = needle.chars().flat_map(char::to_lowercase);
-    let mut haystack = haystack.chars().flat_map(char::to_lowercase);
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|d| c == d))
+}
 
-    'o: for c in needle {
-        for d in &mut haystack {
-            if c == d {
-                continue 'o;
-            }
+const ISP_TAG: &str = "##item-spawn";
+
+/// The item tree, read from `item_ids.json` next to the DLL if present, so
+/// that items and names can be changed without rebuilding. Falls back to the
+/// embedded copy if the file is missing or invalid.
+static ITEM_ID_TREE: Lazy<Vec<ItemIDNode>> = Lazy::new(|| {
+    let path = util::get_dll_path().map(|mut path| {
+        path.pop();
+        path.push("item_ids.json");
+        path
+    });
+
+    if let Some(path) = path.filter(|path| path.exists()) {
+        match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+        {
+            Ok(tree) => {
+                info!("Loaded item IDs from {path:?}");
+                return tree;
+            },
+            Err(e) => error!("Couldn't load item IDs from {path:?}: {e}"),
         }
-        return false;
     }
-    true
+
+    serde_json::from_str(include_str!("item_ids.json")).unwrap()
+});
+
+/// Parses the item tree in a background thread, so that the item widgets
+/// don't stall the game the first time they are opened.
+pub(crate) fn load_item_ids() {
+    let spawned = thread::Builder::new().name("item-ids".to_string()).spawn(|| {
+        Lazy::force(&ITEM_ID_TREE);
+    });
+
+    if let Err(e) = spawned {
+        error!("Couldn't start item IDs thread: {e}");
+    }
 }
 
-const ISP_TAG: &str = "##item-spawn";
-static ITEM_ID_TREE: Lazy<Vec<ItemIDNode>> =
-    Lazy::new(|| serde_json::from_str(include_str!("item_ids.json")).unwrap());
+/// The item tree, if it has been parsed already.
+pub(crate) fn loaded_item_ids() -> Option<&'static [ItemIDNode]> {
+    Lazy::get(&ITEM_ID_TREE).map(Vec::as_slice)
+}
 
 #[derive(Debug)]
 pub(crate) struct ItemSpawner<'a> {
@@ -180,7 +289,10 @@ pub(crate) struct ItemSpawner<'a> {
 
     filter_string: String,
     logs: Vec<String>,
-    item_id_tree: Vec<ItemIDNodeRef<'a>>,
+    item_id_tree: ItemIDTree<'a>,
+
+    batch: Vec<(ItemSpawnInstance, String)>,
+    queue: SpawnQueue,
 }
 
 impl ItemSpawner<'_> {
@@ -212,37 +324,54 @@ impl ItemSpawner<'_> {
             infusion_type: 0,
             filter_string: String::new(),
             logs: Vec::new(),
-            item_id_tree: ITEM_ID_TREE.iter().map(ItemIDNodeRef::from).collect(),
+            item_id_tree: ItemIDTree::new(|tree| tree),
+            batch: Vec::new(),
+            queue: SpawnQueue::default(),
         }
     }
 
-    fn spawn(&mut self) {
-        if self.sentinel.get().is_none() {
-            self.write_log("Not spawning item when not in game".into());
-            return;
-        }
-
-        let upgrade = UPGRADES[self.upgrade].0;
-        let infusion = INFUSION_TYPES[self.infusion_type].0;
-
+    fn current_item(&self) -> (ItemSpawnInstance, String) {
         let i = ItemSpawnInstance {
             spawn_item_func_ptr: self.func_ptr as _,
             map_item_man: self.map_item_man as _,
             qty: self.qty,
             durability: self.durability,
-            upgrade,
-            infusion,
+            upgrade: UPGRADES[self.upgrade].0,
+            infusion: INFUSION_TYPES[self.infusion_type].0,
             item_id: self.item_id,
         };
-
-        self.write_log(format!(
-            "Spawning {} #{} {} {}",
+        let desc = format!(
+            "{} #{} {} {}",
             i.qty, self.item_id, UPGRADES[self.upgrade].1, INFUSION_TYPES[self.infusion_type].1,
-        ));
+        );
+
+        (i, desc)
+    }
+
+    fn spawn(&mut self) {
+        if self.sentinel.get().is_none() {
+            self.write_log("Not spawning item when not in game".into());
+            return;
+        }
+
+        let (i, desc) = self.current_item();
+        self.write_log(format!("Spawning {desc}"));
 
         unsafe {
             i.spawn();
         }
+        feedback::executed();
+    }
+
+    fn spawn_batch(&mut self) {
+        if self.sentinel.get().is_none() {
+            self.write_log("Not spawning items when not in game".into());
+            return;
+        }
+
+        self.write_log(format!("Spawning {} items", self.batch.len()));
+        self.queue.extend(self.batch.drain(..).map(|(i, _)| i));
+        feedback::executed();
     }
 
     fn write_log(&mut self, log: String) {
@@ -290,14 +419,11 @@ impl Widget for ItemSpawner<'_> {
                     .hint("Filter...")
                     .build()
                 {
-                    self.item_id_tree =
-                        ITEM_ID_TREE.iter().filter_map(|n| n.filter(&self.filter_string)).collect();
+                    self.item_id_tree.set_filter(&self.filter_string);
                 }
             }
             ui.child_window("##item-spawn-list").size([400., 200.]).build(|| {
-                for node in &self.item_id_tree {
-                    node.render(ui, &mut self.item_id, !self.filter_string.is_empty());
-                }
+                self.item_id_tree.render(ui, &mut self.item_id);
             });
 
             ui.set_next_item_width(195.);
@@ -314,12 +440,41 @@ impl Widget for ItemSpawner<'_> {
                 Cow::Borrowed(label)
             });
 
-            ui.slider_config("Qty", 1, 99).build(&mut self.qty);
+            ui.slider_config("Qty", 1, MAX_QTY).build(&mut self.qty);
             ui.slider_config("Dur", 0, 9999).build(&mut self.durability);
-            if ui.button_with_size(&self.label_load, [400., button_height]) {
+            if ui.button_with_size(&self.label_load, [195., button_height]) {
                 self.spawn();
             }
 
+            ui.same_line();
+            if ui.button_with_size("Add to batch", [195., button_height]) {
+                let item = self.current_item();
+                self.batch.push(item);
+            }
+
+            if !self.batch.is_empty() {
+                ui.child_window("##item-spawn-batch").size([400., 80.]).build(|| {
+                    let mut remove = None;
+                    for (idx, (_, desc)) in self.batch.iter().enumerate() {
+                        if ui.small_button(format!("x##item-spawn-batch-{idx}")) {
+                            remove = Some(idx);
+                        }
+                        ui.same_line();
+                        ui.text(desc);
+                    }
+                    if let Some(idx) = remove {
+                        self.batch.remove(idx);
+                    }
+                });
+
+                if ui.button_with_size(format!("Spawn batch ({} items)", self.batch.len()), [
+                    400.,
+                    button_height,
+                ]) {
+                    self.spawn_batch();
+                }
+            }
+
             if ui.button_with_size("Clear", [400., button_height]) {
                 self.filter_string.clear();
                 self.qty = 1;
@@ -327,7 +482,7 @@ impl Widget for ItemSpawner<'_> {
                 self.item_id = DEFAULT_ITEM;
                 self.upgrade = 0;
                 self.infusion_type = 0;
-                self.item_id_tree = ITEM_ID_TREE.iter().map(ItemIDNodeRef::from).collect();
+                self.item_id_tree.set_filter("");
             }
 
             if ui.button_with_size(&self.label_close, [400., button_height])
@@ -340,6 +495,8 @@ impl Widget for ItemSpawner<'_> {
     }
 
     fn interact(&mut self, ui: &imgui::Ui) {
+        self.queue.tick();
+
         if self.hotkey_load.map(|k| k.is_pressed(ui)).unwrap_or(false) {
             self.spawn();
         }
@@ -352,8 +509,36 @@ impl Widget for ItemSpawner<'_> {
     }
 }
 
+/// Items waiting to be spawned, one every [`SPAWN_INTERVAL`].
+#[derive(Debug, Default)]
+pub(crate) struct SpawnQueue {
+    items: VecDeque<ItemSpawnInstance>,
+    last_spawn: Option<Instant>,
+}
+
+impl SpawnQueue {
+    pub(crate) fn extend(&mut self, items: impl IntoIterator<Item = ItemSpawnInstance>) {
+        self.items.extend(items);
+    }
+
+    /// Spawns the next item if enough time has passed since the previous one.
+    /// Must be called every frame.
+    pub(crate) fn tick(&mut self) {
+        if self.last_spawn.map(|t| t.elapsed() < SPAWN_INTERVAL).unwrap_or(false) {
+            return;
+        }
+
+        if let Some(i) = self.items.pop_front() {
+            unsafe {
+                i.spawn();
+            }
+            self.last_spawn = Some(Instant::now());
+        }
+    }
+}
+
 #[derive(Debug)]
-struct HexU32(u32);
+pub(crate) struct HexU32(pub(crate) u32);
 
 impl Display for HexU32 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -395,14 +580,14 @@ impl<'de> Deserialize<'de> for HexU32 {
 }
 
 #[derive(Debug)]
-struct ItemSpawnInstance {
-    spawn_item_func_ptr: u64,
-    map_item_man: u64,
-    qty: u32,
-    durability: u32,
-    item_id: u32,
-    infusion: u32,
-    upgrade: u32,
+pub(crate) struct ItemSpawnInstance {
+    pub(crate) spawn_item_func_ptr: u64,
+    pub(crate) map_item_man: u64,
+    pub(crate) qty: u32,
+    pub(crate) durability: u32,
+    pub(crate) item_id: u32,
+    pub(crate) infusion: u32,
+    pub(crate) upgrade: u32,
 }
 
 impl Display for ItemSpawnInstance {
@@ -416,7 +601,7 @@ impl Display for ItemSpawnInstance {
 }
 
 impl ItemSpawnInstance {
-    unsafe fn spawn(&self) {
+    pub(crate) unsafe fn spawn(&self) {
         #[repr(C)]
         struct SpawnRequest {
             unknown: u32,
@@ -425,6 +610,10 @@ impl ItemSpawnInstance {
             durability: u32,
         }
 
+        if self.spawn_item_func_ptr == 0 || self.map_item_man == 0 {
+            return;
+        }
+
         type SpawnItemFn = extern "system" fn(*const c_void, *mut SpawnRequest, *mut [u32; 4]);
         let spawn_fn_ptr = std::mem::transmute::<_, SpawnItemFn>(self.spawn_item_func_ptr);
         let pp_map_item_man = self.map_item_man as *const *const c_void;
@@ -438,3 +627,94 @@ impl ItemSpawnInstance {
         spawn_fn_ptr(*pp_map_item_man, &mut spawn_request as *mut _, &mut [0u32; 4] as *mut _);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ItemIDNode, ItemIDNodeRef, ItemIDTree};
+
+    const TREE: &str = r#"[
+        {"node": "Arrows", "children": [
+            {"id": "00061D3C", "desc": "Dark Arrow"},
+            {"id": "00061AE4", "desc": "Fire Arrow"}
+        ]},
+        {"node": "Spells", "children": [
+            {"node": "Pyromancies", "children": [
+                {"id": "40124F80", "desc": "Fireball"}
+            ]},
+            {"node": "Sorceries", "children": []}
+        ]}
+    ]"#;
+
+    fn visible(nodes: &[ItemIDNodeRef], out: &mut Vec<String>) {
+        for node in nodes {
+            match node {
+                ItemIDNodeRef::Leaf { node, visible: true, .. } => out.push(node.to_string()),
+                ItemIDNodeRef::Node { node, children, visible: true } => {
+                    out.push(format!("[{node}]"));
+                    visible(children, out);
+                },
+                _ => {},
+            }
+        }
+    }
+
+    fn tree(items: &[ItemIDNode]) -> ItemIDTree {
+        let mut tree = ItemIDTree::new(|roots| roots);
+        tree.nodes = Some(items.iter().map(ItemIDNodeRef::from).collect());
+        tree
+    }
+
+    fn shown(tree: &ItemIDTree) -> Vec<String> {
+        let mut out = Vec::new();
+        visible(tree.nodes.as_deref().unwrap(), &mut out);
+        out
+    }
+
+    #[test]
+    fn test_filter_narrowing_and_widening() {
+        let items: Vec<ItemIDNode> = serde_json::from_str(TREE).unwrap();
+        let mut tree = tree(&items);
+        let everything = [
+            "[Arrows]",
+            "Dark Arrow",
+            "Fire Arrow",
+            "[Spells]",
+            "[Pyromancies]",
+            "Fireball",
+            "[Sorceries]",
+        ];
+
+        assert_eq!(shown(&tree), everything);
+
+        // Narrowing: each filter extends the previous one.
+        tree.set_filter("f");
+        assert_eq!(shown(&tree), [
+            "[Arrows]",
+            "Fire Arrow",
+            "[Spells]",
+            "[Pyromancies]",
+            "Fireball"
+        ]);
+        tree.set_filter("FireB");
+        assert_eq!(shown(&tree), ["[Spells]", "[Pyromancies]", "Fireball"]);
+        tree.set_filter("fireba");
+        assert_eq!(shown(&tree), ["[Spells]", "[Pyromancies]", "Fireball"]);
+
+        // Widening: items hidden by the longer filter come back.
+        tree.set_filter("fire");
+        assert_eq!(shown(&tree), [
+            "[Arrows]",
+            "Fire Arrow",
+            "[Spells]",
+            "[Pyromancies]",
+            "Fireball"
+        ]);
+
+        // Neither: a different filter checks every item again.
+        tree.set_filter("dark");
+        assert_eq!(shown(&tree), ["[Arrows]", "Dark Arrow"]);
+
+        tree.set_filter("");
+        assert_eq!(shown(&tree), everything);
+    }
+}
@@ -0,0 +1,153 @@
+use libds3::prelude::*;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::store_value::{ReadWrite, StoreValue};
+use practice_tool_core::widgets::Widget;
+use serde::Deserialize;
+
+use super::item_spawn::INFUSION_TYPES;
+use crate::feedback;
+
+/// A weapon, either as a raw ID or as a base ID with upgrade and infusion.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(untagged)]
+pub(crate) enum WeaponSpec {
+    Id(u32),
+    Upgraded {
+        id: u32,
+        #[serde(default)]
+        upgrade: u32,
+        #[serde(default)]
+        infusion: Infusion,
+    },
+}
+
+impl WeaponSpec {
+    fn id(&self) -> u32 {
+        match self {
+            WeaponSpec::Id(id) => *id,
+            WeaponSpec::Upgraded { id, upgrade, infusion } => *id + infusion.0 + (*upgrade).min(10),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[serde(try_from = "String")]
+pub(crate) struct Infusion(pub(crate) u32);
+
+impl TryFrom<String> for Infusion {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        INFUSION_TYPES
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(&value))
+            .map(|(offset, _)| Infusion(*offset))
+            .ok_or_else(|| format!("Unrecognized infusion: {value}"))
+    }
+}
+
+/// Equipment set in a loadout. Slots that aren't specified are left as they
+/// are.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct LoadoutSpec {
+    left_hand_1: Option<WeaponSpec>,
+    left_hand_2: Option<WeaponSpec>,
+    left_hand_3: Option<WeaponSpec>,
+    right_hand_1: Option<WeaponSpec>,
+    right_hand_2: Option<WeaponSpec>,
+    right_hand_3: Option<WeaponSpec>,
+    arrow_1: Option<u32>,
+    bolt_1: Option<u32>,
+    arrow_2: Option<u32>,
+    bolt_2: Option<u32>,
+    head: Option<u32>,
+    body: Option<u32>,
+    arms: Option<u32>,
+    legs: Option<u32>,
+    ring_1: Option<u32>,
+    ring_2: Option<u32>,
+    ring_3: Option<u32>,
+    ring_4: Option<u32>,
+}
+
+impl LoadoutSpec {
+    fn apply(&self, equipment: &mut Equipment) {
+        fn set<T: Copy>(slot: &mut u32, value: Option<T>, f: impl Fn(T) -> u32) {
+            if let Some(value) = value {
+                *slot = f(value);
+            }
+        }
+
+        let weapon = |w: WeaponSpec| w.id();
+        let id = |i: u32| i;
+
+        let [[l1, r1], [l2, r2], [l3, r3]] = &mut equipment.weapons;
+        set(l1, self.left_hand_1, weapon);
+        set(l2, self.left_hand_2, weapon);
+        set(l3, self.left_hand_3, weapon);
+        set(r1, self.right_hand_1, weapon);
+        set(r2, self.right_hand_2, weapon);
+        set(r3, self.right_hand_3, weapon);
+
+        let [a1, b1, a2, b2] = &mut equipment.ammo;
+        set(a1, self.arrow_1, id);
+        set(b1, self.bolt_1, id);
+        set(a2, self.arrow_2, id);
+        set(b2, self.bolt_2, id);
+
+        let [head, body, arms, legs] = &mut equipment.armor;
+        set(head, self.head, id);
+        set(body, self.body, id);
+        set(arms, self.arms, id);
+        set(legs, self.legs, id);
+
+        let [ring1, ring2, ring3, ring4] = &mut equipment.rings;
+        set(ring1, self.ring_1, id);
+        set(ring2, self.ring_2, id);
+        set(ring3, self.ring_3, id);
+        set(ring4, self.ring_4, id);
+    }
+}
+
+struct Loadout {
+    ptr: PointerChain<Equipment>,
+    current: Equipment,
+    spec: LoadoutSpec,
+    label: String,
+}
+
+impl ReadWrite for Loadout {
+    fn read(&mut self) -> bool {
+        if let Some(current) = self.ptr.read() {
+            self.current = current;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn write(&mut self) {
+        let mut equipment = self.current;
+        self.spec.apply(&mut equipment);
+        if self.ptr.write(equipment).is_some() {
+            feedback::executed();
+        }
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+pub(crate) fn loadout(
+    name: &str,
+    spec: LoadoutSpec,
+    ptr: PointerChain<Equipment>,
+    key: Option<Key>,
+) -> Box<dyn Widget> {
+    Box::new(StoreValue::new(
+        Loadout { ptr, current: Equipment::default(), spec, label: format!("Loadout {name}") },
+        key,
+    ))
+}
@@ -0,0 +1,354 @@
+use std::fmt::Write;
+
+use imgui::InputText;
+use libds3::memedit::PointerChain;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::{feedback, restore};
+
+const MV_TAG: &str = "##memory-viewer";
+const ROW_SIZE: usize = 16;
+const ROWS: usize = 16;
+const PAGE_SIZE: usize = ROW_SIZE * ROWS;
+const COLUMN_WIDTH: f32 = 560.;
+
+/// Parses a list of hexadecimal numbers separated by commas or spaces, with
+/// or without `0x`: an address, followed by the offsets of a pointer chain.
+fn parse_chain(text: &str) -> Result<Vec<usize>, String> {
+    let values = text
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let digits = s.trim_start_matches("0x").trim_start_matches("0X");
+            usize::from_str_radix(digits, 16).map_err(|_| format!("Invalid number {s:?}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if values.is_empty() {
+        Err("Enter an address".to_string())
+    } else {
+        Ok(values)
+    }
+}
+
+/// A chain with `offset` more bytes added at its end.
+fn offset_chain<T>(chain: &[usize], offset: usize) -> PointerChain<T> {
+    let mut chain = chain.to_vec();
+    if let Some(last) = chain.last_mut() {
+        *last = last.wrapping_add(offset);
+    }
+    PointerChain::new(&chain)
+}
+
+/// A byte written again every frame, through the chain it was frozen from.
+#[derive(Debug)]
+struct Frozen {
+    ptr: PointerChain<u8>,
+    value: u8,
+    label: String,
+}
+
+/// Hex dump of the memory at an address or at the end of a pointer chain,
+/// with byte editing and freezing.
+#[derive(Debug)]
+pub(crate) struct MemoryViewer {
+    hotkey_open: Option<Key>,
+    hotkey_close: Key,
+    label_open: String,
+    label_close: String,
+    input: String,
+    chain: Vec<usize>,
+    /// Offset of the shown page from the end of the chain.
+    offset: usize,
+    /// Offset of the selected byte in the page.
+    selected: Option<usize>,
+    edit: String,
+    error: Option<String>,
+    frozen: Vec<Frozen>,
+    logs: Vec<String>,
+}
+
+impl MemoryViewer {
+    pub(crate) fn new(hotkey_open: Option<Key>, hotkey_close: Key) -> Self {
+        let label_open = match hotkey_open {
+            Some(k) => format!("Memory viewer ({k})"),
+            None => "Memory viewer".to_string(),
+        };
+
+        MemoryViewer {
+            hotkey_open,
+            hotkey_close,
+            label_open,
+            label_close: format!("Close ({hotkey_close})"),
+            input: String::new(),
+            chain: Vec::new(),
+            offset: 0,
+            selected: None,
+            edit: String::new(),
+            error: None,
+            frozen: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    fn go(&mut self) {
+        match parse_chain(&self.input) {
+            Ok(chain) => {
+                self.chain = chain;
+                self.offset = 0;
+                self.selected = None;
+                self.error = None;
+            },
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn write_selected(&mut self, freeze: bool) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+        let Ok(value) = u8::from_str_radix(self.edit.trim(), 16) else {
+            self.error = Some(format!("Invalid byte {:?}", self.edit));
+            return;
+        };
+
+        let ptr = offset_chain::<u8>(&self.chain, self.offset + selected);
+        let Some(addr) = ptr.eval() else {
+            self.error = Some("Couldn't resolve the address".to_string());
+            return;
+        };
+        restore::remember(&ptr);
+        if ptr.write(value).is_none() {
+            self.error = Some(format!("Couldn't write at {:#x}", addr as usize));
+            return;
+        }
+
+        self.error = None;
+        feedback::executed();
+        if freeze {
+            let label = format!("{:#x} = {value:02X}", addr as usize);
+            self.logs.push(format!("Froze {label}"));
+            self.frozen.retain(|f| f.ptr != ptr);
+            self.frozen.push(Frozen { ptr, value, label });
+        } else {
+            self.logs.push(format!("Wrote {value:02X} at {:#x}", addr as usize));
+        }
+    }
+
+    fn render_dump(&mut self, ui: &imgui::Ui, base: usize, page: &[u8; PAGE_SIZE]) {
+        let mut buf = String::new();
+
+        for row in 0..ROWS {
+            buf.clear();
+            write!(buf, "{:016X}", base.wrapping_add(row * ROW_SIZE)).ok();
+            ui.text(&buf);
+
+            for col in 0..ROW_SIZE {
+                let i = row * ROW_SIZE + col;
+                buf.clear();
+                write!(buf, "{:02X}##byte{i}", page[i]).ok();
+                ui.same_line();
+                if ui
+                    .selectable_config(&buf)
+                    .selected(self.selected == Some(i))
+                    .size(ui.calc_text_size("00"))
+                    .build()
+                {
+                    self.selected = Some(i);
+                    self.edit = format!("{:02X}", page[i]);
+                }
+            }
+
+            buf.clear();
+            buf.extend(page[row * ROW_SIZE..(row + 1) * ROW_SIZE].iter().map(|&b| {
+                if b.is_ascii_graphic() {
+                    b as char
+                } else {
+                    '.'
+                }
+            }));
+            ui.same_line();
+            ui.text_disabled(&buf);
+        }
+    }
+
+    /// Shows the selected address and the values starting there.
+    fn render_selected(&mut self, ui: &imgui::Ui, base: usize, page: &[u8; PAGE_SIZE]) {
+        let Some(selected) = self.selected else {
+            ui.text_disabled("Click a byte to edit it");
+            return;
+        };
+
+        let mut b = [0u8; 8];
+        let end = (selected + b.len()).min(PAGE_SIZE);
+        b[..end - selected].copy_from_slice(&page[selected..end]);
+        let u32_value = u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+        ui.text(format!(
+            "{:#x}  u32 {}  i32 {}  f32 {}  u64 {:#x}",
+            base.wrapping_add(selected),
+            u32_value,
+            u32_value as i32,
+            f32::from_bits(u32_value),
+            u64::from_le_bytes(b),
+        ));
+
+        ui.set_next_item_width(60.);
+        let entered = InputText::new(ui, "##memory-viewer-byte", &mut self.edit)
+            .chars_hexadecimal(true)
+            .enter_returns_true(true)
+            .build();
+        ui.same_line();
+        if ui.button("Write") || entered {
+            self.write_selected(false);
+        }
+        ui.same_line();
+        if ui.button("Freeze") {
+            self.write_selected(true);
+        }
+    }
+
+    fn render_frozen(&mut self, ui: &imgui::Ui) {
+        if self.frozen.is_empty() {
+            return;
+        }
+
+        ui.separator();
+        ui.text("Frozen:");
+        let mut unfreeze = None;
+        for (i, frozen) in self.frozen.iter().enumerate() {
+            ui.text(&frozen.label);
+            ui.same_line();
+            if ui.small_button(format!("Unfreeze##{i}")) {
+                unfreeze = Some(i);
+            }
+        }
+        if let Some(i) = unfreeze {
+            let frozen = self.frozen.remove(i);
+            self.logs.push(format!("Unfroze {}", frozen.label));
+        }
+    }
+
+    fn render_viewer(&mut self, ui: &imgui::Ui) {
+        ui.set_next_item_width(COLUMN_WIDTH - 60.);
+        let entered = InputText::new(ui, "##memory-viewer-address", &mut self.input)
+            .hint("Address, then pointer offsets: 7FF6A2E0F000, 80, 1F90")
+            .enter_returns_true(true)
+            .build();
+        ui.same_line();
+        if ui.button("Go") || entered {
+            self.go();
+        }
+
+        if let Some(error) = &self.error {
+            ui.text_colored([1., 0.3, 0.3, 1.], error);
+        }
+
+        if self.chain.is_empty() {
+            return;
+        }
+
+        if ui.small_button("<<") {
+            self.offset = self.offset.wrapping_sub(PAGE_SIZE);
+        }
+        ui.same_line();
+        if ui.small_button("<") {
+            self.offset = self.offset.wrapping_sub(ROW_SIZE);
+        }
+        ui.same_line();
+        if ui.small_button(">") {
+            self.offset = self.offset.wrapping_add(ROW_SIZE);
+        }
+        ui.same_line();
+        if ui.small_button(">>") {
+            self.offset = self.offset.wrapping_add(PAGE_SIZE);
+        }
+        ui.same_line();
+        match self.offset as isize {
+            offset if offset < 0 => ui.text(format!("Offset -{:#x}", offset.unsigned_abs())),
+            offset => ui.text(format!("Offset +{offset:#x}")),
+        }
+
+        let ptr = offset_chain::<[u8; PAGE_SIZE]>(&self.chain, self.offset);
+        match (ptr.eval(), ptr.read()) {
+            (Some(base), Some(page)) => {
+                self.render_dump(ui, base as usize, &page);
+                ui.separator();
+                self.render_selected(ui, base as usize, &page);
+            },
+            (Some(base), None) => ui.text(format!("Can't read memory at {:#x}", base as usize)),
+            (None, _) => ui.text("Couldn't resolve the pointer chain"),
+        }
+    }
+}
+
+impl Widget for MemoryViewer {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+
+        if ui.button_with_size(&self.label_open, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            ui.open_popup(MV_TAG);
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(MV_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .scroll_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            self.render_viewer(ui);
+            self.render_frozen(ui);
+
+            ui.separator();
+            if ui.button_with_size(&self.label_close, [BUTTON_WIDTH * scale, BUTTON_HEIGHT])
+                || (self.hotkey_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        for frozen in &self.frozen {
+            if frozen.ptr.read().is_some_and(|v| v != frozen.value) {
+                frozen.ptr.write(frozen.value);
+            }
+        }
+
+        if self.hotkey_open.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            ui.open_popup(MV_TAG);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_chain;
+
+    #[test]
+    fn test_parse_chain() {
+        assert_eq!(parse_chain("0x144740178"), Ok(vec![0x144740178]));
+        assert_eq!(
+            parse_chain("144740178, 0x80 1F90,0X18"),
+            Ok(vec![0x144740178, 0x80, 0x1f90, 0x18])
+        );
+        assert_eq!(parse_chain("  10 ,, 20  "), Ok(vec![0x10, 0x20]));
+
+        assert_eq!(parse_chain(""), Err("Enter an address".to_string()));
+        assert_eq!(parse_chain(" , "), Err("Enter an address".to_string()));
+        assert_eq!(parse_chain("0x10 zz"), Err("Invalid number \"zz\"".to_string()));
+        assert_eq!(parse_chain("0x"), Err("Invalid number \"0x\"".to_string()));
+        assert!(parse_chain("10000000000000000 0").is_err());
+    }
+}
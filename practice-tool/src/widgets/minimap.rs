@@ -0,0 +1,218 @@
+//! Top-down minimap.
+//!
+//! There is no map geometry to draw from, so the map is built from the ground
+//! the player has covered: positions are sampled every frame into square cells
+//! per map, and the cells are drawn north up around the player. Saved
+//! positions and the configured points are drawn on top.
+
+use std::collections::{HashMap, HashSet};
+
+use imgui::{Condition, WindowFlags};
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget};
+
+use crate::config::Placement;
+use crate::widgets::area::NamedPoint;
+use crate::widgets::position::SAVED_POSITIONS;
+
+/// Side of a cell, in meters.
+const CELL_SIZE: f32 = 2.;
+
+/// Side of the window's drawing area, in pixels before scaling.
+const MAP_SIZE: f32 = 200.;
+
+/// Zoom levels, as the distance from the player to the edge of the map in
+/// meters.
+const RANGES: [f32; 4] = [25., 50., 100., 200.];
+
+struct Minimap {
+    ptr_angle: PointerChain<f32>,
+    ptr_pos: PointerChain<[f32; 3]>,
+    map_id: PointerChain<MapId>,
+    points: Vec<NamedPoint>,
+    placement: Option<Placement>,
+    /// Cells covered by the player, by map.
+    explored: HashMap<u32, HashSet<(i32, i32)>>,
+    visible: bool,
+    range: usize,
+    hotkey: Option<Key>,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl Minimap {
+    fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    fn record(&mut self) {
+        let (Some(map_id), Some([x, _, z])) = (self.map_id.read(), self.ptr_pos.read()) else {
+            return;
+        };
+
+        let cell = ((x / CELL_SIZE).floor() as i32, (z / CELL_SIZE).floor() as i32);
+        self.explored.entry(map_id.0).or_default().insert(cell);
+    }
+
+    fn render_window(&self, ui: &imgui::Ui) {
+        let (Some(map_id), Some([px, py, pz]), Some(angle)) =
+            (self.map_id.read(), self.ptr_pos.read(), self.ptr_angle.read())
+        else {
+            return;
+        };
+
+        let (position, pivot) = match self.placement {
+            Some(placement) => placement.position(ui.io().display_size),
+            None => ([ui.io().display_size[0] - 10., 10.], [1., 0.]),
+        };
+
+        ui.window("##minimap")
+            .position(position, Condition::Always)
+            .position_pivot(pivot)
+            .bg_alpha(0.4)
+            .flags(
+                WindowFlags::NO_TITLE_BAR
+                    | WindowFlags::NO_RESIZE
+                    | WindowFlags::NO_MOVE
+                    | WindowFlags::NO_SCROLLBAR
+                    | WindowFlags::ALWAYS_AUTO_RESIZE
+                    | WindowFlags::NO_INPUTS,
+            )
+            .build(|| {
+                let size = MAP_SIZE * scaling_factor(ui);
+                let [x, y] = ui.cursor_screen_pos();
+                let center = [x + size / 2., y + size / 2.];
+                let scale = size / 2. / RANGES[self.range];
+
+                // North up: world X goes right and world Z goes up.
+                let project = |wx: f32, wz: f32| {
+                    [center[0] + (wx - px) * scale, center[1] - (wz - pz) * scale]
+                };
+                let in_range =
+                    |[sx, sy]: [f32; 2]| sx >= x && sx <= x + size && sy >= y && sy <= y + size;
+
+                let draw_list = ui.get_window_draw_list();
+                draw_list.with_clip_rect_intersect([x, y], [x + size, y + size], || {
+                    let cell = CELL_SIZE * scale;
+                    for &(cx, cz) in self.explored.get(&map_id.0).into_iter().flatten() {
+                        let [sx, sy] = project(cx as f32 * CELL_SIZE, (cz + 1) as f32 * CELL_SIZE);
+                        if in_range([sx, sy]) || in_range([sx + cell, sy + cell]) {
+                            draw_list
+                                .add_rect([sx, sy], [sx + cell, sy + cell], [1., 1., 1., 0.25])
+                                .filled(true)
+                                .build();
+                        }
+                    }
+
+                    for &[sx, sy, sz] in SAVED_POSITIONS.lock().values() {
+                        let p = project(sx, sz);
+                        if in_range(p) {
+                            let color = height_color([1., 0.85, 0.2], sy - py);
+                            draw_list.add_circle(p, 4., color).filled(true).build();
+                        }
+                    }
+
+                    for point in &self.points {
+                        if !point.is_in(map_id) {
+                            continue;
+                        }
+
+                        let [wx, wy, wz] = point.position;
+                        let p = project(wx, wz);
+                        if in_range(p) {
+                            let color = height_color([0.4, 0.8, 1.], wy - py);
+                            draw_list.add_circle(p, 4., color).filled(true).build();
+                            draw_list.add_text([p[0] + 6., p[1] - 7.], color, &point.name);
+                        }
+                    }
+
+                    let (sin, cos) = angle.sin_cos();
+                    let heading = [center[0] + sin * 12., center[1] - cos * 12.];
+                    draw_list.add_line(center, heading, [1., 1., 1., 0.9]).thickness(2.).build();
+                    draw_list.add_circle(center, 4., [1., 1., 1., 0.9]).filled(true).build();
+                });
+
+                draw_list.add_rect([x, y], [x + size, y + size], [1., 1., 1., 0.3]).build();
+                ui.dummy([size, size]);
+                ui.text(format!("{map_id} {:.0}m", RANGES[self.range]));
+            });
+    }
+}
+
+/// Fades markers that are far above or below the player.
+fn height_color([r, g, b]: [f32; 3], dy: f32) -> [f32; 4] {
+    [r, g, b, if dy.abs() > 5. { 0.4 } else { 0.9 }]
+}
+
+impl Widget for Minimap {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let mut visible = self.visible;
+        if ui.checkbox(&self.label, &mut visible) {
+            self.toggle();
+        }
+
+        if self.visible {
+            ui.same_line();
+            if ui.small_button("Zoom") {
+                self.range = (self.range + 1) % RANGES.len();
+            }
+            ui.same_line();
+            if ui.small_button("Clear") {
+                if let Some(map_id) = self.map_id.read() {
+                    self.explored.remove(&map_id.0);
+                    self.logs.push(format!("Minimap of {map_id} cleared"));
+                }
+            }
+            self.render_window(ui);
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        if self.visible {
+            self.render_window(ui);
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.toggle();
+        }
+
+        self.record();
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn minimap(
+    position: (PointerChain<f32>, PointerChain<[f32; 3]>),
+    map_id: PointerChain<MapId>,
+    points: Vec<NamedPoint>,
+    placement: Option<Placement>,
+    hotkey: Option<Key>,
+) -> Box<dyn Widget> {
+    let label = match hotkey {
+        Some(k) => format!("Minimap ({k})"),
+        None => "Minimap".to_string(),
+    };
+
+    Box::new(Minimap {
+        ptr_angle: position.0,
+        ptr_pos: position.1,
+        map_id,
+        points,
+        placement,
+        explored: HashMap::new(),
+        visible: false,
+        range: 1,
+        hotkey,
+        label,
+        logs: Vec::new(),
+    })
+}
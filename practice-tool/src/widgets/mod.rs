@@ -1,13 +1,45 @@
+pub(crate) mod animation;
+pub(crate) mod area;
+pub(crate) mod attunement;
+pub(crate) mod boss_practice;
 pub(crate) mod character_stats;
+pub(crate) mod covenant;
 pub(crate) mod cycle_speed;
+pub(crate) mod estus;
 pub(crate) mod flag;
+pub(crate) mod flag_watch;
+pub(crate) mod fov;
+pub(crate) mod ghost;
 pub(crate) mod group;
+pub(crate) mod heal;
+pub(crate) mod hit_counter;
+pub(crate) mod input_macro;
+pub(crate) mod interlock;
+pub(crate) mod item_preset;
 pub(crate) mod item_spawn;
 pub(crate) mod label;
+pub(crate) mod loadout;
+pub(crate) mod memory_viewer;
+pub(crate) mod minimap;
 pub(crate) mod nudge_pos;
 pub(crate) mod open_menu;
+pub(crate) mod param_editor;
 pub(crate) mod position;
 pub(crate) mod quitout;
+pub(crate) mod refill;
+pub(crate) mod repeat;
+pub(crate) mod resurrect_boss;
 pub(crate) mod savefile_manager;
+pub(crate) mod scenario;
+pub(crate) mod script;
+pub(crate) mod sequence;
 pub(crate) mod souls;
+pub(crate) mod splits;
+pub(crate) mod switchable;
 pub(crate) mod target;
+pub(crate) mod target_hp;
+pub(crate) mod target_stats;
+pub(crate) mod tooltip;
+pub(crate) mod trail;
+pub(crate) mod unlock_bonfires;
+pub(crate) mod waypoints;
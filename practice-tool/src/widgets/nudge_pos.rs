@@ -1,15 +1,146 @@
+use std::f32::consts::PI;
+
 use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
 use practice_tool_core::key::Key;
-use practice_tool_core::widgets::nudge_position::NudgePosition;
-use practice_tool_core::widgets::Widget;
+use practice_tool_core::widgets::nudge_position::{NudgePosition, NudgePositionStorage};
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
 
+use crate::feedback;
 use crate::widgets::position::SavePosition;
+use crate::widgets::repeat::{KeyRepeat, Repeat};
+
+/// Rotation nudging and snapping. Angles are in degrees.
+pub(crate) struct NudgeRotation {
+    pub(crate) rotate: f32,
+    pub(crate) rotate_left: Option<Key>,
+    pub(crate) rotate_right: Option<Key>,
+    pub(crate) snap_angle: f32,
+    pub(crate) snap: Option<Key>,
+}
+
+struct NudgePositionRotation {
+    nudge: Box<dyn Widget>,
+    ptr_angle: PointerChain<f32>,
+    rotation: NudgeRotation,
+    label_left: String,
+    label_right: String,
+    label_snap: String,
+    logs: Vec<String>,
+}
+
+/// Wraps an angle in radians to the game's range, -π to π.
+fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(2. * PI) - PI
+}
+
+impl NudgePositionRotation {
+    fn rotate(&mut self, degrees: f32) {
+        if let Some(angle) = self.ptr_angle.read() {
+            self.ptr_angle.write(wrap_angle(angle + degrees.to_radians()));
+            feedback::executed();
+        }
+    }
+
+    fn snap(&mut self) {
+        let step = self.rotation.snap_angle.to_radians();
+        if step <= 0. {
+            return;
+        }
+
+        if let Some(angle) = self.ptr_angle.read() {
+            let snapped = wrap_angle((angle / step).round() * step);
+            self.ptr_angle.write(snapped);
+            self.logs.push(format!("Angle snapped to {:.1}°", snapped.to_degrees()));
+            feedback::executed();
+        }
+    }
+}
+
+impl Widget for NudgePositionRotation {
+    fn render(&mut self, ui: &imgui::Ui) {
+        self.nudge.render(ui);
+
+        let scale = scaling_factor(ui);
+        let button_width = BUTTON_WIDTH * scale;
+        let style = ui.clone_style();
+        let small_width = (button_width - style.item_spacing[0] * 2.) / 3.;
+
+        if ui.button_with_size(&self.label_left, [small_width, BUTTON_HEIGHT]) {
+            self.rotate(-self.rotation.rotate);
+        }
+        ui.same_line();
+        if ui.button_with_size(&self.label_right, [small_width, BUTTON_HEIGHT]) {
+            self.rotate(self.rotation.rotate);
+        }
+        ui.same_line();
+        if ui.button_with_size(&self.label_snap, [small_width, BUTTON_HEIGHT]) {
+            self.snap();
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.nudge.render_closed(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.nudge.interact(ui);
+
+        if self.rotation.rotate_left.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.rotate(-self.rotation.rotate);
+        }
+        if self.rotation.rotate_right.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.rotate(self.rotation.rotate);
+        }
+        if self.rotation.snap.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.snap();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+        self.nudge.log(tx);
+    }
+}
 
 pub(crate) fn nudge_position(
     ptr: (PointerChain<f32>, PointerChain<[f32; 3]>),
     nudge: f32,
     key_nudge_up: Option<Key>,
     key_nudge_down: Option<Key>,
+    repeat: Option<f32>,
+    rotation: NudgeRotation,
 ) -> Box<dyn Widget> {
-    Box::new(NudgePosition::new(SavePosition::new(ptr, nudge), key_nudge_up, key_nudge_down))
+    let ptr_angle = ptr.0.clone();
+    let mut up = SavePosition::new(ptr.clone(), nudge);
+    let mut down = SavePosition::new(ptr.clone(), nudge);
+    let nudge = Repeat::new(Box::new(NudgePosition::new(
+        SavePosition::new(ptr, nudge),
+        key_nudge_up,
+        key_nudge_down,
+    )))
+    .with(KeyRepeat::new(key_nudge_up, repeat), move || up.nudge_up())
+    .with(KeyRepeat::new(key_nudge_down, repeat), move || down.nudge_down())
+    .boxed();
+
+    if rotation.rotate == 0. && rotation.snap.is_none() {
+        return nudge;
+    }
+
+    let with_key = |label: String, key: Option<Key>| match key {
+        Some(k) => format!("{label} ({k})"),
+        None => label,
+    };
+
+    Box::new(NudgePositionRotation {
+        label_left: with_key(format!("-{}°", rotation.rotate), rotation.rotate_left),
+        label_right: with_key(format!("+{}°", rotation.rotate), rotation.rotate_right),
+        label_snap: with_key(format!("Snap {}°", rotation.snap_angle), rotation.snap),
+        nudge,
+        ptr_angle,
+        rotation,
+        logs: Vec::new(),
+    })
 }
@@ -5,6 +5,8 @@ use practice_tool_core::widgets::store_value::{ReadWrite, StoreValue};
 use practice_tool_core::widgets::Widget;
 use serde::Deserialize;
 
+use crate::feedback;
+
 #[derive(Deserialize, Debug, Clone, Copy)]
 pub(crate) enum OpenMenuKind {
     #[serde(rename = "travel")]
@@ -31,6 +33,10 @@ impl OpenMenu {
             OpenMenuKind::Attune => self.attune_ptr,
         };
 
+        if ptr == 0 {
+            return;
+        }
+
         let stack_space = [0u8; 0x48];
 
         let func: extern "system" fn(*const u8) = unsafe { mem::transmute(ptr) };
@@ -44,7 +50,8 @@ impl ReadWrite for OpenMenu {
     }
 
     fn write(&mut self) {
-        self.call()
+        self.call();
+        feedback::executed();
     }
 
     fn label(&self) -> &str {
@@ -0,0 +1,281 @@
+use std::fmt::Write;
+
+use imgui::{InputText, ListBox};
+use libds3::prelude::param_fields::PARAM_FIELDS;
+use libds3::prelude::*;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+const PE_TAG: &str = "##param-editor";
+const COLUMN1: f32 = 240.;
+const COLUMN2: f32 = 360.;
+const COLUMN3: f32 = 420.;
+const HEIGHT: f32 = 400.;
+
+/// Range of the values of an integer field type of [`PARAM_FIELDS`].
+fn field_range(ty: &str) -> Option<(i64, i64)> {
+    match ty {
+        "u8" => Some((0, u8::MAX as i64)),
+        "u16" => Some((0, u16::MAX as i64)),
+        "u32" => Some((0, u32::MAX as i64)),
+        "i8" => Some((i8::MIN as i64, i8::MAX as i64)),
+        "i16" => Some((i16::MIN as i64, i16::MAX as i64)),
+        "i32" => Some((i32::MIN as i64, i32::MAX as i64)),
+        _ => None,
+    }
+}
+
+/// Renders an input for every field whose name matches the filter.
+struct ImguiParamVisitor<'a> {
+    ui: &'a imgui::Ui,
+    filter: &'a str,
+    /// Names and types of the fields of the param.
+    fields: &'a [(&'a str, &'a str)],
+    error: &'a mut Option<String>,
+}
+
+impl ImguiParamVisitor<'_> {
+    fn matches(&self, name: &str) -> bool {
+        self.filter.is_empty() || name.to_lowercase().contains(&self.filter.to_lowercase())
+    }
+
+    fn field_type(&self, name: &str) -> Option<&str> {
+        self.fields.iter().find(|(n, _)| *n == name).map(|(_, ty)| *ty)
+    }
+
+    fn tooltip(&self, name: &str) {
+        if let (true, Some(ty)) = (self.ui.is_item_hovered(), self.field_type(name)) {
+            match field_range(ty) {
+                Some((min, max)) => self.ui.tooltip_text(format!("{ty}, {min} to {max}")),
+                None => self.ui.tooltip_text(ty),
+            }
+        }
+    }
+
+    /// Edits an integer field, rejecting values out of the range of its type
+    /// instead of truncating them.
+    fn input_int<T: Copy + Into<i64> + TryFrom<i64>>(&mut self, name: &str, v: &mut T) {
+        if !self.matches(name) {
+            return;
+        }
+
+        let mut i: i64 = (*v).into();
+        if self.ui.input_scalar(name, &mut i).build() {
+            let range = self.field_type(name).and_then(field_range);
+            match (T::try_from(i), range) {
+                (Ok(n), Some((min, max))) if (min..=max).contains(&i) => {
+                    *v = n;
+                    *self.error = None;
+                },
+                (_, Some((min, max))) => {
+                    *self.error = Some(format!("{name} must be between {min} and {max}"));
+                },
+                (Ok(n), None) => *v = n,
+                (Err(_), None) => *self.error = Some(format!("{i} is out of range for {name}")),
+            }
+        }
+        self.tooltip(name);
+    }
+}
+
+impl ParamVisitor for ImguiParamVisitor<'_> {
+    fn visit_u8(&mut self, name: &str, v: &mut u8) {
+        self.input_int(name, v);
+    }
+
+    fn visit_u16(&mut self, name: &str, v: &mut u16) {
+        self.input_int(name, v);
+    }
+
+    fn visit_u32(&mut self, name: &str, v: &mut u32) {
+        self.input_int(name, v);
+    }
+
+    fn visit_i8(&mut self, name: &str, v: &mut i8) {
+        self.input_int(name, v);
+    }
+
+    fn visit_i16(&mut self, name: &str, v: &mut i16) {
+        self.input_int(name, v);
+    }
+
+    fn visit_i32(&mut self, name: &str, v: &mut i32) {
+        self.input_int(name, v);
+    }
+
+    fn visit_f32(&mut self, name: &str, v: &mut f32) {
+        if self.matches(name) {
+            self.ui.input_float(name, v).build();
+            self.tooltip(name);
+        }
+    }
+
+    fn visit_bool(&mut self, name: &str, v: &mut bool) {
+        if self.matches(name) {
+            self.ui.checkbox(name, v);
+            self.tooltip(name);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParamEditor {
+    hotkey_open: Option<Key>,
+    hotkey_close: Key,
+    label_open: String,
+    label_close: String,
+    selected_param: usize,
+    selected_row: usize,
+    row_filter: String,
+    field_filter: String,
+    error: Option<String>,
+}
+
+impl ParamEditor {
+    pub(crate) fn new(hotkey_open: Option<Key>, hotkey_close: Key) -> Self {
+        let label_open = match hotkey_open {
+            Some(k) => format!("Param editor ({k})"),
+            None => "Param editor".to_string(),
+        };
+
+        ParamEditor {
+            hotkey_open,
+            hotkey_close,
+            label_open,
+            label_close: format!("Close ({hotkey_close})"),
+            selected_param: 0,
+            selected_row: 0,
+            row_filter: String::new(),
+            field_filter: String::new(),
+            error: None,
+        }
+    }
+
+    fn render_params(&mut self, ui: &imgui::Ui) {
+        let Some(params) = loaded_params() else {
+            ui.text("Params not loaded yet");
+            return;
+        };
+        let params = params.write();
+
+        ui.columns(3, "##param-editor-columns", false);
+        ui.set_column_width(0, COLUMN1 + 10.);
+        ui.set_column_width(1, COLUMN2 + 10.);
+
+        ListBox::new("##param-editor-names").size([COLUMN1, HEIGHT]).build(ui, || {
+            for (idx, k) in params.keys().enumerate() {
+                if ui.selectable_config(k).selected(idx == self.selected_param).build() {
+                    self.selected_param = idx;
+                    self.selected_row = 0;
+                    self.error = None;
+                }
+            }
+        });
+
+        let Some(param_name) = params.keys().nth(self.selected_param) else {
+            ui.columns(1, "##param-editor-columns", false);
+            return;
+        };
+
+        ui.next_column();
+        {
+            let _tok = ui.push_item_width(COLUMN2);
+            InputText::new(ui, "##param-editor-row-filter", &mut self.row_filter)
+                .hint("Filter by ID or name...")
+                .build();
+        }
+
+        let row_filter = self.row_filter.to_lowercase();
+        let names = PARAM_NAMES.get(param_name);
+        let mut buf = String::new();
+
+        ListBox::new("##param-editor-rows").size([COLUMN2, HEIGHT - 30.]).build(ui, || {
+            let Some(ids) = (unsafe { params.iter_param_ids(param_name) }) else {
+                return;
+            };
+
+            for (idx, id) in ids.enumerate() {
+                buf.clear();
+                match names.and_then(|names| names.get(&(id as usize))) {
+                    Some(name) => write!(buf, "{id} - {name}").ok(),
+                    None => write!(buf, "{id}").ok(),
+                };
+
+                if !row_filter.is_empty() && !buf.to_lowercase().contains(&row_filter) {
+                    continue;
+                }
+
+                if ui.selectable_config(&buf).selected(idx == self.selected_row).build() {
+                    self.selected_row = idx;
+                    self.error = None;
+                }
+            }
+        });
+
+        ui.next_column();
+        {
+            let _tok = ui.push_item_width(COLUMN3);
+            InputText::new(ui, "##param-editor-field-filter", &mut self.field_filter)
+                .hint("Filter fields...")
+                .build();
+        }
+
+        let fields = PARAM_FIELDS
+            .iter()
+            .find(|(name, _)| *name == param_name.as_str())
+            .map(|(_, fields)| *fields)
+            .unwrap_or_default();
+        let list_height = if self.error.is_some() { HEIGHT - 55. } else { HEIGHT - 30. };
+
+        ListBox::new("##param-editor-fields").size([COLUMN3, list_height]).build(ui, || {
+            let _tok = ui.push_item_width(120.);
+            params.visit_param_item(param_name, self.selected_row, &mut ImguiParamVisitor {
+                ui,
+                filter: &self.field_filter,
+                fields,
+                error: &mut self.error,
+            });
+        });
+
+        if let Some(error) = &self.error {
+            ui.text_colored([1., 0.3, 0.3, 1.], error);
+        }
+
+        ui.columns(1, "##param-editor-columns", false);
+    }
+}
+
+impl Widget for ParamEditor {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+
+        if ui.button_with_size(&self.label_open, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            ui.open_popup(PE_TAG);
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(PE_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .scroll_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            self.render_params(ui);
+
+            if ui.button_with_size(&self.label_close, [COLUMN1, BUTTON_HEIGHT * scale])
+                || (self.hotkey_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey_open.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            ui.open_popup(PE_TAG);
+        }
+    }
+}
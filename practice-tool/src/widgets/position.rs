@@ -1,15 +1,44 @@
+use std::collections::BTreeMap;
 use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use libds3::memedit::PointerChain;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use practice_tool_core::crossbeam_channel::Sender;
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::nudge_position::NudgePositionStorage;
 use practice_tool_core::widgets::position::{Position, PositionStorage};
-use practice_tool_core::widgets::Widget;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
 
-pub(super) struct SavePosition {
+use crate::feedback;
+
+const TELEPORT_TAG: &str = "##teleport";
+
+/// Last position saved by each [`SavePosition`], for the minimap.
+pub(crate) static SAVED_POSITIONS: Lazy<Mutex<BTreeMap<usize, [f32; 3]>>> =
+    Lazy::new(Default::default);
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Slot of the position widget, shared with the remote control commands so
+/// that both save and load the same position.
+static SHARED_SLOT: Lazy<(usize, Arc<Mutex<Slot>>)> =
+    Lazy::new(|| (NEXT_ID.fetch_add(1, Ordering::Relaxed), Default::default()));
+
+#[derive(Default)]
+struct Slot {
+    position: [f32; 4],
+    camera: Option<[f32; 16]>,
+}
+
+pub(crate) struct SavePosition {
+    id: usize,
     ptr_angle: PointerChain<f32>,
     ptr_pos: PointerChain<[f32; 3]>,
-    saved_position: [f32; 4],
+    ptr_camera: Option<PointerChain<[f32; 16]>>,
+    slot: Arc<Mutex<Slot>>,
     label_current: String,
     label_stored: String,
     valid: bool,
@@ -17,36 +46,63 @@ pub(super) struct SavePosition {
 }
 
 impl SavePosition {
-    pub(super) fn new(ptr: (PointerChain<f32>, PointerChain<[f32; 3]>), nudge: f32) -> Self {
+    pub(crate) fn new(ptr: (PointerChain<f32>, PointerChain<[f32; 3]>), nudge: f32) -> Self {
         Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
             ptr_angle: ptr.0,
             ptr_pos: ptr.1,
-            saved_position: [0.0; 4],
+            ptr_camera: None,
+            slot: Default::default(),
             label_current: String::new(),
             label_stored: String::new(),
             valid: false,
             nudge,
         }
     }
+
+    /// Uses the slot shared by the position widget and the remote control
+    /// commands instead of a slot of its own.
+    pub(crate) fn shared(mut self) -> Self {
+        let (id, slot) = &*SHARED_SLOT;
+        self.id = *id;
+        self.slot = Arc::clone(slot);
+        self
+    }
+
+    /// Also saves and restores the camera, for setups that depend on its
+    /// angle.
+    pub(crate) fn with_camera(mut self, ptr: Option<PointerChain<[f32; 16]>>) -> Self {
+        self.ptr_camera = ptr;
+        self
+    }
 }
 
 impl PositionStorage for SavePosition {
     fn save(&mut self) {
         if let (Some(pos), Some(angle)) = (self.ptr_pos.read(), self.ptr_angle.read()) {
-            self.saved_position = [pos[0], pos[1], pos[2], angle];
+            let mut slot = self.slot.lock();
+            slot.position = [pos[0], pos[1], pos[2], angle];
+            slot.camera = self.ptr_camera.as_ref().and_then(|ptr| ptr.read());
             self.valid = true;
+            SAVED_POSITIONS.lock().insert(self.id, pos);
+            feedback::executed();
         } else {
             self.valid = false;
         }
     }
 
     fn load(&mut self) {
-        self.ptr_pos.write([
-            self.saved_position[0],
-            self.saved_position[1],
-            self.saved_position[2],
-        ]);
-        self.ptr_angle.write(self.saved_position[3]);
+        let slot = self.slot.lock();
+        let [x, y, z, angle] = slot.position;
+        if self.ptr_pos.write([x, y, z]).is_none() {
+            return;
+        }
+        self.ptr_angle.write(angle);
+
+        if let (Some(ptr), Some(camera)) = (&self.ptr_camera, slot.camera) {
+            ptr.write(camera);
+        }
+        feedback::executed();
     }
 
     fn display_current(&mut self) -> &str {
@@ -76,7 +132,7 @@ impl PositionStorage for SavePosition {
     fn display_stored(&mut self) -> &str {
         self.label_stored.clear();
 
-        let [x, y, z, a] = self.saved_position;
+        let [x, y, z, a] = self.slot.lock().position;
 
         write!(self.label_stored, "{:7.1} {:7.1} {:7.1} {:7.1}", x, y, z, a).ok();
 
@@ -92,20 +148,155 @@ impl NudgePositionStorage for SavePosition {
     fn nudge_up(&mut self) {
         if let Some([x, y, z]) = self.ptr_pos.read() {
             self.ptr_pos.write([x, y + self.nudge, z]);
+            feedback::executed();
         }
     }
 
     fn nudge_down(&mut self) {
         if let Some([x, y, z]) = self.ptr_pos.read() {
             self.ptr_pos.write([x, y - self.nudge, z]);
+            feedback::executed();
+        }
+    }
+}
+
+/// Parses a position from four numbers, as written by "Copy position", in
+/// order X, Y, Z, angle. Any mix of whitespace and commas separates them.
+fn parse_position(text: &str) -> Result<[f32; 4], String> {
+    let values = text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<f32>().map_err(|e| format!("Invalid coordinate \"{s}\": {e}")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    values.try_into().map_err(|v: Vec<f32>| format!("Expected 4 coordinates, got {}", v.len()))
+}
+
+/// Position saving and loading, plus teleporting to typed or pasted
+/// coordinates and copying the current ones to the clipboard.
+struct Teleport {
+    position: Position<SavePosition>,
+    ptr_angle: PointerChain<f32>,
+    ptr_pos: PointerChain<[f32; 3]>,
+    input: [f32; 4],
+    key_close: Key,
+    label_close: String,
+    logs: Vec<String>,
+}
+
+impl Teleport {
+    fn teleport(&mut self) {
+        let [x, y, z, a] = self.input;
+        if self.ptr_pos.write([x, y, z]).is_none() {
+            self.logs.push("Can't teleport when not in game".to_string());
+            return;
+        }
+        self.ptr_angle.write(a);
+        self.logs.push(format!("Teleported to {x:.3} {y:.3} {z:.3} {a:.3}"));
+        feedback::executed();
+    }
+
+    fn copy(&mut self, ui: &imgui::Ui) {
+        if let (Some([x, y, z]), Some(a)) = (self.ptr_pos.read(), self.ptr_angle.read()) {
+            ui.set_clipboard_text(format!("{x:.3} {y:.3} {z:.3} {a:.3}"));
+            self.logs.push("Position copied to clipboard".to_string());
+        }
+    }
+
+    fn paste(&mut self, ui: &imgui::Ui) {
+        let text = ui.clipboard_text().unwrap_or_default();
+        match parse_position(&text) {
+            Ok(input) => self.input = input,
+            Err(e) => self.logs.push(e),
+        }
+    }
+}
+
+impl Widget for Teleport {
+    fn render(&mut self, ui: &imgui::Ui) {
+        self.position.render(ui);
+
+        let scale = scaling_factor(ui);
+        let button_width = BUTTON_WIDTH * scale;
+        let style = ui.clone_style();
+        let half_width = (button_width - style.item_spacing[0]) / 2.;
+
+        if ui.button_with_size("Teleport", [half_width, BUTTON_HEIGHT]) {
+            if let (Some([x, y, z]), Some(a)) = (self.ptr_pos.read(), self.ptr_angle.read()) {
+                self.input = [x, y, z, a];
+            }
+            ui.open_popup(TELEPORT_TAG);
+        }
+        ui.same_line();
+        if ui.button_with_size("Copy position", [half_width, BUTTON_HEIGHT]) {
+            self.copy(ui);
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(TELEPORT_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            ui.text("X / Y / Z / Angle");
+            ui.set_next_item_width(400.);
+            ui.input_float4("##teleport-input", &mut self.input).display_format("%.3f").build();
+
+            if ui.button_with_size("Paste", [195., BUTTON_HEIGHT]) {
+                self.paste(ui);
+            }
+            ui.same_line();
+            if ui.button_with_size("Go", [195., BUTTON_HEIGHT]) {
+                self.teleport();
+                ui.close_current_popup();
+            }
+
+            if ui.button_with_size(&self.label_close, [400., BUTTON_HEIGHT])
+                || (self.key_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.position.render_closed(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.position.interact(ui);
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
         }
+        self.position.log(tx);
     }
 }
 
 pub(crate) fn save_position(
     ptr: (PointerChain<f32>, PointerChain<[f32; 3]>),
+    camera: Option<PointerChain<[f32; 16]>>,
     key_load: Option<Key>,
     key_save: Option<Key>,
+    key_close: Key,
 ) -> Box<dyn Widget> {
-    Box::new(Position::new(SavePosition::new(ptr, 0.0), key_load, key_save))
+    let (ptr_angle, ptr_pos) = ptr.clone();
+    Box::new(Teleport {
+        position: Position::new(
+            SavePosition::new(ptr, 0.0).shared().with_camera(camera),
+            key_load,
+            key_save,
+        ),
+        ptr_angle,
+        ptr_pos,
+        input: [0.; 4],
+        key_close,
+        label_close: format!("Close ({key_close})"),
+        logs: Vec::new(),
+    })
 }
@@ -1,7 +1,19 @@
+use std::time::{Duration, Instant};
+
 use libds3::memedit::PointerChain;
+use practice_tool_core::crossbeam_channel::Sender;
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::store_value::{ReadWrite, StoreValue};
-use practice_tool_core::widgets::Widget;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::{feedback, util};
+
+/// Scan code of the Enter key, which confirms in menus.
+const SCAN_ENTER: u16 = 0x1c;
+/// Time between confirm presses while going through the title menus.
+const CONFIRM_INTERVAL: Duration = Duration::from_millis(1500);
+/// Time after which reloading is given up on.
+const RELOAD_TIMEOUT: Duration = Duration::from_secs(60);
 
 struct Quitout {
     ptr: PointerChain<u8>,
@@ -19,7 +31,9 @@ impl ReadWrite for Quitout {
     }
 
     fn write(&mut self) {
-        self.ptr.write(1);
+        if self.ptr.write(1).is_some() {
+            feedback::executed();
+        }
     }
 
     fn label(&self) -> &str {
@@ -27,6 +41,220 @@ impl ReadWrite for Quitout {
     }
 }
 
-pub(crate) fn quitout(ptr: PointerChain<u8>, key: Option<Key>) -> Box<dyn Widget> {
-    Box::new(StoreValue::new(Quitout::new(ptr), key))
+enum ReloadStage {
+    Idle,
+    /// Waiting for the game to leave the world.
+    QuittingOut(Instant),
+    /// On the title screen, pressing confirm until the save is loaded.
+    Loading {
+        started: Instant,
+        last_press: Instant,
+    },
+}
+
+/// Loads the save again after a quitout, by going through the title screen
+/// and main menu, pressing confirm until the player is back in game.
+pub(crate) struct SaveReload {
+    stage: ReloadStage,
+    last_update: Instant,
+}
+
+impl SaveReload {
+    pub(crate) fn new() -> Self {
+        SaveReload { stage: ReloadStage::Idle, last_update: Instant::now() }
+    }
+
+    /// Waits for the quitout just requested, then reloads.
+    pub(crate) fn start(&mut self) {
+        self.stage = ReloadStage::QuittingOut(Instant::now());
+    }
+
+    /// Advances the reload. Returns `true` once the player is back in game
+    /// after it. Failures are pushed to `logs`.
+    pub(crate) fn update(&mut self, in_game: bool, logs: &mut Vec<String>) -> bool {
+        let now = Instant::now();
+        let mut done = false;
+
+        self.stage = match self.stage {
+            ReloadStage::Idle => ReloadStage::Idle,
+            ReloadStage::QuittingOut(started) if in_game => {
+                if now - started > RELOAD_TIMEOUT {
+                    logs.push("Quitout didn't happen".to_string());
+                    ReloadStage::Idle
+                } else {
+                    ReloadStage::QuittingOut(started)
+                }
+            },
+            ReloadStage::QuittingOut(started) => ReloadStage::Loading { started, last_press: now },
+            ReloadStage::Loading { .. } if in_game => {
+                done = true;
+                ReloadStage::Idle
+            },
+            // Presses would go to whichever window has the focus, so wait
+            // for the game to get it back, without counting towards the
+            // timeout.
+            ReloadStage::Loading { started, last_press } if !util::game_is_foreground() => {
+                let paused = now - self.last_update;
+                ReloadStage::Loading { started: started + paused, last_press: last_press + paused }
+            },
+            ReloadStage::Loading { started, .. } if now - started > RELOAD_TIMEOUT => {
+                logs.push("Couldn't reload the save".to_string());
+                ReloadStage::Idle
+            },
+            ReloadStage::Loading { started, last_press } if now - last_press > CONFIRM_INTERVAL => {
+                util::press_key(SCAN_ENTER);
+                ReloadStage::Loading { started, last_press: now }
+            },
+            ReloadStage::Loading { started, last_press } => {
+                ReloadStage::Loading { started, last_press }
+            },
+        };
+        self.last_update = now;
+
+        done
+    }
+
+    pub(crate) fn render_closed(&self, ui: &imgui::Ui) {
+        if matches!(self.stage, ReloadStage::Loading { .. }) {
+            if util::game_is_foreground() {
+                ui.text("Reloading...");
+            } else {
+                ui.text("Reloading paused until the game is focused");
+            }
+        }
+    }
+}
+
+/// Quitout with extras: optionally goes through the title screen and main
+/// menu to load the save again, by pressing confirm until the player is back
+/// in game, and optionally snapshots the position right before quitting out
+/// so it can be restored after reloading.
+struct QuitoutMacro {
+    ptr: PointerChain<u8>,
+    hp: PointerChain<[u32; 3]>,
+    reload: Option<SaveReload>,
+    position: Option<(PointerChain<f32>, PointerChain<[f32; 3]>)>,
+    saved_position: Option<([f32; 3], f32)>,
+    hotkey: Option<Key>,
+    key_restore: Option<Key>,
+    label: String,
+    label_restore: String,
+    logs: Vec<String>,
+}
+
+impl QuitoutMacro {
+    fn trigger(&mut self) {
+        if self.hp.read().is_none() {
+            return;
+        }
+
+        if let Some((angle, pos)) = &self.position {
+            if let (Some(pos), Some(angle)) = (pos.read(), angle.read()) {
+                self.saved_position = Some((pos, angle));
+            }
+        }
+
+        self.ptr.write(1);
+        if let Some(reload) = &mut self.reload {
+            reload.start();
+        }
+        feedback::executed();
+    }
+
+    fn restore(&mut self) {
+        let (Some((angle_ptr, pos_ptr)), Some((pos, angle))) =
+            (&self.position, self.saved_position)
+        else {
+            self.logs.push("No position saved before quitting out".to_string());
+            return;
+        };
+
+        pos_ptr.write(pos);
+        angle_ptr.write(angle);
+        self.logs.push("Pre-quitout position restored".to_string());
+        feedback::executed();
+    }
+
+    fn update(&mut self) {
+        let in_game = self.hp.read().is_some();
+        if let Some(reload) = &mut self.reload {
+            reload.update(in_game, &mut self.logs);
+        }
+    }
+}
+
+impl Widget for QuitoutMacro {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            self.trigger();
+        }
+
+        if self.position.is_some()
+            && ui.button_with_size(&self.label_restore, [BUTTON_WIDTH * scale, BUTTON_HEIGHT])
+        {
+            self.restore();
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        if let Some(reload) = &self.reload {
+            reload.render_closed(ui);
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.update();
+
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.trigger();
+        }
+        if self.key_restore.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.restore();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+/// Position to snapshot before quitting out, and the hotkey to restore it.
+pub(crate) struct QuitoutPosition {
+    pub(crate) ptr: (PointerChain<f32>, PointerChain<[f32; 3]>),
+    pub(crate) restore: Option<Key>,
+}
+
+pub(crate) fn quitout(
+    ptr: PointerChain<u8>,
+    hp: PointerChain<[u32; 3]>,
+    reload: bool,
+    position: Option<QuitoutPosition>,
+    key: Option<Key>,
+) -> Box<dyn Widget> {
+    if !reload && position.is_none() {
+        return Box::new(StoreValue::new(Quitout::new(ptr), key));
+    }
+
+    let with_key = |label: &str, key: Option<Key>| match key {
+        Some(k) => format!("{label} ({k})"),
+        None => label.to_string(),
+    };
+    let label = with_key(if reload { "Quitout and reload" } else { "Quitout" }, key);
+    let key_restore = position.as_ref().and_then(|p| p.restore);
+
+    Box::new(QuitoutMacro {
+        ptr,
+        hp,
+        reload: reload.then(SaveReload::new),
+        position: position.map(|p| p.ptr),
+        saved_position: None,
+        hotkey: key,
+        key_restore,
+        label,
+        label_restore: with_key("Restore pre-quitout position", key_restore),
+        logs: Vec::new(),
+    })
 }
@@ -0,0 +1,135 @@
+use std::mem::size_of;
+use std::ops::RangeInclusive;
+
+use libds3::prelude::*;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::store_value::{ReadWrite, StoreValue};
+use practice_tool_core::widgets::Widget;
+
+use crate::feedback;
+
+const GOODS: u32 = 0x40000000;
+
+// Every upgrade level of the flasks is a separate goods item, with a full and
+// an empty variant: +0 is 150 and 151, +1 is 152 and 153, and so on.
+pub(crate) const ESTUS_FLASK: RangeInclusive<u32> = GOODS | 150..=GOODS | 171;
+pub(crate) const ASHEN_ESTUS_FLASK: RangeInclusive<u32> = GOODS | 190..=GOODS | 211;
+
+/// Returns the full variant of an estus flask, with its charges set to its
+/// allocation, or `None` if the item isn't a flask.
+fn refilled_flask(item: &InventoryItem, [estus, ashen_estus]: [u8; 2]) -> Option<InventoryItem> {
+    let (flasks, charges) = if ESTUS_FLASK.contains(&item.id) {
+        (ESTUS_FLASK, estus)
+    } else if ASHEN_ESTUS_FLASK.contains(&item.id) {
+        (ASHEN_ESTUS_FLASK, ashen_estus)
+    } else {
+        return None;
+    };
+
+    let id = flasks.start() + ((item.id - flasks.start()) & !1);
+    Some(InventoryItem { id, quantity: charges as u32, ..*item })
+}
+
+fn changed(item: &InventoryItem, new: &InventoryItem) -> bool {
+    item.id != new.id || item.quantity != new.quantity
+}
+
+/// Writes the given slots of the inventory, leaving the others alone.
+fn write_slots(
+    inventory: &PointerChain<[InventoryItem; INVENTORY_SLOTS]>,
+    slots: impl IntoIterator<Item = (usize, InventoryItem)>,
+) {
+    let Some(base) = inventory.eval() else {
+        return;
+    };
+    let base = base as usize;
+
+    for (slot, item) in slots {
+        PointerChain::<InventoryItem>::new(&[base + slot * size_of::<InventoryItem>()]).write(item);
+    }
+}
+
+/// Restores the estus charges of both flasks.
+pub(crate) fn refill_estus(
+    inventory: &PointerChain<[InventoryItem; INVENTORY_SLOTS]>,
+    estus_allocation: &PointerChain<[u8; 2]>,
+) {
+    if let (Some(items), Some(allocation)) = (inventory.read(), estus_allocation.read()) {
+        let refilled = items.iter().enumerate().filter_map(|(slot, item)| {
+            let refilled = refilled_flask(item, allocation)?;
+            changed(item, &refilled).then_some((slot, refilled))
+        });
+        write_slots(inventory, refilled);
+    }
+}
+
+/// Restores the estus charges of both flasks and brings the given consumables
+/// (goods IDs, with or without the category) back to their maximum quantity.
+struct Refill {
+    inventory: PointerChain<[InventoryItem; INVENTORY_SLOTS]>,
+    estus_allocation: PointerChain<[u8; 2]>,
+    consumables: Vec<u32>,
+}
+
+impl Refill {
+    /// Looks up the maximum quantity of each consumable.
+    fn consumable_caps(&self) -> Vec<(u32, u32)> {
+        let Some(params) = loaded_params() else {
+            return Vec::new();
+        };
+        let params = params.read();
+        let Some(goods) = (unsafe { params.iter_param::<EquipParamGoods>("EquipParamGoods") })
+        else {
+            return Vec::new();
+        };
+
+        goods
+            .filter_map(|p| {
+                let id = GOODS | p.id as u32;
+                let max_num = p.param?.max_num;
+                self.consumables.contains(&id).then_some((id, max_num.max(0) as u32))
+            })
+            .collect()
+    }
+}
+
+impl ReadWrite for Refill {
+    fn read(&mut self) -> bool {
+        self.inventory.eval().is_some()
+    }
+
+    fn write(&mut self) {
+        let (Some(inventory), Some(allocation)) =
+            (self.inventory.read(), self.estus_allocation.read())
+        else {
+            return;
+        };
+
+        let caps = if self.consumables.is_empty() { Vec::new() } else { self.consumable_caps() };
+
+        let refilled = inventory.iter().enumerate().filter_map(|(slot, item)| {
+            let refilled = refilled_flask(item, allocation).or_else(|| {
+                let &(_, cap) = caps.iter().find(|(id, _)| *id == item.id)?;
+                Some(InventoryItem { quantity: cap, ..*item })
+            })?;
+            changed(item, &refilled).then_some((slot, refilled))
+        });
+
+        write_slots(&self.inventory, refilled);
+        feedback::executed();
+    }
+
+    fn label(&self) -> &str {
+        "Refill"
+    }
+}
+
+pub(crate) fn refill(
+    inventory: PointerChain<[InventoryItem; INVENTORY_SLOTS]>,
+    estus_allocation: PointerChain<[u8; 2]>,
+    consumables: Vec<u32>,
+    key: Option<Key>,
+) -> Box<dyn Widget> {
+    let consumables = consumables.into_iter().map(|id| GOODS | id).collect();
+    Box::new(StoreValue::new(Refill { inventory, estus_allocation, consumables }, key))
+}
@@ -0,0 +1,110 @@
+//! Repeating an action while its hotkey is held down.
+//!
+//! Hotkeys normally act once, when released. With a repeat rate, holding the
+//! key also runs the action continuously after a short delay, like keyboard
+//! auto-repeat.
+
+use std::time::{Duration, Instant};
+
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::Widget;
+
+use crate::key_state::KeyState;
+
+/// How long the key has to be held before the action starts repeating.
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+/// Tracks a held key combination and tells when the action should run.
+pub(crate) struct KeyRepeat {
+    key: KeyState,
+    interval: Duration,
+    next: Option<Instant>,
+}
+
+impl KeyRepeat {
+    /// `rate` is the number of repetitions per second. Returns `None` if
+    /// either the key or the rate is missing.
+    pub(crate) fn new(key: Option<Key>, rate: Option<f32>) -> Option<Self> {
+        let rate = rate.filter(|&rate| rate > 0.)?;
+        let key = KeyState::new(&key?)?;
+        Some(KeyRepeat { key, interval: Duration::from_secs_f32(1. / rate), next: None })
+    }
+
+    /// Whether the action should run this frame.
+    pub(crate) fn tick(&mut self) -> bool {
+        self.key.update();
+
+        let now = Instant::now();
+        if self.key.is_pressed() {
+            self.next = Some(now + REPEAT_DELAY);
+        } else if self.key.keyup() {
+            self.next = None;
+        }
+
+        match self.next {
+            Some(next) if self.key.is_down() && now >= next => {
+                self.next = Some(now + self.interval);
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Wraps a widget, running extra actions while their keys are held. The
+/// widget keeps handling the key presses and releases itself.
+pub(crate) struct Repeat {
+    widget: Box<dyn Widget>,
+    actions: Vec<(KeyRepeat, Box<dyn FnMut() + Send + Sync>)>,
+}
+
+impl Repeat {
+    pub(crate) fn new(widget: Box<dyn Widget>) -> Self {
+        Repeat { widget, actions: Vec::new() }
+    }
+
+    pub(crate) fn with(
+        mut self,
+        repeat: Option<KeyRepeat>,
+        action: impl FnMut() + Send + Sync + 'static,
+    ) -> Self {
+        if let Some(repeat) = repeat {
+            self.actions.push((repeat, Box::new(action)));
+        }
+        self
+    }
+
+    /// Returns the bare widget if there is nothing to repeat.
+    pub(crate) fn boxed(self) -> Box<dyn Widget> {
+        if self.actions.is_empty() {
+            self.widget
+        } else {
+            Box::new(self)
+        }
+    }
+}
+
+impl Widget for Repeat {
+    fn render(&mut self, ui: &imgui::Ui) {
+        self.widget.render(ui);
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.widget.render_closed(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.widget.interact(ui);
+
+        for (repeat, action) in &mut self.actions {
+            if repeat.tick() {
+                action();
+            }
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        self.widget.log(tx);
+    }
+}
@@ -0,0 +1,196 @@
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
+
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use super::quitout::SaveReload;
+use crate::feedback;
+
+/// Time between two reads of the boss flags.
+const TRACK_INTERVAL: Duration = Duration::from_millis(500);
+/// Time to let the world settle after reloading before moving the player.
+const SETTLE_TIME: Duration = Duration::from_secs(1);
+
+/// Brings back a defeated boss: clears its defeated event flag, quits out and
+/// loads the save again, then puts the player back where they stood, so that
+/// the fight can start over right away.
+///
+/// The boss defaults to the last one defeated, or to the furthest one along
+/// the route which is already defeated when the save is first read.
+pub(crate) struct ResurrectBoss {
+    hp: PointerChain<[u32; 3]>,
+    position: (PointerChain<f32>, PointerChain<[f32; 3]>),
+    quitout: PointerChain<u8>,
+    reload: SaveReload,
+    bosses: Vec<Option<bool>>,
+    last_track: Option<Instant>,
+    selected: Option<usize>,
+    respawn: Option<([f32; 3], f32)>,
+    respawn_at: Option<Instant>,
+    hotkey: Option<Key>,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl ResurrectBoss {
+    pub(crate) fn new(
+        hp: PointerChain<[u32; 3]>,
+        position: (PointerChain<f32>, PointerChain<[f32; 3]>),
+        quitout: PointerChain<u8>,
+        hotkey: Option<Key>,
+    ) -> Self {
+        let mut this = ResurrectBoss {
+            hp,
+            position,
+            quitout,
+            reload: SaveReload::new(),
+            bosses: vec![None; BOSS_FLAGS.len()],
+            last_track: None,
+            selected: None,
+            respawn: None,
+            respawn_at: None,
+            hotkey,
+            label: String::new(),
+            logs: Vec::new(),
+        };
+        this.update_label();
+        this
+    }
+
+    fn update_label(&mut self) {
+        let name = self.selected.map(|i| BOSS_FLAGS[i].1).unwrap_or("last boss");
+        self.label = match self.hotkey {
+            Some(k) => format!("Resurrect {name} ({k})"),
+            None => format!("Resurrect {name}"),
+        };
+    }
+
+    fn select(&mut self, idx: usize) {
+        if self.selected != Some(idx) {
+            self.selected = Some(idx);
+            self.update_label();
+        }
+    }
+
+    fn track(&mut self, in_game: bool) {
+        let now = Instant::now();
+        if self.last_track.is_some_and(|t| now - t < TRACK_INTERVAL) {
+            return;
+        }
+        self.last_track = Some(now);
+
+        // Flags can change arbitrarily while a save is being loaded.
+        let mut defeated = None;
+        for (idx, ((flag, _), prev)) in BOSS_FLAGS.iter().zip(self.bosses.iter_mut()).enumerate() {
+            let state = if in_game { EventFlag(*flag).get() } else { None };
+            if let (Some(false), Some(true)) = (*prev, state) {
+                defeated = Some(idx);
+            }
+            *prev = state;
+        }
+
+        if let Some(idx) = defeated {
+            self.select(idx);
+        } else if self.selected.is_none() {
+            if let Some(idx) = self.bosses.iter().rposition(|&b| b == Some(true)) {
+                self.select(idx);
+            }
+        }
+    }
+
+    fn resurrect(&mut self) {
+        let Some(idx) = self.selected else {
+            self.logs.push("No boss defeated yet".to_string());
+            return;
+        };
+
+        let (angle_ptr, pos_ptr) = &self.position;
+        let (Some(pos), Some(angle)) = (pos_ptr.read(), angle_ptr.read()) else {
+            self.logs.push("Can't resurrect a boss outside of the game".to_string());
+            return;
+        };
+
+        let (flag, name) = BOSS_FLAGS[idx];
+        if EventFlag(flag).set(false).is_none() {
+            self.logs.push(format!("Couldn't resurrect {name}"));
+            return;
+        }
+
+        // Keep the cleared flag from registering as a new state once reloaded.
+        self.bosses[idx] = None;
+        self.respawn = Some((pos, angle));
+        self.respawn_at = None;
+        self.quitout.write(1);
+        self.reload.start();
+        self.logs.push(format!("{name} resurrected, reloading"));
+        feedback::executed();
+    }
+
+    fn update(&mut self) {
+        let in_game = self.hp.read().is_some();
+        let now = Instant::now();
+
+        if self.reload.update(in_game, &mut self.logs) {
+            self.respawn_at = Some(now + SETTLE_TIME);
+        }
+
+        if self.respawn_at.is_some_and(|t| now >= t) {
+            self.respawn_at = None;
+            if let Some((pos, angle)) = self.respawn.take() {
+                let (angle_ptr, pos_ptr) = &self.position;
+                pos_ptr.write(pos);
+                angle_ptr.write(angle);
+            }
+        }
+
+        self.track(in_game);
+    }
+}
+
+impl Widget for ResurrectBoss {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        let defeated: Vec<usize> = (0..BOSS_FLAGS.len())
+            .filter(|&i| self.bosses[i] == Some(true) || self.selected == Some(i))
+            .collect();
+
+        if !defeated.is_empty() {
+            let mut current = self.selected.and_then(|s| defeated.iter().position(|&i| i == s));
+            let mut index = current.unwrap_or(0);
+            ui.set_next_item_width(BUTTON_WIDTH * scale);
+            if ui.combo("##resurrect-boss", &mut index, &defeated, |&i| {
+                Cow::Borrowed(BOSS_FLAGS[i].1)
+            }) {
+                current = Some(index);
+            }
+            if let Some(index) = current {
+                self.select(defeated[index]);
+            }
+        }
+
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            self.resurrect();
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.reload.render_closed(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.update();
+
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.resurrect();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
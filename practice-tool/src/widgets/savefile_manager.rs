@@ -1,14 +1,489 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use std::{fs, thread};
 
+use hudhook::tracing::error;
+use imgui::ListBox;
+use libds3::prelude::*;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use practice_tool_core::crossbeam_channel::{self, Receiver, Sender};
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::savefile_manager::SavefileManager;
-use practice_tool_core::widgets::Widget;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use windows::Win32::Foundation::SYSTEMTIME;
+use windows::Win32::System::SystemInformation::GetLocalTime;
 
-pub(crate) fn savefile_manager(key_load: Option<Key>, key_close: Key) -> Box<dyn Widget> {
-    Box::new(SavefileManager::new(key_load, Some(key_close), get_savefile_path().unwrap()))
+use crate::feedback;
+use crate::sl2::{self, CharacterSummary};
+
+const INFO_TAG: &str = "##savefile-info";
+
+/// Job sender of the savefile manager's worker, for the savefiles loaded by
+/// remote commands.
+static JOBS: Lazy<Mutex<Option<Sender<Job>>>> = Lazy::new(Default::default);
+
+/// Loads `file`, relative to the savefile's directory, on the savefile
+/// manager's worker thread. The outcome is logged once the copy is done.
+pub(crate) fn load_savefile(file: PathBuf) -> Result<(), String> {
+    match &*JOBS.lock() {
+        Some(jobs) => {
+            jobs.send(Job::Load(file)).map_err(|_| "Savefile thread unavailable".to_string())
+        },
+        None => Err("Loading savefiles needs the savefile manager".to_string()),
+    }
+}
+
+/// Hotkeys to load the next or previous savefile of `folder` without going
+/// through the file browser. `folder` is relative to the savefile directory.
+pub(crate) struct SavefileCycle {
+    pub(crate) next: Option<Key>,
+    pub(crate) previous: Option<Key>,
+    pub(crate) folder: Option<PathBuf>,
+}
+
+pub(crate) fn savefile_manager(
+    key_load: Option<Key>,
+    key_close: Key,
+    backups: Option<usize>,
+    cycle: SavefileCycle,
+    hp: PointerChain<[u32; 3]>,
+) -> Box<dyn Widget> {
+    let savefile_path = get_savefile_path().unwrap();
+    // The load hotkey is handled here, so that the copy runs on the worker.
+    let manager = SavefileManager::new(None, Some(key_close), savefile_path.clone());
+    let cycle_dir = match (savefile_path.parent(), cycle.folder) {
+        (Some(dir), Some(folder)) => dir.join(folder),
+        (Some(dir), None) => dir.to_path_buf(),
+        (None, _) => PathBuf::new(),
+    };
+
+    let (jobs, results) = SavefileWorker {
+        savefile_path: savefile_path.clone(),
+        cycle_dir,
+        cycle_current: None,
+        loaded: None,
+    }
+    .start();
+    *JOBS.lock() = Some(jobs.clone());
+
+    Box::new(Savefiles {
+        manager,
+        savefile_stamp: None,
+        savefile_path,
+        key_load,
+        key_next: cycle.next,
+        key_previous: cycle.previous,
+        cycle_current: None,
+        jobs,
+        results,
+        backups: backups.filter(|&retention| retention > 0),
+        hp,
+        prev_hp: None,
+        key_close,
+        label_close: format!("Close ({key_close})"),
+        files: Vec::new(),
+        selected: None,
+        summaries: None,
+        logs: Vec::new(),
+    })
+}
+
+/// Savefile reads and copies for the worker thread.
+enum Job {
+    /// Back up the savefile, keeping this many backups. The backup is named
+    /// after the time of death, not the time the job runs.
+    Backup(usize, SYSTEMTIME),
+    /// Load the savefile of the cycle folder this many positions away from
+    /// the last one loaded.
+    Cycle(isize),
+    /// Remember the savefile just loaded from the file browser, for the load
+    /// hotkey.
+    Remember,
+    /// Load the savefile last loaded again.
+    Reload,
+    /// Load a savefile, relative to the savefile's directory.
+    Load(PathBuf),
+    /// Read the character summaries of a savefile.
+    Summaries(PathBuf),
+}
+
+enum JobResult {
+    BackedUp(PathBuf),
+    Loaded(String),
+    Reloaded,
+    LoadedFile(PathBuf),
+    Remembered,
+    Summaries(PathBuf, Result<Vec<CharacterSummary>, String>),
+    Failed(String),
+}
+
+/// Reads and copies savefiles on a background thread, one job after the
+/// other, so that the game doesn't stall on large savefiles.
+struct SavefileWorker {
+    savefile_path: PathBuf,
+    cycle_dir: PathBuf,
+    cycle_current: Option<String>,
+    /// Contents of the savefile last loaded, restored by the load hotkey.
+    loaded: Option<Vec<u8>>,
+}
+
+/// Wraps the savefile manager with a savefile info browser and, if `backups`
+/// is set, copies the savefile to a timestamped backup in the `backups`
+/// directory next to it every time the player dies, keeping only the most
+/// recent `backups` copies.
+struct Savefiles {
+    manager: SavefileManager,
+    savefile_path: PathBuf,
+    /// Size and modification time of the savefile, to tell when the file
+    /// browser loads one.
+    savefile_stamp: Option<(u64, SystemTime)>,
+    key_load: Option<Key>,
+    key_next: Option<Key>,
+    key_previous: Option<Key>,
+    /// The savefile of the cycle folder last loaded by the worker.
+    cycle_current: Option<String>,
+    jobs: Sender<Job>,
+    results: Receiver<JobResult>,
+    backups: Option<usize>,
+    hp: PointerChain<[u32; 3]>,
+    prev_hp: Option<u32>,
+    key_close: Key,
+    label_close: String,
+    files: Vec<(String, PathBuf)>,
+    selected: Option<usize>,
+    /// Summaries of the selected savefile, `None` while they are read.
+    summaries: Option<Result<Vec<CharacterSummary>, String>>,
+    logs: Vec<String>,
+}
+
+impl SavefileWorker {
+    /// Starts the worker thread. It stops once the job sender is dropped.
+    fn start(mut self) -> (Sender<Job>, Receiver<JobResult>) {
+        let (job_tx, job_rx) = crossbeam_channel::unbounded();
+        let (result_tx, result_rx) = crossbeam_channel::unbounded();
+
+        let spawned = thread::Builder::new().name("savefiles".to_string()).spawn(move || {
+            for job in job_rx {
+                let result = match job {
+                    Job::Backup(retention, t) => self.backup(retention, t).map(JobResult::BackedUp),
+                    Job::Cycle(step) => self.cycle(step).map(JobResult::Loaded),
+                    Job::Remember => self.remember().map(|()| JobResult::Remembered),
+                    Job::Reload => self.reload().map(|()| JobResult::Reloaded),
+                    Job::Load(file) => self.load(&file).map(|()| JobResult::LoadedFile(file)),
+                    Job::Summaries(path) => {
+                        let summaries = sl2::read_summaries(&path);
+                        Ok(JobResult::Summaries(path, summaries))
+                    },
+                };
+                if result_tx.send(result.unwrap_or_else(JobResult::Failed)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        if let Err(e) = spawned {
+            error!("Couldn't start savefile thread: {e}");
+        }
+
+        (job_tx, result_rx)
+    }
+
+    fn backup(&self, retention: usize, t: SYSTEMTIME) -> Result<PathBuf, String> {
+        let dir = self.savefile_path.with_file_name("backups");
+        fs::create_dir_all(&dir).map_err(|e| format!("Couldn't create {dir:?}: {e}"))?;
+
+        let stem = self.savefile_path.file_stem().unwrap_or_default().to_string_lossy();
+        let backup_path = dir.join(format!(
+            "{stem}_{:04}{:02}{:02}_{:02}{:02}{:02}.sl2",
+            t.wYear, t.wMonth, t.wDay, t.wHour, t.wMinute, t.wSecond
+        ));
+
+        fs::copy(&self.savefile_path, &backup_path)
+            .map_err(|e| format!("Couldn't back up savefile: {e}"))?;
+
+        prune_backups(&dir, &stem, retention)?;
+
+        Ok(backup_path)
+    }
+
+    /// Loads the savefile of the cycle folder that comes `step` positions
+    /// after the last one loaded, wrapping around.
+    fn cycle(&mut self, step: isize) -> Result<String, String> {
+        let mut files: Vec<String> = fs::read_dir(&self.cycle_dir)
+            .map_err(|e| format!("Couldn't read {:?}: {e}", self.cycle_dir))?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| {
+                p.is_file()
+                    && p != &self.savefile_path
+                    && p.extension().map(|e| e.eq_ignore_ascii_case("sl2")).unwrap_or(false)
+            })
+            .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+            .collect();
+        files.sort();
+
+        if files.is_empty() {
+            return Err(format!("No savefiles in {:?}", self.cycle_dir));
+        }
+
+        let len = files.len() as isize;
+        let index =
+            match self.cycle_current.as_ref().and_then(|c| files.iter().position(|f| f == c)) {
+                Some(current) => (current as isize + step).rem_euclid(len),
+                None if step > 0 => 0,
+                None => len - 1,
+            };
+        let file = files.swap_remove(index as usize);
+
+        let contents = fs::read(self.cycle_dir.join(&file))
+            .and_then(|contents| fs::write(&self.savefile_path, &contents).map(|()| contents))
+            .map_err(|e| format!("Couldn't load {file}: {e}"))?;
+        self.cycle_current = Some(file.clone());
+        self.loaded = Some(contents);
+
+        Ok(file)
+    }
+
+    fn remember(&mut self) -> Result<(), String> {
+        let contents =
+            fs::read(&self.savefile_path).map_err(|e| format!("Couldn't read savefile: {e}"))?;
+        self.loaded = Some(contents);
+        Ok(())
+    }
+
+    fn load(&mut self, file: &Path) -> Result<(), String> {
+        let contents = fs::read(self.savefile_path.with_file_name(file))
+            .and_then(|contents| fs::write(&self.savefile_path, &contents).map(|()| contents))
+            .map_err(|e| format!("Couldn't load savefile {}: {e}", file.display()))?;
+        self.loaded = Some(contents);
+        Ok(())
+    }
+
+    fn reload(&self) -> Result<(), String> {
+        let contents = self.loaded.as_ref().ok_or_else(|| "No savefile loaded yet".to_string())?;
+        fs::write(&self.savefile_path, contents).map_err(|e| format!("Couldn't load savefile: {e}"))
+    }
+}
+
+impl Savefiles {
+    fn send(&mut self, job: Job) {
+        if self.jobs.send(job).is_err() {
+            self.logs.push("Savefile thread unavailable".to_string());
+        }
+    }
+
+    fn read_stamp(&self) -> Option<(u64, SystemTime)> {
+        let metadata = fs::metadata(&self.savefile_path).ok()?;
+        Some((metadata.len(), metadata.modified().ok()?))
+    }
+
+    /// Lists the savefiles in the savefile's directory and its subdirectories.
+    fn scan_files(&mut self) {
+        fn walk(dir: &Path, root: &Path, depth: usize, files: &mut Vec<(String, PathBuf)>) {
+            let Ok(entries) = fs::read_dir(dir) else {
+                return;
+            };
+
+            let mut paths: Vec<PathBuf> =
+                entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+            paths.sort();
+
+            for path in paths {
+                if path.is_dir() && depth > 0 {
+                    walk(&path, root, depth - 1, files);
+                } else if path.extension().map(|e| e.eq_ignore_ascii_case("sl2")).unwrap_or(false) {
+                    let label = path.strip_prefix(root).unwrap_or(&path).display().to_string();
+                    files.push((label, path));
+                }
+            }
+        }
+
+        self.files.clear();
+        self.selected = None;
+        self.summaries = None;
+
+        if let Some(root) = self.savefile_path.parent() {
+            walk(root, root, 4, &mut self.files);
+        }
+    }
+
+    fn render_info(&mut self, ui: &imgui::Ui) {
+        ui.columns(2, "##savefile-info-columns", false);
+        ui.set_column_width(0, 330.);
+
+        ListBox::new("##savefile-info-files").size([320., 300.]).build(ui, || {
+            for (idx, (label, path)) in self.files.iter().enumerate() {
+                if ui.selectable_config(label).selected(self.selected == Some(idx)).build() {
+                    self.selected = Some(idx);
+                    self.summaries = None;
+                    if self.jobs.send(Job::Summaries(path.clone())).is_err() {
+                        self.summaries = Some(Err("Savefile thread unavailable".to_string()));
+                    }
+                }
+            }
+        });
+
+        ui.next_column();
+        match (&self.summaries, self.selected) {
+            (_, None) => ui.text("Select a savefile"),
+            (None, _) => ui.text("Reading..."),
+            (Some(Err(e)), _) => ui.text_wrapped(e),
+            (Some(Ok(summaries)), _) if summaries.is_empty() => ui.text("No characters"),
+            (Some(Ok(summaries)), _) => {
+                for c in summaries {
+                    let (h, m, s) = (c.play_time / 3600, (c.play_time / 60) % 60, c.play_time % 60);
+                    ui.text(format!("{}. {}", c.slot + 1, c.name));
+                    ui.text(format!("   SL {}  {h}:{m:02}:{s:02}", c.level));
+                    ui.text(format!("   Bonfire {}", c.location));
+                }
+            },
+        }
+        ui.columns(1, "##savefile-info-columns", false);
+    }
+}
+
+/// Removes the oldest backups of `stem` in `dir` so that at most `retention`
+/// remain. Backup names sort chronologically.
+fn prune_backups(dir: &Path, stem: &str, retention: usize) -> Result<(), String> {
+    let prefix = format!("{stem}_");
+    let mut backups: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| format!("Couldn't read {dir:?}: {e}"))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name().map(|n| n.to_string_lossy().starts_with(&prefix)).unwrap_or(false)
+        })
+        .collect();
+    backups.sort();
+
+    let excess = backups.len().saturating_sub(retention);
+    for path in &backups[..excess] {
+        fs::remove_file(path).map_err(|e| format!("Couldn't remove {path:?}: {e}"))?;
+    }
+
+    Ok(())
+}
+
+impl Widget for Savefiles {
+    fn render(&mut self, ui: &imgui::Ui) {
+        // The file browser copies the savefile itself. A change of the file
+        // around it means a savefile was loaded, which the hotkey reloads.
+        let stamp = self.read_stamp();
+        self.manager.render(ui);
+        if self.read_stamp() != stamp {
+            feedback::executed();
+            self.send(Job::Remember);
+        }
+
+        let scale = scaling_factor(ui);
+        if ui.button_with_size("Savefile info", [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            self.scan_files();
+            ui.open_popup(INFO_TAG);
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(INFO_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            self.render_info(ui);
+
+            if ui.button_with_size(&self.label_close, [320., BUTTON_HEIGHT * scale])
+                || (self.key_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.manager.render_closed(ui);
+
+        if let Some(current) = &self.cycle_current {
+            ui.text(format!("Savefile {current}"));
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        let hp = self.hp.read().map(|[hp, _, _]| hp);
+        if let (Some(retention), Some(prev), Some(0)) = (self.backups, self.prev_hp, hp) {
+            if prev > 0 {
+                self.send(Job::Backup(retention, unsafe { GetLocalTime() }));
+            }
+        }
+        self.prev_hp = hp;
+
+        let step = match (self.key_next, self.key_previous) {
+            (Some(k), _) if k.is_pressed(ui) => 1,
+            (_, Some(k)) if k.is_pressed(ui) => -1,
+            _ => 0,
+        };
+        if step != 0 {
+            self.send(Job::Cycle(step));
+        }
+
+        if self.key_load.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.send(Job::Reload);
+        }
+
+        for result in self.results.try_iter() {
+            match result {
+                JobResult::BackedUp(path) => self.logs.push(format!(
+                    "Savefile backed up to {}",
+                    path.file_name().unwrap_or_default().to_string_lossy()
+                )),
+                JobResult::Loaded(file) => {
+                    self.logs.push(format!("Loaded savefile {file}"));
+                    self.cycle_current = Some(file);
+                    feedback::executed();
+                },
+                JobResult::Reloaded => {
+                    self.logs.push("Savefile loaded".to_string());
+                    feedback::executed();
+                },
+                JobResult::LoadedFile(file) => {
+                    self.logs.push(format!("Loaded savefile {}", file.display()));
+                    feedback::executed();
+                },
+                JobResult::Remembered => {},
+                JobResult::Summaries(path, summaries) => {
+                    let selected = self.selected.and_then(|i| self.files.get(i));
+                    if selected.map(|(_, p)| *p == path).unwrap_or(false) {
+                        self.summaries = Some(summaries);
+                    }
+                },
+                JobResult::Failed(e) => self.logs.push(e),
+            }
+        }
+
+        self.manager.interact(ui);
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+        self.manager.log(tx);
+    }
+}
+
+impl Drop for Savefiles {
+    fn drop(&mut self) {
+        // The widgets of a reloaded configuration can be built before the old
+        // ones are dropped, so only forget this widget's worker.
+        let mut jobs = JOBS.lock();
+        if jobs.as_ref().is_some_and(|jobs| jobs.same_channel(&self.jobs)) {
+            *jobs = None;
+        }
+    }
 }
 
-fn get_savefile_path() -> Result<PathBuf, String> {
+pub(crate) fn get_savefile_path() -> Result<PathBuf, String> {
     let re = regex::Regex::new(r"^[a-f0-9]+$").unwrap();
     let savefile_path: PathBuf =
         [std::env::var("APPDATA").map_err(|e| format!("{}", e))?.as_str(), "DarkSoulsIII"]
@@ -0,0 +1,211 @@
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use serde::Deserialize;
+
+use super::item_preset::PresetItem;
+use super::item_spawn::SpawnQueue;
+use crate::config::FlagSpec;
+use crate::{feedback, restore};
+
+/// Difference between the sum of the attributes and the soul level.
+const LEVEL_OFFSET: i32 = 89;
+
+/// A practice setup applied all at once: position, flags, stats, items and
+/// speed. Everything is optional.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ScenarioConfig {
+    name: String,
+    hotkey: Option<Key>,
+    /// X, Y, Z and angle, as shown by the position widget.
+    position: Option<[f32; 4]>,
+    #[serde(default)]
+    flags: Vec<ScenarioFlag>,
+    #[serde(default)]
+    event_flags: Vec<ScenarioEventFlag>,
+    #[serde(default)]
+    stats: ScenarioStats,
+    #[serde(default)]
+    items: Vec<PresetItem>,
+    speed: Option<f32>,
+    world_speed: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+struct ScenarioFlag {
+    flag: FlagSpec,
+    state: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+struct ScenarioEventFlag {
+    id: u32,
+    state: bool,
+}
+
+/// Attributes to set. The soul level follows the attributes.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ScenarioStats {
+    vigor: Option<i32>,
+    attunement: Option<i32>,
+    endurance: Option<i32>,
+    vitality: Option<i32>,
+    strength: Option<i32>,
+    dexterity: Option<i32>,
+    intelligence: Option<i32>,
+    faith: Option<i32>,
+    luck: Option<i32>,
+    souls: Option<i32>,
+}
+
+impl ScenarioStats {
+    pub(crate) fn is_empty(&self) -> bool {
+        [
+            self.vigor,
+            self.attunement,
+            self.endurance,
+            self.vitality,
+            self.strength,
+            self.dexterity,
+            self.intelligence,
+            self.faith,
+            self.luck,
+            self.souls,
+        ]
+        .iter()
+        .all(Option::is_none)
+    }
+
+    pub(crate) fn apply(&self, stats: &mut CharacterStats) {
+        let attributes = [
+            (self.vigor, &mut stats.vigor),
+            (self.attunement, &mut stats.attunement),
+            (self.endurance, &mut stats.endurance),
+            (self.vitality, &mut stats.vitality),
+            (self.strength, &mut stats.strength),
+            (self.dexterity, &mut stats.dexterity),
+            (self.intelligence, &mut stats.intelligence),
+            (self.faith, &mut stats.faith),
+            (self.luck, &mut stats.luck),
+        ];
+
+        let mut total = 0;
+        for (value, attribute) in attributes {
+            if let Some(value) = value {
+                *attribute = value.clamp(1, 99);
+            }
+            total += *attribute;
+        }
+        stats.level = (total - LEVEL_OFFSET).max(1);
+
+        if let Some(souls) = self.souls {
+            stats.souls = souls.max(0);
+        }
+    }
+}
+
+struct Scenario {
+    config: ScenarioConfig,
+    pointers: PointerChains,
+    label: String,
+    queue: SpawnQueue,
+    logs: Vec<String>,
+}
+
+impl Scenario {
+    fn apply(&mut self) {
+        let config = &self.config;
+        let pointers = &self.pointers;
+
+        if pointers.hp.read().is_none() {
+            self.logs.push(format!("Can't apply {} when not in game", config.name));
+            return;
+        }
+
+        for ScenarioFlag { flag, state } in &config.flags {
+            let bitflag = (flag.getter)(pointers);
+            restore::remember_flag(bitflag);
+            bitflag.set(*state);
+        }
+
+        let mut failed = Vec::new();
+        for &ScenarioEventFlag { id, state } in &config.event_flags {
+            if EventFlag(id).set(state).is_none() {
+                failed.push(id);
+            }
+        }
+        if !failed.is_empty() {
+            self.logs.push(format!("Couldn't set event flags {failed:?}"));
+        }
+
+        if !config.stats.is_empty() {
+            if let Some(mut stats) = pointers.character_stats.read() {
+                config.stats.apply(&mut stats);
+                pointers.character_stats.write(stats);
+            }
+        }
+
+        if let Some(speed) = config.speed {
+            restore::remember(&pointers.speed);
+            pointers.speed.write(speed);
+        }
+        if let Some(world_speed) = config.world_speed {
+            restore::remember(&pointers.world_speed);
+            pointers.world_speed.write(world_speed);
+        }
+
+        if let Some([x, y, z, angle]) = config.position {
+            pointers.position.1.write([x, y, z]);
+            pointers.position.0.write(angle);
+        }
+
+        let (func_ptr, map_item_man) =
+            (pointers.spawn_item_func_ptr as usize, pointers.map_item_man as usize);
+        self.queue.extend(config.items.iter().map(|item| item.instance(func_ptr, map_item_man)));
+
+        self.logs.push(format!("Applied {}", config.name));
+        feedback::executed();
+    }
+}
+
+impl Widget for Scenario {
+    fn render(&mut self, ui: &imgui::Ui) {
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT]) {
+            self.apply();
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.queue.tick();
+
+        if self.config.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.apply();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn scenario(config: ScenarioConfig) -> Box<dyn Widget> {
+    let label = match config.hotkey {
+        Some(k) => format!("{} ({k})", config.name),
+        None => config.name.clone(),
+    };
+
+    Box::new(Scenario {
+        config,
+        pointers: PointerChains::new(),
+        label,
+        queue: SpawnQueue::default(),
+        logs: Vec::new(),
+    })
+}
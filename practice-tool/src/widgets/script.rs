@@ -0,0 +1,204 @@
+//! User scripts.
+//!
+//! Scripts are [Rhai](https://rhai.rs) files stored in the `scripts` folder
+//! next to the DLL. They are read again every time they are run, so they can
+//! be edited while the game is running. The following functions are available:
+//!
+//! ```rhai
+//! flag("gravity")              // -> bool
+//! set_flag("gravity", false)
+//! toggle_flag("collision")
+//! event_flag(14000800)         // -> bool
+//! set_event_flag(14000800, false)
+//! position()                   // -> [x, y, z, angle]
+//! set_position(x, y, z)
+//! speed()                      // -> float
+//! set_speed(2.0)
+//! souls()                      // -> int
+//! set_souls(10000)
+//! igt()                        // -> int, milliseconds
+//! spawn_item(0x40000190, 5)
+//! quitout()
+//! print("message")             // shown in the tool's log
+//! ```
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use libds3::prelude::*;
+use parking_lot::Mutex;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use rhai::{Array, Dynamic, Engine, FLOAT, INT};
+
+use crate::config::FlagSpec;
+use crate::widgets::item_spawn::ItemSpawnInstance;
+use crate::{feedback, restore, util};
+
+/// Scripts run on the render thread, so a runaway loop or recursion would
+/// freeze the game. These limits abort such scripts with an error instead.
+const MAX_OPERATIONS: u64 = 1_000_000;
+const MAX_CALL_LEVELS: usize = 32;
+const MAX_EXPR_DEPTH: usize = 64;
+
+fn get_flag(pointers: &PointerChains, name: &str) -> Option<&Bitflag<u8>> {
+    FlagSpec::try_from(name.to_string()).ok().map(|flag| (flag.getter)(pointers))
+}
+
+fn make_engine(pointers: Arc<PointerChains>, logs: Arc<Mutex<Vec<String>>>) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(MAX_CALL_LEVELS);
+    engine.set_max_expr_depths(MAX_EXPR_DEPTH, MAX_EXPR_DEPTH);
+
+    engine.on_print(move |s| logs.lock().push(s.to_string()));
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("flag", move |name: &str| {
+        get_flag(&p, name).and_then(|flag| flag.get()).unwrap_or(false)
+    });
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("set_flag", move |name: &str, state: bool| {
+        if let Some(flag) = get_flag(&p, name) {
+            restore::remember_flag(flag);
+            flag.set(state);
+        }
+    });
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("toggle_flag", move |name: &str| {
+        if let Some(flag) = get_flag(&p, name) {
+            restore::remember_flag(flag);
+            flag.toggle();
+        }
+    });
+
+    engine.register_fn("event_flag", |id: INT| EventFlag(id as u32).get().unwrap_or(false));
+    engine.register_fn("set_event_flag", |id: INT, state: bool| {
+        EventFlag(id as u32).set(state);
+    });
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("position", move || -> Array {
+        match (p.position.1.read(), p.position.0.read()) {
+            (Some([x, y, z]), Some(angle)) => {
+                [x, y, z, angle].into_iter().map(|v| Dynamic::from_float(v as FLOAT)).collect()
+            },
+            _ => Array::new(),
+        }
+    });
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("set_position", move |x: FLOAT, y: FLOAT, z: FLOAT| {
+        p.position.1.write([x as f32, y as f32, z as f32]);
+    });
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("speed", move || p.speed.read().unwrap_or(0.) as FLOAT);
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("set_speed", move |speed: FLOAT| {
+        restore::remember(&p.speed);
+        p.speed.write(speed as f32);
+    });
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("souls", move || p.souls.read().unwrap_or(0) as INT);
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("set_souls", move |souls: INT| {
+        p.souls.write(souls.clamp(0, u32::MAX as INT) as u32);
+    });
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("igt", move || p.igt.read().unwrap_or(0) as INT);
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("spawn_item", move |item_id: INT, qty: INT| {
+        if p.gravity.get().is_none() {
+            return;
+        }
+
+        let instance = ItemSpawnInstance {
+            spawn_item_func_ptr: p.spawn_item_func_ptr,
+            map_item_man: p.map_item_man,
+            qty: qty.clamp(1, 99) as u32,
+            durability: 100,
+            item_id: item_id as u32,
+            infusion: 0,
+            upgrade: 0,
+        };
+        unsafe { instance.spawn() };
+    });
+
+    let p = Arc::clone(&pointers);
+    engine.register_fn("quitout", move || {
+        p.quitout.write(1);
+    });
+
+    engine
+}
+
+pub(crate) struct Script {
+    label: String,
+    name: String,
+    path: Option<PathBuf>,
+    hotkey: Option<Key>,
+    engine: Engine,
+    logs: Arc<Mutex<Vec<String>>>,
+}
+
+impl Script {
+    pub(crate) fn new(name: String, hotkey: Option<Key>) -> Self {
+        let label = match hotkey {
+            Some(k) => format!("Run {name} ({k})"),
+            None => format!("Run {name}"),
+        };
+
+        let path = util::get_dll_path().map(|mut path| {
+            path.pop();
+            path.push("scripts");
+            path.push(&name);
+            path
+        });
+
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let engine = make_engine(Arc::new(PointerChains::new()), Arc::clone(&logs));
+
+        Script { label, name, path, hotkey, engine, logs }
+    }
+
+    fn run(&mut self) {
+        let Some(path) = self.path.clone() else {
+            self.logs.lock().push(format!("Couldn't find script {}", self.name));
+            return;
+        };
+
+        match self.engine.run_file(path) {
+            Ok(()) => feedback::executed(),
+            Err(e) => self.logs.lock().push(format!("Script {} failed: {e}", self.name)),
+        }
+    }
+}
+
+impl Widget for Script {
+    fn render(&mut self, ui: &imgui::Ui) {
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT]) {
+            self.run();
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.run();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.lock().drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
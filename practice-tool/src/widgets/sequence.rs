@@ -0,0 +1,107 @@
+use std::time::{Duration, Instant};
+
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use serde::Deserialize;
+
+use crate::action::Action;
+use crate::feedback;
+use crate::widgets::position::SavePosition;
+
+/// A step of a sequence: either a pause, in milliseconds, or an action as
+/// bound to gamepad chords and radial menu entries.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub(crate) enum SequenceStep {
+    Wait { wait: u64 },
+    Action(Action),
+}
+
+/// Runs a list of actions, with pauses in between, on a single hotkey.
+struct Sequence {
+    steps: Vec<SequenceStep>,
+    pointers: PointerChains,
+    position: SavePosition,
+    /// Next step to run, and when to run it.
+    next: Option<(usize, Instant)>,
+    hotkey: Option<Key>,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl Sequence {
+    fn start(&mut self) {
+        if self.next.is_some() {
+            self.next = None;
+            self.logs.push(format!("{} stopped", self.label));
+        } else {
+            self.next = Some((0, Instant::now()));
+        }
+        feedback::executed();
+    }
+
+    /// Runs every step that is due, up to the next pause.
+    fn advance(&mut self) {
+        while let Some((idx, at)) = self.next {
+            if Instant::now() < at {
+                return;
+            }
+
+            self.next = match self.steps.get(idx) {
+                Some(SequenceStep::Wait { wait }) => {
+                    Some((idx + 1, Instant::now() + Duration::from_millis(*wait)))
+                },
+                Some(SequenceStep::Action(Action::Key { key })) => {
+                    key.press();
+                    Some((idx + 1, at))
+                },
+                Some(SequenceStep::Action(Action::Command(command))) => {
+                    let result = command.clone().execute(&self.pointers, &mut self.position);
+                    self.logs.push(result.unwrap_or_else(|e| e));
+                    Some((idx + 1, at))
+                },
+                None => None,
+            };
+        }
+    }
+}
+
+impl Widget for Sequence {
+    fn render(&mut self, ui: &imgui::Ui) {
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT]) {
+            self.start();
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.start();
+        }
+
+        self.advance();
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn sequence(
+    steps: Vec<SequenceStep>,
+    label: Option<String>,
+    hotkey: Option<Key>,
+) -> Box<dyn Widget> {
+    let label = label.unwrap_or_else(|| "Sequence".to_string());
+    let label = match hotkey {
+        Some(k) => format!("{label} ({k})"),
+        None => label,
+    };
+    let pointers = PointerChains::new();
+    let position = SavePosition::new(pointers.position.clone(), 0.0);
+
+    Box::new(Sequence { steps, pointers, position, next: None, hotkey, label, logs: Vec::new() })
+}
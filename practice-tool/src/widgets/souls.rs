@@ -1,18 +1,49 @@
 use libds3::memedit::PointerChain;
+use practice_tool_core::crossbeam_channel::Sender;
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::store_value::{ReadWrite, StoreValue};
-use practice_tool_core::widgets::Widget;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use serde::Deserialize;
+
+use crate::feedback;
+use crate::widgets::repeat::{KeyRepeat, Repeat};
+
+/// What the souls button does with its amount.
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum SoulsMode {
+    #[default]
+    Add,
+    Subtract,
+    Set,
+}
+
+impl SoulsMode {
+    fn apply(self, current: u32, amount: u32) -> u32 {
+        match self {
+            SoulsMode::Add => current.saturating_add(amount),
+            SoulsMode::Subtract => current.saturating_sub(amount),
+            SoulsMode::Set => amount,
+        }
+    }
+}
 
 struct Souls {
     ptr: PointerChain<u32>,
     current: u32,
     amount: u32,
+    mode: SoulsMode,
     label: String,
 }
 
 impl Souls {
-    fn new(amount: u32, ptr: PointerChain<u32>) -> Self {
-        Self { ptr, current: 0, amount, label: format!("Add {amount} souls") }
+    fn new(amount: u32, mode: SoulsMode, ptr: PointerChain<u32>) -> Self {
+        let label = match mode {
+            SoulsMode::Add => format!("Add {amount} souls"),
+            SoulsMode::Subtract => format!("Subtract {amount} souls"),
+            SoulsMode::Set => format!("Set souls to {amount}"),
+        };
+        Self { ptr, current: 0, amount, mode, label }
     }
 }
 
@@ -27,7 +58,9 @@ impl ReadWrite for Souls {
     }
 
     fn write(&mut self) {
-        self.ptr.write(self.current + self.amount);
+        if self.ptr.write(self.mode.apply(self.current, self.amount)).is_some() {
+            feedback::executed();
+        }
     }
 
     fn label(&self) -> &str {
@@ -35,6 +68,86 @@ impl ReadWrite for Souls {
     }
 }
 
-pub(crate) fn souls(amount: u32, ptr: PointerChain<u32>, key: Option<Key>) -> Box<dyn Widget> {
-    Box::new(StoreValue::new(Souls::new(amount, ptr), key))
+pub(crate) fn souls(
+    amount: u32,
+    mode: SoulsMode,
+    ptr: PointerChain<u32>,
+    key: Option<Key>,
+    repeat: Option<f32>,
+) -> Box<dyn Widget> {
+    let mut repeated = Souls::new(amount, mode, ptr.clone());
+    Repeat::new(Box::new(StoreValue::new(Souls::new(amount, mode, ptr), key)))
+        .with(KeyRepeat::new(key, repeat), move || {
+            if repeated.read() {
+                repeated.write();
+            }
+        })
+        .boxed()
+}
+
+/// Numeric input to set, add or subtract an arbitrary amount of souls. The
+/// hotkey sets the souls to the entered amount.
+pub(crate) struct SoulsInput {
+    ptr: PointerChain<u32>,
+    amount: i32,
+    hotkey: Option<Key>,
+    label_set: String,
+    logs: Vec<String>,
+}
+
+impl SoulsInput {
+    pub(crate) fn new(ptr: PointerChain<u32>, hotkey: Option<Key>) -> Self {
+        let label_set = match hotkey {
+            Some(k) => format!("Set ({k})"),
+            None => "Set".to_string(),
+        };
+        SoulsInput { ptr, amount: 0, hotkey, label_set, logs: Vec::new() }
+    }
+
+    fn apply(&mut self, mode: SoulsMode) {
+        let Some(current) = self.ptr.read() else {
+            self.logs.push("Souls unavailable when not in game".to_string());
+            return;
+        };
+
+        let souls = mode.apply(current, self.amount.max(0) as u32);
+        self.ptr.write(souls);
+        self.logs.push(format!("Souls set to {souls}"));
+    }
+}
+
+impl Widget for SoulsInput {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        let button_width = BUTTON_WIDTH * scale;
+        let style = ui.clone_style();
+        let small_width = (button_width - style.item_spacing[0] * 2.) / 3.;
+
+        ui.set_next_item_width(button_width);
+        ui.input_int("##souls-input", &mut self.amount).step(1000).build();
+
+        if ui.button_with_size(&self.label_set, [small_width, BUTTON_HEIGHT]) {
+            self.apply(SoulsMode::Set);
+        }
+        ui.same_line();
+        if ui.button_with_size("Add", [small_width, BUTTON_HEIGHT]) {
+            self.apply(SoulsMode::Add);
+        }
+        ui.same_line();
+        if ui.button_with_size("Subtract", [small_width, BUTTON_HEIGHT]) {
+            self.apply(SoulsMode::Subtract);
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.apply(SoulsMode::Set);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
 }
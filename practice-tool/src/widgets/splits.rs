@@ -0,0 +1,284 @@
+//! Practice splits.
+//!
+//! Segments are defined in the configuration file and end either when an
+//! event flag is set or on the next area transition (loading screen). Segment
+//! times are measured in IGT and the best times are stored in
+//! `splits_<name>.json` next to the DLL.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+use serde::Deserialize;
+
+use crate::audio::{self, Cue};
+use crate::{feedback, util};
+
+const COLOR_AHEAD: [f32; 4] = [0.3, 0.9, 0.3, 1.];
+const COLOR_BEHIND: [f32; 4] = [0.9, 0.3, 0.3, 1.];
+const COLOR_GOLD: [f32; 4] = [1., 0.85, 0.2, 1.];
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(try_from = "SegmentFields")]
+pub(crate) struct SegmentConfig {
+    name: String,
+    event_flag: Option<u32>,
+    area_transition: bool,
+}
+
+#[derive(Deserialize)]
+struct SegmentFields {
+    name: String,
+    event_flag: Option<u32>,
+    #[serde(default)]
+    area_transition: bool,
+}
+
+impl TryFrom<SegmentFields> for SegmentConfig {
+    type Error = String;
+
+    fn try_from(fields: SegmentFields) -> Result<Self, Self::Error> {
+        let SegmentFields { name, event_flag, area_transition } = fields;
+        if event_flag.is_none() && !area_transition {
+            return Err(format!(
+                "Segment {name} never ends: set its event_flag or area_transition"
+            ));
+        }
+        Ok(SegmentConfig { name, event_flag, area_transition })
+    }
+}
+
+struct Segment {
+    config: SegmentConfig,
+    time: Option<u32>,
+    gold: bool,
+}
+
+fn format_time(ms: u32) -> String {
+    let (m, s, ms) = (ms / 60000, (ms / 1000) % 60, ms % 1000);
+    format!("{m:02}:{s:02}.{ms:03}")
+}
+
+pub(crate) struct Splits {
+    name: String,
+    path: Option<PathBuf>,
+    igt: PointerChain<u32>,
+    position: PointerChain<[f32; 3]>,
+    segments: Vec<Segment>,
+    golds: BTreeMap<String, u32>,
+    hotkey_reset: Option<Key>,
+    label_reset: String,
+    current: Option<usize>,
+    segment_start: u32,
+    prev_flag: bool,
+    prev_loading: bool,
+    logs: Vec<String>,
+}
+
+impl Splits {
+    pub(crate) fn new(
+        name: String,
+        segments: Vec<SegmentConfig>,
+        igt: PointerChain<u32>,
+        position: PointerChain<[f32; 3]>,
+        hotkey_reset: Option<Key>,
+    ) -> Self {
+        let path = util::get_dll_path().map(|mut path| {
+            path.pop();
+            path.push(format!("splits_{name}.json"));
+            path
+        });
+
+        let golds = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        let label_reset = match hotkey_reset {
+            Some(k) => format!("Start {name} splits ({k})"),
+            None => format!("Start {name} splits"),
+        };
+
+        Splits {
+            name,
+            path,
+            igt,
+            position,
+            segments: segments
+                .into_iter()
+                .map(|config| Segment { config, time: None, gold: false })
+                .collect(),
+            golds,
+            hotkey_reset,
+            label_reset,
+            current: None,
+            segment_start: 0,
+            prev_flag: false,
+            prev_loading: false,
+            logs: Vec::new(),
+        }
+    }
+
+    fn is_loading(&self) -> bool {
+        self.position.read().is_none()
+    }
+
+    fn flag_state(&self, index: usize) -> bool {
+        self.segments[index]
+            .config
+            .event_flag
+            .and_then(|flag| EventFlag(flag).get())
+            .unwrap_or(false)
+    }
+
+    fn start_segment(&mut self, index: usize, igt: u32) {
+        self.current = Some(index);
+        self.segment_start = igt;
+        self.prev_flag = self.flag_state(index);
+        self.prev_loading = self.is_loading();
+    }
+
+    fn reset(&mut self) {
+        let Some(igt) = self.igt.read() else {
+            self.logs.push("Splits can only be started in game".to_string());
+            return;
+        };
+
+        for segment in &mut self.segments {
+            segment.time = None;
+            segment.gold = false;
+        }
+        feedback::executed();
+
+        if self.segments.is_empty() {
+            self.current = None;
+        } else {
+            self.start_segment(0, igt);
+            self.logs.push(format!("{} splits started", self.name));
+        }
+    }
+
+    fn save_golds(&mut self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let result = serde_json::to_string_pretty(&self.golds)
+            .map_err(|e| e.to_string())
+            .and_then(|content| fs::write(path, content).map_err(|e| e.to_string()));
+
+        if let Err(e) = result {
+            self.logs.push(format!("Couldn't save golds: {e}"));
+        }
+    }
+
+    fn split(&mut self, index: usize, igt: u32) {
+        let time = igt.saturating_sub(self.segment_start);
+        let segment = &mut self.segments[index];
+        segment.time = Some(time);
+
+        let best = self.golds.get(&segment.config.name).copied();
+        if best.map(|best| time < best).unwrap_or(true) {
+            segment.gold = true;
+            audio::play(Cue::Gold);
+            self.golds.insert(segment.config.name.clone(), time);
+            self.save_golds();
+        }
+
+        if index + 1 < self.segments.len() {
+            self.start_segment(index + 1, igt);
+        } else {
+            self.current = None;
+            self.logs.push(format!("{} splits finished", self.name));
+        }
+    }
+
+    fn update(&mut self) {
+        let Some(index) = self.current else {
+            return;
+        };
+
+        let loading = self.is_loading();
+        let flag = self.flag_state(index);
+        let config = &self.segments[index].config;
+
+        let done = (config.event_flag.is_some() && flag && !self.prev_flag)
+            || (config.area_transition && loading && !self.prev_loading);
+
+        self.prev_flag = flag;
+        self.prev_loading = loading;
+
+        if done {
+            if let Some(igt) = self.igt.read() {
+                self.split(index, igt);
+            }
+        }
+    }
+}
+
+impl Widget for Splits {
+    fn render(&mut self, ui: &imgui::Ui) {
+        if ui
+            .button_with_size(&self.label_reset, [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT])
+        {
+            self.reset();
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        if self.current.is_none() && self.segments.iter().all(|s| s.time.is_none()) {
+            return;
+        }
+
+        let igt = self.igt.read().unwrap_or(self.segment_start);
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            let best = self.golds.get(&segment.config.name).copied();
+            let time = match (segment.time, self.current) {
+                (Some(time), _) => Some(time),
+                (None, Some(current)) if current == i => {
+                    Some(igt.saturating_sub(self.segment_start))
+                },
+                _ => None,
+            };
+
+            ui.text(format!("{:<24}", segment.config.name));
+            ui.same_line();
+            ui.text(time.map(format_time).unwrap_or_else(|| "--:--.---".to_string()));
+            ui.same_line();
+
+            match (time, best) {
+                _ if segment.gold => ui.text_colored(COLOR_GOLD, "gold"),
+                (Some(time), Some(best)) => {
+                    let (color, sign, delta) = if time <= best {
+                        (COLOR_AHEAD, '-', best - time)
+                    } else {
+                        (COLOR_BEHIND, '+', time - best)
+                    };
+                    ui.text_colored(color, format!("{sign}{}", format_time(delta)));
+                },
+                (None, Some(best)) => ui.text(format_time(best)),
+                _ => ui.text(""),
+            }
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.update();
+
+        if self.hotkey_reset.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.reset();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::widgets::Widget;
+
+use crate::ui_state;
+
+/// Command that can be turned off from the command checklist. While off, it
+/// is neither shown nor reacts to its hotkeys.
+struct Switchable {
+    name: String,
+    enabled: bool,
+    command: Box<dyn Widget>,
+}
+
+impl Widget for Switchable {
+    fn render(&mut self, ui: &imgui::Ui) {
+        if self.enabled {
+            self.command.render(ui);
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        if self.enabled {
+            self.command.render_closed(ui);
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.enabled = ui_state::command_enabled(&self.name);
+        if self.enabled {
+            self.command.interact(ui);
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        self.command.log(tx);
+    }
+}
+
+pub(crate) fn switchable(name: String, command: Box<dyn Widget>) -> Box<dyn Widget> {
+    Box::new(Switchable { enabled: ui_state::command_enabled(&name), name, command })
+}
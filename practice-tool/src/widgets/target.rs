@@ -1,12 +1,19 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use imgui::{ProgressBar, StyleColor};
 use libds3::memedit::PointerChain;
+use libds3::offsets::Offsets;
 use libds3::pointer_chain;
+use libds3::pointers::StatusBuildup;
+use libds3::version::VERSION;
 use practice_tool_core::key::Key;
 use practice_tool_core::widgets::Widget;
 use windows::Win32::System::Memory::{
     VirtualAlloc, MEM_COMMIT, MEM_RESERVE, PAGE_EXECUTE_READWRITE,
 };
 
+use crate::{feedback, restore};
+
 #[derive(Debug, Default)]
 struct EnemyInfo {
     hp: u32,
@@ -15,25 +22,10 @@ struct EnemyInfo {
     max_mp: u32,
     sp: u32,
     max_sp: u32,
-    res: EnemyResistances,
+    res: StatusBuildup,
     poise: PoiseMeter,
 }
 
-#[derive(Debug, Default)]
-#[repr(C)]
-struct EnemyResistances {
-    poison: u32,
-    toxic: u32,
-    bleed: u32,
-    curse: u32,
-    frost: u32,
-    poison_max: u32,
-    toxic_max: u32,
-    bleed_max: u32,
-    curse_max: u32,
-    frost_max: u32,
-}
-
 #[derive(Debug, Default)]
 #[repr(C)]
 struct PoiseMeter {
@@ -48,7 +40,7 @@ struct EntityPointerChains {
     hp: PointerChain<[u32; 3]>,
     sp: PointerChain<[u32; 3]>,
     mp: PointerChain<[u32; 3]>,
-    res: PointerChain<EnemyResistances>,
+    res: PointerChain<StatusBuildup>,
     poise: PointerChain<PoiseMeter>,
 }
 
@@ -61,7 +53,47 @@ pub(crate) struct Target {
     hotkey: Option<Key>,
     xa: u32,
     is_enabled: bool,
-    entity_addr: u64,
+}
+
+/// Address of the last entity the player locked on to, written by the
+/// detour while a target widget is enabled.
+static ENTITY_ADDR: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the status buildup of the locked on entity, if there is one.
+pub(crate) fn target_status_buildup(xa: u32) -> Option<StatusBuildup> {
+    match ENTITY_ADDR.load(Ordering::Relaxed) {
+        0 => None,
+        entity_addr => pointer_chain!(entity_addr as usize + xa as usize, 0x20, 0x10).read(),
+    }
+}
+
+/// Returns the HP, base max HP and max HP of the locked on entity, if there
+/// is one.
+pub(crate) fn target_hp(xa: u32) -> Option<PointerChain<[u32; 3]>> {
+    match ENTITY_ADDR.load(Ordering::Relaxed) {
+        0 => None,
+        entity_addr => Some(pointer_chain!(entity_addr as usize + xa as usize, 0x18, 0xd8)),
+    }
+}
+
+/// Returns the animation speed of the locked on entity, if there is one.
+pub(crate) fn target_speed(xa: u32) -> Option<PointerChain<f32>> {
+    match ENTITY_ADDR.load(Ordering::Relaxed) {
+        0 => None,
+        entity_addr => {
+            let offs_speed = Offsets::from(*VERSION).speed;
+            Some(pointer_chain!(entity_addr as usize + xa as usize, 0x28, offs_speed))
+        },
+    }
+}
+
+/// Returns the position of the locked on entity, if there is one.
+pub(crate) fn target_position(xa: u32) -> Option<[f32; 3]> {
+    match ENTITY_ADDR.load(Ordering::Relaxed) {
+        0 => None,
+        // SprjChrPhysicsModule
+        entity_addr => pointer_chain!(entity_addr as usize + xa as usize, 0x68, 0x80).read(),
+    }
 }
 
 unsafe impl Send for Target {}
@@ -99,24 +131,24 @@ impl Target {
             hotkey,
             xa,
             is_enabled: false,
-            entity_addr: 0,
         }
     }
 
     fn get_data(&self) -> Option<EnemyInfo> {
-        if !self.is_enabled || self.entity_addr == 0 {
+        let entity_addr = ENTITY_ADDR.load(Ordering::Relaxed);
+        if !self.is_enabled || entity_addr == 0 {
             return None;
         }
 
         let epc = EntityPointerChains {
             // SprjChrDataModule
-            hp: pointer_chain!(self.entity_addr as usize + self.xa as usize, 0x18, 0xd8),
-            sp: pointer_chain!(self.entity_addr as usize + self.xa as usize, 0x18, 0xf0),
-            mp: pointer_chain!(self.entity_addr as usize + self.xa as usize, 0x18, 0xe4),
+            hp: pointer_chain!(entity_addr as usize + self.xa as usize, 0x18, 0xd8),
+            sp: pointer_chain!(entity_addr as usize + self.xa as usize, 0x18, 0xf0),
+            mp: pointer_chain!(entity_addr as usize + self.xa as usize, 0x18, 0xe4),
             // SprjChrResistModule
-            res: pointer_chain!(self.entity_addr as usize + self.xa as usize, 0x20, 0x10),
+            res: pointer_chain!(entity_addr as usize + self.xa as usize, 0x20, 0x10),
             // SprjChrSuperArmorModule
-            poise: pointer_chain!(self.entity_addr as usize + self.xa as usize, 0x40, 0x28),
+            poise: pointer_chain!(entity_addr as usize + self.xa as usize, 0x40, 0x28),
         };
 
         let [hp, _, max_hp] = epc.hp.read()?;
@@ -136,7 +168,7 @@ impl Target {
         let detour_addr = self.detour_addr.eval().unwrap();
         let alloc_addr = self.alloc_addr.eval().unwrap();
 
-        let data_ptr = (&self.entity_addr as *const u64) as usize;
+        let data_ptr = ENTITY_ADDR.as_ptr() as usize;
         let going_jmp_to = (alloc_addr as isize - detour_addr as isize - 5) as i32;
         let returning_jmp_to = (detour_addr as isize - alloc_addr as isize - 15) as i32;
 
@@ -153,6 +185,7 @@ impl Target {
         patch_data[13..17].copy_from_slice(&u32_to_array(self.xa));
         patch_data[18..].copy_from_slice(&u32_to_array(returning_jmp_to as _));
 
+        restore::remember(&self.detour_addr);
         self.alloc_addr.write(patch_data);
         self.detour_addr.write(detour_bytes);
         self.is_enabled = true;
@@ -161,6 +194,15 @@ impl Target {
     fn disable(&mut self) {
         self.detour_addr.write(self.detour_orig_data);
         self.is_enabled = false;
+        ENTITY_ADDR.store(0, Ordering::Relaxed);
+    }
+}
+
+impl Drop for Target {
+    fn drop(&mut self) {
+        if self.is_enabled {
+            self.disable();
+        }
     }
 }
 
@@ -195,7 +237,6 @@ impl Widget for Target {
                 self.enable();
             } else {
                 self.disable();
-                self.entity_addr = 0;
             }
         }
     }
@@ -213,7 +254,7 @@ impl Widget for Target {
 
         let PoiseMeter { poise, poise_max, _unk, poise_time } = poise;
 
-        let EnemyResistances {
+        let StatusBuildup {
             poison,
             toxic,
             bleed,
@@ -298,6 +339,7 @@ impl Widget for Target {
             } else {
                 self.enable();
             }
+            feedback::executed();
         }
     }
 }
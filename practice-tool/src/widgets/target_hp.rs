@@ -0,0 +1,148 @@
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::audio::{self, Cue};
+use crate::feedback;
+use crate::widgets::target::target_hp;
+
+/// Sets the locked on enemy's HP to a percentage of its maximum. Needs the
+/// target entity info widget to be enabled to know which enemy that is.
+pub(crate) struct TargetHp {
+    xa: u32,
+    percent: f32,
+    hotkey: Option<Key>,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl TargetHp {
+    pub(crate) fn new(xa: u32, percent: f32, hotkey: Option<Key>) -> Self {
+        let label = match hotkey {
+            Some(k) => format!("Set target HP ({k})"),
+            None => "Set target HP".to_string(),
+        };
+        TargetHp { xa, percent: percent.clamp(0., 100.), hotkey, label, logs: Vec::new() }
+    }
+
+    fn apply(&mut self) {
+        let Some(ptr) = target_hp(self.xa) else {
+            self.logs.push("No enemy locked on".to_string());
+            return;
+        };
+        let Some([_, base_max_hp, max_hp]) = ptr.read() else {
+            return;
+        };
+
+        let hp = (max_hp as f32 * self.percent / 100.).round() as u32;
+        ptr.write([hp, base_max_hp, max_hp]);
+        self.logs.push(format!("Target HP set to {hp}/{max_hp}"));
+        feedback::executed();
+    }
+}
+
+impl Widget for TargetHp {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+        let button_width = BUTTON_WIDTH * scale;
+
+        ui.set_next_item_width(button_width);
+        ui.slider_config("##target-hp", 0., 100.).display_format("%.0f%%").build(&mut self.percent);
+
+        if ui.button_with_size(&self.label, [button_width, BUTTON_HEIGHT]) {
+            self.apply();
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.apply();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+/// Logs a line, and plays the `boss_hp` audio cue, whenever the locked on
+/// enemy's HP drops below one of the thresholds, to practice reacting to
+/// phase transitions without watching the health bar.
+pub(crate) struct HpAlerts {
+    xa: u32,
+    /// Percentages of the maximum HP, highest first.
+    thresholds: Vec<f32>,
+    sound: bool,
+    enabled: bool,
+    /// Target and its HP percentage on the last frame.
+    last: Option<(*mut [u32; 3], f32)>,
+    label: String,
+    logs: Vec<String>,
+}
+
+// SAFETY
+// The target address is only compared, never dereferenced.
+unsafe impl Send for HpAlerts {}
+unsafe impl Sync for HpAlerts {}
+
+impl HpAlerts {
+    pub(crate) fn new(xa: u32, mut thresholds: Vec<f32>, sound: bool) -> Self {
+        thresholds.retain(|t| (0. ..100.).contains(t));
+        thresholds.sort_by(|a, b| b.total_cmp(a));
+        thresholds.dedup();
+
+        let list = thresholds.iter().map(|t| format!("{t}%")).collect::<Vec<_>>().join(", ");
+        HpAlerts {
+            xa,
+            thresholds,
+            sound,
+            enabled: true,
+            last: None,
+            label: format!("HP alerts ({list})"),
+            logs: Vec::new(),
+        }
+    }
+
+    fn check(&mut self) {
+        let current = target_hp(self.xa).and_then(|ptr| {
+            let addr = ptr.eval()?;
+            let [hp, _, max_hp] = ptr.read()?;
+            (max_hp > 0).then(|| (addr, hp as f32 * 100. / max_hp as f32))
+        });
+
+        // A new target only sets the baseline, so locking on doesn't alert.
+        if let (Some((last_addr, last_percent)), Some((addr, percent))) = (self.last, current) {
+            let crossed = self.thresholds.iter().find(|&&t| last_percent > t && percent <= t);
+            if let (true, Some(threshold)) = (addr == last_addr, crossed) {
+                self.logs.push(format!("Target HP below {threshold}%"));
+                if self.sound {
+                    audio::play(Cue::BossHp);
+                }
+            }
+        }
+
+        self.last = current;
+    }
+}
+
+impl Widget for HpAlerts {
+    fn render(&mut self, ui: &imgui::Ui) {
+        ui.checkbox(&self.label, &mut self.enabled);
+    }
+
+    fn interact(&mut self, _ui: &imgui::Ui) {
+        if self.enabled {
+            self.check();
+        } else {
+            self.last = None;
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
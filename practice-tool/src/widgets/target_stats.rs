@@ -0,0 +1,177 @@
+use std::fmt::Write;
+
+use imgui::{InputText, ListBox};
+use libds3::prelude::*;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use super::target::{target_hp, target_status_buildup};
+
+const TARGET_STATS_TAG: &str = "##target-stats";
+
+/// Shows an enemy's defenses, absorptions, resistances and soul reward from
+/// its `NpcParam` row, next to the live values of the locked on target. The
+/// row is picked from a searchable list, since the same enemy often has
+/// several rows for its different placements.
+pub(crate) struct TargetStats {
+    xa: u32,
+    filter: String,
+    selected: Option<u64>,
+    hotkey_open: Option<Key>,
+    hotkey_close: Key,
+    label_open: String,
+    label_close: String,
+}
+
+impl TargetStats {
+    pub(crate) fn new(xa: u32, hotkey_open: Option<Key>, hotkey_close: Key) -> Self {
+        let label_open = match hotkey_open {
+            Some(k) => format!("Target stats ({k})"),
+            None => "Target stats".to_string(),
+        };
+
+        TargetStats {
+            xa,
+            filter: String::new(),
+            selected: None,
+            hotkey_open,
+            hotkey_close,
+            label_open,
+            label_close: format!("Close ({hotkey_close})"),
+        }
+    }
+
+    fn render_rows(&mut self, ui: &imgui::Ui) {
+        let Some(params) = loaded_params() else {
+            ui.text("Params not loaded yet");
+            return;
+        };
+        let params = params.read();
+
+        {
+            let _tok = ui.push_item_width(300.);
+            InputText::new(ui, "##target-stats-filter", &mut self.filter)
+                .hint("Filter by ID or name...")
+                .build();
+        }
+
+        let filter = self.filter.to_lowercase();
+        let names = PARAM_NAMES.get("NpcParam");
+        let mut buf = String::new();
+
+        ListBox::new("##target-stats-rows").size([300., 300.]).build(ui, || {
+            let Some(ids) = (unsafe { params.iter_param_ids("NpcParam") }) else {
+                return;
+            };
+
+            for id in ids {
+                buf.clear();
+                match names.and_then(|names| names.get(&(id as usize))) {
+                    Some(name) => write!(buf, "{id} - {name}").ok(),
+                    None => write!(buf, "{id}").ok(),
+                };
+
+                if !filter.is_empty() && !buf.to_lowercase().contains(&filter) {
+                    continue;
+                }
+
+                if ui.selectable_config(&buf).selected(self.selected == Some(id)).build() {
+                    self.selected = Some(id);
+                }
+            }
+        });
+    }
+
+    fn render_stats(&self, ui: &imgui::Ui) {
+        let params = loaded_params().map(|params| params.read());
+        let npc = self.selected.zip(params.as_ref()).and_then(|(selected, params)| {
+            unsafe { params.iter_param::<NpcParam>("NpcParam") }?
+                .find(|p| p.id == selected)
+                .and_then(|p| p.param)
+        });
+
+        match npc {
+            Some(npc) => {
+                ui.text(format!("HP        {}", npc.hp));
+                ui.text(format!("Souls     {}", npc.get_soul));
+                ui.separator();
+                ui.text("Defense");
+                ui.text(format!("  Physical {:5}", npc.def_phys));
+                ui.text(format!("  Magic    {:5}", npc.def_mag));
+                ui.text(format!("  Fire     {:5}", npc.def_fire));
+                ui.text(format!("  Lightning{:5}", npc.def_thunder));
+                ui.text(format!("  Dark     {:5}", npc.def_dark));
+                ui.text("Damage taken (%)");
+                ui.text(format!("  Slash    {:5}", npc.def_slash));
+                ui.text(format!("  Strike   {:5}", npc.def_blow));
+                ui.text(format!("  Thrust   {:5}", npc.def_thrust));
+                ui.text("Guard absorption (%)");
+                ui.text(format!("  Physical {:5.1}", npc.phys_guard_cut_rate));
+                ui.text(format!("  Magic    {:5.1}", npc.mag_guard_cut_rate));
+                ui.text(format!("  Fire     {:5.1}", npc.fire_guard_cut_rate));
+                ui.text(format!("  Lightning{:5.1}", npc.thun_guard_cut_rate));
+                ui.text("Resistances");
+                ui.text(format!("  Poison   {:5}", npc.resist_poison));
+                ui.text(format!("  Toxic    {:5}", npc.resist_toxic));
+                ui.text(format!("  Bleed    {:5}", npc.resist_blood));
+                ui.text(format!("  Curse    {:5}", npc.resist_curse));
+                ui.text(format!("  Frost    {:5}", npc.resist_frost));
+                ui.text(format!("Poise     {}", npc.super_armor_durability));
+            },
+            None => ui.text("Select an NpcParam row"),
+        }
+
+        ui.separator();
+        ui.text("Locked on target");
+        match (target_hp(self.xa).and_then(|hp| hp.read()), target_status_buildup(self.xa)) {
+            (Some([hp, _, max_hp]), Some(res)) => {
+                ui.text(format!("  HP       {hp}/{max_hp}"));
+                ui.text(format!("  Poison   {:5}", res.poison_max));
+                ui.text(format!("  Toxic    {:5}", res.toxic_max));
+                ui.text(format!("  Bleed    {:5}", res.bleed_max));
+                ui.text(format!("  Curse    {:5}", res.curse_max));
+                ui.text(format!("  Frost    {:5}", res.frost_max));
+            },
+            _ => ui.text("  No target (needs target entity info enabled)"),
+        }
+    }
+}
+
+impl Widget for TargetStats {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let scale = scaling_factor(ui);
+
+        if ui.button_with_size(&self.label_open, [BUTTON_WIDTH * scale, BUTTON_HEIGHT]) {
+            ui.open_popup(TARGET_STATS_TAG);
+        }
+
+        if let Some(_token) = ui
+            .modal_popup_config(TARGET_STATS_TAG)
+            .resizable(false)
+            .movable(false)
+            .title_bar(false)
+            .always_auto_resize(true)
+            .begin_popup()
+        {
+            ui.columns(2, "##target-stats-columns", false);
+            ui.set_column_width(0, 310.);
+            self.render_rows(ui);
+            ui.next_column();
+            self.render_stats(ui);
+            ui.columns(1, "##target-stats-columns", false);
+
+            if ui.button_with_size(&self.label_close, [400., BUTTON_HEIGHT])
+                || (self.hotkey_close.is_pressed(ui)
+                    && !(ui.io().want_capture_keyboard && ui.is_any_item_active()))
+            {
+                ui.close_current_popup();
+            }
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey_open.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            ui.open_popup(TARGET_STATS_TAG);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::widgets::Widget;
+
+/// Command with a description from the config file, shown as a tooltip when
+/// hovering any part of it.
+struct Described {
+    description: String,
+    command: Box<dyn Widget>,
+}
+
+impl Widget for Described {
+    fn render(&mut self, ui: &imgui::Ui) {
+        ui.group(|| self.command.render(ui));
+        if ui.is_item_hovered() {
+            ui.tooltip_text(&self.description);
+        }
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.command.render_closed(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        self.command.interact(ui);
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        self.command.log(tx);
+    }
+}
+
+pub(crate) fn described(description: String, command: Box<dyn Widget>) -> Box<dyn Widget> {
+    Box::new(Described { description, command })
+}
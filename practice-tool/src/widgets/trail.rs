@@ -0,0 +1,154 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::Widget;
+
+use crate::feedback;
+use crate::projection::Projection;
+
+/// Time between two points of the trail.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Distance covered between two samples past which the player is considered
+/// to have teleported, e.g. by loading a saved position.
+const JUMP_DISTANCE: f32 = 5.;
+
+const COLOR_CURRENT: [f32; 4] = [0.3, 1., 0.5, 0.9];
+const COLOR_PREVIOUS: [f32; 4] = [1., 1., 1., 0.45];
+
+struct TrailPoint {
+    time: Instant,
+    position: [f32; 3],
+    /// Whether a teleport happened right before this point.
+    jump: bool,
+}
+
+/// Draws the player's path over the last few seconds in the world. Teleports
+/// split the path, and the parts before the last teleport are drawn fainter,
+/// so that each attempt from a saved position can be compared to the previous.
+struct Trail {
+    ptr_pos: PointerChain<[f32; 3]>,
+    camera: PointerChain<[f32; 16]>,
+    fov: PointerChain<f32>,
+    duration: Duration,
+    points: VecDeque<TrailPoint>,
+    enabled: bool,
+    hotkey: Option<Key>,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl Trail {
+    fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+        self.points.clear();
+        let state = if self.enabled { "enabled" } else { "disabled" };
+        self.logs.push(format!("Path trail {state}"));
+        feedback::executed();
+    }
+
+    fn sample(&mut self) {
+        let now = Instant::now();
+        while self.points.front().is_some_and(|p| now.duration_since(p.time) > self.duration) {
+            self.points.pop_front();
+        }
+
+        if self.points.back().is_some_and(|p| now.duration_since(p.time) < SAMPLE_INTERVAL) {
+            return;
+        }
+
+        let Some(position) = self.ptr_pos.read() else {
+            return;
+        };
+
+        let jump = self.points.back().is_some_and(|p| {
+            let [x, y, z] = p.position;
+            let [nx, ny, nz] = position;
+            (nx - x).powi(2) + (ny - y).powi(2) + (nz - z).powi(2) > JUMP_DISTANCE.powi(2)
+        });
+
+        self.points.push_back(TrailPoint { time: now, position, jump });
+    }
+
+    fn draw(&self, ui: &imgui::Ui) {
+        if !self.enabled {
+            return;
+        }
+
+        let Some(projection) = Projection::read(&self.camera, &self.fov, ui.io().display_size)
+        else {
+            return;
+        };
+
+        let last_jump = self.points.iter().rposition(|p| p.jump).unwrap_or(0);
+        let draw_list = ui.get_background_draw_list();
+
+        for (i, (a, b)) in self.points.iter().zip(self.points.iter().skip(1)).enumerate() {
+            if b.jump {
+                continue;
+            }
+
+            let (Some(from), Some(to)) =
+                (projection.to_screen(a.position), projection.to_screen(b.position))
+            else {
+                continue;
+            };
+
+            let color = if i + 1 > last_jump { COLOR_CURRENT } else { COLOR_PREVIOUS };
+            draw_list.add_line(from, to, color).thickness(2.).build();
+        }
+    }
+}
+
+impl Widget for Trail {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let mut enabled = self.enabled;
+        if ui.checkbox(&self.label, &mut enabled) {
+            self.toggle();
+        }
+
+        self.draw(ui);
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.draw(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.toggle();
+        }
+
+        if self.enabled {
+            self.sample();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn trail(chains: &PointerChains, seconds: f32, hotkey: Option<Key>) -> Box<dyn Widget> {
+    let label = match hotkey {
+        Some(k) => format!("Path trail ({k})"),
+        None => "Path trail".to_string(),
+    };
+
+    Box::new(Trail {
+        ptr_pos: chains.position.1.clone(),
+        camera: chains.camera.clone(),
+        fov: chains.fov.clone(),
+        duration: Duration::from_secs_f32(seconds.max(0.)),
+        points: VecDeque::new(),
+        enabled: false,
+        hotkey,
+        label,
+        logs: Vec::new(),
+    })
+}
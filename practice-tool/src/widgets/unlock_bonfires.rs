@@ -0,0 +1,89 @@
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::feedback;
+
+/// Lights bonfires by setting their event flags, so that they can be warped
+/// to. Without a configured list of flags, every bonfire of the warp menu is
+/// unlocked.
+struct UnlockBonfires {
+    hp: PointerChain<[u32; 3]>,
+    flags: Vec<u32>,
+    hotkey: Option<Key>,
+    label: String,
+    logs: Vec<String>,
+}
+
+impl UnlockBonfires {
+    /// Flags shown in the warp menu once set, from `BonfireWarpParam`.
+    fn warp_flags() -> Vec<u32> {
+        let Some(params) = loaded_params() else {
+            return Vec::new();
+        };
+        let params = params.read();
+        let Some(bonfires) = (unsafe { params.iter_param::<BonfireWarpParam>("BonfireWarpParam") })
+        else {
+            return Vec::new();
+        };
+
+        bonfires
+            .filter_map(|p| p.param.map(|p| p.warp_event_id))
+            .filter(|&flag| flag > 0)
+            .map(|flag| flag as u32)
+            .collect()
+    }
+
+    fn unlock(&mut self) {
+        if self.hp.read().is_none() {
+            self.logs.push("Can't unlock bonfires when not in game".to_string());
+            return;
+        }
+
+        let flags = if self.flags.is_empty() { Self::warp_flags() } else { self.flags.clone() };
+        if flags.is_empty() {
+            self.logs.push("No bonfires found".to_string());
+            return;
+        }
+
+        let unlocked = flags.iter().filter(|&&flag| EventFlag(flag).set(true).is_some()).count();
+        self.logs.push(format!("Unlocked {unlocked} of {} bonfires", flags.len()));
+        if unlocked > 0 {
+            feedback::executed();
+        }
+    }
+}
+
+impl Widget for UnlockBonfires {
+    fn render(&mut self, ui: &imgui::Ui) {
+        if ui.button_with_size(&self.label, [BUTTON_WIDTH * scaling_factor(ui), BUTTON_HEIGHT]) {
+            self.unlock();
+        }
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.hotkey.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.unlock();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn unlock_bonfires(
+    hp: PointerChain<[u32; 3]>,
+    flags: Vec<u32>,
+    hotkey: Option<Key>,
+) -> Box<dyn Widget> {
+    let label = match hotkey {
+        Some(k) => format!("Unlock bonfires ({k})"),
+        None => "Unlock bonfires".to_string(),
+    };
+
+    Box::new(UnlockBonfires { hp, flags, hotkey, label, logs: Vec::new() })
+}
@@ -0,0 +1,145 @@
+use libds3::prelude::*;
+use practice_tool_core::crossbeam_channel::Sender;
+use practice_tool_core::key::Key;
+use practice_tool_core::widgets::{scaling_factor, Widget, BUTTON_HEIGHT, BUTTON_WIDTH};
+
+use crate::feedback;
+use crate::projection::Projection;
+use crate::widgets::area::{AreaSpec, NamedPoint};
+
+const COLOR: [f32; 4] = [1., 0.6, 0.2, 0.9];
+
+/// Markers drawn over the world at fixed coordinates, with their distance
+/// from the player.
+struct Waypoints {
+    ptr_pos: PointerChain<[f32; 3]>,
+    camera: PointerChain<[f32; 16]>,
+    fov: PointerChain<f32>,
+    map_id: PointerChain<MapId>,
+    /// Waypoints from the config file, followed by the placed ones.
+    points: Vec<NamedPoint>,
+    configured: usize,
+    visible: bool,
+    key_place: Option<Key>,
+    key_clear: Option<Key>,
+    label_place: String,
+    label_clear: String,
+    logs: Vec<String>,
+}
+
+impl Waypoints {
+    fn place(&mut self) {
+        let (Some(map_id), Some(position)) = (self.map_id.read(), self.ptr_pos.read()) else {
+            self.logs.push("Waypoints unavailable when not in game".to_string());
+            return;
+        };
+
+        let name = format!("#{}", self.points.len() - self.configured + 1);
+        let [x, y, z] = position;
+        self.logs.push(format!("Waypoint {name} placed at {x:.2} {y:.2} {z:.2}"));
+        self.points.push(NamedPoint { name, position, area: Some(AreaSpec::of(map_id)) });
+        feedback::executed();
+    }
+
+    fn clear(&mut self) {
+        self.points.truncate(self.configured);
+        self.logs.push("Placed waypoints cleared".to_string());
+        feedback::executed();
+    }
+
+    fn draw(&self, ui: &imgui::Ui) {
+        if !self.visible {
+            return;
+        }
+
+        let (Some(map_id), Some([px, py, pz])) = (self.map_id.read(), self.ptr_pos.read()) else {
+            return;
+        };
+        let Some(projection) = Projection::read(&self.camera, &self.fov, ui.io().display_size)
+        else {
+            return;
+        };
+
+        let draw_list = ui.get_background_draw_list();
+        for point in self.points.iter().filter(|p| p.is_in(map_id)) {
+            let Some(screen) = projection.to_screen(point.position) else {
+                continue;
+            };
+
+            let [x, y, z] = point.position;
+            let distance = ((x - px).powi(2) + (y - py).powi(2) + (z - pz).powi(2)).sqrt();
+
+            // Markers shrink with the distance from the camera, down to a dot.
+            let radius = (40. / projection.distance(point.position)).clamp(3., 12.);
+            draw_list.add_circle(screen, radius, COLOR).thickness(2.).build();
+            draw_list.add_text(
+                [screen[0] + radius + 4., screen[1] - 7.],
+                COLOR,
+                format!("{} {distance:.1}m", point.name),
+            );
+        }
+    }
+}
+
+impl Widget for Waypoints {
+    fn render(&mut self, ui: &imgui::Ui) {
+        let button_width = BUTTON_WIDTH * scaling_factor(ui);
+
+        ui.checkbox("Show waypoints", &mut self.visible);
+        if ui.button_with_size(&self.label_place, [button_width, BUTTON_HEIGHT]) {
+            self.place();
+        }
+        if ui.button_with_size(&self.label_clear, [button_width, BUTTON_HEIGHT]) {
+            self.clear();
+        }
+
+        self.draw(ui);
+    }
+
+    fn render_closed(&mut self, ui: &imgui::Ui) {
+        self.draw(ui);
+    }
+
+    fn interact(&mut self, ui: &imgui::Ui) {
+        if self.key_place.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.place();
+        }
+
+        if self.key_clear.map(|k| k.is_pressed(ui)).unwrap_or(false) {
+            self.clear();
+        }
+    }
+
+    fn log(&mut self, tx: Sender<String>) {
+        for log in self.logs.drain(..) {
+            tx.send(log).ok();
+        }
+    }
+}
+
+pub(crate) fn waypoints(
+    chains: &PointerChains,
+    points: Vec<NamedPoint>,
+    key_place: Option<Key>,
+    key_clear: Option<Key>,
+) -> Box<dyn Widget> {
+    let with_key = |label: &str, key: Option<Key>| match key {
+        Some(k) => format!("{label} ({k})"),
+        None => label.to_string(),
+    };
+
+    Box::new(Waypoints {
+        ptr_pos: chains.position.1.clone(),
+        camera: chains.camera.clone(),
+        fov: chains.fov.clone(),
+        map_id: chains.map_id.clone(),
+        configured: points.len(),
+        points,
+        visible: true,
+        label_place: with_key("Place waypoint", key_place),
+        label_clear: with_key("Clear waypoints", key_clear),
+        key_place,
+        key_clear,
+        logs: Vec::new(),
+    })
+}
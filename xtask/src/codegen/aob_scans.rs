@@ -1,9 +1,100 @@
-use std::env;
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
+use std::{env, fs};
 
+use heck::AsSnakeCase;
 use practice_tool_tasks::codegen::{self, aob_direct, aob_indirect, aob_indirect_twice};
 use textwrap::dedent;
 
+use crate::Result;
+
+/// How the address is read from a signature's match.
+enum Resolve {
+    /// The address of the match.
+    Direct,
+    /// A RIP-relative address: displacement offset and instruction length.
+    RipRelative(usize, usize),
+    /// A 32-bit immediate at the given offset into the match.
+    Immediate(usize),
+}
+
+/// Signatures of the base addresses. They are scanned in every patch to
+/// generate the base addresses, and the runtime scan for unknown versions is
+/// generated from them too.
+const SIGNATURES: [(&str, &str, Resolve); 19] = [
+    (
+        "WorldChrMan",
+        "48 8B 1D ?? ?? ?? 04 48 8B F9 48 85 DB ?? ?? 8B 11 85 D2 ?? ?? 8D",
+        Resolve::RipRelative(3, 7),
+    ),
+    (
+        "WorldChrManDbg",
+        "48 8B 05 ?? ?? ?? ?? 66 0F 7F 44 24 40 48 85 C0",
+        Resolve::RipRelative(3, 7),
+    ),
+    (
+        "MenuMan",
+        "48 89 15 ?? ?? ?? ?? 44 8b 82 ?? ?? ?? ?? 44 8b 8a ?? ?? ?? ?? 48 8b c3",
+        Resolve::RipRelative(3, 7),
+    ),
+    ("BaseA", "48 8B 05 ?? ?? ?? ?? 48 85 C0 ?? ?? 48 8b 40 ?? C3", Resolve::RipRelative(3, 7)),
+    ("BaseD", "48 8B 0D ?? ?? ?? ?? 48 85 C9 74 26 44 8B", Resolve::RipRelative(3, 7)),
+    ("SprjDebugEvent", "48 8B 05 ?? ?? ?? ?? 41 0F B6 D8 8B EA", Resolve::RipRelative(3, 7)),
+    (
+        "Debug",
+        "C6 05 ?? ?? ?? ?? 01 48 8B 8C 24 ?? ?? ?? ?? 48 33 CC E8 ?? ?? ?? ?? 4C 8D 9C 24",
+        Resolve::RipRelative(2, 7),
+    ),
+    (
+        "Grend",
+        "C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? ?? 00 4C \
+         8B 05 ?? ?? ?? ?? 4C 89 44 24 58",
+        Resolve::RipRelative(2, 7),
+    ),
+    (
+        "BaseHBD",
+        "48 8B 0D ?? ?? ?? ?? 41 B0 01 E8 ?? ?? ?? ?? 48 8B D3 48 8B CF",
+        Resolve::RipRelative(3, 7),
+    ),
+    (
+        "MapItemMan",
+        "48 8B 0D ?? ?? ?? ?? 48 8B 89 ?? ?? ?? ?? E8 ?? ?? ?? ?? E9",
+        Resolve::RipRelative(3, 7),
+    ),
+    (
+        "SpawnItemFuncPtr",
+        // "E8 ?? ?? ?? ?? C7 44 24 20 00 01 00 00 4C 8D 4C 24 40 41 B8 2C 00 00 00 48 8B D3",
+        "E8 ?? ?? ?? ?? C7 44 24 20 00 01 00 00 4C 8D 4C 24 40 41 B8",
+        Resolve::RipRelative(1, 5),
+    ),
+    ("Param", "48 8B 0D ?? ?? ?? ?? 48 85 C9 74 0B 4C 8B C0 48 8B D7", Resolve::RipRelative(3, 7)),
+    (
+        "FormatString",
+        "3C 00 54 00 45 00 58 00 54 00 46 00 4F 00 52 00 4D 00 41 00 54 00",
+        Resolve::Direct,
+    ),
+    ("NoLogo", "E8 ?? ?? ?? FF 90 4D 8B C7 49 8B D4 48 8B C8 E8 ?? ?? ?? FF", Resolve::Direct),
+    ("CurrentTarget", "48 8B 80 ?? ?? ?? ?? 48 8B 08 48 8B ?? 58", Resolve::Direct),
+    (
+        "MenuTravel",
+        "40 55 53 56 57 41 56 48 8D 6C 24 C9 48 81 EC 00 01 00 00 48 C7 45 97 FE FF FF FF",
+        Resolve::Direct,
+    ),
+    (
+        "MenuAttune",
+        "48 8D 45 0F 48 89 45 EF 48 8D 45 0F 48 89 45 F7 48 8D ?? ?? ?? ?? ?? 48 89 45 0F 48 8D \
+         ?? ?? ?? ?? ?? 48 89 45 0F 48 8D ?? ?? ?? ?? ?? 48 89 45 17",
+        Resolve::Direct,
+    ),
+    ("XA", "48 8B 83 ?? ?? ?? ?? 48 8B 10 48 85 D2 ?? ?? 8B", Resolve::Immediate(3)),
+    (
+        "BaseFPS",
+        "48 8B 0D ?? ?? ?? ?? 84 C0 74 32 48 85 C9 75 26 4C 8D 0D ?? ?? ?? ?? 4C 8D 05 ?? ?? ?? \
+         ?? 48 8D 0D ?? ?? ?? ??",
+        Resolve::RipRelative(3, 7),
+    ),
+];
+
 fn patches_paths() -> impl Iterator<Item = PathBuf> {
     let base_path = PathBuf::from(
         env::var("DSIII_PATCHES_PATH").unwrap_or_else(|_| panic!("{}", dedent(r"
@@ -14,11 +105,11 @@ fn patches_paths() -> impl Iterator<Item = PathBuf> {
     base_path
         .read_dir()
         .expect("Couldn't scan patches directory")
-        .map(Result::unwrap)
+        .map(|entry| entry.unwrap())
         .map(|dir| dir.path().join("Game").join("DarkSoulsIII.exe"))
 }
 
-fn base_addresses_rs_path() -> PathBuf {
+fn codegen_path(file: &str) -> PathBuf {
     Path::new(&env!("CARGO_MANIFEST_DIR"))
         .ancestors()
         .nth(1)
@@ -28,123 +119,62 @@ fn base_addresses_rs_path() -> PathBuf {
         .join("libds3")
         .join("src")
         .join("codegen")
-        .join("base_addresses.rs")
+        .join(file)
 }
 
 pub fn get_base_addresses() {
-    let aobs = &[
-        aob_indirect_twice(
-            "WorldChrMan",
-            &["48 8B 1D ?? ?? ?? 04 48 8B F9 48 85 DB ?? ?? 8B 11 85 D2 ?? ?? 8D"],
-            3,
-            7,
-            true,
-        ),
-        aob_indirect_twice(
-            "WorldChrManDbg",
-            &["48 8B 05 ?? ?? ?? ?? 66 0F 7F 44 24 40 48 85 C0"],
-            3,
-            7,
-            true,
-        ),
-        aob_indirect_twice(
-            "MenuMan",
-            &["48 89 15 ?? ?? ?? ?? 44 8b 82 ?? ?? ?? ?? 44 8b 8a ?? ?? ?? ?? 48 8b c3"],
-            3,
-            7,
-            true,
-        ),
-        aob_indirect_twice(
-            "BaseA",
-            &["48 8B 05 ?? ?? ?? ?? 48 85 C0 ?? ?? 48 8b 40 ?? C3"],
-            3,
-            7,
-            true,
-        ),
-        aob_indirect_twice("BaseD", &["48 8B 0D ?? ?? ?? ?? 48 85 C9 74 26 44 8B"], 3, 7, true),
-        aob_indirect_twice(
-            "SprjDebugEvent",
-            &["48 8B 05 ?? ?? ?? ?? 41 0F B6 D8 8B EA"],
-            3,
-            7,
-            true,
-        ),
-        aob_indirect_twice(
-            "Debug",
-            &["C6 05 ?? ?? ?? ?? 01 48 8B 8C 24 ?? ?? ?? ?? 48 33 CC E8 ?? ?? ?? ?? 4C 8D 9C 24"],
-            2,
-            7,
-            true,
-        ),
-        aob_indirect_twice(
-            "Grend",
-            &["C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? ?? 00 C6 05 ?? ?? ?? ?? \
-               00 4C 8B 05 ?? ?? ?? ?? 4C 89 44 24 58"],
-            2,
-            7,
-            true,
-        ),
-        aob_indirect_twice(
-            "BaseHBD",
-            &["48 8B 0D ?? ?? ?? ?? 41 B0 01 E8 ?? ?? ?? ?? 48 8B D3 48 8B CF"],
-            3,
-            7,
-            true,
-        ),
-        aob_indirect_twice(
-            "MapItemMan",
-            &["48 8B 0D ?? ?? ?? ?? 48 8B 89 ?? ?? ?? ?? E8 ?? ?? ?? ?? E9"],
-            3,
-            7,
-            true,
-        ),
-        aob_indirect_twice(
-            "SpawnItemFuncPtr",
-            // "E8 ?? ?? ?? ?? C7 44 24 20 00 01 00 00 4C 8D 4C 24 40 41 B8 2C 00 00 00 48 8B D3",
-            &["E8 ?? ?? ?? ?? C7 44 24 20 00 01 00 00 4C 8D 4C 24 40 41 B8"],
-            1,
-            5,
-            true,
-        ),
-        aob_indirect_twice(
-            "Param",
-            &["48 8B 0D ?? ?? ?? ?? 48 85 C9 74 0B 4C 8B C0 48 8B D7"],
-            3,
-            7,
-            true,
-        ),
-        aob_direct(
-            "FormatString",
-            &["3C 00 54 00 45 00 58 00 54 00 46 00 4F 00 52 00 4D 00 41 00 54 00"],
-            true,
-        ),
-        aob_direct(
-            "NoLogo",
-            &["E8 ?? ?? ?? FF 90 4D 8B C7 49 8B D4 48 8B C8 E8 ?? ?? ?? FF"],
-            true,
-        ),
-        aob_direct("CurrentTarget", &["48 8B 80 ?? ?? ?? ?? 48 8B 08 48 8B ?? 58"], true),
-        aob_direct(
-            "MenuTravel",
-            &["40 55 53 56 57 41 56 48 8D 6C 24 C9 48 81 EC 00 01 00 00 48 C7 45 97 FE FF FF FF"],
-            true,
-        ),
-        aob_direct(
-            "MenuAttune",
-            &["48 8D 45 0F 48 89 45 EF 48 8D 45 0F 48 89 45 F7 48 8D ?? ?? ?? ?? ?? 48 89 45 0F \
-               48 8D ?? ?? ?? ?? ?? 48 89 45 0F 48 8D ?? ?? ?? ?? ?? 48 89 45 17"],
-            true,
-        ),
-        aob_indirect("XA", &["48 8B 83 ?? ?? ?? ?? 48 8B 10 48 85 D2 ?? ?? 8B"], 3, false),
-        aob_indirect_twice(
-            "BaseFPS",
-            &["48 8B 0D ?? ?? ?? ?? 84 C0 74 32 48 85 C9 75 26 4C 8D 0D ?? ?? ?? ?? 4C 8D 05 ?? \
-               ?? ?? ?? 48 8D 0D ?? ?? ?? ??"],
-            3,
-            7,
-            true,
-        ),
-    ];
+    let aobs = SIGNATURES
+        .iter()
+        .map(|(name, pattern, resolve)| match *resolve {
+            Resolve::Direct => aob_direct(name, &[*pattern], true),
+            Resolve::RipRelative(offset, len) => {
+                aob_indirect_twice(name, &[*pattern], offset, len, true)
+            },
+            Resolve::Immediate(offset) => aob_indirect(name, &[*pattern], offset, false),
+        })
+        .collect::<Vec<_>>();
+
+    codegen::codegen_base_addresses(codegen_path("base_addresses.rs"), patches_paths(), &aobs)
+}
+
+/// Generates the signatures scanned at runtime on unknown game versions, and
+/// the base addresses built from what they find.
+pub(crate) fn codegen_signatures() -> Result<()> {
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask codegen` from `xtask/src/codegen/aob_scans.rs`.")?;
+    writeln!(out)?;
+    writeln!(out, "use crate::codegen::base_addresses::BaseAddresses;")?;
+    writeln!(out, "use crate::signatures::{{Resolve, Signature}};")?;
+    writeln!(out)?;
+    writeln!(out, "/// Signatures of the base addresses, for scanning them at runtime.")?;
+    writeln!(out, "pub(crate) static SIGNATURES: [Signature; {}] = [", SIGNATURES.len())?;
+    for (name, pattern, resolve) in &SIGNATURES {
+        let resolve = match resolve {
+            Resolve::Direct => "Direct".to_string(),
+            Resolve::RipRelative(offset, len) => format!("RipRelative({offset}, {len})"),
+            Resolve::Immediate(offset) => format!("Immediate({offset})"),
+        };
+        writeln!(out, "    Signature {{")?;
+        writeln!(out, "        name: {name:?},")?;
+        writeln!(out, "        pattern: {pattern:?},")?;
+        writeln!(out, "        resolve: Resolve::{resolve},")?;
+        writeln!(out, "    }},")?;
+    }
+    writeln!(out, "];")?;
+    writeln!(out)?;
+    writeln!(out, "/// Base addresses from the address `scanned` finds for each signature.")?;
+    writeln!(
+        out,
+        "pub(crate) fn scanned_base_addresses(scanned: impl Fn(&str) -> usize) -> BaseAddresses {{"
+    )?;
+    writeln!(out, "    BaseAddresses {{")?;
+    for (name, ..) in &SIGNATURES {
+        writeln!(out, "        {}: scanned({name:?}),", AsSnakeCase(name))?;
+    }
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+
+    fs::write(codegen_path("base_signatures.rs"), out)?;
 
-    codegen::codegen_base_addresses(base_addresses_rs_path(), patches_paths(), aobs)
+    Ok(())
 }
@@ -0,0 +1,43 @@
+use std::fmt::Write as _;
+use std::fs;
+
+use anyhow::{bail, Context};
+
+use crate::{project_root, Result};
+
+/// Generates the built-in boss practice positions from `boss_positions.txt`.
+pub(crate) fn codegen() -> Result<()> {
+    let source = fs::read_to_string(project_root().join("xtask/src/codegen/boss_positions.txt"))
+        .context("boss_positions.txt")?;
+
+    let mut entries = Vec::new();
+    for line in source.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')) {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let [flag, x, y, z, angle] = fields[..] else {
+            bail!("Invalid boss position line: {line}");
+        };
+
+        let flag = flag.parse::<u32>().with_context(|| format!("Invalid flag: {flag}"))?;
+        let position = [x, y, z, angle]
+            .map(|v| v.parse::<f32>().with_context(|| format!("Invalid coordinate: {v}")));
+        let [x, y, z, angle] = position;
+        entries.push((flag, [x?, y?, z?, angle?]));
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated by `cargo xtask codegen` from `xtask/src/codegen/boss_positions.txt`."
+    )?;
+    writeln!(out)?;
+    writeln!(out, "/// Boss defeated flags and where to stand to fight the boss: x, y, z, angle.")?;
+    writeln!(out, "pub static BOSS_POSITIONS: [(u32, [f32; 4]); {}] = [", entries.len())?;
+    for (flag, [x, y, z, angle]) in &entries {
+        writeln!(out, "    ({flag}, [{x:?}, {y:?}, {z:?}, {angle:?}]),")?;
+    }
+    writeln!(out, "];")?;
+
+    fs::write(project_root().join("lib/libds3/src/codegen/boss_positions.rs"), out)?;
+
+    Ok(())
+}
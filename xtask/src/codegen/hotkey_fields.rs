@@ -0,0 +1,73 @@
+use std::fmt::Write as _;
+use std::fs;
+
+use anyhow::{bail, Context};
+use regex::Regex;
+
+use crate::{project_root, Result};
+
+/// Types of the configuration fields that hold a hotkey.
+const KEY_TYPES: [&str; 3] = ["Key", "Option<Key>", "PlaceholderOption<Key>"];
+
+/// Generates the lists of configuration fields holding a hotkey, for the
+/// hotkey editor, from the command and settings types in `config.rs`.
+pub(crate) fn codegen() -> Result<()> {
+    let source = fs::read_to_string(project_root().join("practice-tool/src/config.rs"))
+        .context("config.rs")?;
+
+    let commands = key_fields(&source, "enum CfgCommand {")?;
+    let settings = key_fields(&source, "pub(crate) struct Settings {")?;
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask codegen` from `practice-tool/src/config.rs`.")?;
+    writeln!(out)?;
+    writeln!(out, "/// Command fields holding a hotkey.")?;
+    write_list(&mut out, "HOTKEY_FIELDS", &commands)?;
+    writeln!(out)?;
+    writeln!(out, "/// Settings fields holding a hotkey.")?;
+    write_list(&mut out, "SETTINGS_FIELDS", &settings)?;
+
+    fs::write(project_root().join("practice-tool/src/codegen/hotkey_fields.rs"), out)?;
+
+    Ok(())
+}
+
+/// Names of the fields of a key type in the item declared by `decl`, as they
+/// are written in the configuration file.
+fn key_fields(source: &str, decl: &str) -> Result<Vec<String>> {
+    let Some(start) = source.find(decl) else {
+        bail!("Couldn't find `{decl}` in config.rs");
+    };
+    let end = source[start..].find("\n}\n").map(|end| start + end).unwrap_or(source.len());
+
+    let rename_re = Regex::new(r#"^#\[serde\(.*rename = "([^"]+)""#)?;
+    let field_re = Regex::new(r"^(?:pub(?:\(crate\))? )?(\w+): (.+),$")?;
+
+    let mut fields = Vec::new();
+    let mut rename = None;
+    for line in source[start..end].lines().map(str::trim) {
+        if let Some(c) = rename_re.captures(line) {
+            rename = Some(c[1].to_string());
+        } else if line.starts_with("#[") {
+            continue;
+        } else if let Some(c) = field_re.captures(line) {
+            let field = rename.take().unwrap_or_else(|| c[1].to_string());
+            if KEY_TYPES.contains(&&c[2]) && !fields.contains(&field) {
+                fields.push(field);
+            }
+        } else {
+            rename = None;
+        }
+    }
+
+    Ok(fields)
+}
+
+fn write_list(out: &mut String, name: &str, fields: &[String]) -> Result<()> {
+    writeln!(out, "pub(crate) const {name}: [&str; {}] = [", fields.len())?;
+    for field in fields {
+        writeln!(out, "    {field:?},")?;
+    }
+    writeln!(out, "];")?;
+    Ok(())
+}
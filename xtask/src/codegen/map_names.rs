@@ -0,0 +1,44 @@
+use std::fmt::Write as _;
+use std::fs;
+
+use anyhow::{bail, Context};
+
+use crate::{project_root, Result};
+
+/// Generates the map name table from `map_names.txt`.
+pub(crate) fn codegen() -> Result<()> {
+    let source = fs::read_to_string(project_root().join("xtask/src/codegen/map_names.txt"))
+        .context("map_names.txt")?;
+
+    let mut entries = Vec::new();
+    for line in source.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')) {
+        let Some((map, name)) = line.split_once(' ') else {
+            bail!("Invalid map name line: {line}");
+        };
+
+        let bytes = map
+            .strip_prefix('m')
+            .map(|m| m.split('_').map(str::parse::<u8>).collect::<Result<Vec<_>, _>>())
+            .with_context(|| format!("Invalid map ID: {map}"))??;
+        let [aa, bb, cc, dd] = bytes[..] else {
+            bail!("Invalid map ID: {map}");
+        };
+
+        entries.push((u32::from_be_bytes([aa, bb, cc, dd]), name.trim().to_string()));
+    }
+    entries.sort();
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo xtask codegen` from `xtask/src/codegen/map_names.txt`.")?;
+    writeln!(out)?;
+    writeln!(out, "/// Map IDs, packed as in [`crate::pointers::MapId`], and their names.")?;
+    writeln!(out, "pub static MAP_NAMES: [(u32, &str); {}] = [", entries.len())?;
+    for (id, name) in &entries {
+        writeln!(out, "    (0x{id:08x}, {name:?}),")?;
+    }
+    writeln!(out, "];")?;
+
+    fs::write(project_root().join("lib/libds3/src/codegen/map_names.rs"), out)?;
+
+    Ok(())
+}
@@ -1,11 +1,20 @@
 use anyhow::Result;
 
 mod aob_scans;
+mod boss_positions;
+mod hotkey_fields;
+mod map_names;
+mod param_fields;
 mod params;
 
 pub(crate) fn codegen() -> Result<()> {
     aob_scans::get_base_addresses();
+    aob_scans::codegen_signatures()?;
     params::codegen()?;
+    param_fields::codegen()?;
+    map_names::codegen()?;
+    boss_positions::codegen()?;
+    hotkey_fields::codegen()?;
 
     Ok(())
 }
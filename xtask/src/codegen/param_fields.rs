@@ -0,0 +1,67 @@
+use std::fmt::Write as _;
+use std::fs;
+
+use anyhow::Context;
+use regex::Regex;
+
+use crate::{project_root, Result};
+
+/// Generates the table of the param fields shown by the param editor, with
+/// their types, from the param structs in `param_data.rs`.
+///
+/// The fields are listed as the `ParamStruct` derive visits them: `u8` fields
+/// with bitflags become one `bool` per flag, and arrays are skipped.
+pub(crate) fn codegen() -> Result<()> {
+    let source = fs::read_to_string(project_root().join("lib/libds3/src/params/param_data.rs"))
+        .context("param_data.rs")?;
+
+    let struct_re = Regex::new(r"^pub struct (\w+) \{$")?;
+    let bitflag_re = Regex::new(r"^#\[bitflag\((\w+), \d+\)\]$")?;
+    let field_re = Regex::new(r"^pub (\w+): (\w+),$")?;
+
+    let mut params: Vec<(String, Vec<(String, String)>)> = Vec::new();
+    let mut flags = Vec::new();
+    for line in source.lines().map(str::trim) {
+        if let Some(c) = struct_re.captures(line) {
+            params.push((c[1].to_string(), Vec::new()));
+        } else if let Some(c) = bitflag_re.captures(line) {
+            flags.push(c[1].to_string());
+        } else if let (Some(c), Some((_, fields))) = (field_re.captures(line), params.last_mut()) {
+            match &c[2] {
+                "u8" if !flags.is_empty() => {
+                    fields.extend(flags.drain(..).map(|flag| (flag, "bool".to_string())));
+                },
+                ty => fields.push((c[1].to_string(), ty.to_string())),
+            }
+            flags.clear();
+        } else if line.starts_with("pub ") {
+            // Arrays.
+            flags.clear();
+        }
+    }
+    params.sort();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated by `cargo xtask codegen` from `lib/libds3/src/params/param_data.rs`."
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "/// Names and types of the fields of each param, in the order they are visited."
+    )?;
+    writeln!(out, "pub static PARAM_FIELDS: [(&str, &[(&str, &str)]); {}] = [", params.len())?;
+    for (param, fields) in &params {
+        writeln!(out, "    ({param:?}, &[")?;
+        for (name, ty) in fields {
+            writeln!(out, "        ({name:?}, {ty:?}),")?;
+        }
+        writeln!(out, "    ]),")?;
+    }
+    writeln!(out, "];")?;
+
+    fs::write(project_root().join("lib/libds3/src/codegen/param_fields.rs"), out)?;
+
+    Ok(())
+}